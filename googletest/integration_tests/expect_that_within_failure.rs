@@ -0,0 +1,63 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::clock::FakeClock;
+    use googletest::poll::PollPolicy;
+    use googletest::prelude::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+    use tokio::time::sleep;
+
+    #[googletest::test(config = googletest::config().poll_policy(PollPolicy::fixed(Duration::from_millis(5))))]
+    #[tokio::test]
+    async fn value_settling_before_deadline() -> Result<()> {
+        let counter = Arc::new(AtomicU32::new(0));
+        let worker_counter = counter.clone();
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(20)).await;
+            worker_counter.store(3, Ordering::SeqCst);
+        });
+
+        expect_that_within!(Duration::from_secs(5), counter.load(Ordering::SeqCst), eq(3));
+        Ok(())
+    }
+
+    #[googletest::test(config = googletest::config().poll_policy(PollPolicy::fixed(Duration::from_millis(5))))]
+    #[tokio::test]
+    async fn value_not_settling_before_deadline() -> Result<()> {
+        let counter = AtomicU32::new(1);
+
+        expect_that_within!(
+            Duration::from_millis(50),
+            async { counter.fetch_add(1, Ordering::SeqCst) }.await,
+            gt(1000)
+        );
+        Ok(())
+    }
+
+    #[googletest::test(config = googletest::config().poll_policy(PollPolicy::fixed(Duration::from_secs(1))))]
+    #[tokio::test]
+    async fn value_not_settling_before_deadline_of_fake_clock() -> Result<()> {
+        let _clock = FakeClock::install();
+
+        expect_that_within!(Duration::from_secs(3600), 1, gt(1000));
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use googletest::generators::{any, range, vec_of};
+    use googletest::prelude::*;
+
+    #[googletest::property_test(value = range(0..100))]
+    fn fails_for_large_values(value: i32) -> Result<()> {
+        verify_that!(value, lt(50))
+    }
+
+    #[googletest::property_test(value = range(0..100))]
+    fn fails_non_fatally_for_large_values(value: i32) -> Result<()> {
+        expect_that!(value, lt(50));
+        Ok(())
+    }
+
+    #[googletest::property_test(values = vec_of(any::<u8>(), 0..10))]
+    fn fails_for_vectors_with_large_elements(values: Vec<u8>) -> Result<()> {
+        verify_that!(values, each(le(100)))
+    }
+}
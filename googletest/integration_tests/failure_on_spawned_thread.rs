@@ -0,0 +1,42 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use googletest::test_scope::TestScope;
+
+    #[googletest::test]
+    fn fails_due_to_failure_on_spawned_thread() -> Result<()> {
+        let scope = TestScope::current();
+        std::thread::Builder::new()
+            .name("worker".into())
+            .spawn(move || {
+                let _guard = scope.enter();
+                expect_that!(1, eq(2));
+            })?
+            .join()
+            .unwrap();
+        Ok(())
+    }
+
+    #[googletest::test]
+    fn passes_with_passing_assertion_on_spawned_thread() -> Result<()> {
+        googletest::test_scope::spawn(|| expect_that!(1, eq(1))).join().unwrap();
+        Ok(())
+    }
+}
@@ -168,6 +168,15 @@ mod tests {
         )
     }
 
+    #[test]
+    fn should_not_print_failures_beyond_configured_maximum() -> Result<()> {
+        let output = run_external_process_in_tests_directory("failures_beyond_max_failures")?;
+
+        expect_that!(output, contains_substring("Actual: 2, which isn't equal to 3"));
+        expect_that!(output, not(contains_substring("Actual: 2, which isn't equal to 4")));
+        verify_that!(output, contains_substring("...and 2 further failures which were not printed"))
+    }
+
     #[test]
     fn should_abort_after_first_failure() -> Result<()> {
         let output = run_external_process_in_tests_directory("first_failure_aborts")?;
@@ -368,7 +377,7 @@ mod tests {
         verify_that!(
             output,
             contains_substring(indoc! {"
-                a_submodule :: A_STRUCT_IN_SUBMODULE.eq_predicate_as_method(a, b) was false with
+                a_submodule::A_STRUCT_IN_SUBMODULE.eq_predicate_as_method(a, b) was false with
                   a = 1,
                   b = 2
                 "})
@@ -504,6 +513,274 @@ mod tests {
         verify_that!(output, contains_substring("Expected: is equal to 4"))
     }
 
+    #[test]
+    fn expect_that_within_reports_last_value_and_wait_on_timeout() -> Result<()> {
+        let output = run_external_process_in_tests_directory("expect_that_within_failure")?;
+
+        expect_that!(output, contains_substring("tests::value_settling_before_deadline ... ok"));
+        verify_that!(
+            output,
+            contains_regex(indoc! {r"
+                Value of: async \{ counter.fetch_add\(1, Ordering::SeqCst\) \}.await
+                Expected: is greater than 1000
+                Actual: \d+, which is less than or equal to 1000
+                  at .*
+                Still not matching after waiting \d+(\.\d+)?ms \(deadline 50ms\)
+            "})
+        )
+    }
+
+    #[test]
+    fn expect_that_within_follows_fake_clock() -> Result<()> {
+        let output = run_external_process_in_tests_directory("expect_that_within_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring("Still not matching after waiting 3600s (deadline 3600s)")
+        )
+    }
+
+    #[test]
+    fn failure_on_spawned_thread_fails_owning_test() -> Result<()> {
+        let output = run_external_process_in_tests_directory("failure_on_spawned_thread")?;
+
+        expect_that!(
+            output,
+            contains_substring("tests::passes_with_passing_assertion_on_spawned_thread ... ok")
+        );
+        verify_that!(
+            output,
+            contains_regex(indoc! {r"
+                tests::fails_due_to_failure_on_spawned_thread ... FAILED
+                (.|\n)*Expected: is equal to 2
+                Actual: 1, which isn't equal to 2
+                  at .*
+                  on thread worker
+            "})
+        )
+    }
+
+    #[test]
+    fn panic_is_reported_as_failure_when_configured() -> Result<()> {
+        let output = run_external_process_in_tests_directory("panic_caught_as_failure")?;
+
+        verify_that!(
+            output,
+            contains_regex(indoc! {r"
+                tests::reports_panic_as_failure ... FAILED
+                (.|\n)*---- tests::reports_panic_as_failure stdout ----
+                The test panicked with the message:
+                  unexpected state
+                  at googletest/integration_tests/panic_caught_as_failure.rs:\d+:\d+
+            "})
+        )
+    }
+
+    #[test]
+    fn shared_fixture_is_shared_by_concurrent_users_and_torn_down_after_last() -> Result<()> {
+        let std::process::Output { stdout, .. } =
+            run_external_process("shared_fixture_torn_down_after_last_user")
+                .args(["--test-threads=2", "--nocapture", "concurrent_user"])
+                .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        verify_that!(output, contains_substring("Set the resource up").times(eq(1)))?;
+        verify_that!(output, contains_substring("Tore the resource down").times(eq(1)))?;
+        verify_that!(
+            output,
+            contains_regex(r"Tore the resource down(.|\n)*test result: ok\. 2 passed")
+        )
+    }
+
+    #[test]
+    fn shared_fixture_is_set_up_anew_for_later_user() -> Result<()> {
+        let std::process::Output { stdout, .. } =
+            run_external_process("shared_fixture_torn_down_after_last_user")
+                .args(["--test-threads=1", "--nocapture", "sequential_user"])
+                .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        verify_that!(
+            output,
+            contains_regex(concat!(
+                r"Set the resource up\nTore the resource down(.|\n)*",
+                r"Set the resource up\nTore the resource down(.|\n)*test result: ok\. 2 passed"
+            ))
+        )
+    }
+
+    #[test]
+    fn skips_tests_whose_prerequisite_failed() -> Result<()> {
+        let xml_output_file =
+            std::env::temp_dir().join("googletest_rust_test_results_prerequisite.xml");
+        let _ = std::fs::remove_file(&xml_output_file);
+        let std::process::Output { stdout, .. } =
+            run_external_process("test_with_failing_prerequisite")
+                .arg("--show-output")
+                .env("XML_OUTPUT_FILE", &xml_output_file)
+                .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        verify_that!(
+            output,
+            contains_substring(
+                "SKIPPED tests::inserts_user: the prerequisite test sets_up_schema did not pass\n"
+            )
+            .times(eq(1))
+        )?;
+        verify_that!(
+            output,
+            contains_substring(
+                "SKIPPED tests::reads_user: the prerequisite test inserts_user was skipped: the \
+                 prerequisite test sets_up_schema did not pass\n"
+            )
+            .times(eq(1))
+        )?;
+        verify_that!(output, contains_substring("its dependencies form a cycle").times(eq(2)))?;
+        verify_that!(output, contains_substring("8 passed; 1 failed"))?;
+        let xml = std::fs::read_to_string(&xml_output_file)?;
+        verify_that!(xml, contains_substring("<skipped message=").times(eq(4)))?;
+        verify_that!(
+            xml,
+            contains_substring(
+                r#"<skipped message="the prerequisite test sets_up_schema did not pass" />"#
+            )
+        )
+    }
+
+    #[test]
+    fn fails_test_whose_prerequisite_belongs_to_other_shard() -> Result<()> {
+        // The test uses_table belongs to shard 1, its prerequisite creates_table
+        // to shard 0.
+        let std::process::Output { stdout, .. } =
+            run_external_process("test_with_failing_prerequisite")
+                .env("TEST_TOTAL_SHARDS", "2")
+                .env("TEST_SHARD_INDEX", "1")
+                .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        verify_that!(
+            output,
+            contains_substring(
+                "The prerequisite test creates_table is not available: it belongs to shard 0 of \
+                 2, not to shard 1"
+            )
+        )?;
+        verify_that!(output, contains_substring("test tests::uses_table ... FAILED"))
+    }
+
+    #[test]
+    fn runs_tests_without_dependencies_every_time_they_are_called() -> Result<()> {
+        let output = run_external_process_in_tests_directory("test_with_failing_prerequisite")?;
+
+        verify_that!(output, contains_substring("test tests::runs_every_time ... ok"))
+    }
+
+    #[test]
+    fn test_fails_when_exceeding_allocation_limit() -> Result<()> {
+        let output = run_external_process_in_tests_directory("test_exceeding_allocation_limit")?;
+
+        expect_that!(
+            output,
+            contains_substring(
+                "The peak allocation of the test was 2.0 KB (2048 bytes), exceeding its limit of \
+                 1.0 KB (1024 bytes)"
+            )
+        );
+        expect_that!(output, not(contains_substring("total allocation")));
+        verify_that!(output, contains_substring("1 passed; 1 failed"))
+    }
+
+    #[test]
+    fn property_test_reports_failing_input_and_seed() -> Result<()> {
+        let std::process::Output { stdout, .. } = run_external_process("failing_property_test")
+            .env("GOOGLETEST_PROPERTY_SEED", "42")
+            .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        expect_that!(
+            output,
+            contains_regex(r"Property failed for input #\d+ of 100:\n  value = [5-9]\d\n")
+        );
+        expect_that!(output, contains_substring("Reproduce with GOOGLETEST_PROPERTY_SEED=42."));
+        verify_that!(output, contains_substring("Expected: is less than 50").times(eq(2)))
+    }
+
+    #[test]
+    fn property_test_reports_shrunk_input() -> Result<()> {
+        let std::process::Output { stdout, .. } = run_external_process("failing_property_test")
+            .env("GOOGLETEST_PROPERTY_SEED", "42")
+            .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        expect_that!(
+            output,
+            contains_regex(r"Shrunk in \d+ steps? to the failing input:\n  value = 50\n")
+        );
+        expect_that!(
+            output,
+            contains_substring("Actual: 50, which is greater than or equal to 50")
+        );
+        verify_that!(
+            output,
+            contains_regex(r"Shrunk in \d+ steps? to the failing input:\n  values = \[101\]\n")
+        )
+    }
+
+    #[test]
+    fn property_test_runs_configured_number_of_cases() -> Result<()> {
+        let std::process::Output { stdout, .. } = run_external_process("failing_property_test")
+            .env("GOOGLETEST_PROPERTY_CASES", "1")
+            .env("GOOGLETEST_PROPERTY_SEED", "42")
+            .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        verify_that!(output, not(contains_substring("for input #2 ")))
+    }
+
+    #[test]
+    fn reports_ignored_assertion_result() -> Result<()> {
+        let std::process::Output { stdout, .. } =
+            run_external_process("ignored_assertion_result").arg("--nocapture").output()?;
+        let output = String::from_utf8(stdout)?;
+
+        verify_that!(output, contains_substring("Warning: assertion result ignored at"))?;
+        verify_that!(output, contains_regex(r"ignored at .*ignored_assertion_result.rs:\d+\n"))?;
+        verify_that!(output, contains_substring("assertion result ignored").times(eq(1)))
+    }
+
+    #[test]
+    fn stray_thread_fails_test_when_configured() -> Result<()> {
+        let std::process::Output { stdout, .. } =
+            run_external_process("stray_threads").arg("--test-threads=1").output()?;
+        let output = String::from_utf8(stdout)?;
+
+        expect_that!(
+            output,
+            contains_regex(
+                r"1 thread started during the test is still running at its end: \d+ \(failing-stray\)"
+            )
+        );
+        expect_that!(output, contains_substring("fails_with_stray_thread ... FAILED"));
+        verify_that!(output, contains_substring("passes_when_thread_is_joined ... ok"))
+    }
+
+    #[test]
+    fn stray_thread_causes_warning_when_configured() -> Result<()> {
+        // The output of passing tests is only shown with --nocapture.
+        let std::process::Output { stdout, .. } = run_external_process("stray_threads")
+            .args(["--test-threads=1", "--nocapture"])
+            .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        verify_that!(
+            output,
+            contains_regex(
+                r"warns_about_stray_thread ... Warning: 1 thread .* \d+ \(warning-stray\)\nok\n"
+            )
+        )
+    }
+
     #[test]
     fn test_can_return_anyhow_generated_error() -> Result<()> {
         let output = run_external_process_in_tests_directory("test_returning_anyhow_error")?;
@@ -511,6 +788,109 @@ mod tests {
         verify_that!(output, contains_substring("Error from Anyhow"))
     }
 
+    #[test]
+    fn writes_xml_report_when_requested_by_bazel() -> Result<()> {
+        let xml_output_file = std::env::temp_dir().join("googletest_rust_test_results.xml");
+        run_external_process("two_non_fatal_failures")
+            .env("XML_OUTPUT_FILE", &xml_output_file)
+            .output()?;
+
+        let xml = std::fs::read_to_string(&xml_output_file)?;
+        expect_that!(xml, contains_substring(r#"<testcase name="more_than_one_failure""#));
+        expect_that!(xml, contains_substring(r#"assertions="2""#));
+        verify_that!(xml, contains_substring(r#"<failure message="Value of: value""#).times(eq(2)))
+    }
+
+    #[test]
+    fn writes_panicking_test_to_xml_report() -> Result<()> {
+        let xml_output_file = std::env::temp_dir().join("googletest_rust_panic_results.xml");
+        run_external_process("panic_not_caught")
+            .env("XML_OUTPUT_FILE", &xml_output_file)
+            .output()?;
+
+        let xml = std::fs::read_to_string(&xml_output_file)?;
+        verify_that!(
+            xml,
+            contains_substring(
+                r#"<failure message="The test panicked with the message:" type=""><![CDATA[The test panicked with the message:
+  unexpected state
+  at googletest/integration_tests/panic_not_caught.rs:"#
+            )
+        )
+    }
+
+    #[test]
+    fn reports_tests_of_other_shards_as_skipped() -> Result<()> {
+        let mut reports = vec![];
+        for shard_index in ["0", "1"] {
+            let xml_output_file = std::env::temp_dir()
+                .join(format!("googletest_rust_test_results_shard_{shard_index}.xml"));
+            let _ = std::fs::remove_file(&xml_output_file);
+            run_external_process("two_non_fatal_failures")
+                .env("XML_OUTPUT_FILE", &xml_output_file)
+                .env("TEST_TOTAL_SHARDS", "2")
+                .env("TEST_SHARD_INDEX", shard_index)
+                .output()?;
+            reports.push(std::fs::read_to_string(&xml_output_file)?);
+        }
+
+        verify_that!(
+            reports,
+            unordered_elements_are![
+                contains_substring(r#"<failure message="Value of: value""#),
+                contains_substring(r#"<skipped message="it belongs to shard "#),
+            ]
+        )
+    }
+
+    #[test]
+    fn prints_gtest_console_output_when_requested() -> Result<()> {
+        let std::process::Output { stdout, .. } = run_external_process("two_non_fatal_failures")
+            .env("GOOGLETEST_CONSOLE_OUTPUT", "gtest")
+            .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        expect_that!(output, contains_substring("[ RUN      ] tests.more_than_one_failure\n"));
+        verify_that!(
+            output,
+            contains_regex(r"\[  FAILED  \] tests.more_than_one_failure \(\d+ ms\)")
+        )
+    }
+
+    #[test]
+    fn prints_each_failure_once_in_gtest_console_output() -> Result<()> {
+        let std::process::Output { stdout, .. } = run_external_process("two_non_fatal_failures")
+            .env("GOOGLETEST_CONSOLE_OUTPUT", "gtest")
+            .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        expect_that!(output.matches("Expected: is equal to 3").count(), eq(1));
+        verify_that!(output.matches("Expected: is equal to 4").count(), eq(1))
+    }
+
+    #[test]
+    fn prints_brief_gtest_console_output_without_time_when_requested() -> Result<()> {
+        let std::process::Output { stdout, .. } = run_external_process("two_non_fatal_failures")
+            .env("GOOGLETEST_CONSOLE_OUTPUT", "gtest")
+            .env("GTEST_BRIEF", "1")
+            .env("GTEST_PRINT_TIME", "0")
+            .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        expect_that!(output, not(contains_substring("[ RUN      ]")));
+        verify_that!(output, contains_substring("[  FAILED  ] tests.more_than_one_failure\n"))
+    }
+
+    #[test]
+    fn reads_maximum_number_of_failures_from_environment() -> Result<()> {
+        let std::process::Output { stdout, .. } = run_external_process("two_non_fatal_failures")
+            .env("GOOGLETEST_MAX_FAILURES", "1")
+            .output()?;
+        let output = String::from_utf8(stdout)?;
+
+        verify_that!(output, contains_substring("...and 1 further failures which were not printed"))
+    }
+
     fn run_external_process_in_tests_directory(name: &'static str) -> Result<String> {
         let mut command = run_external_process(name);
         let std::process::Output { stdout, .. } = command.output()?;
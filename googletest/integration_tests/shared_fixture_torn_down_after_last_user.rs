@@ -0,0 +1,79 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::fixture::SharedFixture;
+    use googletest::prelude::*;
+    use std::sync::{Condvar, Mutex};
+
+    struct Resource;
+
+    impl Drop for Resource {
+        fn drop(&mut self) {
+            println!("Tore the resource down");
+        }
+    }
+
+    static RESOURCE: SharedFixture<Resource> = SharedFixture::new(|| {
+        println!("Set the resource up");
+        Resource
+    });
+
+    static ARRIVED: Mutex<usize> = Mutex::new(0);
+    static ALL_ARRIVED: Condvar = Condvar::new();
+
+    /// Waits until both concurrent users use the resource at the same time.
+    fn wait_for_other_concurrent_user() {
+        let mut arrived = ARRIVED.lock().unwrap();
+        *arrived += 1;
+        ALL_ARRIVED.notify_all();
+        while *arrived < 2 {
+            arrived = ALL_ARRIVED.wait(arrived).unwrap();
+        }
+    }
+
+    #[googletest::test]
+    fn concurrent_user_1() -> Result<()> {
+        drop(RESOURCE.get());
+        wait_for_other_concurrent_user();
+
+        verify_that!(RESOURCE.is_set_up(), eq(true))
+    }
+
+    #[googletest::test]
+    fn concurrent_user_2() -> Result<()> {
+        drop(RESOURCE.get());
+        wait_for_other_concurrent_user();
+
+        verify_that!(RESOURCE.is_set_up(), eq(true))
+    }
+
+    #[googletest::test]
+    fn sequential_user_1() -> Result<()> {
+        drop(RESOURCE.get());
+
+        verify_that!(RESOURCE.is_set_up(), eq(true))
+    }
+
+    #[googletest::test]
+    fn sequential_user_2() -> Result<()> {
+        drop(RESOURCE.get());
+
+        verify_that!(RESOURCE.is_set_up(), eq(true))
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use googletest::config::StrayThreads;
+    use googletest::prelude::*;
+    use std::time::Duration;
+
+    fn spawn_sleeping_thread(name: &str) {
+        std::thread::Builder::new()
+            .name(name.into())
+            .spawn(|| std::thread::sleep(Duration::from_secs(5)))
+            .unwrap();
+    }
+
+    #[googletest::test(config = googletest::config().stray_threads(StrayThreads::Fail))]
+    fn fails_with_stray_thread() -> Result<()> {
+        spawn_sleeping_thread("failing-stray");
+        Ok(())
+    }
+
+    #[googletest::test(config = googletest::config().stray_threads(StrayThreads::Warn))]
+    fn warns_about_stray_thread() -> Result<()> {
+        spawn_sleeping_thread("warning-stray");
+        Ok(())
+    }
+
+    #[googletest::test(config = googletest::config().stray_threads(StrayThreads::Fail))]
+    fn passes_when_thread_is_joined() -> Result<()> {
+        std::thread::spawn(|| {}).join().unwrap();
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::allocation::TrackingAllocator;
+    use googletest::prelude::*;
+
+    #[global_allocator]
+    static ALLOCATOR: TrackingAllocator = TrackingAllocator::system();
+
+    #[googletest::test(max_alloc = "1KB", max_total_alloc = "4KB")]
+    fn allocates_too_much() -> Result<()> {
+        let values = vec![0u8; 2048];
+        verify_that!(values.len(), eq(2048))
+    }
+
+    #[googletest::test(max_alloc = "1KB")]
+    fn stays_within_limit() -> Result<()> {
+        let values = vec![0u8; 512];
+        verify_that!(values.len(), eq(512))
+    }
+}
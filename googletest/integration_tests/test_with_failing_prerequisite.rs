@@ -0,0 +1,76 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static TABLES_CREATED: AtomicUsize = AtomicUsize::new(0);
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    #[googletest::test]
+    fn creates_table() -> Result<()> {
+        TABLES_CREATED.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    #[googletest::test(depends_on = "creates_table")]
+    fn uses_table() -> Result<()> {
+        verify_that!(TABLES_CREATED.load(Ordering::SeqCst), ge(1))
+    }
+
+    #[googletest::test]
+    fn sets_up_schema() -> Result<()> {
+        verify_that!(1, eq(2))
+    }
+
+    #[googletest::test(depends_on = "sets_up_schema")]
+    fn inserts_user() -> Result<()> {
+        Ok(())
+    }
+
+    #[googletest::test(depends_on = "inserts_user")]
+    fn reads_user() -> Result<()> {
+        Ok(())
+    }
+
+    #[googletest::test]
+    fn counts_calls() -> Result<()> {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    #[googletest::test]
+    fn runs_every_time() -> Result<()> {
+        let calls = CALLS.load(Ordering::SeqCst);
+        for _ in 0..2 {
+            verify_that!(std::thread::spawn(counts_calls).join(), ok(ok(eq(()))))?;
+        }
+        verify_that!(CALLS.load(Ordering::SeqCst), ge(calls + 2))
+    }
+
+    #[googletest::test(depends_on = "second_in_cycle")]
+    fn first_in_cycle() -> Result<()> {
+        Ok(())
+    }
+
+    #[googletest::test(depends_on = "first_in_cycle")]
+    fn second_in_cycle() -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Measuring and limiting the memory which tests allocate.
+//!
+//! Allocations can only be observed through the global allocator, so a test
+//! binary which wants to measure them installs a [`TrackingAllocator`], which
+//! wraps another allocator:
+//!
+//! ```
+//! use googletest::allocation::TrackingAllocator;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: TrackingAllocator = TrackingAllocator::system();
+//! ```
+//!
+//! A test annotated with `#[googletest::test]` can then limit the memory it
+//! allocates with the arguments `max_alloc`, which limits the peak number of
+//! bytes allocated and not yet freed at any one time, and `max_total_alloc`,
+//! which limits the number of bytes allocated in total:
+//!
+//! ```ignore
+//! #[googletest::test(max_alloc = "256MB", max_total_alloc = "1GB")]
+//! fn parses_large_document() -> Result<()> {
+//!     ...
+//! }
+//! ```
+//!
+//! The limits are given in bytes, optionally followed by one of the units
+//! `KB`, `MB`, and `GB`, each 1024 times the previous. The test fails once it
+//! has ended if it exceeded a limit.
+//!
+//! Only allocations on the thread running the test are counted, including
+//! those of async tests running on a single-threaded runtime, but not those
+//! of threads which the test starts.
+
+use crate::internal::test_outcome::TestAssertionFailure;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether a [`TrackingAllocator`] is the global allocator.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static USAGE: Cell<Usage> = const { Cell::new(Usage::NONE) };
+}
+
+/// The memory allocated on a thread since the tracking started.
+#[derive(Clone, Copy)]
+struct Usage {
+    tracking: bool,
+    current: usize,
+    peak: usize,
+    total: usize,
+}
+
+impl Usage {
+    const NONE: Usage = Usage { tracking: false, current: 0, peak: 0, total: 0 };
+}
+
+/// A global allocator which counts the memory allocated on threads which
+/// currently measure their allocations.
+///
+/// See the [module documentation][self].
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+impl TrackingAllocator<System> {
+    /// Creates a `TrackingAllocator` wrapping the [`System`] allocator.
+    pub const fn system() -> Self {
+        Self { inner: System }
+    }
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Creates a `TrackingAllocator` wrapping `inner`.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = self.inner.alloc(layout);
+        if !pointer.is_null() {
+            record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = self.inner.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        self.inner.dealloc(pointer, layout);
+        record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = self.inner.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+        new_pointer
+    }
+}
+
+fn record_allocation(size: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    // The thread-local storage may already have been torn down.
+    let _ = USAGE.try_with(|usage| {
+        let mut current = usage.get();
+        if current.tracking {
+            current.current += size;
+            current.peak = current.peak.max(current.current);
+            current.total += size;
+            usage.set(current);
+        }
+    });
+}
+
+fn record_deallocation(size: usize) {
+    let _ = USAGE.try_with(|usage| {
+        let mut current = usage.get();
+        if current.tracking {
+            // Memory allocated before the tracking started may be freed.
+            current.current = current.current.saturating_sub(size);
+            usage.set(current);
+        }
+    });
+}
+
+/// The memory allocated while running some code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// The largest number of bytes allocated and not yet freed at any one
+    /// time.
+    pub peak: usize,
+    /// The number of bytes allocated in total.
+    pub total: usize,
+}
+
+/// Runs `f` and returns its result together with the memory it allocated on
+/// the current thread.
+///
+/// Returns `None` in place of the statistics if no [`TrackingAllocator`] is
+/// the global allocator.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocationStats>) {
+    let measurement = Measurement::start();
+    let result = f();
+    (result, measurement.finish())
+}
+
+/// The measurement of the allocations on the current thread, which stops
+/// when this is finished or dropped.
+struct Measurement {
+    outer: Usage,
+    finished: bool,
+}
+
+impl Measurement {
+    fn start() -> Self {
+        let outer = USAGE.with(|usage| usage.replace(Usage { tracking: true, ..Usage::NONE }));
+        Self { outer, finished: false }
+    }
+
+    fn finish(mut self) -> Option<AllocationStats> {
+        self.finished = true;
+        let inner = self.stop();
+        INSTALLED
+            .load(Ordering::Relaxed)
+            .then_some(AllocationStats { peak: inner.peak, total: inner.total })
+    }
+
+    /// Restores the measurement which was in progress when this one started,
+    /// counting the allocations of this one towards it, and returns the usage
+    /// measured by this one.
+    fn stop(&self) -> Usage {
+        let outer = self.outer;
+        USAGE.with(|usage| {
+            let inner = usage.get();
+            usage.set(if outer.tracking {
+                Usage {
+                    tracking: true,
+                    current: outer.current + inner.current,
+                    peak: outer.peak.max(outer.current + inner.peak),
+                    total: outer.total + inner.total,
+                }
+            } else {
+                outer
+            });
+            inner
+        })
+    }
+}
+
+impl Drop for Measurement {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.stop();
+        }
+    }
+}
+
+/// The limits on the memory which a test may allocate.
+///
+/// This is intended only for use by the attribute macro
+/// `#[googletest::test]`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub struct AllocationLimit {
+    pub peak: Option<usize>,
+    pub total: Option<usize>,
+}
+
+impl AllocationLimit {
+    /// Starts measuring the allocations of the current test.
+    #[doc(hidden)]
+    pub fn start(self) -> LimitedAllocations {
+        LimitedAllocations { limit: self, measurement: Measurement::start() }
+    }
+}
+
+/// The measurement of the allocations of a test which has limits on them.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub struct LimitedAllocations {
+    limit: AllocationLimit,
+    measurement: Measurement,
+}
+
+impl LimitedAllocations {
+    /// Records a failure of the current test if it exceeded one of its
+    /// limits.
+    #[doc(hidden)]
+    pub fn check(self) {
+        let Some(stats) = self.measurement.finish() else {
+            TestAssertionFailure::create(
+                "The allocation limits of this test require a \
+                 googletest::allocation::TrackingAllocator as the global allocator."
+                    .into(),
+            )
+            .log();
+            return;
+        };
+        for (kind, used, limit) in [
+            ("peak allocation", stats.peak, self.limit.peak),
+            ("total allocation", stats.total, self.limit.total),
+        ] {
+            if let Some(limit) = limit.filter(|limit| used > *limit) {
+                TestAssertionFailure::create(format!(
+                    "The {kind} of the test was {}, exceeding its limit of {}",
+                    describe_size(used),
+                    describe_size(limit),
+                ))
+                .log();
+            }
+        }
+    }
+}
+
+/// Describes a number of bytes in the largest unit in which it is at least
+/// one.
+fn describe_size(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = None;
+    for next_unit in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = Some(next_unit);
+    }
+    match unit {
+        Some(unit) => format!("{value:.1} {unit} ({bytes} bytes)"),
+        None => format!("{bytes} bytes"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe_size, measure, AllocationStats, TrackingAllocator};
+    use crate::prelude::*;
+
+    #[global_allocator]
+    static ALLOCATOR: TrackingAllocator = TrackingAllocator::system();
+
+    #[test]
+    fn measures_peak_and_total_allocation() -> Result<()> {
+        let ((), stats) = measure(|| {
+            drop(Vec::<u8>::with_capacity(1000));
+            drop(Vec::<u8>::with_capacity(500));
+        });
+
+        verify_that!(stats, some(eq(AllocationStats { peak: 1000, total: 1500 })))
+    }
+
+    #[test]
+    fn counts_nested_measurement_towards_outer_measurement() -> Result<()> {
+        let (inner_stats, outer_stats) = measure(|| {
+            let kept = Vec::<u8>::with_capacity(100);
+            let ((), inner_stats) = measure(|| drop(Vec::<u8>::with_capacity(1000)));
+            drop(kept);
+            inner_stats
+        });
+
+        expect_that!(inner_stats, some(eq(AllocationStats { peak: 1000, total: 1000 })));
+        verify_that!(outer_stats, some(eq(AllocationStats { peak: 1100, total: 1100 })))
+    }
+
+    #[test]
+    fn describes_sizes_in_largest_unit() -> Result<()> {
+        expect_that!(describe_size(512), eq("512 bytes"));
+        expect_that!(describe_size(1536), eq("1.5 KB (1536 bytes)"));
+        verify_that!(describe_size(3 << 30), eq("3.0 GB (3221225472 bytes)"))
+    }
+}
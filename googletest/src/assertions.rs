@@ -28,7 +28,10 @@
 ///
 /// **Invoking this macro by itself does not cause a test failure to be recorded
 /// or output.** The resulting `Result` must be handled as described above to
-/// cause the test to be recorded as a failure.
+/// cause the test to be recorded as a failure. In debug builds, a failing
+/// result which is dropped without being handled is reported at the end of a
+/// test annotated with [`#[googletest::test]`][crate::test] with a warning
+/// "assertion result ignored at file:line".
 ///
 /// Example:
 /// ```
@@ -49,16 +52,52 @@
 /// # verify_that!(should_fail(), err(displays_as(contains_substring("Expected: is equal to 123"))))
 /// #     .unwrap();
 /// ```
+///
+/// The second argument can also be a plain value, which is then compared with
+/// the actual value as if it were wrapped in [`eq`][crate::matchers::eq]. This
+/// works with any value which the actual value can be compared with through
+/// [`PartialEq`], as described for [`IntoMatcher`][crate::matcher::IntoMatcher]:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(42, 42)?;
+/// verify_that!("A string".to_string(), "A string")?;
+/// # Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// The actual value may also be a reference, or a reference to a reference,
+/// to the value which the matcher matches. The matcher is applied to the
+/// actual value itself whenever it can match that type:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let values = vec![1, 2, 3];
+/// verify_that!(values.iter().next().unwrap(), eq(1))?;
+/// verify_that!(&values, elements_are![eq(1), eq(2), eq(3)])?;
+/// # Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// The same holds for [`expect_that!`][crate::expect_that] and
+/// [`assert_that!`][crate::assert_that].
 #[macro_export]
 macro_rules! verify_that {
-    ($actual:expr, $expected:expr) => {
-        $crate::assertions::internal::check_matcher(
+    ($actual:expr, $expected:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::assertions::internal::verify::*;
+        $crate::assertions::internal::Assertion::check(
             &$actual,
             $expected,
             stringify!($actual),
             $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
+            |assertion| (&&&assertion).verify(),
         )
-    };
+    }};
 }
 
 /// Asserts that the given predicate applied to the given arguments returns
@@ -142,6 +181,7 @@ macro_rules! verify_pred {
                 ),
             )
         } else {
+            $crate::internal::test_outcome::TestOutcome::record_assertion(true);
             Ok(())
         }
     };
@@ -205,15 +245,17 @@ macro_rules! fail {
         // must_use on expressions is still experimental.
         #[must_use = "The assertion result must be evaluated to affect the test result."]
         fn create_fail_result(message: String) -> $crate::Result<()> {
+            $crate::internal::test_outcome::TestOutcome::record_assertion(false);
+            let source_location = $crate::internal::source_location::SourceLocation::new(
+                file!(),
+                line!(),
+                column!(),
+            );
             Err($crate::internal::test_outcome::TestAssertionFailure::create(format!(
                 "{}\n{}",
-                message,
-                $crate::internal::source_location::SourceLocation::new(
-                    file!(),
-                    line!(),
-                    column!(),
-                ),
-            )))
+                message, source_location,
+            ))
+            .track_if_ignored(source_location))
         }
         create_fail_result(format!($($message),*))
     }};
@@ -264,7 +306,8 @@ macro_rules! assert_pred {
 ///
 /// This can only be invoked inside tests with the
 /// [`google_test`][crate::google_test] attribute. The assertion must occur in
-/// the same thread as that running the test itself.
+/// the same thread as that running the test itself, or in a thread which has
+/// entered its [`TestScope`][crate::test_scope::TestScope].
 ///
 /// Invoking this macro is equivalent to using
 /// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure) as follows:
@@ -288,7 +331,8 @@ macro_rules! expect_that {
 ///
 /// This can only be invoked inside tests with the
 /// [`google_test`][crate::google_test] attribute. The assertion must occur in
-/// the same thread as that running the test itself.
+/// the same thread as that running the test itself, or in a thread which has
+/// entered its [`TestScope`][crate::test_scope::TestScope].
 ///
 /// Invoking this macro is equivalent to using
 /// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure) as follows:
@@ -304,37 +348,383 @@ macro_rules! expect_pred {
     }};
 }
 
+/// Matches the value of `actual` against the given matcher repeatedly until it
+/// matches or the deadline passes, marking the test as failed but continuing
+/// execution if it never matches.
+///
+/// This is a *non-fatal* assertion for state which settles asynchronously. It
+/// can only be used inside `async` tests running on a Tokio runtime, since it
+/// waits with [`tokio::time::sleep`] between attempts. The expression `actual`
+/// is evaluated anew on each attempt and may itself contain `.await`:
+///
+/// ```ignore
+/// #[googletest::test]
+/// #[tokio::test]
+/// async fn worker_processes_messages() -> Result<()> {
+///     let worker = Worker::start();
+///     worker.send("hello").await;
+///     expect_that_within!(Duration::from_secs(1), worker.processed().await, eq(1));
+///     Ok(())
+/// }
+/// ```
+///
+/// The waits between attempts follow the [`PollPolicy`] in effect, with its
+/// last wait repeated until the deadline, and are cut short at the deadline.
+/// Like other time-based assertions, the deadline follows [`clock::now`], so
+/// with a [`FakeClock`] installed the assertion does not actually wait.
+/// If the value still does not match at the deadline, the failure shows the
+/// last observed value and how long the assertion waited.
+///
+/// This macro is only available with the feature `tokio`.
+///
+/// [`PollPolicy`]: crate::poll::PollPolicy
+/// [`clock::now`]: crate::clock::now
+/// [`FakeClock`]: crate::clock::FakeClock
+#[cfg(feature = "tokio")]
+#[macro_export]
+macro_rules! expect_that_within {
+    ($deadline:expr, $actual:expr, $expected:expr) => {{
+        use $crate::GoogleTestSupport;
+        #[allow(unused_imports)]
+        use $crate::assertions::internal::verify::*;
+        let expected = $expected;
+        let mut deadline = $crate::assertions::internal::Deadline::new($deadline);
+        loop {
+            let actual = $actual;
+            let assertion = $crate::assertions::internal::Assertion::new(
+                &actual,
+                &expected,
+                stringify!($actual),
+                $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
+            );
+            if (&&&&assertion).matches() || !deadline.wait().await {
+                (&&&&assertion)
+                    .verify()
+                    .map_err(|failure| deadline.annotate(failure))
+                    .and_log_failure();
+                break;
+            }
+        }
+    }};
+}
+
+/// Checks that the given block evaluates a number of assertions matched by the
+/// given matcher.
+///
+/// This guarantees that assertions which are only reached indirectly, such as
+/// inside a callback, actually ran:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// fn for_each_record(records: &[i32], callback: impl Fn(&i32)) {
+///     records.iter().for_each(callback)
+/// }
+///
+/// verify_assertion_count!(eq(2), {
+///     for_each_record(&[1, 2], |record| expect_that!(record, gt(&0)));
+/// })?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # fn for_each_record(records: &[i32], callback: impl Fn(&i32)) {
+/// #     records.iter().for_each(callback)
+/// # }
+/// verify_assertion_count!(gt(0), {
+///     for_each_record(&[], |record| expect_that!(record, gt(&0)));
+/// })?; // Fails: the callback never ran
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// Every assertion evaluated on the current thread is counted, regardless of
+/// whether it passed or failed. Assertions evaluated on other threads are not
+/// counted.
+///
+/// On success, this evaluates to `Ok` with the value of the block. As with
+/// [`verify_that`], the result must be handled, e.g., with the question mark
+/// operator or [`and_log_failure`](crate::GoogleTestSupport::and_log_failure).
+#[macro_export]
+macro_rules! verify_assertion_count {
+    ($expected:expr, $body:block) => {{
+        let assertions_before =
+            $crate::internal::test_outcome::TestOutcome::assertion_counts().total();
+        let value = $body;
+        let assertions = $crate::internal::test_outcome::TestOutcome::assertion_counts().total()
+            - assertions_before;
+        $crate::assertions::internal::check_matcher(
+            &assertions,
+            $expected,
+            "number of assertions evaluated by the block",
+            $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
+        )
+        .map(|()| value)
+    }};
+}
+
 /// Functions for use only by the procedural macros in this module.
 ///
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
 pub mod internal {
     use crate::{
-        internal::{source_location::SourceLocation, test_outcome::TestAssertionFailure},
-        matcher::{create_assertion_failure, Matcher, MatcherResult},
+        internal::{
+            source_location::SourceLocation,
+            test_outcome::{TestAssertionFailure, TestOutcome},
+        },
+        matcher::{IntoMatcher, Matcher, MatcherResult, TestDebug, create_assertion_failure},
     };
-    use std::fmt::Debug;
 
     /// Checks whether the matcher `expected` matches the value `actual`, adding
     /// a test failure report if it does not match.
     ///
+    /// The argument `expected` can also be a plain value, which is then
+    /// compared with `actual` for equality as described for [`IntoMatcher`].
+    ///
     /// Returns `Ok(())` if the value matches and `Err(())` if it does not
     /// match.
     ///
     /// **For internal use only. API stablility is not guaranteed!**
     #[must_use = "The assertion result must be evaluated to affect the test result."]
-    pub fn check_matcher<T: Debug + ?Sized>(
+    pub fn check_matcher<T: TestDebug + ?Sized, KindT, ExpectedT>(
         actual: &T,
-        expected: impl Matcher<ActualT = T>,
+        expected: ExpectedT,
+        actual_expr: &'static str,
+        source_location: SourceLocation,
+    ) -> Result<(), TestAssertionFailure>
+    where
+        for<'a> &'a ExpectedT: IntoMatcher<T, KindT>,
+    {
+        check(actual, &(&expected).into_matcher(), actual_expr, source_location)
+    }
+
+    fn check<T: TestDebug + ?Sized>(
+        actual: &T,
+        matcher: &impl Matcher<ActualT = T>,
         actual_expr: &'static str,
         source_location: SourceLocation,
     ) -> Result<(), TestAssertionFailure> {
-        match expected.matches(actual) {
-            MatcherResult::Matches => Ok(()),
+        match matcher.matches(actual) {
+            MatcherResult::Matches => {
+                TestOutcome::record_assertion(true);
+                Ok(())
+            }
             MatcherResult::DoesNotMatch => {
-                Err(create_assertion_failure(&expected, actual, actual_expr, source_location))
+                TestOutcome::record_assertion(false);
+                Err(create_assertion_failure(matcher, actual, actual_expr, source_location)
+                    .track_if_ignored(source_location))
+            }
+        }
+    }
+
+    /// An assertion made with [`crate::verify_that`] and the macros built on
+    /// it, which checks `expected` against `actual`.
+    ///
+    /// The value `actual` may be a reference, or a reference to a reference,
+    /// to the value which `expected` matches. The traits in [`verify`] check
+    /// the assertion against the first of `actual`, `*actual`, and `**actual`
+    /// which `expected` accepts, in that order. To that end, the macros call
+    /// their method `verify` on `&&&&Assertion`, so that method resolution
+    /// tries the implementation for the most references first.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    pub struct Assertion<'a, ActualT: ?Sized, ExpectedT> {
+        actual: &'a ActualT,
+        expected: &'a ExpectedT,
+        actual_expr: &'static str,
+        source_location: SourceLocation,
+    }
+
+    impl<'a, ActualT: ?Sized, ExpectedT> Assertion<'a, ActualT, ExpectedT> {
+        pub fn new(
+            actual: &'a ActualT,
+            expected: &'a ExpectedT,
+            actual_expr: &'static str,
+            source_location: SourceLocation,
+        ) -> Self {
+            Self { actual, expected, actual_expr, source_location }
+        }
+
+        /// Calls `verify` with the assertion that `expected` matches `actual`.
+        ///
+        /// This takes `expected` by value, like [`check_matcher`], so that it
+        /// is dropped before the values `actual` borrows even when the
+        /// assertion is the last expression of a function.
+        pub fn check<R>(
+            actual: &'a ActualT,
+            expected: ExpectedT,
+            actual_expr: &'static str,
+            source_location: SourceLocation,
+            verify: impl FnOnce(&Assertion<'_, ActualT, ExpectedT>) -> R,
+        ) -> R {
+            verify(&Assertion::new(actual, &expected, actual_expr, source_location))
+        }
+    }
+
+    /// The traits through which the macros check an [`Assertion`], one for
+    /// each number of references removed from the actual value.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    pub mod verify {
+        use super::{check, Assertion};
+        use crate::{
+            internal::test_outcome::TestAssertionFailure,
+            matcher::{IntoMatcher, Matcher, TestDebug},
+        };
+
+        /// Checks the assertion against the actual value itself.
+        pub trait VerifyActual<KindT> {
+            fn matches(self) -> bool;
+            fn verify(self) -> Result<(), TestAssertionFailure>;
+        }
+
+        impl<'a, T: TestDebug + ?Sized, E, K> VerifyActual<K> for &&&&Assertion<'a, T, E>
+        where
+            for<'b> &'b E: IntoMatcher<T, K>,
+        {
+            fn matches(self) -> bool {
+                self.expected.into_matcher().matches(self.actual).into()
+            }
+
+            fn verify(self) -> Result<(), TestAssertionFailure> {
+                let matcher = self.expected.into_matcher();
+                check(self.actual, &matcher, self.actual_expr, self.source_location)
             }
         }
+
+        /// Checks the assertion against the value to which the actual value
+        /// refers.
+        pub trait VerifyDereferencedActual<KindT> {
+            fn matches(self) -> bool;
+            fn verify(self) -> Result<(), TestAssertionFailure>;
+        }
+
+        impl<'a, T: TestDebug + ?Sized, E, K> VerifyDereferencedActual<K> for &&&Assertion<'a, &T, E>
+        where
+            for<'b> &'b E: IntoMatcher<T, K>,
+        {
+            fn matches(self) -> bool {
+                self.expected.into_matcher().matches(*self.actual).into()
+            }
+
+            fn verify(self) -> Result<(), TestAssertionFailure> {
+                let matcher = self.expected.into_matcher();
+                check(*self.actual, &matcher, self.actual_expr, self.source_location)
+            }
+        }
+
+        /// Checks the assertion against the value to which the actual value
+        /// refers through two references.
+        pub trait VerifyTwiceDereferencedActual<KindT> {
+            fn matches(self) -> bool;
+            fn verify(self) -> Result<(), TestAssertionFailure>;
+        }
+
+        impl<'a, T: TestDebug + ?Sized, E, K> VerifyTwiceDereferencedActual<K> for &&Assertion<'a, &&T, E>
+        where
+            for<'b> &'b E: IntoMatcher<T, K>,
+        {
+            fn matches(self) -> bool {
+                self.expected.into_matcher().matches(**self.actual).into()
+            }
+
+            fn verify(self) -> Result<(), TestAssertionFailure> {
+                let matcher = self.expected.into_matcher();
+                check(**self.actual, &matcher, self.actual_expr, self.source_location)
+            }
+        }
+
+        /// Checks the assertion against the actual value itself when the
+        /// expected value matches none of the values the other traits try.
+        ///
+        /// Unlike the other traits, this states its requirement on the
+        /// methods, which method resolution does not consider. It is thus
+        /// chosen whenever the others are not, so that the compiler reports
+        /// that the expected value cannot match the actual value as written.
+        pub trait VerifyActualFallback<T: ?Sized, E> {
+            fn matches<K>(self) -> bool
+            where
+                for<'b> &'b E: IntoMatcher<T, K>;
+            fn verify<K>(self) -> Result<(), TestAssertionFailure>
+            where
+                for<'b> &'b E: IntoMatcher<T, K>;
+        }
+
+        impl<'a, T: TestDebug + ?Sized, E> VerifyActualFallback<T, E> for &Assertion<'a, T, E> {
+            fn matches<K>(self) -> bool
+            where
+                for<'b> &'b E: IntoMatcher<T, K>,
+            {
+                VerifyActual::matches(&&&self)
+            }
+
+            fn verify<K>(self) -> Result<(), TestAssertionFailure>
+            where
+                for<'b> &'b E: IntoMatcher<T, K>,
+            {
+                VerifyActual::verify(&&&self)
+            }
+        }
+    }
+
+    /// The deadline of an assertion made with [`crate::expect_that_within`].
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[cfg(feature = "tokio")]
+    pub struct Deadline {
+        start: std::time::Instant,
+        timeout: std::time::Duration,
+        delays: Box<dyn Iterator<Item = std::time::Duration> + Send>,
+        last_delay: std::time::Duration,
+    }
+
+    #[cfg(feature = "tokio")]
+    impl Deadline {
+        /// Starts waiting for at most `timeout`.
+        pub fn new(timeout: std::time::Duration) -> Self {
+            let policy = crate::poll::PollPolicy::current();
+            Self {
+                start: crate::clock::now(),
+                timeout,
+                delays: Box::new(policy.delays()),
+                last_delay: policy.delays().next().unwrap_or(timeout),
+            }
+        }
+
+        /// Waits until the next attempt, returning `false` without waiting if
+        /// the deadline has passed.
+        pub async fn wait(&mut self) -> bool {
+            let Some(remaining) = self.timeout.checked_sub(self.elapsed()) else {
+                return false;
+            };
+            if remaining.is_zero() {
+                return false;
+            }
+            if let Some(delay) = self.delays.next() {
+                self.last_delay = delay;
+            }
+            crate::clock::sleep_async(self.last_delay.min(remaining)).await;
+            true
+        }
+
+        /// Returns how long the assertion has waited according to
+        /// [`crate::clock::now`].
+        pub fn elapsed(&self) -> std::time::Duration {
+            crate::clock::now().saturating_duration_since(self.start)
+        }
+
+        /// Adds to `failure`, the failure of the last attempt, how long the
+        /// assertion waited.
+        pub fn annotate(&self, mut failure: TestAssertionFailure) -> TestAssertionFailure {
+            failure.custom_message = Some(format!(
+                "Still not matching after waiting {:?} (deadline {:?})",
+                self.elapsed(),
+                self.timeout
+            ));
+            failure
+        }
     }
 
     /// Constructs a `Result::Err(TestAssertionFailure)` for a predicate failure
@@ -349,11 +739,13 @@ pub mod internal {
         formatted_arguments: Vec<String>,
         source_location: SourceLocation,
     ) -> Result<(), TestAssertionFailure> {
+        TestOutcome::record_assertion(false);
         Err(TestAssertionFailure::create(format!(
             "{} was false with\n  {}\n{}",
             actual_expr,
             formatted_arguments.join(",\n  "),
             source_location,
-        )))
+        ))
+        .track_if_ignored(source_location))
     }
 }
@@ -0,0 +1,266 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A clock which tests can control.
+//!
+//! Time-based assertions and test helpers should read the time with [`now`]
+//! and wait with [`sleep`] rather than using [`std::time`] directly. By
+//! default these follow the real time. Once a test installs a [`FakeClock`],
+//! time stands still for the test until it advances it, and sleeping returns
+//! immediately after advancing the fake time accordingly. Time-based
+//! assertions then run instantly and deterministically:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::clock::{self, FakeClock};
+//! # use std::time::Duration;
+//! # fn should_pass() -> Result<()> {
+//! let clock = FakeClock::install();
+//! let start = clock::now();
+//!
+//! clock::sleep(Duration::from_secs(3600)); // Returns immediately.
+//! clock.advance(Duration::from_secs(1));
+//!
+//! verify_that!(clock::now() - start, eq(Duration::from_secs(3601)))
+//! # }
+//! # should_pass().unwrap();
+//! ```
+//!
+//! The fake clock applies only to the thread which installed it and to the
+//! threads which entered the [`TestScope`] of the test running there, such as
+//! those started with [`test_scope::spawn`]. Tests running concurrently on
+//! other threads keep following the real time.
+//!
+//! [`TestScope`]: crate::test_scope::TestScope
+//! [`test_scope::spawn`]: crate::test_scope::spawn
+
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+thread_local! {
+    /// The fake clocks which apply to the current thread, if a fake clock was
+    /// ever installed on it or it entered the scope of a test.
+    static FAKE_CLOCKS: RefCell<Option<FakeClocks>> = const { RefCell::new(None) };
+}
+
+/// The fake clocks installed for a test, of which the last one is in effect.
+///
+/// The thread running the test and the threads which entered its
+/// [`TestScope`][crate::test_scope::TestScope] share them.
+#[derive(Clone, Default)]
+pub(crate) struct FakeClocks(Arc<Mutex<Vec<Arc<FakeTime>>>>);
+
+impl FakeClocks {
+    fn lock(&self) -> MutexGuard<'_, Vec<Arc<FakeTime>>> {
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Returns the fake clocks which apply to the current thread.
+pub(crate) fn fake_clocks() -> FakeClocks {
+    FAKE_CLOCKS
+        .with(|fake_clocks| fake_clocks.borrow_mut().get_or_insert_with(Default::default).clone())
+}
+
+/// Applies `fake_clocks` to the current thread instead of its own.
+///
+/// Returns the fake clocks which applied to the thread before.
+pub(crate) fn use_fake_clocks(fake_clocks: Option<FakeClocks>) -> Option<FakeClocks> {
+    FAKE_CLOCKS.with(|current| current.replace(fake_clocks))
+}
+
+/// Returns the fake clock in effect on the current thread, if any.
+fn fake_time() -> Option<Arc<FakeTime>> {
+    FAKE_CLOCKS
+        .try_with(|fake_clocks| fake_clocks.borrow().as_ref()?.lock().last().cloned())
+        .ok()
+        .flatten()
+}
+
+/// The state of a fake clock, shared between the installed clock and the
+/// [`FakeClock`] handle.
+struct FakeTime {
+    start: Instant,
+    elapsed: Mutex<Duration>,
+}
+
+impl FakeTime {
+    fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) += duration;
+    }
+}
+
+/// Returns the current time according to the clock of the current test.
+///
+/// This is the real time unless a [`FakeClock`] is installed.
+pub fn now() -> Instant {
+    match fake_time() {
+        Some(fake_time) => fake_time.now(),
+        None => Instant::now(),
+    }
+}
+
+/// Waits for `duration` according to the clock of the current test.
+///
+/// When a [`FakeClock`] is installed, this advances the fake time by
+/// `duration` and returns immediately. Otherwise it puts the thread to sleep.
+pub fn sleep(duration: Duration) {
+    match fake_time() {
+        Some(fake_time) => fake_time.advance(duration),
+        None => std::thread::sleep(duration),
+    }
+}
+
+/// Waits asynchronously for `duration` according to the clock of the current
+/// test.
+///
+/// When a [`FakeClock`] is installed, this advances the fake time by
+/// `duration` and returns immediately. Otherwise it waits with
+/// [`tokio::time::sleep`].
+#[cfg(feature = "tokio")]
+pub(crate) async fn sleep_async(duration: Duration) {
+    match fake_time() {
+        Some(fake_time) => fake_time.advance(duration),
+        None => tokio::time::sleep(duration).await,
+    }
+}
+
+/// A handle to a fake clock installed for the current test.
+///
+/// The fake clock replaces the clock seen by [`now`] and [`sleep`] on the
+/// thread which installed it and in the scope of its test, as described in
+/// the [module documentation][self], until the handle is dropped. It starts
+/// at the time at which it is installed and only moves forward through
+/// [`FakeClock::advance`] and [`sleep`].
+#[must_use = "the fake clock is uninstalled as soon as the handle is dropped"]
+pub struct FakeClock {
+    fake_clocks: FakeClocks,
+    time: Arc<FakeTime>,
+}
+
+impl FakeClock {
+    /// Installs a fake clock for the current test.
+    ///
+    /// If a fake clock is already installed, the new one replaces it until the
+    /// returned handle is dropped.
+    pub fn install() -> Self {
+        let fake_clocks = fake_clocks();
+        let time = {
+            let mut installed = fake_clocks.lock();
+            let start = installed.last().map_or_else(Instant::now, |previous| previous.now());
+            let time = Arc::new(FakeTime { start, elapsed: Mutex::new(Duration::ZERO) });
+            installed.push(time.clone());
+            time
+        };
+        Self { fake_clocks, time }
+    }
+
+    /// Moves the fake time forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.time.advance(duration);
+    }
+
+    /// Returns how far the fake time has moved since the clock was installed.
+    pub fn elapsed(&self) -> Duration {
+        self.time.elapsed()
+    }
+}
+
+impl Drop for FakeClock {
+    fn drop(&mut self) {
+        self.fake_clocks.lock().retain(|time| !Arc::ptr_eq(time, &self.time));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{now, sleep, FakeClock};
+    use crate::prelude::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn fake_time_stands_still_until_advanced() -> Result<()> {
+        let clock = FakeClock::install();
+        let start = now();
+
+        verify_that!(now(), eq(start))?;
+        clock.advance(Duration::from_millis(5));
+        verify_that!(now() - start, eq(Duration::from_millis(5)))
+    }
+
+    #[test]
+    fn sleep_advances_fake_time_immediately() -> Result<()> {
+        let clock = FakeClock::install();
+        let real_start = Instant::now();
+
+        sleep(Duration::from_secs(60));
+
+        expect_that!(clock.elapsed(), eq(Duration::from_secs(60)));
+        verify_that!(real_start.elapsed(), lt(Duration::from_secs(60)))
+    }
+
+    #[test]
+    fn dropping_fake_clock_restores_previous_clock() -> Result<()> {
+        let outer = FakeClock::install();
+        {
+            let _inner = FakeClock::install();
+            sleep(Duration::from_secs(1));
+        }
+
+        sleep(Duration::from_secs(2));
+
+        verify_that!(outer.elapsed(), eq(Duration::from_secs(2)))
+    }
+
+    #[test]
+    fn fake_clock_applies_to_threads_in_test_scope() -> Result<()> {
+        let clock = FakeClock::install();
+        let start = now();
+
+        crate::test_scope::spawn(|| sleep(Duration::from_secs(60))).join().unwrap();
+
+        expect_that!(clock.elapsed(), eq(Duration::from_secs(60)));
+        verify_that!(
+            crate::test_scope::spawn(now).join().unwrap() - start,
+            eq(Duration::from_secs(60))
+        )
+    }
+
+    #[test]
+    fn fake_clock_does_not_apply_to_other_threads() -> Result<()> {
+        let clock = FakeClock::install();
+        let before = Instant::now();
+
+        std::thread::spawn(|| sleep(Duration::from_millis(1))).join().unwrap();
+
+        expect_that!(clock.elapsed(), eq(Duration::ZERO));
+        verify_that!(std::thread::spawn(now).join().unwrap(), ge(before))
+    }
+
+    #[test]
+    fn real_clock_is_used_without_fake_clock() -> Result<()> {
+        let before = Instant::now();
+
+        verify_that!(now(), ge(before))
+    }
+}
@@ -0,0 +1,351 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration of how test failures are rendered and reported.
+//!
+//! The configuration is scoped to the current thread, and hence to the
+//! currently running test. Obtain a builder with [`config()`], adjust it, and
+//! apply it either for the rest of a scope with [`Config::apply`]:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::config::DiffStyle;
+//! # fn should_pass() -> Result<()> {
+//! let _guard =
+//!     googletest::config().max_container_elements(10).diff_style(DiffStyle::ChangesOnly).apply();
+//! verify_that!((0..1000).collect::<Vec<_>>(), contains(eq(999)))
+//! # }
+//! # should_pass().unwrap();
+//! ```
+//!
+//! or for a whole test through the `config` argument of
+//! [`#[googletest::test]`][crate::test]:
+//!
+//! ```ignore
+//! #[googletest::test(config = googletest::config().max_failures(3))]
+//! fn checks_many_records() -> Result<()> {
+//!     ...
+//! }
+//! ```
+//!
+//! Until a test applies its own configuration, the defaults may be set
+//! through environment variables, which are read once per process:
+//!
+//!  * `GTEST_COLOR=yes` enables [`Config::color`];
+//!  * `GOOGLETEST_MAX_FAILURES` sets [`Config::max_failures`];
+//!  * `GOOGLETEST_MAX_CONTAINER_ELEMENTS` sets
+//!    [`Config::max_container_elements`];
+//!  * `GOOGLETEST_MAX_ACTUAL_LINES` and `GOOGLETEST_MAX_ACTUAL_BYTES` set
+//!    [`Config::max_actual_lines`] and [`Config::max_actual_bytes`];
+//!  * `GOOGLETEST_DEBUG_STYLE`, one of `pretty` or `compact`, sets
+//!    [`Config::debug_style`];
+//!  * `GOOGLETEST_MAX_DEBUG_DEPTH` and `GOOGLETEST_MAX_DEBUG_WIDTH` set
+//!    [`Config::max_debug_depth`] and [`Config::max_debug_width`];
+//!  * `GOOGLETEST_DIFF_STYLE`, one of `full`, `changes_only`, `unified`,
+//!    `unified:<context>`, `side_by_side`, or `disabled`, sets
+//!    [`Config::diff_style`], where `unified` shows 3 lines of context;
+//!  * `GOOGLETEST_STRAY_THREADS`, one of `ignore`, `warn`, or `fail`, sets
+//!    [`Config::stray_threads`];
+//!  * `GOOGLETEST_CATCH_PANICS=1` enables [`Config::catch_panics`].
+//!
+//! Invalid values are reported on standard error and otherwise ignored.
+
+use crate::internal::environment::environment;
+use crate::poll::PollPolicy;
+use std::cell::Cell;
+
+thread_local! {
+    static CURRENT_CONFIG: Cell<Config> = Cell::new(environment().config);
+}
+
+/// Returns a [`Config`] initialized with the configuration currently in
+/// effect, to be adjusted with its builder methods and then applied.
+pub fn config() -> Config {
+    CURRENT_CONFIG.with(Cell::get)
+}
+
+/// Settings controlling how test failures are rendered and reported.
+///
+/// Construct with [`config()`] and apply with [`Config::apply`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub(crate) color: bool,
+    pub(crate) max_container_elements: Option<usize>,
+    pub(crate) max_actual_lines: Option<usize>,
+    pub(crate) max_actual_bytes: usize,
+    pub(crate) debug_style: DebugStyle,
+    pub(crate) max_debug_depth: Option<usize>,
+    pub(crate) max_debug_width: Option<usize>,
+    pub(crate) diff_style: DiffStyle,
+    pub(crate) max_failures: Option<usize>,
+    pub(crate) stray_threads: StrayThreads,
+    pub(crate) poll_policy: PollPolicy,
+    pub(crate) catch_panics: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            color: false,
+            max_container_elements: None,
+            max_actual_lines: None,
+            max_actual_bytes: DEFAULT_MAX_ACTUAL_BYTES,
+            debug_style: DebugStyle::Pretty,
+            max_debug_depth: None,
+            max_debug_width: None,
+            diff_style: DiffStyle::Full,
+            max_failures: None,
+            stray_threads: StrayThreads::Ignore,
+            poll_policy: PollPolicy::default(),
+            catch_panics: false,
+        }
+    }
+}
+
+/// The default of [`Config::max_actual_bytes`], 64 KiB.
+pub const DEFAULT_MAX_ACTUAL_BYTES: usize = 64 * 1024;
+
+/// How values are rendered with their `Debug` output in failure messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DebugStyle {
+    /// Pretty-print values as `{:#?}` does, with every field and element on
+    /// a line of its own, unless [`Config::max_debug_width`] allows joining
+    /// them. This is the default.
+    Pretty,
+    /// Render values on a single line as `{:?}` does.
+    Compact,
+}
+
+/// How the differences between the `Debug` output of the actual and
+/// expected values are shown when an equality matcher fails.
+///
+/// In the styles which list the lines one below the other, the words which
+/// differ between a changed line of the actual and of the expected value are
+/// marked with `^` below each of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffStyle {
+    /// Show every line, marking those which differ with `+` (actual) and `-`
+    /// (expected). This is the default.
+    Full,
+    /// Show only the lines which differ, replacing each run of identical
+    /// lines with `...`.
+    ChangesOnly,
+    /// Show the lines which differ in hunks as in a unified diff, each
+    /// headed by `@@ -l,s +l,s @@` with the line ranges of the expected and
+    /// actual value it covers, and surrounded by up to `context` identical
+    /// lines.
+    Unified {
+        /// The number of identical lines shown before and after each change.
+        context: usize,
+    },
+    /// Show the actual value in a left and the expected value in a right
+    /// column, as `diff --side-by-side` does. The column between them marks
+    /// lines which differ with `|`, lines only in the actual value with `<`,
+    /// and lines only in the expected value with `>`.
+    SideBySide,
+    /// Show no diff at all.
+    Disabled,
+}
+
+/// What happens when threads started during a test are still running at its
+/// end.
+///
+/// Threads can only be detected on Linux. Since all threads of the process
+/// are considered, tests running in parallel may also be blamed for each
+/// other's threads, so the check is most reliable with `--test-threads=1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StrayThreads {
+    /// Do not check for stray threads. This is the default.
+    Ignore,
+    /// Print a warning listing the stray threads, without failing the test.
+    Warn,
+    /// Fail the test, listing the stray threads in the failure message.
+    Fail,
+}
+
+impl Config {
+    /// Sets whether failure output written to the console is colored with
+    /// ANSI escape sequences.
+    ///
+    /// This affects only the console output, not the failure messages
+    /// themselves nor structured reports.
+    pub fn color(self, color: bool) -> Self {
+        Self { color, ..self }
+    }
+
+    /// Limits the number of elements shown per container when rendering the
+    /// actual value of a failed assertion.
+    ///
+    /// Further elements are elided and replaced with a note stating how many
+    /// were omitted. This applies at every nesting level of the value's
+    /// pretty-printed `Debug` output.
+    pub fn max_container_elements(self, max_container_elements: usize) -> Self {
+        Self { max_container_elements: Some(max_container_elements), ..self }
+    }
+
+    /// Limits the number of lines shown when rendering the actual value of a
+    /// failed assertion.
+    ///
+    /// Further lines are replaced with a line stating how many were
+    /// truncated. This applies after [`Config::max_container_elements`].
+    /// There is no limit by default.
+    pub fn max_actual_lines(self, max_actual_lines: usize) -> Self {
+        Self { max_actual_lines: Some(max_actual_lines), ..self }
+    }
+
+    /// Limits the number of bytes shown when rendering the actual value of a
+    /// failed assertion, [`DEFAULT_MAX_ACTUAL_BYTES`] by default.
+    ///
+    /// Further bytes are replaced with a note stating how many were
+    /// truncated, so that huge values such as multi-megabyte strings do not
+    /// swamp the failure message. Raise the limit to see more, or pass
+    /// `usize::MAX` to show the whole value. This applies after
+    /// [`Config::max_container_elements`] and [`Config::max_actual_lines`].
+    pub fn max_actual_bytes(self, max_actual_bytes: usize) -> Self {
+        Self { max_actual_bytes, ..self }
+    }
+
+    /// Sets whether values are pretty-printed or rendered on a single line in
+    /// failure messages.
+    ///
+    /// This applies to the actual value of a failed assertion as well as to
+    /// the values which matchers quote in their descriptions and
+    /// explanations. A line-by-line diff is only shown for pretty-printed
+    /// values.
+    pub fn debug_style(self, debug_style: DebugStyle) -> Self {
+        Self { debug_style, ..self }
+    }
+
+    /// Limits the depth to which nested values are rendered in failure
+    /// messages.
+    ///
+    /// The contents of fields and elements nested deeper are replaced with
+    /// `...`, where the fields or elements of the value itself are at depth
+    /// 1. There is no limit by default.
+    pub fn max_debug_depth(self, max_debug_depth: usize) -> Self {
+        Self { max_debug_depth: Some(max_debug_depth), ..self }
+    }
+
+    /// Joins the fields or elements of each nested value onto a single line
+    /// when pretty-printing, if that line fits into `max_debug_width`
+    /// characters.
+    ///
+    /// This keeps short nested values, such as points or small lists, from
+    /// taking up many lines. By default, nested values are not joined.
+    pub fn max_debug_width(self, max_debug_width: usize) -> Self {
+        Self { max_debug_width: Some(max_debug_width), ..self }
+    }
+
+    /// Sets how equality matchers show the differences between the actual
+    /// and the expected value.
+    pub fn diff_style(self, diff_style: DiffStyle) -> Self {
+        Self { diff_style, ..self }
+    }
+
+    /// Limits the number of non-fatal failures printed per test.
+    ///
+    /// Failures beyond the limit still cause the test to fail, but are only
+    /// counted in a summary at the end of the test rather than printed.
+    pub fn max_failures(self, max_failures: usize) -> Self {
+        Self { max_failures: Some(max_failures), ..self }
+    }
+
+    /// Sets what happens when threads started during a test are still
+    /// running at its end.
+    ///
+    /// This takes effect only for a whole test, i.e., through the `config`
+    /// argument of [`#[googletest::test]`][crate::test] or the environment,
+    /// since the running threads are recorded when the test starts. Stray
+    /// threads get a short grace period to finish before they are reported.
+    pub fn stray_threads(self, stray_threads: StrayThreads) -> Self {
+        Self { stray_threads, ..self }
+    }
+
+    /// Sets the [`PollPolicy`] with which checks of asynchronously settling
+    /// state are retried, as returned by [`PollPolicy::current`].
+    pub fn poll_policy(self, poll_policy: PollPolicy) -> Self {
+        Self { poll_policy, ..self }
+    }
+
+    /// Sets whether a panic in a test is caught and reported as a failure of
+    /// the test, like a failed assertion.
+    ///
+    /// The failure states the panic message and location, and the backtrace
+    /// if backtraces are enabled through `RUST_BACKTRACE`. The test then
+    /// fails like any other, so that the panic also appears in structured
+    /// reports.
+    ///
+    /// This takes effect only for a whole test, i.e., through the `config`
+    /// argument of [`#[googletest::test]`][crate::test] or the environment.
+    pub fn catch_panics(self, catch_panics: bool) -> Self {
+        Self { catch_panics, ..self }
+    }
+
+    /// Makes this the configuration in effect on the current thread until the
+    /// returned guard is dropped, upon which the previous configuration is
+    /// restored.
+    pub fn apply(self) -> ConfigGuard {
+        ConfigGuard { previous: CURRENT_CONFIG.with(|current| current.replace(self)) }
+    }
+}
+
+/// Restores the previous configuration when dropped.
+///
+/// Returned by [`Config::apply`].
+#[must_use = "the configuration is reverted as soon as the guard is dropped"]
+pub struct ConfigGuard {
+    previous: Config,
+}
+
+impl Drop for ConfigGuard {
+    fn drop(&mut self) {
+        CURRENT_CONFIG.with(|current| current.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{config, DiffStyle};
+    use crate::internal::environment::environment;
+    use crate::prelude::*;
+
+    #[test]
+    fn config_starts_from_environment() -> Result<()> {
+        verify_that!(config(), eq(environment().config))
+    }
+
+    #[test]
+    fn apply_takes_effect_until_guard_is_dropped() -> Result<()> {
+        {
+            let _guard = config().max_failures(3).diff_style(DiffStyle::Disabled).apply();
+
+            verify_that!(config().max_failures, some(eq(3)))?;
+            verify_that!(config().diff_style, eq(DiffStyle::Disabled))?;
+        }
+
+        verify_that!(config(), eq(environment().config))
+    }
+
+    #[test]
+    fn nested_guards_restore_enclosing_configuration() -> Result<()> {
+        let _outer = config().color(true).apply();
+        {
+            let _inner = config().max_container_elements(5).apply();
+
+            verify_that!(config(), eq(environment().config.color(true).max_container_elements(5)))?;
+        }
+
+        verify_that!(config(), eq(environment().config.color(true)))
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Expensive resources shared between the tests which use them.
+//!
+//! A [`SharedFixture`] is declared as a `static` together with the function
+//! which sets the resource up. The first test to call
+//! [`get`][SharedFixture::get] sets it up, and all later calls share the same
+//! instance:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::fixture::SharedFixture;
+//! struct Database { url: String }
+//!
+//! impl Drop for Database {
+//!     fn drop(&mut self) {
+//!         // Stop the database server.
+//!     }
+//! }
+//!
+//! static DATABASE: SharedFixture<Database> =
+//!     SharedFixture::new(|| Database { url: "postgres://localhost:5432".into() });
+//!
+//! #[googletest::test]
+//! fn stores_users() -> Result<()> {
+//!     let database = DATABASE.get();
+//!     verify_that!(database.url, starts_with("postgres://"))
+//! }
+//! ```
+//!
+//! Only tests annotated with `#[googletest::test]` count as users of the
+//! resource, from their first call of `get` until they end. The resource is
+//! torn down by dropping it when the last of its users ends, and set up anew
+//! should a later test use it again. Since the Rust test harness does not
+//! announce which tests are still to run, tests which run one after another
+//! rather than in parallel each set the resource up for themselves.
+//!
+//! A use outside of such a test, for example in a plain `#[test]`, does not
+//! count. A resource which it sets up stays set up until a test which counts
+//! as a user ends. A thread which still holds the [`Arc`] returned by `get`
+//! when the resource is torn down keeps it alive until it drops the [`Arc`].
+
+use crate::internal::test_outcome::TestOutcome;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// A resource which is set up on first use and shared by the tests using it.
+///
+/// See the [module documentation][self].
+pub struct SharedFixture<T> {
+    set_up: fn() -> T,
+    /// Held while the resource is set up or torn down, so that a test does
+    /// not set it up anew before it has been torn down. Poisoned if setting
+    /// the resource up panicked.
+    lifecycle: Mutex<()>,
+    state: Mutex<State<T>>,
+}
+
+struct State<T> {
+    resource: Option<Arc<T>>,
+    /// The number of running tests which use the resource.
+    users: usize,
+}
+
+impl<T: Send + Sync + 'static> SharedFixture<T> {
+    /// Creates a fixture whose resource is set up by `set_up`.
+    ///
+    /// Nothing is set up until the fixture is first used.
+    pub const fn new(set_up: fn() -> T) -> Self {
+        Self {
+            set_up,
+            lifecycle: Mutex::new(()),
+            state: Mutex::new(State { resource: None, users: 0 }),
+        }
+    }
+
+    /// Returns the resource, setting it up if it is not set up, and records
+    /// the current test as one of its users.
+    ///
+    /// If another thread is setting the resource up at the same time, this
+    /// waits for it to finish and shares its result. Panics if setting the
+    /// resource up panicked, here or in another test.
+    pub fn get(&'static self) -> Arc<T> {
+        let _lifecycle = self
+            .lifecycle
+            .lock()
+            .unwrap_or_else(|_| panic!("Setting up the shared fixture panicked"));
+        let resource = self.lock().resource.clone();
+        // The resource is set up without holding the lock on the state, so
+        // that is_set_up does not block on a slow setup.
+        let resource = resource.unwrap_or_else(|| {
+            let resource = Arc::new((self.set_up)());
+            self.lock().resource = Some(resource.clone());
+            resource
+        });
+        if TestOutcome::use_fixture(self) {
+            self.lock().users += 1;
+        }
+        resource
+    }
+
+    /// Returns whether the resource is currently set up.
+    pub fn is_set_up(&self) -> bool {
+        self.lock().resource.is_some()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A shared fixture as seen by the tests using it.
+pub(crate) trait Fixture {
+    /// Records that a test using the fixture has ended, and tears the
+    /// resource down if that was its last user.
+    fn release(&self);
+}
+
+impl<T: Send + Sync + 'static> Fixture for SharedFixture<T> {
+    fn release(&self) {
+        let _lifecycle = self.lifecycle.lock().unwrap_or_else(PoisonError::into_inner);
+        let resource = {
+            let mut state = self.lock();
+            state.users -= 1;
+            if state.users > 0 {
+                return;
+            }
+            state.resource.take()
+        };
+        // The resource is torn down without holding the lock on the state, so
+        // that is_set_up does not block on a slow tear-down.
+        drop(resource);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SharedFixture;
+    use crate::prelude::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static SET_UPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Resource(usize);
+
+    fn set_up() -> Resource {
+        Resource(SET_UPS.fetch_add(1, Ordering::SeqCst))
+    }
+
+    #[test]
+    fn shares_resource_between_uses() -> Result<()> {
+        static FIXTURE: SharedFixture<Resource> = SharedFixture::new(set_up);
+
+        let first = FIXTURE.get();
+        let second = FIXTURE.get();
+
+        verify_that!(Arc::ptr_eq(&first, &second), eq(true))
+    }
+
+    #[test]
+    fn keeps_resource_set_up_outside_of_googletest_test() -> Result<()> {
+        static FIXTURE: SharedFixture<Resource> = SharedFixture::new(set_up);
+        let first = FIXTURE.get().0;
+
+        let second = FIXTURE.get().0;
+
+        expect_that!(FIXTURE.is_set_up(), eq(true));
+        verify_that!(second, eq(first))
+    }
+
+    #[test]
+    fn is_not_set_up_before_first_use() -> Result<()> {
+        static FIXTURE: SharedFixture<Resource> = SharedFixture::new(set_up);
+
+        verify_that!(FIXTURE.is_set_up(), eq(false))
+    }
+
+    #[test]
+    fn shares_resource_between_threads() -> Result<()> {
+        static FIXTURE: SharedFixture<Resource> = SharedFixture::new(set_up);
+
+        let [first, second] =
+            [(); 2].map(|_| std::thread::spawn(|| FIXTURE.get().0)).map(|t| t.join().unwrap());
+
+        verify_that!(first, eq(second))
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Custom rendering of actual values in failure messages.
+//!
+//! By default, the actual value of a failed assertion is shown with its
+//! pretty-printed `Debug` output. A formatter registered for a type with
+//! [`register_formatter`] rewrites that output for values of the type, for
+//! example to lay out a configuration more compactly:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::formatters::register_formatter;
+//! #[derive(Debug, PartialEq)]
+//! struct Config {
+//!     name: &'static str,
+//!     retries: u32,
+//! }
+//!
+//! register_formatter::<Config>(|debug| debug.split_whitespace().collect::<Vec<_>>().join(" "));
+//!
+//! let config = Config { name: "staging", retries: 3 };
+//! let result = verify_that!(config, eq(Config { name: "production", retries: 3 }));
+//!
+//! # verify_that!(
+//! #     result,
+//! #     err(displays_as(contains_substring(
+//! #         r#"Actual: Config { name: "staging", retries: 3, },"#
+//! #     )))
+//! # )
+//! # .unwrap();
+//! ```
+//!
+//! Since the type of an actual value need not be `'static`, and hence cannot
+//! be recovered at runtime, a formatter does not receive the value itself. It
+//! receives the `Debug` output of the value, which is always rendered first,
+//! and returns the text to show instead. This has some limitations:
+//!
+//!  * A formatter applies only to values whose type is exactly `T`, or a
+//!    reference to `T` up to two levels deep. A formatter registered for a
+//!    type with lifetimes, such as `Borrowed<'static>`, applies to that type
+//!    with any lifetimes. Values of type `T` nested in other values, such as the
+//!    elements of a `Vec<T>` or the fields of a struct, keep their `Debug`
+//!    output.
+//!  * It applies only to the line `Actual:` of failure messages and to the
+//!    values which [`eq`][crate::matchers::eq] describes and diffs. Other
+//!    matchers quote expected values or parts of the actual value in their
+//!    own way, with their `Debug` output.
+//!
+//! Formatters are therefore not suited to redact secrets. A type holding a
+//! secret should implement `Debug` so that it omits the secret, or be wrapped
+//! with [`opaque`][crate::opaque::opaque] when it is matched.
+//!
+//! Formatters are registered for the whole process, typically in the setup of
+//! the tests which need them.
+
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A formatter registered for a type.
+type Formatter = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// The registered formatters, each under the [`TypeId`] of every type whose
+/// values it formats.
+static FORMATTERS: Mutex<Vec<(TypeId, Formatter)>> = Mutex::new(Vec::new());
+
+/// Registers `formatter` to render actual values of type `T` in failure
+/// messages, replacing any formatter previously registered for `T`.
+///
+/// The formatter receives the `Debug` output of the value, pretty-printed
+/// (`{:#?}`) except in the description of [`eq`][crate::matchers::eq], and
+/// returns the text to show instead. It is also used for values of type `T`
+/// behind up to two references, but not for values of type `T` nested in
+/// other values. See the [module documentation][self] for where formatters
+/// apply.
+pub fn register_formatter<T: ?Sized + 'static>(
+    formatter: impl Fn(&str) -> String + Send + Sync + 'static,
+) {
+    let formatter: Formatter = Arc::new(formatter);
+    let type_ids = formatted_types::<T>();
+    let mut formatters = FORMATTERS.lock().unwrap_or_else(PoisonError::into_inner);
+    formatters.retain(|(type_id, _)| !type_ids.contains(type_id));
+    formatters.extend(type_ids.into_iter().map(|type_id| (type_id, formatter.clone())));
+}
+
+/// Removes the formatter registered for type `T`, if any, so that its values
+/// are shown with their `Debug` output again.
+pub fn unregister_formatter<T: ?Sized + 'static>() {
+    let type_ids = formatted_types::<T>();
+    FORMATTERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .retain(|(type_id, _)| !type_ids.contains(type_id));
+}
+
+/// Renders `debug`, the `Debug` output of a value of type `T`, with the
+/// formatter registered for `T`, if any.
+pub(crate) fn format_value<T: ?Sized>(debug: String) -> String {
+    let type_id = non_static_type_id::<T>();
+    let formatter = FORMATTERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .find(|(registered, _)| *registered == type_id)
+        .map(|(_, formatter)| formatter.clone());
+    // The formatter runs outside the lock, so that it may itself fail an
+    // assertion without deadlocking.
+    match formatter {
+        Some(formatter) => formatter(&debug),
+        None => debug,
+    }
+}
+
+/// The types whose values the formatter registered for `T` formats: `T`
+/// itself and the references to it, up to two levels deep.
+fn formatted_types<T: ?Sized + 'static>() -> [TypeId; 7] {
+    [
+        TypeId::of::<T>(),
+        TypeId::of::<&T>(),
+        TypeId::of::<&mut T>(),
+        TypeId::of::<&&T>(),
+        TypeId::of::<&&mut T>(),
+        TypeId::of::<&mut &T>(),
+        TypeId::of::<&mut &mut T>(),
+    ]
+}
+
+/// Returns the [`TypeId`] of `T`, which need not be `'static`.
+///
+/// Since lifetimes are erased before code is generated, this is the
+/// [`TypeId`] of `T` with all of its lifetimes replaced by `'static`.
+pub(crate) fn non_static_type_id<T: ?Sized>() -> TypeId {
+    trait NonStaticAny {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+
+    impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+
+    let phantom = PhantomData::<T>;
+    // SAFETY: The lifetime only enables the call of `type_id`, which does not
+    // use the value at all. The resulting `TypeId` does not depend on
+    // lifetimes.
+    let phantom = unsafe {
+        std::mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom)
+    };
+    NonStaticAny::type_id(phantom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_value, register_formatter, unregister_formatter};
+    use crate::prelude::*;
+
+    #[test]
+    fn leaves_value_without_formatter_unchanged() -> Result<()> {
+        struct Unregistered;
+
+        verify_that!(format_value::<Unregistered>("Unregistered".into()), eq("Unregistered"))
+    }
+
+    #[test]
+    fn formats_value_with_registered_formatter() -> Result<()> {
+        struct Registered;
+        register_formatter::<Registered>(|debug| format!("<{debug}>"));
+
+        verify_that!(format_value::<Registered>("Registered".into()), eq("<Registered>"))
+    }
+
+    #[test]
+    fn formats_referenced_value_with_registered_formatter() -> Result<()> {
+        struct Referenced;
+        register_formatter::<Referenced>(|_| "formatted".into());
+
+        expect_that!(format_value::<&Referenced>("Referenced".into()), eq("formatted"));
+        verify_that!(format_value::<&mut &Referenced>("Referenced".into()), eq("formatted"))
+    }
+
+    #[test]
+    fn later_registration_replaces_formatter() -> Result<()> {
+        struct Replaced;
+        register_formatter::<Replaced>(|_| "first".into());
+        register_formatter::<Replaced>(|_| "second".into());
+
+        verify_that!(format_value::<Replaced>("Replaced".into()), eq("second"))
+    }
+
+    #[test]
+    fn unregistered_formatter_is_no_longer_used() -> Result<()> {
+        struct Unregistered;
+        register_formatter::<Unregistered>(|_| "formatted".into());
+        unregister_formatter::<Unregistered>();
+
+        verify_that!(format_value::<Unregistered>("Unregistered".into()), eq("Unregistered"))
+    }
+
+    #[test]
+    fn distinguishes_types_with_the_same_name() -> Result<()> {
+        let formatted = {
+            struct Shadowed;
+            register_formatter::<Shadowed>(|_| "formatted".into());
+            format_value::<Shadowed>("Shadowed".into())
+        };
+        struct Shadowed;
+
+        expect_that!(formatted, eq("formatted"));
+        verify_that!(format_value::<Shadowed>("Shadowed".into()), eq("Shadowed"))
+    }
+
+    #[test]
+    fn formats_value_of_type_with_lifetime() -> Result<()> {
+        struct Borrowing<'a>(&'a str);
+        fn format_as_value_of<T>(_: &T, debug: &str) -> String {
+            format_value::<T>(debug.into())
+        }
+        register_formatter::<Borrowing<'static>>(|_| "formatted".into());
+        let text = String::from("text");
+        let borrowing = Borrowing(&text);
+
+        verify_that!(format_as_value_of(&borrowing, borrowing.0), eq("formatted"))
+    }
+
+    #[test]
+    fn failure_message_shows_formatted_actual_value() -> Result<()> {
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+        register_formatter::<Point>(|debug| debug.split_whitespace().collect::<Vec<_>>().join(" "));
+
+        let result = verify_that!(Point { x: 1, y: 2 }, eq(Point { x: 2, y: 1 }));
+
+        verify_that!(result, err(displays_as(contains_substring("Actual: Point { x: 1, y: 2, },"))))
+    }
+}
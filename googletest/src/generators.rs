@@ -0,0 +1,666 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generators of random test inputs for
+//! [`#[googletest::property_test]`][crate::property_test].
+//!
+//! Each parameter of a property test is paired with a [`Generator`] which
+//! produces its values:
+//!
+//! ```ignore
+//! use googletest::generators::{any, range, vec_of};
+//!
+//! #[googletest::property_test(values = vec_of(any::<i32>(), 0..10), n = range(1..5))]
+//! fn rotating_preserves_length(mut values: Vec<i32>, n: usize) -> Result<()> {
+//!     let len = values.len();
+//!     values.rotate_left(n.min(len));
+//!     verify_that!(values, size(eq(len)))
+//! }
+//! ```
+//!
+//! When a property test fails, its input is shrunk: the generators propose
+//! simpler variants of the input, such as smaller numbers or shorter vectors,
+//! and the simplest variant which still fails is reported alongside the
+//! original input.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::{self, RangeInclusive};
+
+/// A source of random values to be turned into test inputs.
+///
+/// The sequence of values is fully determined by the seed, so that a failing
+/// property test can be reproduced.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator of random values starting from the given seed.
+    pub fn from_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random 64-bit value.
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64, see https://prng.di.unimi.it/splitmix64.c.
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random value between 0 and `max`, inclusive.
+    pub fn up_to(&mut self, max: u128) -> u128 {
+        let value = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+        match max.checked_add(1) {
+            Some(bound) => value % bound,
+            None => value,
+        }
+    }
+
+    /// Returns `true` with a probability of one in `n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64() % n == 0
+    }
+}
+
+/// Produces random values of a property test parameter.
+///
+/// See the [module documentation][self] for how generators are used.
+pub trait Generator {
+    /// The type of the values produced.
+    type Value: Debug + Clone;
+
+    /// Produces a new value from the randomness in `rng`.
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Returns simpler variants of `value`, the most promising first, which
+    /// this generator could also have produced.
+    ///
+    /// A failing input is shrunk by repeatedly replacing it with the first of
+    /// its variants which still fails. The default implementation returns no
+    /// variants, so that inputs are not shrunk.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let _ = value;
+        vec![]
+    }
+}
+
+/// Types which can produce arbitrary values of themselves, for use with
+/// [`any`].
+pub trait Arbitrary: Debug + Clone {
+    /// Produces an arbitrary value from the randomness in `rng`.
+    fn arbitrary(rng: &mut Rng) -> Self;
+
+    /// Returns simpler variants of `self`, as described in
+    /// [`Generator::shrink`].
+    fn shrink(&self) -> Vec<Self> {
+        vec![]
+    }
+}
+
+/// Generates arbitrary values of the type `T`.
+///
+/// Integers are drawn from their whole range, with their minimum, zero, and
+/// maximum produced more often than other values. Strings and vectors are
+/// at most 16 elements long.
+///
+/// ```
+/// # use googletest::generators::{any, Generator, Rng};
+/// let value: u8 = any::<u8>().generate(&mut Rng::from_seed(1));
+/// ```
+pub fn any<T: Arbitrary>() -> Any<T> {
+    Any { phantom: Default::default() }
+}
+
+/// A generator of arbitrary values of `T`.
+///
+/// See [`any`].
+pub struct Any<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: Arbitrary> Generator for Any<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        T::arbitrary(rng)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        value.shrink()
+    }
+}
+
+/// Generates values within `bounds`, which may be a [`Range`](ops::Range) or a
+/// [`RangeInclusive`] of integers or `char`s.
+///
+/// Panics when the range is empty.
+///
+/// ```
+/// # use googletest::generators::{range, Generator, Rng};
+/// let value = range(1..=6).generate(&mut Rng::from_seed(1));
+/// assert!((1..=6).contains(&value));
+/// ```
+pub fn range<T: Sample, R: Into<InclusiveBounds<T>>>(bounds: R) -> Range<T> {
+    let InclusiveBounds { low, high } = bounds.into();
+    assert!(T::offset(low, high).is_some(), "range requires a non-empty range");
+    Range { low, high }
+}
+
+/// A generator of values within a range.
+///
+/// See [`range`].
+pub struct Range<T> {
+    low: T,
+    high: T,
+}
+
+impl<T: Sample> Generator for Range<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        let span = T::offset(self.low, self.high).unwrap();
+        loop {
+            if let Some(value) = T::from_offset(self.low, rng.up_to(span)) {
+                return value;
+            }
+        }
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        // Shrink towards the lower end of the range.
+        let Some(offset) = T::offset(self.low, *value) else {
+            return vec![];
+        };
+        let mut offsets = vec![0];
+        let mut delta = offset / 2;
+        while delta > 0 {
+            offsets.push(offset - delta);
+            delta /= 2;
+        }
+        offsets
+            .into_iter()
+            .filter(|o| *o < offset)
+            .filter_map(|o| T::from_offset(self.low, o))
+            .collect()
+    }
+}
+
+/// The inclusive bounds of a range passed to [`range`].
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub struct InclusiveBounds<T> {
+    low: T,
+    high: T,
+}
+
+impl<T: Sample> From<RangeInclusive<T>> for InclusiveBounds<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (low, high) = range.into_inner();
+        Self { low, high }
+    }
+}
+
+impl<T: Sample> From<ops::Range<T>> for InclusiveBounds<T> {
+    fn from(range: ops::Range<T>) -> Self {
+        let high = T::predecessor(range.end).expect("range requires a non-empty range");
+        Self { low: range.start, high }
+    }
+}
+
+/// Types whose values can be drawn uniformly from a range with [`range`].
+pub trait Sample: Arbitrary + Copy {
+    /// Returns the distance from `low` to `high`, or `None` if `high` is
+    /// below `low`.
+    fn offset(low: Self, high: Self) -> Option<u128>;
+
+    /// Returns the value at the distance `offset` above `low`, or `None` if
+    /// there is no valid value there.
+    fn from_offset(low: Self, offset: u128) -> Option<Self>;
+
+    /// Returns the value immediately below `self`, if any.
+    fn predecessor(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty => $u:ty),* $(,)?) => {
+        $(
+            impl Arbitrary for $t {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    if rng.one_in(8) {
+                        [<$t>::MIN, 0, <$t>::MAX][rng.up_to(2) as usize]
+                    } else {
+                        rng.up_to(<$u>::MAX as u128) as $t
+                    }
+                }
+
+                fn shrink(&self) -> Vec<Self> {
+                    // Shrink towards zero.
+                    if *self == 0 {
+                        return vec![];
+                    }
+                    let mut candidates = vec![0];
+                    let mut delta = *self / 2;
+                    while delta != 0 {
+                        candidates.push(*self - delta);
+                        delta /= 2;
+                    }
+                    candidates
+                }
+            }
+
+            impl Sample for $t {
+                fn offset(low: Self, high: Self) -> Option<u128> {
+                    (low <= high).then(|| (high as $u).wrapping_sub(low as $u) as u128)
+                }
+
+                fn from_offset(low: Self, offset: u128) -> Option<Self> {
+                    Some((low as $u).wrapping_add(offset as $u) as $t)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer! {
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+}
+
+impl Arbitrary for char {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        if rng.one_in(2) {
+            range(' '..='~').generate(rng)
+        } else {
+            range('\0'..=char::MAX).generate(rng)
+        }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if *self == 'a' { vec![] } else { vec!['a'] }
+    }
+}
+
+impl Sample for char {
+    fn offset(low: Self, high: Self) -> Option<u128> {
+        (low <= high).then(|| (high as u32 - low as u32) as u128)
+    }
+
+    fn from_offset(low: Self, offset: u128) -> Option<Self> {
+        char::from_u32(low as u32 + offset as u32)
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        (0..self as u32).rev().find_map(char::from_u32)
+    }
+}
+
+impl Arbitrary for bool {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        rng.one_in(2)
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if *self { vec![false] } else { vec![] }
+    }
+}
+
+impl Arbitrary for f64 {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        if rng.one_in(8) {
+            [0.0, -0.0, 1.0, -1.0, f64::MIN_POSITIVE, f64::MAX, f64::MIN][rng.up_to(6) as usize]
+        } else {
+            // A value of random sign and magnitude, avoiding infinities and NaN.
+            let magnitude = rng.up_to(600) as i32 - 300;
+            let mantissa = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+            let value = mantissa * 10f64.powi(magnitude);
+            if rng.one_in(2) { -value } else { value }
+        }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = vec![0.0, self.trunc()];
+        candidates.dedup();
+        candidates.retain(|c| c != self);
+        candidates
+    }
+}
+
+impl Arbitrary for f32 {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        let value = f64::arbitrary(rng) as f32;
+        if value.is_finite() { value } else { 0.0 }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        (*self as f64).shrink().into_iter().map(|c| c as f32).collect()
+    }
+}
+
+impl Arbitrary for String {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        string_of(any::<char>(), 0..17).generate(rng)
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        string_of(any::<char>(), 0..17).shrink(self)
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Vec<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        vec_of(any::<T>(), 0..17).generate(rng)
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        vec_of(any::<T>(), 0..17).shrink(self)
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Option<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        if rng.one_in(4) { None } else { Some(T::arbitrary(rng)) }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            Some(value) => {
+                std::iter::once(None).chain(value.shrink().into_iter().map(Some)).collect()
+            }
+            None => vec![],
+        }
+    }
+}
+
+/// Generates vectors whose elements are produced by `element` and whose
+/// lengths lie within `len`.
+///
+/// ```
+/// # use googletest::generators::{any, vec_of, Generator, Rng};
+/// let values = vec_of(any::<u8>(), 1..4).generate(&mut Rng::from_seed(1));
+/// assert!((1..4).contains(&values.len()));
+/// ```
+pub fn vec_of<G: Generator, R: Into<InclusiveBounds<usize>>>(element: G, len: R) -> VecOf<G> {
+    VecOf { element, len: range(len) }
+}
+
+/// A generator of vectors.
+///
+/// See [`vec_of`].
+pub struct VecOf<G> {
+    element: G,
+    len: Range<usize>,
+}
+
+impl<G: Generator> Generator for VecOf<G> {
+    type Value = Vec<G::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = self.len.generate(rng);
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        // First remove elements, then shrink the remaining ones.
+        let min_len = self.len.low;
+        let mut candidates = vec![];
+        if value.len() > min_len {
+            candidates.push(value[..min_len].to_vec());
+            if value.len() / 2 > min_len {
+                candidates.push(value[..value.len() / 2].to_vec());
+            }
+            for index in 0..value.len() {
+                let mut candidate = value.clone();
+                candidate.remove(index);
+                candidates.push(candidate);
+            }
+        }
+        for (index, element) in value.iter().enumerate() {
+            for shrunk in self.element.shrink(element) {
+                let mut candidate = value.clone();
+                candidate[index] = shrunk;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+/// Generates strings whose characters are produced by `char` and whose
+/// lengths in characters lie within `len`.
+///
+/// ```
+/// # use googletest::generators::{range, string_of, Generator, Rng};
+/// let word = string_of(range('a'..='z'), 1..=8).generate(&mut Rng::from_seed(1));
+/// assert!(word.chars().all(|c| c.is_ascii_lowercase()));
+/// ```
+pub fn string_of<G: Generator<Value = char>, R: Into<InclusiveBounds<usize>>>(
+    char: G,
+    len: R,
+) -> StringOf<G> {
+    StringOf { chars: vec_of(char, len) }
+}
+
+/// A generator of strings.
+///
+/// See [`string_of`].
+pub struct StringOf<G> {
+    chars: VecOf<G>,
+}
+
+impl<G: Generator<Value = char>> Generator for StringOf<G> {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        self.chars.generate(rng).into_iter().collect()
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let chars = value.chars().collect::<Vec<_>>();
+        self.chars.shrink(&chars).into_iter().map(|c| c.into_iter().collect()).collect()
+    }
+}
+
+/// Generates one of the given `values`, each equally often.
+///
+/// Panics when `values` is empty.
+///
+/// ```
+/// # use googletest::generators::{one_of, Generator, Rng};
+/// let method = one_of(["GET", "POST"]).generate(&mut Rng::from_seed(1));
+/// ```
+pub fn one_of<T: Clone + Debug>(values: impl IntoIterator<Item = T>) -> OneOf<T> {
+    let values: Vec<T> = values.into_iter().collect();
+    assert!(!values.is_empty(), "one_of requires at least one value");
+    OneOf { values }
+}
+
+/// A generator choosing among fixed values.
+///
+/// See [`one_of`].
+pub struct OneOf<T> {
+    values: Vec<T>,
+}
+
+impl<T: Clone + Debug> Generator for OneOf<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        self.values[rng.up_to(self.values.len() as u128 - 1) as usize].clone()
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($g:ident $index:tt),*) => {
+        impl<$($g: Generator),*> Generator for ($($g,)*) {
+            type Value = ($($g::Value,)*);
+
+            #[allow(unused_variables, clippy::unused_unit)]
+            fn generate(&self, rng: &mut Rng) -> Self::Value {
+                ($(self.$index.generate(rng),)*)
+            }
+
+            #[allow(unused_variables, unused_mut)]
+            fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+                // Shrink one component at a time.
+                let mut candidates = vec![];
+                $(
+                    for shrunk in self.$index.shrink(&value.$index) {
+                        let mut candidate = value.clone();
+                        candidate.$index = shrunk;
+                        candidates.push(candidate);
+                    }
+                )*
+                candidates
+            }
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(G0 0);
+impl_tuple!(G0 0, G1 1);
+impl_tuple!(G0 0, G1 1, G2 2);
+impl_tuple!(G0 0, G1 1, G2 2, G3 3);
+impl_tuple!(G0 0, G1 1, G2 2, G3 3, G4 4);
+impl_tuple!(G0 0, G1 1, G2 2, G3 3, G4 4, G5 5);
+impl_tuple!(G0 0, G1 1, G2 2, G3 3, G4 4, G5 5, G6 6);
+impl_tuple!(G0 0, G1 1, G2 2, G3 3, G4 4, G5 5, G6 6, G7 7);
+
+#[cfg(test)]
+mod tests {
+    use super::{any, one_of, range, string_of, vec_of, Generator, Rng};
+    use crate::prelude::*;
+
+    fn samples<G: Generator>(generator: G) -> Vec<G::Value> {
+        let mut rng = Rng::from_seed(42);
+        (0..1000).map(|_| generator.generate(&mut rng)).collect()
+    }
+
+    #[test]
+    fn rng_is_deterministic_for_seed() -> Result<()> {
+        let mut first = Rng::from_seed(7);
+        let mut second = Rng::from_seed(7);
+
+        verify_that!(first.next_u64(), eq(second.next_u64()))
+    }
+
+    #[test]
+    fn range_stays_within_exclusive_range() -> Result<()> {
+        verify_that!(samples(range(-3..3)), each(all!(ge(-3), lt(3))))
+    }
+
+    #[test]
+    fn range_covers_inclusive_range() -> Result<()> {
+        verify_that!(samples(range(1u8..=3)), superset_of([1, 2, 3]))
+    }
+
+    #[test]
+    fn range_supports_full_range_of_wide_types() -> Result<()> {
+        verify_that!(samples(range(i128::MIN..=i128::MAX)), not(empty()))
+    }
+
+    #[test]
+    fn range_produces_only_valid_chars() -> Result<()> {
+        verify_that!(
+            samples(range('\u{d7ff}'..='\u{e000}')),
+            each(predicate(|c: &char| *c == '\u{d7ff}' || *c == '\u{e000}'))
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "range requires a non-empty range")]
+    fn range_panics_on_empty_range() {
+        let _ = range(3..3);
+    }
+
+    #[test]
+    fn any_integer_includes_extremes() -> Result<()> {
+        verify_that!(samples(any::<i16>()), superset_of([i16::MIN, 0, i16::MAX]))
+    }
+
+    #[test]
+    fn any_float_is_finite() -> Result<()> {
+        verify_that!(samples(any::<f64>()), each(predicate(|v: &f64| v.is_finite())))
+    }
+
+    #[test]
+    fn vec_of_respects_length_range() -> Result<()> {
+        verify_that!(samples(vec_of(any::<u8>(), 2..=4)), each(size(all!(ge(2), le(4)))))
+    }
+
+    #[test]
+    fn string_of_uses_given_characters() -> Result<()> {
+        verify_that!(
+            samples(string_of(range('a'..='c'), 0..5)),
+            each(predicate(|s: &String| s.chars().all(|c| ('a'..='c').contains(&c))))
+        )
+    }
+
+    #[test]
+    fn one_of_chooses_every_value() -> Result<()> {
+        verify_that!(samples(one_of(["a", "b"])), superset_of(["a", "b"]))
+    }
+
+    #[test]
+    fn any_integer_shrinks_towards_zero() -> Result<()> {
+        expect_that!(
+            any::<i32>().shrink(&100),
+            elements_are![eq(0), eq(50), eq(75), eq(88), eq(94), eq(97), eq(99)]
+        );
+        expect_that!(any::<i32>().shrink(&-3), elements_are![eq(0), eq(-2)]);
+        verify_that!(any::<u8>().shrink(&0), empty())
+    }
+
+    #[test]
+    fn range_shrinks_towards_lower_bound() -> Result<()> {
+        verify_that!(range(10..20).shrink(&16), elements_are![eq(10), eq(13), eq(15)])
+    }
+
+    #[test]
+    fn vec_of_shrinks_without_going_below_minimum_length() -> Result<()> {
+        let candidates = vec_of(any::<u8>(), 1..5).shrink(&vec![3, 4]);
+
+        expect_that!(candidates, contains(eq(vec![3])));
+        expect_that!(candidates, contains(eq(vec![0, 4])));
+        verify_that!(candidates, each(size(ge(1))))
+    }
+
+    #[test]
+    fn string_of_shrinks_characters() -> Result<()> {
+        verify_that!(string_of(any::<char>(), 0..5).shrink(&"ab".into()), contains(eq("aa")))
+    }
+
+    #[test]
+    fn tuple_shrinks_each_component() -> Result<()> {
+        verify_that!(
+            (any::<bool>(), any::<u8>()).shrink(&(true, 1)),
+            unordered_elements_are![eq((false, 1)), eq((true, 0))]
+        )
+    }
+
+    #[test]
+    fn tuple_combines_generators() -> Result<()> {
+        verify_that!(samples((range(0..1), range(5..6))), each(eq((0, 5))))
+    }
+}
@@ -0,0 +1,322 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Support for the test environment which Bazel provides.
+//
+// When running under `bazel test`, the environment variable
+// `XML_OUTPUT_FILE` names a file into which the test runner should write a
+// JUnit-style XML report of the test results. The variables
+// `TEST_TOTAL_SHARDS`, `TEST_SHARD_INDEX`, and `TEST_SHARD_STATUS_FILE`
+// request that only a subset of the tests be run in this process.
+//
+// Only tests annotated with `#[googletest::test]` appear in the report,
+// including those which panic. Other tests cannot be observed: a panic hook
+// sees their panics, but cannot tell whether `#[should_panic]` expects them.
+//
+// See <https://bazel.build/reference/test-encyclopedia> for details.
+
+use crate::internal::test_outcome::{TestInfo, TestRecord};
+use std::fmt::Write as _;
+use std::sync::{Mutex, Once};
+
+/// The results of all tests which have finished so far in this process.
+///
+/// The XML report is rewritten in full whenever a test finishes, since the
+/// Rust test harness offers no hook which runs after the last test.
+static FINISHED_TESTS: Mutex<Vec<TestRecord>> = Mutex::new(Vec::new());
+
+static ANNOUNCE_SHARDING_SUPPORT: Once = Once::new();
+
+/// Returns why the test `info` does not run in this process if it belongs to
+/// a shard other than the one which this process should run.
+///
+/// This always returns `None` if the test run is not sharded.
+pub(crate) fn excluded_by_sharding(info: &TestInfo) -> Option<String> {
+    let (shard_index, total_shards) = current_shard()?;
+    ANNOUNCE_SHARDING_SUPPORT.call_once(|| {
+        // Bazel checks for the existence of this file to verify that the test
+        // runner actually honours the sharding request.
+        if let Ok(status_file) = std::env::var("TEST_SHARD_STATUS_FILE") {
+            let _ = std::fs::write(status_file, "");
+        }
+    });
+    let shard = shard_of(&info.qualified_name(), total_shards);
+    (shard != shard_index).then(|| {
+        format!("it belongs to shard {shard} of {total_shards}, not to shard {shard_index}")
+    })
+}
+
+/// Records the result of a finished test in the XML report, if one was
+/// requested.
+pub(crate) fn record_test_result(record: TestRecord) {
+    let Ok(output_file) = std::env::var("XML_OUTPUT_FILE") else {
+        return;
+    };
+    let mut finished_tests = FINISHED_TESTS.lock().unwrap_or_else(|e| e.into_inner());
+    finished_tests.push(record);
+    if let Err(e) = std::fs::write(&output_file, render_xml(&finished_tests)) {
+        eprintln!("Could not write test results to {output_file}: {e}");
+    }
+}
+
+/// Returns the index of this shard and the total number of shards, if the
+/// test run is sharded.
+fn current_shard() -> Option<(usize, usize)> {
+    let total_shards: usize = std::env::var("TEST_TOTAL_SHARDS").ok()?.parse().ok()?;
+    let shard_index: usize = std::env::var("TEST_SHARD_INDEX").ok()?.parse().ok()?;
+    (total_shards > 0 && shard_index < total_shards).then_some((shard_index, total_shards))
+}
+
+/// Assigns the test with the given qualified name to one of `total_shards`
+/// shards.
+///
+/// This uses the FNV-1a hash of the name, so that the assignment is stable
+/// across processes and independent of the order in which tests run.
+fn shard_of(qualified_name: &str, total_shards: usize) -> usize {
+    let hash = qualified_name
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+    (hash % total_shards as u64) as usize
+}
+
+/// Renders the given test results as an XML document in the format which
+/// Bazel expects.
+///
+/// Tests are grouped into one `testsuite` per module.
+fn render_xml(records: &[TestRecord]) -> String {
+    let mut modules: Vec<&str> = vec![];
+    for record in records {
+        if !modules.contains(&record.info.module_path) {
+            modules.push(record.info.module_path);
+        }
+    }
+    let total_failures = records.iter().filter(|r| !r.passed).count();
+    let total_time: f64 = records.iter().map(|r| r.duration.as_secs_f64()).sum();
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites tests=\"{}\" failures=\"{total_failures}\" errors=\"0\" time=\"{total_time:.3}\">",
+        records.len(),
+    );
+    for module in modules {
+        let tests: Vec<_> = records.iter().filter(|r| r.info.module_path == module).collect();
+        let failures = tests.iter().filter(|r| !r.passed).count();
+        let time: f64 = tests.iter().map(|r| r.duration.as_secs_f64()).sum();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" \
+             time=\"{time:.3}\">",
+            escape_attribute(module),
+            tests.len(),
+        );
+        for test in tests {
+            if let Some(reason) = &test.skipped {
+                let _ = writeln!(
+                    xml,
+                    "    <testcase name=\"{}\" classname=\"{}\" status=\"notrun\" \
+                     result=\"suppressed\" time=\"0.000\" assertions=\"0\">\n      \
+                     <skipped message=\"{}\" />\n    </testcase>",
+                    escape_attribute(test.info.name),
+                    escape_attribute(module),
+                    escape_attribute(reason),
+                );
+                continue;
+            }
+            let _ = write!(
+                xml,
+                "    <testcase name=\"{}\" classname=\"{}\" status=\"run\" result=\"completed\" \
+                 time=\"{:.3}\" assertions=\"{}\"",
+                escape_attribute(test.info.name),
+                escape_attribute(module),
+                test.duration.as_secs_f64(),
+                test.assertions.total(),
+            );
+            if test.passed {
+                xml.push_str(" />\n");
+                continue;
+            }
+            xml.push_str(">\n");
+            let failures = if test.failures.is_empty() {
+                vec!["Test failed".to_string()]
+            } else {
+                test.failures.clone()
+            };
+            for failure in failures {
+                let message = failure.lines().next().unwrap_or_default();
+                let _ = writeln!(
+                    xml,
+                    "      <failure message=\"{}\" type=\"\"><![CDATA[{}]]></failure>",
+                    escape_attribute(message),
+                    valid_xml_text(failure.trim_end()).replace("]]>", "]]]]><![CDATA[>"),
+                );
+            }
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in valid_xml_text(value).chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Removes from `value` what XML 1.0 does not allow in a document.
+///
+/// ANSI escape sequences, such as those coloring the output of a test, are
+/// removed entirely. Any other character which XML does not allow, such as
+/// most control characters, is replaced with U+FFFD.
+fn valid_xml_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' if chars.peek() == Some(&'[') => {
+                chars.next();
+                // A control sequence ends with a character in the range from
+                // `@` to `~`.
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            '\t' | '\n' | '\r' => text.push(c),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => text.push('\u{fffd}'),
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_xml, shard_of};
+    use crate::internal::test_outcome::{AssertionCounts, TestInfo, TestRecord};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::time::Duration;
+
+    fn record(name: &'static str, failures: &[&str]) -> TestRecord {
+        TestRecord {
+            skipped: None,
+            info: TestInfo { module_path: "my_crate::tests", name },
+            passed: failures.is_empty(),
+            duration: Duration::from_millis(5),
+            failures: failures.iter().map(|f| f.to_string()).collect(),
+            assertions: AssertionCounts {
+                passed: 2,
+                failed: failures.len(),
+                non_fatal_failed: failures.len(),
+            },
+        }
+    }
+
+    #[test]
+    fn renders_passing_and_failing_tests() -> Result<()> {
+        let xml = render_xml(&[record("passes", &[]), record("fails", &["Value of: x\nmore"])]);
+
+        verify_that!(
+            xml,
+            eq(indoc! {r#"
+                <?xml version="1.0" encoding="UTF-8"?>
+                <testsuites tests="2" failures="1" errors="0" time="0.010">
+                  <testsuite name="my_crate::tests" tests="2" failures="1" errors="0" time="0.010">
+                    <testcase name="passes" classname="my_crate::tests" status="run" result="completed" time="0.005" assertions="2" />
+                    <testcase name="fails" classname="my_crate::tests" status="run" result="completed" time="0.005" assertions="3">
+                      <failure message="Value of: x" type=""><![CDATA[Value of: x
+                more]]></failure>
+                    </testcase>
+                  </testsuite>
+                </testsuites>
+            "#})
+        )
+    }
+
+    #[test]
+    fn renders_skipped_test() -> Result<()> {
+        let skipped = TestRecord::skipped(
+            TestInfo { module_path: "my_crate::tests", name: "skipped" },
+            "it belongs to shard 1 of 2, not to shard 0".into(),
+        );
+
+        let xml = render_xml(&[skipped]);
+
+        verify_that!(
+            xml,
+            eq(indoc! {r#"
+                <?xml version="1.0" encoding="UTF-8"?>
+                <testsuites tests="1" failures="0" errors="0" time="0.000">
+                  <testsuite name="my_crate::tests" tests="1" failures="0" errors="0" time="0.000">
+                    <testcase name="skipped" classname="my_crate::tests" status="notrun" result="suppressed" time="0.000" assertions="0">
+                      <skipped message="it belongs to shard 1 of 2, not to shard 0" />
+                    </testcase>
+                  </testsuite>
+                </testsuites>
+            "#})
+        )
+    }
+
+    #[test]
+    fn escapes_special_characters() -> Result<()> {
+        let xml = render_xml(&[record("fails", &["\"<&>\" ]]> end"])]);
+
+        verify_that!(
+            xml,
+            contains_substring(
+                r#"<failure message="&quot;&lt;&amp;&gt;&quot; ]]&gt; end" type=""><![CDATA["<&>" ]]]]><![CDATA[> end]]></failure>"#
+            )
+        )
+    }
+
+    #[test]
+    fn removes_characters_invalid_in_xml() -> Result<()> {
+        let xml = render_xml(&[record("fails", &["\x1b[31mRed\x1b[0m and \x07 bell"])]);
+
+        verify_that!(
+            xml,
+            contains_substring(
+                "<failure message=\"Red and \u{fffd} bell\" type=\"\">\
+                 <![CDATA[Red and \u{fffd} bell]]></failure>"
+            )
+        )
+    }
+
+    #[test]
+    fn shard_assignment_is_within_range_and_stable() -> Result<()> {
+        let shard = shard_of("tests::some_test", 3);
+
+        verify_that!(shard, lt(3))?;
+        verify_that!(shard_of("tests::some_test", 3), eq(shard))
+    }
+
+    #[test]
+    fn single_shard_contains_every_test() -> Result<()> {
+        verify_that!(shard_of("tests::some_test", 1), eq(0))
+    }
+}
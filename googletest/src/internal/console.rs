@@ -0,0 +1,314 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Console output in the style of C++ GoogleTest.
+//
+// When the environment variable `GOOGLETEST_CONSOLE_OUTPUT` is set to
+// `gtest`, each test annotated with `#[googletest::test]` prints the familiar
+// `[ RUN      ]`, `[       OK ]`, and `[  FAILED  ]` lines, so that tooling
+// which scrapes the logs of C++ GoogleTest works unchanged. As in C++
+// GoogleTest, `GTEST_BRIEF=1` restricts the output to failing tests and
+// `GTEST_PRINT_TIME=0` omits the time each test took. The failures of such
+// a test are printed once, right before its `[  FAILED  ]` line, rather than
+// when they occur.
+//
+// These lines are written directly to the process' standard output rather
+// than through `print!`, since the Rust test harness would otherwise capture
+// them and only show them for failing tests.
+
+use crate::config::config;
+use crate::internal::environment::environment;
+use crate::internal::test_outcome::{TestInfo, TestRecord};
+use std::io::Write;
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const CYAN: &str = "\x1b[36m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Announces that the test `info` is about to run.
+pub(crate) fn report_test_started(info: &TestInfo) {
+    let environment = environment();
+    if environment.gtest_console_output && !environment.brief {
+        write_to_stdout(&started_output(info));
+    }
+}
+
+/// Announces the result of a finished test.
+pub(crate) fn report_test_finished(record: &TestRecord) {
+    let environment = environment();
+    if environment.gtest_console_output && !(environment.brief && record.passed) {
+        write_to_stdout(&finished_output(record, environment.print_time));
+    }
+}
+
+/// Whether the announcement of a finished test lists its failures, so that
+/// they must not be printed when they occur as well.
+pub(crate) fn prints_failures_of_finished_tests() -> bool {
+    environment().gtest_console_output
+}
+
+/// Announces that the test `info` was skipped for the given reason.
+///
+/// Unlike the other announcements, this is also written in the default
+/// console output, since a skipped test would otherwise appear to have
+/// passed. It goes through the test harness's output capture, so that it
+/// appears with the output of the test rather than interleaved with the
+/// harness's own.
+pub(crate) fn report_test_skipped(info: &TestInfo, reason: &str) {
+    let environment = environment();
+    if !environment.gtest_console_output {
+        println!("SKIPPED {}: {reason}", info.qualified_name());
+    } else if !environment.brief {
+        print!("{}", skipped_output(info, reason));
+    }
+}
+
+/// Colors the failure message `failure`, provided colored output is
+/// configured. Otherwise returns `failure` unchanged.
+///
+/// In the diff sections, such as `Debug diff:`, additions (lines of the actual
+/// value) are green, deletions (lines of the expected value) red, and the
+/// headers of unified diff hunks cyan. The description of the expected value
+/// is dimmed, so that the actual value and the diff stand out.
+pub(crate) fn paint_failure(failure: &str) -> String {
+    if !config().color {
+        return failure.to_string();
+    }
+    let mut output = String::new();
+    let mut in_diff = false;
+    let mut in_expected = false;
+    for line in failure.split_inclusive('\n') {
+        let content = line.trim_end_matches('\n');
+        let paint = |color: &str| format!("{color}{content}{RESET}{}", &line[content.len()..]);
+        in_expected = content.starts_with("Expected: ")
+            || (in_expected && content.starts_with(' ') && !in_diff);
+        if in_expected {
+            output.push_str(&paint(DIM));
+            continue;
+        }
+        if in_diff && content.starts_with('+') {
+            output.push_str(&paint(GREEN));
+            continue;
+        }
+        if in_diff && content.starts_with('-') {
+            output.push_str(&paint(RED));
+            continue;
+        }
+        if in_diff && content.starts_with("@@") {
+            output.push_str(&paint(CYAN));
+            continue;
+        }
+        in_diff = is_diff_header(content) || (in_diff && content.starts_with(' '));
+        output.push_str(line);
+    }
+    output
+}
+
+/// Whether `line` introduces a line-by-line diff in a failure message.
+fn is_diff_header(line: &str) -> bool {
+    matches!(line, "Debug diff:" | "Normalized diff:" | "Difference ignoring case:")
+}
+
+/// Wraps `marker` in the ANSI escape sequences for `color`, provided colored
+/// output is configured.
+fn paint_marker(marker: &str, color: &str) -> String {
+    if config().color { format!("{color}{marker}{RESET}") } else { marker.to_string() }
+}
+
+fn write_to_stdout(output: &str) {
+    let mut stdout = std::io::stdout().lock();
+    let _ = stdout.write_all(output.as_bytes());
+    let _ = stdout.flush();
+}
+
+fn started_output(info: &TestInfo) -> String {
+    format!("{} {}\n", paint_marker("[ RUN      ]", GREEN), gtest_name(info))
+}
+
+fn finished_output(record: &TestRecord, print_time: bool) -> String {
+    let name = gtest_name(&record.info);
+    let time =
+        if print_time { format!(" ({} ms)", record.duration.as_millis()) } else { String::new() };
+    if record.passed {
+        format!("{} {name}{time}\n", paint_marker("[       OK ]", GREEN))
+    } else {
+        let mut output = String::new();
+        for failure in &record.failures {
+            output.push_str(&paint_failure(failure));
+            if !failure.ends_with('\n') {
+                output.push('\n');
+            }
+        }
+        output.push_str(&format!("{} {name}{time}\n", paint_marker("[  FAILED  ]", RED)));
+        output
+    }
+}
+
+fn skipped_output(info: &TestInfo, reason: &str) -> String {
+    format!("{} {}: {reason}\n", paint_marker("[  SKIPPED ]", GREEN), gtest_name(info))
+}
+
+/// The name of the test in the form `Suite.Test` which C++ GoogleTest uses.
+///
+/// The suite is the module path of the test without the crate name. Tests
+/// at the root of a crate use the crate name as their suite.
+fn gtest_name(info: &TestInfo) -> String {
+    let suite = info.module_path.split_once("::").map(|(_, rest)| rest).unwrap_or(info.module_path);
+    format!("{suite}.{}", info.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{finished_output, paint_failure, skipped_output, started_output};
+    use crate::internal::test_outcome::{AssertionCounts, TestInfo, TestRecord};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::time::Duration;
+
+    const INFO: TestInfo = TestInfo { module_path: "my_crate::tests", name: "a_test" };
+
+    #[test]
+    fn announces_started_test() -> Result<()> {
+        let _guard = crate::config().color(false).apply();
+
+        verify_that!(started_output(&INFO), eq("[ RUN      ] tests.a_test\n"))
+    }
+
+    #[test]
+    fn announces_passing_test() -> Result<()> {
+        let _guard = crate::config().color(false).apply();
+
+        let record = TestRecord {
+            info: INFO,
+            passed: true,
+            duration: Duration::from_millis(12),
+            failures: vec![],
+            assertions: AssertionCounts::default(),
+            skipped: None,
+        };
+
+        verify_that!(finished_output(&record, true), eq("[       OK ] tests.a_test (12 ms)\n"))
+    }
+
+    #[test]
+    fn omits_time_when_disabled() -> Result<()> {
+        let _guard = crate::config().color(false).apply();
+
+        let record = TestRecord {
+            info: INFO,
+            passed: true,
+            duration: Duration::from_millis(12),
+            failures: vec![],
+            assertions: AssertionCounts::default(),
+            skipped: None,
+        };
+
+        verify_that!(finished_output(&record, false), eq("[       OK ] tests.a_test\n"))
+    }
+
+    #[test]
+    fn announces_failing_test_with_its_failures() -> Result<()> {
+        let _guard = crate::config().color(false).apply();
+
+        let record = TestRecord {
+            info: INFO,
+            passed: false,
+            duration: Duration::from_millis(3),
+            failures: vec!["Value of: x\n".into(), "Some error".into()],
+            assertions: AssertionCounts::default(),
+            skipped: None,
+        };
+
+        verify_that!(
+            finished_output(&record, true),
+            eq(indoc! {"
+                Value of: x
+                Some error
+                [  FAILED  ] tests.a_test (3 ms)
+            "})
+        )
+    }
+
+    #[test]
+    fn announces_skipped_test_with_reason() -> Result<()> {
+        let _guard = crate::config().color(false).apply();
+
+        verify_that!(
+            skipped_output(&INFO, "the prerequisite test setup failed"),
+            eq("[  SKIPPED ] tests.a_test: the prerequisite test setup failed\n")
+        )
+    }
+
+    #[test]
+    fn uses_crate_name_as_suite_for_tests_at_crate_root() -> Result<()> {
+        let _guard = crate::config().color(false).apply();
+
+        let info = TestInfo { module_path: "my_crate", name: "a_test" };
+
+        verify_that!(started_output(&info), eq("[ RUN      ] my_crate.a_test\n"))
+    }
+
+    #[test]
+    fn paints_nothing_without_color() -> Result<()> {
+        let _guard = crate::config().color(false).apply();
+
+        verify_that!(paint_failure("Debug diff:\n+a\n-b\n"), eq("Debug diff:\n+a\n-b\n"))
+    }
+
+    #[test]
+    fn paints_diff_lines_with_color() -> Result<()> {
+        let _guard = crate::config().color(true).apply();
+
+        verify_that!(
+            paint_failure("-x\nDebug diff:\n [\n+a\n-b\n ]\n-y\nother\n-z"),
+            eq(
+                "-x\nDebug diff:\n [\n\x1b[32m+a\x1b[0m\n\x1b[31m-b\x1b[0m\n ]\n\x1b[31m-y\x1b[0m\nother\n-z"
+            )
+        )
+    }
+
+    #[test]
+    fn paints_other_diff_sections_and_hunk_headers_with_color() -> Result<()> {
+        let _guard = crate::config().color(true).apply();
+
+        verify_that!(
+            paint_failure("Normalized diff:\n@@ -1,1 +1,1 @@\n+a\n-b\n"),
+            eq(
+                "Normalized diff:\n\x1b[36m@@ -1,1 +1,1 @@\x1b[0m\n\x1b[32m+a\x1b[0m\n\x1b[31m-b\x1b[0m\n"
+            )
+        )
+    }
+
+    #[test]
+    fn dims_description_of_expected_value() -> Result<()> {
+        let _guard = crate::config().color(true).apply();
+
+        verify_that!(
+            paint_failure("Value of: x\nExpected: has elements:\n  0. is 1\nActual: [2]\n"),
+            eq(
+                "Value of: x\n\x1b[2mExpected: has elements:\x1b[0m\n\x1b[2m  0. is 1\x1b[0m\nActual: \
+                [2]\n"
+            )
+        )
+    }
+
+    #[test]
+    fn paints_markers_with_color() -> Result<()> {
+        let _guard = crate::config().color(true).apply();
+
+        verify_that!(started_output(&INFO), eq("\x1b[32m[ RUN      ]\x1b[0m tests.a_test\n"))
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Running the tests on which a test declares that it depends.
+//!
+//! A test annotated with `#[googletest::test(depends_on = "other_test")]`
+//! runs `other_test` first, on a separate thread, unless it has already run.
+//! Only tests which declare prerequisites or run as a prerequisite are
+//! tracked, so that a prerequisite runs at most once and the test harness
+//! reports its earlier result when it reaches it later. Other tests run
+//! exactly as they would without this module.
+//!
+//! **For internal use only. API stablility is not guaranteed!**
+
+use crate::internal::bazel;
+use crate::internal::test_outcome::{TestInfo, TestOutcome, TestRecord};
+use std::cell::RefCell;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+/// A test function as generated by `#[googletest::test]`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+pub type TestFn = fn() -> Result<(), ()>;
+
+enum RunState {
+    /// The test is running, and is waiting for the named prerequisite if it
+    /// is running one.
+    Running {
+        waiting_for: Option<String>,
+    },
+    Finished {
+        passed: bool,
+        failures: Vec<String>,
+    },
+    Skipped {
+        reason: String,
+    },
+}
+
+/// Why a prerequisite did not run to completion.
+enum NotRun {
+    /// The prerequisite was skipped for the given reason.
+    Skipped(String),
+    /// The prerequisite cannot run in this process for the given reason.
+    Unavailable(String),
+}
+
+/// The tracked tests which started in this process, by their qualified names.
+static RUNS: Mutex<Vec<(String, RunState)>> = Mutex::new(Vec::new());
+
+/// Notified whenever a test in [`RUNS`] finishes.
+static RUN_FINISHED: Condvar = Condvar::new();
+
+thread_local! {
+    /// The test on whose behalf this thread runs a prerequisite, if it does.
+    static DEPENDENT: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// Why the prerequisite which ran on this thread did not run to
+    /// completion, if it did not.
+    static NOT_RUN: RefCell<Option<NotRun>> = const { RefCell::new(None) };
+}
+
+/// The run of a test which started, held until the test function returns.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+pub struct TestRun {
+    /// The qualified name of the test if it is tracked.
+    name: Option<String>,
+}
+
+/// Starts the run of the test `info`, after running the tests named in
+/// `prerequisites` which have not run yet.
+///
+/// Returns the result with which the test function should return right away
+/// if the test already ran as a prerequisite or cannot run since one of its
+/// prerequisites did not pass.
+///
+/// This is intended only for use by the attribute macro
+/// `#[googletest::test]`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+pub fn start(
+    info: TestInfo,
+    prerequisites: &[(&'static str, TestFn)],
+) -> Result<TestRun, Result<(), ()>> {
+    let name = info.qualified_name();
+    let dependent = DEPENDENT.with(|dependent| dependent.borrow().clone());
+    let as_prerequisite = dependent.is_some();
+    if let Some(reason) = bazel::excluded_by_sharding(&info) {
+        if as_prerequisite {
+            NOT_RUN.with(|not_run| *not_run.borrow_mut() = Some(NotRun::Unavailable(reason)));
+            return Err(Err(()));
+        }
+        // The test is reported as skipped when its outcome is initialised.
+        return Ok(TestRun { name: None });
+    }
+    let mut runs = lock_runs();
+    if let Some(dependent) = &dependent {
+        set_waiting_for(&mut runs, dependent, Some(&name));
+    }
+    loop {
+        match runs.iter().find(|(run, _)| *run == name).map(|(_, state)| state) {
+            None => break,
+            Some(RunState::Finished { passed, failures }) => {
+                if !as_prerequisite {
+                    println!("This test already ran as a prerequisite of another test.");
+                    for failure in failures {
+                        print!("{failure}");
+                    }
+                }
+                return Err(if *passed { Ok(()) } else { Err(()) });
+            }
+            Some(RunState::Skipped { reason }) => {
+                let reason = reason.clone();
+                drop(runs);
+                return Err(skip(info, reason, as_prerequisite));
+            }
+            Some(RunState::Running { .. }) => {
+                if let Some(cycle) = dependent
+                    .as_deref()
+                    .and_then(|dependent| dependency_cycle(&runs, dependent, &name))
+                {
+                    let reason = format!("its dependencies form a cycle: {cycle}");
+                    drop(runs);
+                    return Err(skip(info, reason, as_prerequisite));
+                }
+                runs = RUN_FINISHED.wait(runs).unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+    }
+    if prerequisites.is_empty() && !as_prerequisite {
+        return Ok(TestRun { name: None });
+    }
+    runs.push((name.clone(), RunState::Running { waiting_for: None }));
+    drop(runs);
+    let run_name = name.clone();
+    let run = TestRun { name: Some(name) };
+    for (prerequisite, test) in prerequisites {
+        let result = run_prerequisite(&info, *test);
+        set_waiting_for(&mut lock_runs(), &run_name, None);
+        match result {
+            Ok(()) => {}
+            Err(None) => {
+                let reason = format!("the prerequisite test {prerequisite} did not pass");
+                run.finish(RunState::Skipped { reason: reason.clone() });
+                return Err(skip(info, reason, as_prerequisite));
+            }
+            Err(Some(NotRun::Skipped(reason))) => {
+                let reason = format!("the prerequisite test {prerequisite} was skipped: {reason}");
+                run.finish(RunState::Skipped { reason: reason.clone() });
+                return Err(skip(info, reason, as_prerequisite));
+            }
+            Err(Some(NotRun::Unavailable(reason))) => {
+                TestOutcome::fail_test(
+                    info,
+                    format!("The prerequisite test {prerequisite} is not available: {reason}\n"),
+                );
+                return Err(Err(()));
+            }
+        }
+    }
+    Ok(run)
+}
+
+/// Reports that the test `info` is skipped for the given reason.
+///
+/// A prerequisite is not reported, so that each skipped test is reported
+/// only once, when the test harness reaches it. Its dependent learns the
+/// reason instead.
+fn skip(info: TestInfo, reason: String, as_prerequisite: bool) -> Result<(), ()> {
+    if as_prerequisite {
+        NOT_RUN.with(|not_run| *not_run.borrow_mut() = Some(NotRun::Skipped(reason)));
+    } else {
+        TestOutcome::skip_test(info, reason);
+    }
+    Ok(())
+}
+
+/// Records the result of a finished test, so that it is reused should the
+/// test be run again.
+pub(crate) fn record_test_result(record: &TestRecord) {
+    let mut runs = lock_runs();
+    if let Some((_, state)) = runs.iter_mut().find(|(run, _)| *run == record.info.qualified_name())
+    {
+        *state = RunState::Finished { passed: record.passed, failures: record.failures.clone() };
+        RUN_FINISHED.notify_all();
+    }
+}
+
+impl TestRun {
+    fn finish(&self, finished: RunState) {
+        let Some(name) = &self.name else {
+            return;
+        };
+        if let Some((_, state)) = lock_runs().iter_mut().find(|(run, _)| run == name) {
+            if let RunState::Running { .. } = state {
+                *state = finished;
+            }
+        }
+        RUN_FINISHED.notify_all();
+    }
+}
+
+impl Drop for TestRun {
+    fn drop(&mut self) {
+        // The result is normally recorded when the test closes its outcome.
+        // Otherwise the test panicked before it could.
+        let panicked = std::thread::panicking();
+        self.finish(RunState::Finished {
+            passed: !panicked,
+            failures: if panicked { vec!["The test panicked.\n".into()] } else { vec![] },
+        });
+    }
+}
+
+/// Runs the prerequisite `test` of the test `dependent` on a new thread.
+///
+/// Returns `Err` if the prerequisite did not pass, along with the reason if
+/// it did not run to completion.
+fn run_prerequisite(dependent: &TestInfo, test: TestFn) -> Result<(), Option<NotRun>> {
+    let dependent = dependent.qualified_name();
+    std::thread::spawn(move || {
+        DEPENDENT.with(|current| *current.borrow_mut() = Some(dependent));
+        let result = test();
+        match NOT_RUN.with(|not_run| not_run.borrow_mut().take()) {
+            Some(not_run) => Err(Some(not_run)),
+            None => result.map_err(|()| None),
+        }
+    })
+    .join()
+    .unwrap_or(Err(None))
+}
+/// Records that the running test `dependent` waits for its prerequisite
+/// `prerequisite`, or no longer waits if `prerequisite` is `None`.
+fn set_waiting_for(runs: &mut [(String, RunState)], dependent: &str, prerequisite: Option<&str>) {
+    if let Some((_, RunState::Running { waiting_for })) =
+        runs.iter_mut().find(|(run, _)| run == dependent)
+    {
+        *waiting_for = prerequisite.map(String::from);
+    }
+}
+
+/// Describes the cycle of dependencies if the running test `name`, for which
+/// the test `dependent` is about to wait, directly or indirectly waits for
+/// `dependent` itself.
+///
+/// The tests in a cycle may have been started by different threads of the
+/// test harness, so this follows the prerequisites for which each running
+/// test waits rather than those of the current thread.
+fn dependency_cycle(runs: &[(String, RunState)], dependent: &str, name: &str) -> Option<String> {
+    let mut cycle = vec![dependent, name];
+    while cycle.len() <= runs.len() + 1 {
+        let last = cycle[cycle.len() - 1];
+        if last == dependent {
+            return Some(cycle.join(" -> "));
+        }
+        let next = runs.iter().find(|(run, _)| run == last).and_then(|(_, state)| match state {
+            RunState::Running { waiting_for } => waiting_for.as_deref(),
+            _ => None,
+        })?;
+        cycle.push(next);
+    }
+    None
+}
+
+fn lock_runs() -> MutexGuard<'static, Vec<(String, RunState)>> {
+    RUNS.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,454 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Runtime configuration through environment variables.
+//
+// The variables are read once per process, on first use, so that the
+// behaviour can be tuned in CI without recompiling. The following variables
+// are shared with C++ GoogleTest:
+//
+//  * `GTEST_COLOR`: `yes` to color the console output, `no` not to, or
+//    `auto` (the default) to decide by the conventional `NO_COLOR`,
+//    `CLICOLOR_FORCE`, and `CLICOLOR` variables and, failing those, by whether
+//    the standard output is a terminal.
+//  * `GTEST_BRIEF`: `1` to print only failing tests in the GoogleTest-style
+//    console output.
+//  * `GTEST_PRINT_TIME`: `0` to omit the time each test took from the
+//    GoogleTest-style console output.
+//
+// The following variables are specific to GoogleTest Rust:
+//
+//  * `GOOGLETEST_CONSOLE_OUTPUT`: `gtest` to print the console output in the
+//    style of C++ GoogleTest.
+//  * `GOOGLETEST_MAX_FAILURES`, `GOOGLETEST_MAX_CONTAINER_ELEMENTS`,
+//    `GOOGLETEST_MAX_ACTUAL_LINES`, `GOOGLETEST_MAX_ACTUAL_BYTES`,
+//    `GOOGLETEST_DEBUG_STYLE` (one of `pretty` or `compact`),
+//    `GOOGLETEST_MAX_DEBUG_DEPTH`, `GOOGLETEST_MAX_DEBUG_WIDTH`,
+//    `GOOGLETEST_DIFF_STYLE` (one of `full`, `changes_only`, `unified`,
+//    `unified:<context>`, `side_by_side`, or `disabled`),
+//    `GOOGLETEST_STRAY_THREADS` (one of `ignore`, `warn`, or `fail`), and
+//    `GOOGLETEST_CATCH_PANICS` (`0` or `1`): the defaults of the
+//    corresponding settings of `crate::config::Config`.
+//  * `GOOGLETEST_PROPERTY_CASES`: the number of inputs with which each
+//    property test runs, 100 by default.
+//  * `GOOGLETEST_PROPERTY_SEED`: the seed from which property tests generate
+//    their inputs, to reproduce a failure. By default, the seed is random.
+//
+// Invalid values are reported on standard error and otherwise ignored.
+
+use crate::config::{Config, DebugStyle, DiffStyle, StrayThreads};
+use std::sync::Mutex;
+
+/// The configuration read from the environment variables of the process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Environment {
+    /// Whether to print the console output in the style of C++ GoogleTest.
+    pub(crate) gtest_console_output: bool,
+    /// Whether the GoogleTest-style console output shows only failing tests.
+    pub(crate) brief: bool,
+    /// Whether the GoogleTest-style console output shows the time each test
+    /// took.
+    pub(crate) print_time: bool,
+    /// The configuration in effect for each test until it applies its own.
+    pub(crate) config: Config,
+    /// The number of inputs with which each property test runs.
+    pub(crate) property_cases: usize,
+    /// The seed from which property tests generate their inputs, if fixed.
+    pub(crate) property_seed: Option<u64>,
+}
+
+static ENVIRONMENT: Mutex<Option<Environment>> = Mutex::new(None);
+
+/// Returns the configuration read from the environment, reading it on the
+/// first call.
+pub(crate) fn environment() -> Environment {
+    let mut environment = ENVIRONMENT.lock().unwrap_or_else(|e| e.into_inner());
+    *environment.get_or_insert_with(|| {
+        Environment::from_lookup(
+            |name| std::env::var(name).ok(),
+            |warning| eprintln!("{warning}"),
+            stdout_is_terminal(),
+        )
+    })
+}
+
+impl Environment {
+    /// Reads the configuration through `lookup`, which returns the value of
+    /// the environment variable with the given name, and reports invalid
+    /// values through `warn`. Whether the standard output is a terminal,
+    /// `stdout_is_terminal`, decides whether to color the output if no
+    /// variable does.
+    fn from_lookup(
+        lookup: impl Fn(&str) -> Option<String>,
+        mut warn: impl FnMut(String),
+        stdout_is_terminal: bool,
+    ) -> Self {
+        let mut parse = |name: &str, accepted: &str, parser: &dyn Fn(&str) -> Option<Setting>| {
+            let value = lookup(name)?;
+            let setting = parser(value.trim());
+            if setting.is_none() {
+                warn(format!(
+                    "googletest: ignoring the value {value:?} of {name}, which should be \
+                    {accepted}"
+                ));
+            }
+            setting
+        };
+        let color = parse("GTEST_COLOR", "one of yes, no, or auto", &|value| match value
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "yes" | "true" | "1" => Some(Setting::Flag(true)),
+            "no" | "false" | "0" => Some(Setting::Flag(false)),
+            "auto" => Some(Setting::Auto),
+            _ => None,
+        });
+        let brief = parse("GTEST_BRIEF", "0 or 1", &parse_flag);
+        let print_time = parse("GTEST_PRINT_TIME", "0 or 1", &parse_flag);
+        let console_output = parse("GOOGLETEST_CONSOLE_OUTPUT", "gtest", &|value| match value {
+            "gtest" => Some(Setting::Flag(true)),
+            "" => Some(Setting::Flag(false)),
+            _ => None,
+        });
+        let max_failures = parse("GOOGLETEST_MAX_FAILURES", "a number", &parse_number);
+        let max_container_elements =
+            parse("GOOGLETEST_MAX_CONTAINER_ELEMENTS", "a number", &parse_number);
+        let max_actual_lines = parse("GOOGLETEST_MAX_ACTUAL_LINES", "a number", &parse_number);
+        let max_actual_bytes = parse("GOOGLETEST_MAX_ACTUAL_BYTES", "a number", &parse_number);
+        let debug_style =
+            parse("GOOGLETEST_DEBUG_STYLE", "one of pretty or compact", &|value| match value {
+                "pretty" => Some(Setting::DebugStyle(DebugStyle::Pretty)),
+                "compact" => Some(Setting::DebugStyle(DebugStyle::Compact)),
+                _ => None,
+            });
+        let max_debug_depth = parse("GOOGLETEST_MAX_DEBUG_DEPTH", "a number", &parse_number);
+        let max_debug_width = parse("GOOGLETEST_MAX_DEBUG_WIDTH", "a number", &parse_number);
+        let diff_style = parse(
+            "GOOGLETEST_DIFF_STYLE",
+            "one of full, changes_only, unified, unified:<context>, side_by_side, or disabled",
+            &|value| match value {
+                "full" => Some(Setting::DiffStyle(DiffStyle::Full)),
+                "changes_only" => Some(Setting::DiffStyle(DiffStyle::ChangesOnly)),
+                "unified" => Some(Setting::DiffStyle(DiffStyle::Unified { context: 3 })),
+                "side_by_side" => Some(Setting::DiffStyle(DiffStyle::SideBySide)),
+                "disabled" => Some(Setting::DiffStyle(DiffStyle::Disabled)),
+                _ => {
+                    let context = value.strip_prefix("unified:")?.parse().ok()?;
+                    Some(Setting::DiffStyle(DiffStyle::Unified { context }))
+                }
+            },
+        );
+        let stray_threads = parse(
+            "GOOGLETEST_STRAY_THREADS",
+            "one of ignore, warn, or fail",
+            &|value| match value {
+                "ignore" => Some(Setting::StrayThreads(StrayThreads::Ignore)),
+                "warn" => Some(Setting::StrayThreads(StrayThreads::Warn)),
+                "fail" => Some(Setting::StrayThreads(StrayThreads::Fail)),
+                _ => None,
+            },
+        );
+        let catch_panics = parse("GOOGLETEST_CATCH_PANICS", "0 or 1", &parse_flag);
+        let property_cases = parse("GOOGLETEST_PROPERTY_CASES", "a number", &parse_number);
+        let property_seed = parse("GOOGLETEST_PROPERTY_SEED", "a number", &|value| {
+            value.parse().ok().map(Setting::Seed)
+        });
+
+        let color = match color {
+            Some(Setting::Flag(color)) => color,
+            _ => automatic_color(&lookup, stdout_is_terminal),
+        };
+
+        let mut config = Config::default().color(color);
+        if let Some(Setting::Number(max_failures)) = max_failures {
+            config = config.max_failures(max_failures);
+        }
+        if let Some(Setting::Number(max_container_elements)) = max_container_elements {
+            config = config.max_container_elements(max_container_elements);
+        }
+        if let Some(Setting::Number(max_actual_lines)) = max_actual_lines {
+            config = config.max_actual_lines(max_actual_lines);
+        }
+        if let Some(Setting::Number(max_actual_bytes)) = max_actual_bytes {
+            config = config.max_actual_bytes(max_actual_bytes);
+        }
+        if let Some(Setting::DebugStyle(debug_style)) = debug_style {
+            config = config.debug_style(debug_style);
+        }
+        if let Some(Setting::Number(max_debug_depth)) = max_debug_depth {
+            config = config.max_debug_depth(max_debug_depth);
+        }
+        if let Some(Setting::Number(max_debug_width)) = max_debug_width {
+            config = config.max_debug_width(max_debug_width);
+        }
+        if let Some(Setting::DiffStyle(diff_style)) = diff_style {
+            config = config.diff_style(diff_style);
+        }
+        if let Some(Setting::StrayThreads(stray_threads)) = stray_threads {
+            config = config.stray_threads(stray_threads);
+        }
+        if let Some(Setting::Flag(catch_panics)) = catch_panics {
+            config = config.catch_panics(catch_panics);
+        }
+        Self {
+            gtest_console_output: matches!(console_output, Some(Setting::Flag(true))),
+            brief: matches!(brief, Some(Setting::Flag(true))),
+            print_time: !matches!(print_time, Some(Setting::Flag(false))),
+            config,
+            property_cases: match property_cases {
+                Some(Setting::Number(cases)) => cases,
+                _ => 100,
+            },
+            property_seed: match property_seed {
+                Some(Setting::Seed(seed)) => Some(seed),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Decides whether to color the output following the conventions of
+/// <https://no-color.org> and <https://bixense.com/clicolors/>: `NO_COLOR`
+/// disables color, `CLICOLOR_FORCE` enables it, `CLICOLOR=0` disables it, and
+/// otherwise the output is colored if it goes to a terminal.
+fn automatic_color(lookup: &impl Fn(&str) -> Option<String>, stdout_is_terminal: bool) -> bool {
+    let is_set = |name: &str| lookup(name).map_or(false, |value| !value.is_empty());
+    if is_set("NO_COLOR") {
+        false
+    } else if is_set("CLICOLOR_FORCE") && lookup("CLICOLOR_FORCE").as_deref() != Some("0") {
+        true
+    } else if lookup("CLICOLOR").as_deref() == Some("0") {
+        false
+    } else {
+        stdout_is_terminal
+    }
+}
+
+/// Returns whether the standard output of the process is a terminal.
+///
+/// This can only be determined on Unix; elsewhere the output is never
+/// considered a terminal.
+fn stdout_is_terminal() -> bool {
+    #[cfg(unix)]
+    {
+        extern "C" {
+            fn isatty(fd: std::os::raw::c_int) -> std::os::raw::c_int;
+        }
+        // SAFETY: isatty only inspects the given file descriptor, and is safe
+        // to call with any value.
+        unsafe { isatty(1) == 1 }
+    }
+    #[cfg(not(unix))]
+    {
+        false
+    }
+}
+
+/// The parsed value of a single environment variable.
+enum Setting {
+    Flag(bool),
+    Auto,
+    Number(usize),
+    Seed(u64),
+    DebugStyle(DebugStyle),
+    DiffStyle(DiffStyle),
+    StrayThreads(StrayThreads),
+}
+
+fn parse_flag(value: &str) -> Option<Setting> {
+    match value {
+        "1" => Some(Setting::Flag(true)),
+        "0" => Some(Setting::Flag(false)),
+        _ => None,
+    }
+}
+
+fn parse_number(value: &str) -> Option<Setting> {
+    value.parse().ok().map(Setting::Number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Environment;
+    use crate::config::{Config, DebugStyle, DiffStyle, StrayThreads};
+    use crate::prelude::*;
+    use std::collections::HashMap;
+
+    fn environment_from(variables: &[(&str, &str)]) -> (Environment, Vec<String>) {
+        environment_on_terminal_from(false, variables)
+    }
+
+    fn environment_on_terminal_from(
+        stdout_is_terminal: bool,
+        variables: &[(&str, &str)],
+    ) -> (Environment, Vec<String>) {
+        let variables: HashMap<String, String> =
+            variables.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        let mut warnings = vec![];
+        let environment = Environment::from_lookup(
+            |name| variables.get(name).cloned(),
+            |warning| warnings.push(warning),
+            stdout_is_terminal,
+        );
+        (environment, warnings)
+    }
+
+    #[test]
+    fn uses_defaults_without_variables() -> Result<()> {
+        let (environment, warnings) = environment_from(&[]);
+
+        expect_that!(warnings, empty());
+        verify_that!(
+            environment,
+            eq(Environment {
+                gtest_console_output: false,
+                brief: false,
+                print_time: true,
+                config: Config::default(),
+                property_cases: 100,
+                property_seed: None,
+            })
+        )
+    }
+
+    #[test]
+    fn reads_gtest_variables() -> Result<()> {
+        let (environment, warnings) = environment_from(&[
+            ("GTEST_COLOR", "yes"),
+            ("GTEST_BRIEF", "1"),
+            ("GTEST_PRINT_TIME", "0"),
+        ]);
+
+        expect_that!(warnings, empty());
+        expect_that!(environment.config.color, eq(true));
+        expect_that!(environment.brief, eq(true));
+        verify_that!(environment.print_time, eq(false))
+    }
+
+    #[test]
+    fn auto_color_does_not_enable_color_without_terminal() -> Result<()> {
+        let (environment, _) = environment_from(&[("GTEST_COLOR", "auto")]);
+
+        verify_that!(environment.config.color, eq(false))
+    }
+
+    #[test]
+    fn auto_color_enables_color_on_terminal() -> Result<()> {
+        let (environment, _) = environment_on_terminal_from(true, &[("GTEST_COLOR", "auto")]);
+        expect_that!(environment.config.color, eq(true));
+
+        let (environment, _) = environment_on_terminal_from(true, &[]);
+        verify_that!(environment.config.color, eq(true))
+    }
+
+    #[test]
+    fn no_color_disables_color_on_terminal() -> Result<()> {
+        let (environment, _) = environment_on_terminal_from(true, &[("NO_COLOR", "1")]);
+        expect_that!(environment.config.color, eq(false));
+
+        let (environment, _) = environment_on_terminal_from(true, &[("CLICOLOR", "0")]);
+        verify_that!(environment.config.color, eq(false))
+    }
+
+    #[test]
+    fn clicolor_force_enables_color_without_terminal() -> Result<()> {
+        let (environment, _) = environment_from(&[("CLICOLOR_FORCE", "1")]);
+        expect_that!(environment.config.color, eq(true));
+
+        let (environment, _) = environment_from(&[("CLICOLOR_FORCE", "0")]);
+        verify_that!(environment.config.color, eq(false))
+    }
+
+    #[test]
+    fn gtest_color_overrides_conventional_variables() -> Result<()> {
+        let (environment, _) =
+            environment_on_terminal_from(false, &[("GTEST_COLOR", "yes"), ("NO_COLOR", "1")]);
+        expect_that!(environment.config.color, eq(true));
+
+        let (environment, _) = environment_on_terminal_from(true, &[("GTEST_COLOR", "no")]);
+        verify_that!(environment.config.color, eq(false))
+    }
+
+    #[test]
+    fn reads_googletest_variables() -> Result<()> {
+        let (environment, warnings) = environment_from(&[
+            ("GOOGLETEST_CONSOLE_OUTPUT", "gtest"),
+            ("GOOGLETEST_MAX_FAILURES", "5"),
+            ("GOOGLETEST_MAX_CONTAINER_ELEMENTS", "20"),
+            ("GOOGLETEST_MAX_ACTUAL_LINES", "100"),
+            ("GOOGLETEST_MAX_ACTUAL_BYTES", "1000000"),
+            ("GOOGLETEST_DEBUG_STYLE", "compact"),
+            ("GOOGLETEST_MAX_DEBUG_DEPTH", "3"),
+            ("GOOGLETEST_MAX_DEBUG_WIDTH", "80"),
+            ("GOOGLETEST_DIFF_STYLE", "changes_only"),
+            ("GOOGLETEST_STRAY_THREADS", "warn"),
+            ("GOOGLETEST_CATCH_PANICS", "1"),
+            ("GOOGLETEST_PROPERTY_CASES", "1000"),
+            ("GOOGLETEST_PROPERTY_SEED", "12345678901234"),
+        ]);
+
+        expect_that!(warnings, empty());
+        expect_that!(environment.gtest_console_output, eq(true));
+        expect_that!(environment.property_cases, eq(1000));
+        expect_that!(environment.property_seed, some(eq(12345678901234)));
+        verify_that!(
+            environment.config,
+            eq(Config::default()
+                .max_failures(5)
+                .max_container_elements(20)
+                .max_actual_lines(100)
+                .max_actual_bytes(1000000)
+                .debug_style(DebugStyle::Compact)
+                .max_debug_depth(3)
+                .max_debug_width(80)
+                .diff_style(DiffStyle::ChangesOnly)
+                .stray_threads(StrayThreads::Warn)
+                .catch_panics(true))
+        )
+    }
+
+    #[test]
+    fn reads_unified_diff_style_with_and_without_context() -> Result<()> {
+        let (environment, _) = environment_from(&[("GOOGLETEST_DIFF_STYLE", "unified")]);
+        expect_that!(environment.config.diff_style, eq(DiffStyle::Unified { context: 3 }));
+
+        let (environment, warnings) = environment_from(&[("GOOGLETEST_DIFF_STYLE", "unified:0")]);
+        expect_that!(warnings, empty());
+        verify_that!(environment.config.diff_style, eq(DiffStyle::Unified { context: 0 }))
+    }
+
+    #[test]
+    fn reads_side_by_side_diff_style() -> Result<()> {
+        let (environment, _) = environment_from(&[("GOOGLETEST_DIFF_STYLE", "side_by_side")]);
+
+        verify_that!(environment.config.diff_style, eq(DiffStyle::SideBySide))
+    }
+
+    #[test]
+    fn warns_about_and_ignores_invalid_values() -> Result<()> {
+        let (environment, warnings) =
+            environment_from(&[("GTEST_BRIEF", "yes"), ("GOOGLETEST_MAX_FAILURES", "many")]);
+
+        expect_that!(environment.brief, eq(false));
+        expect_that!(environment.config, eq(Config::default()));
+        verify_that!(
+            warnings,
+            unordered_elements_are![
+                eq("googletest: ignoring the value \"yes\" of GTEST_BRIEF, which should be 0 or 1"),
+                eq("googletest: ignoring the value \"many\" of GOOGLETEST_MAX_FAILURES, which \
+                    should be a number"),
+            ]
+        )
+    }
+}
@@ -14,5 +14,12 @@
 
 #![doc(hidden)]
 
+pub(crate) mod bazel;
+pub(crate) mod console;
+pub mod dependencies;
+pub(crate) mod environment;
+pub mod panics;
+pub mod property;
 pub mod source_location;
 pub mod test_outcome;
+pub(crate) mod threads;
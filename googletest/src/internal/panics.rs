@@ -0,0 +1,209 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Catching panics in tests to report them as test failures.
+//!
+//! **For internal use only. API stablility is not guaranteed!**
+
+use crate::config::config;
+use crate::internal::test_outcome::{TestAssertionFailure, TestOutcome};
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Once;
+use std::task::Poll;
+
+thread_local! {
+    /// Whether a panic on this thread is about to be caught and reported as a
+    /// test failure, so that the panic hook only records it.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The location and backtrace of the last panic caught on this thread.
+    static LAST_PANIC: RefCell<Option<(Option<String>, Backtrace)>> = const { RefCell::new(None) };
+    /// The description of the last panic on this thread which was not about
+    /// to be caught, for the report of the test which it ends.
+    static UNCAUGHT_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs the body of a test, catching a panic in it and converting it into a
+/// [`TestAssertionFailure`] if [`Config::catch_panics`] is set.
+///
+/// Otherwise the panic ends the test, which is then reported as failed with
+/// the panic message while the panic unwinds.
+///
+/// This is intended only for use by the attribute macro
+/// `#[googletest::test]`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+///
+/// [`Config::catch_panics`]: crate::config::Config::catch_panics
+pub fn run_test<R>(test: impl FnOnce() -> R) -> Result<R, TestAssertionFailure> {
+    install_hook();
+    if !config().catch_panics {
+        let _report_panic = ReportPanic;
+        return Ok(test());
+    }
+    let _catching = Catching::start();
+    catch_unwind(AssertUnwindSafe(test)).map_err(failure_from_panic)
+}
+
+/// Like [`run_test`], but for the body of an async test.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+pub async fn run_async_test<F: Future>(test: F) -> Result<F::Output, TestAssertionFailure> {
+    install_hook();
+    if !config().catch_panics {
+        let _report_panic = ReportPanic;
+        return Ok(test.await);
+    }
+    let mut test = Box::pin(test);
+    std::future::poll_fn(|context| {
+        let _catching = Catching::start();
+        match catch_unwind(AssertUnwindSafe(|| test.as_mut().poll(context))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(failure_from_panic(payload))),
+        }
+    })
+    .await
+}
+
+/// Installs a panic hook which records the location and backtrace of panics
+/// which are about to be caught. For all others, it records their message and
+/// location and defers to the previous hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let location = info.location().map(|location| location.to_string());
+            if CATCHING.try_with(Cell::get).unwrap_or(false) {
+                LAST_PANIC.with(|last_panic| {
+                    *last_panic.borrow_mut() = Some((location, Backtrace::capture()))
+                });
+            } else {
+                let mut description = describe_panic(info.payload());
+                if let Some(location) = location {
+                    description.push_str(&format!("\n  at {location}"));
+                }
+                let _ = UNCAUGHT_PANIC.try_with(|uncaught_panic| {
+                    if let Ok(mut uncaught_panic) = uncaught_panic.try_borrow_mut() {
+                        *uncaught_panic = Some(description);
+                    }
+                });
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Reports the current test as failed if it is dropped while a panic unwinds
+/// out of the test, which is not caught.
+///
+/// The Rust test harness reports such a test as failed, but nothing else in
+/// the test would record its result.
+struct ReportPanic;
+
+impl Drop for ReportPanic {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            return;
+        }
+        let description = UNCAUGHT_PANIC
+            .try_with(|uncaught_panic| uncaught_panic.try_borrow_mut().ok()?.take())
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| "The test panicked.".into());
+        TestOutcome::close_panicked_test_outcome(description);
+    }
+}
+
+/// Marks the current thread as catching panics until dropped.
+struct Catching {
+    previous: bool,
+}
+
+impl Catching {
+    fn start() -> Self {
+        Self { previous: CATCHING.with(|catching| catching.replace(true)) }
+    }
+}
+
+impl Drop for Catching {
+    fn drop(&mut self) {
+        CATCHING.with(|catching| catching.set(self.previous));
+    }
+}
+
+/// Describes the caught panic with the given payload as a test failure.
+fn failure_from_panic(payload: Box<dyn Any + Send>) -> TestAssertionFailure {
+    let mut description = describe_panic(payload.as_ref());
+    if let Some((location, backtrace)) = LAST_PANIC.with(|last_panic| last_panic.take()) {
+        if let Some(location) = location {
+            description.push_str(&format!("\n  at {location}"));
+        }
+        if backtrace.status() == BacktraceStatus::Captured {
+            description.push_str(&format!("\nBacktrace:\n{backtrace}"));
+        }
+    }
+    TestAssertionFailure::create(description)
+}
+
+/// Describes a panic with the given payload by its message.
+fn describe_panic(payload: &(dyn Any + Send)) -> String {
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    };
+    format!("The test panicked with the message:\n  {message}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_test;
+    use crate::prelude::*;
+
+    #[test]
+    fn returns_result_of_test_which_does_not_panic() -> Result<()> {
+        let _guard = crate::config().catch_panics(true).apply();
+
+        verify_that!(run_test(|| 42), ok(eq(42)))
+    }
+
+    #[test]
+    fn converts_panic_into_failure_with_message_and_location() -> Result<()> {
+        let _guard = crate::config().catch_panics(true).apply();
+
+        let result = run_test(|| panic!("out of {}", "cheese"));
+
+        verify_that!(
+            result,
+            err(displays_as(all!(
+                starts_with("The test panicked with the message:\n  out of cheese\n"),
+                contains_substring("  at googletest/src/internal/panics.rs:")
+            )))
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "not caught")]
+    fn does_not_catch_panic_if_not_configured() {
+        let _ = run_test(|| panic!("not caught"));
+    }
+}
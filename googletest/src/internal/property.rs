@@ -0,0 +1,93 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::generators::{Generator, Rng};
+use crate::internal::environment::environment;
+use crate::internal::test_outcome::{TestAssertionFailure, TestOutcome};
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The maximum number of variants of a failing input which are tried while
+/// shrinking it.
+const MAX_SHRINK_ATTEMPTS: usize = 1000;
+
+/// Runs `property` with inputs produced by `generator` until it fails or the
+/// configured number of cases have passed.
+///
+/// The function `describe` renders an input as one line per parameter for the
+/// failure report. An input fails when `property` returns `Err` or records a
+/// non-fatal failure. A failing input is shrunk with
+/// [`Generator::shrink`], and the report shows both the original and the
+/// shrunk input together with the failures of the latter.
+///
+/// This is intended only for use by the attribute macro
+/// `#[googletest::property_test]`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+pub fn run_property<G: Generator, E: Display>(
+    generator: G,
+    describe: impl Fn(&G::Value) -> Vec<String>,
+    mut property: impl FnMut(G::Value) -> Result<(), E>,
+) -> Result<(), TestAssertionFailure> {
+    let environment = environment();
+    let seed = environment.property_seed.unwrap_or_else(|| {
+        SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0)
+    });
+    // Returns the failures of `property` on the given input, if any.
+    let mut check = |input: G::Value| -> Option<String> {
+        let (result, mut failures) = TestOutcome::capture_failures(|| property(input));
+        if let Err(e) = result {
+            failures.push(e.to_string());
+        }
+        (!failures.is_empty()).then(|| failures.concat())
+    };
+    let mut rng = Rng::from_seed(seed);
+    for case in 1..=environment.property_cases {
+        let input = generator.generate(&mut rng);
+        let Some(failure) = check(input.clone()) else {
+            continue;
+        };
+        let mut report = format!(
+            "Property failed for input #{case} of {}:\n  {}\n",
+            environment.property_cases,
+            describe(&input).join("\n  "),
+        );
+        let (mut minimal_input, mut minimal_failure, mut steps) = (input, failure, 0);
+        let mut candidates = generator.shrink(&minimal_input).into_iter();
+        let mut attempts = 0;
+        while let Some(candidate) = candidates.next() {
+            attempts += 1;
+            if attempts > MAX_SHRINK_ATTEMPTS {
+                break;
+            }
+            if let Some(failure) = check(candidate.clone()) {
+                // Carry on with the variants of the newly found input.
+                candidates = generator.shrink(&candidate).into_iter();
+                (minimal_input, minimal_failure) = (candidate, failure);
+                steps += 1;
+            }
+        }
+        if steps > 0 {
+            report.push_str(&format!(
+                "Shrunk in {steps} step{} to the failing input:\n  {}\n",
+                if steps == 1 { "" } else { "s" },
+                describe(&minimal_input).join("\n  "),
+            ));
+        }
+        report.push_str(&format!("Reproduce with GOOGLETEST_PROPERTY_SEED={seed}.\n"));
+        report.push_str(minimal_failure.trim_end());
+        return Err(TestAssertionFailure::create(report));
+    }
+    Ok(())
+}
@@ -21,6 +21,7 @@ use std::fmt::{Display, Error, Formatter};
 ///
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
+#[derive(Clone, Copy)]
 pub struct SourceLocation {
     file: &'static str,
     line: u32,
@@ -35,6 +36,11 @@ impl SourceLocation {
     pub fn new(file: &'static str, line: u32, column: u32) -> Self {
         Self { file, line, column }
     }
+
+    /// The file and line of the location, separated by a colon.
+    pub(crate) fn file_and_line(&self) -> String {
+        format!("{}:{}", self.file, self.line)
+    }
 }
 
 impl Display for SourceLocation {
@@ -12,14 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cell::{RefCell, RefMut};
+use crate::config::{config, StrayThreads};
+use crate::fixture::Fixture;
+use crate::internal::source_location::SourceLocation;
+use crate::internal::{bazel, console, dependencies, threads};
+use crate::matcher::MatchExplanation;
+use std::cell::RefCell;
 use std::fmt::{Debug, Display, Error, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 use std::thread_local;
+use std::time::{Duration, Instant};
 
 /// The outcome hitherto of running a test.
 ///
-/// This is kept as a running record as the test progresses. One can access it
-/// with `TestOutcome::with_current_test_outcome`.
+/// This is kept as a running record as the test progresses, together with the
+/// identity of the test and the failures recorded so far.
 ///
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
@@ -31,22 +39,197 @@ pub enum TestOutcome {
     Failure,
 }
 
+/// Everything recorded about the currently running test.
+struct CurrentTest {
+    outcome: TestOutcome,
+    info: Option<TestInfo>,
+    started_at: Option<Instant>,
+    failures: Vec<String>,
+    /// The number of non-fatal failures which were not printed since they
+    /// exceeded [`Config::max_failures`][crate::config::Config::max_failures].
+    suppressed_failures: usize,
+    assertions: AssertionCounts,
+    /// The descriptions of non-fatal failures which are being captured
+    /// rather than recorded, see [`TestOutcome::capture_failures`].
+    captured_failures: Option<Vec<String>>,
+    /// The threads which were running when the test started, if stray
+    /// threads are to be detected.
+    threads_at_start: Option<Vec<u64>>,
+    /// The locations of the assertions whose failing results were dropped
+    /// without being handled, see [`TestAssertionFailure::track_if_ignored`].
+    ignored_results: Vec<String>,
+    /// The failures recorded on other threads on behalf of this test, see
+    /// [`TestScope`][crate::test_scope::TestScope].
+    failure_inbox: Option<FailureInbox>,
+    /// Where the non-fatal failures recorded on this thread are forwarded to
+    /// if it runs on behalf of a test on another thread.
+    forward_failures_to: Option<FailureInbox>,
+    /// The shared fixtures which this test uses, released when it ends, see
+    /// [`TestOutcome::use_fixture`].
+    fixtures: Vec<&'static (dyn Fixture + Sync)>,
+}
+
+impl CurrentTest {
+    /// Whether the failures of this test are printed in the announcement of
+    /// its result when it ends, rather than when they occur.
+    fn prints_failures_when_ending(&self) -> bool {
+        self.info.is_some() && console::prints_failures_of_finished_tests()
+    }
+}
+
+/// The descriptions of the failures recorded on other threads on behalf of a
+/// test.
+#[derive(Clone)]
+pub(crate) struct FailureInbox {
+    pub(crate) failures: Arc<Mutex<Vec<String>>>,
+    /// Whether the failures are printed when the test ends, so that the
+    /// threads recording them must not print them as well.
+    printed_when_test_ends: bool,
+}
+
 thread_local! {
-    static CURRENT_TEST_OUTCOME: RefCell<TestOutcome> =
-        RefCell::new(TestOutcome::Success);
+    static CURRENT_TEST: RefCell<CurrentTest> = const {
+        RefCell::new(CurrentTest {
+            outcome: TestOutcome::Success,
+            info: None,
+            started_at: None,
+            failures: Vec::new(),
+            suppressed_failures: 0,
+            assertions: AssertionCounts { passed: 0, failed: 0, non_fatal_failed: 0 },
+            captured_failures: None,
+            threads_at_start: None,
+            ignored_results: Vec::new(),
+            failure_inbox: None,
+            forward_failures_to: None,
+            fixtures: Vec::new(),
+        })
+    };
+}
+
+/// Identifies a test function annotated with `#[googletest::test]`.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct TestInfo {
+    /// The output of `module_path!()` at the location of the test function.
+    pub module_path: &'static str,
+    /// The name of the test function itself.
+    pub name: &'static str,
+}
+
+impl TestInfo {
+    /// The name of the test as the Rust test harness reports it, i.e., the
+    /// module path without the crate name followed by the function name.
+    pub(crate) fn qualified_name(&self) -> String {
+        match self.module_path.split_once("::") {
+            Some((_, rest)) => format!("{rest}::{}", self.name),
+            None => self.name.to_string(),
+        }
+    }
+}
+
+/// The number of assertions which a test evaluated, by their outcome.
+///
+/// A test which evaluated no assertions at all may well check nothing. The
+/// counts of the current test are returned by
+/// [`TestOutcome::assertion_counts`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AssertionCounts {
+    /// The number of assertions which passed.
+    pub passed: usize,
+    /// The number of assertions which failed, whether fatally or not.
+    pub failed: usize,
+    /// The number of failed assertions which were recorded as non-fatal
+    /// failures, e.g., by [`expect_that!`][crate::expect_that] or
+    /// [`and_log_failure`][crate::GoogleTestSupport::and_log_failure].
+    pub non_fatal_failed: usize,
+}
+
+impl AssertionCounts {
+    /// The total number of assertions evaluated.
+    pub fn total(&self) -> usize {
+        self.passed + self.failed
+    }
+
+    /// The number of failed assertions which were not recorded as non-fatal
+    /// failures, e.g., because they were returned with the `?` operator.
+    pub fn fatal_failed(&self) -> usize {
+        self.failed.saturating_sub(self.non_fatal_failed)
+    }
+}
+
+/// The complete record of a finished test, as handed to the test reporters.
+pub(crate) struct TestRecord {
+    pub(crate) info: TestInfo,
+    pub(crate) passed: bool,
+    pub(crate) duration: Duration,
+    pub(crate) failures: Vec<String>,
+    pub(crate) assertions: AssertionCounts,
+    /// Why the test did not run, if it was skipped.
+    pub(crate) skipped: Option<String>,
+}
+
+impl TestRecord {
+    /// The record of the test `info`, which was skipped for the given
+    /// reason.
+    pub(crate) fn skipped(info: TestInfo, reason: String) -> Self {
+        Self {
+            info,
+            passed: true,
+            duration: Duration::ZERO,
+            failures: vec![],
+            assertions: AssertionCounts::default(),
+            skipped: Some(reason),
+        }
+    }
 }
 
 impl TestOutcome {
-    /// Resets the current test's [`TestOutcome`].
+    /// Resets the current test's [`TestOutcome`] and records the identity of
+    /// the test which is about to run.
+    ///
+    /// Returns whether the test should run at all. This is `false` when the
+    /// test belongs to a different shard in a sharded test run, in which case
+    /// the test is reported as skipped.
     ///
-    /// This is intended only for use by the attribute macro `#[google_test]`.
+    /// This is intended only for use by the attribute macro
+    /// `#[googletest::test]`.
     ///
     /// **For internal use only. API stablility is not guaranteed!**
     #[doc(hidden)]
-    pub fn init_current_test_outcome() {
-        Self::with_current_test_outcome(|mut current_test_outcome| {
-            *current_test_outcome = TestOutcome::Success;
-        })
+    #[must_use]
+    pub fn init_current_test_outcome(info: TestInfo) -> bool {
+        if let Some(reason) = bazel::excluded_by_sharding(&info) {
+            TestOutcome::skip_test(info, reason);
+            return false;
+        }
+        console::report_test_started(&info);
+        let previous = CURRENT_TEST.with(|current_test| {
+            std::mem::replace(
+                &mut *current_test.borrow_mut(),
+                CurrentTest {
+                    outcome: TestOutcome::Success,
+                    info: Some(info),
+                    started_at: Some(Instant::now()),
+                    failures: Vec::new(),
+                    suppressed_failures: 0,
+                    assertions: AssertionCounts::default(),
+                    captured_failures: None,
+                    threads_at_start: (config().stray_threads != StrayThreads::Ignore)
+                        .then(threads::running_threads)
+                        .flatten(),
+                    ignored_results: Vec::new(),
+                    failure_inbox: None,
+                    forward_failures_to: None,
+                    fixtures: Vec::new(),
+                },
+            )
+        });
+        // Tearing fixtures down may record failures, so the fixtures of an
+        // earlier test on this thread are released outside the borrow.
+        previous.fixtures.into_iter().for_each(|fixture| fixture.release());
+        true
     }
 
     /// Evaluates the current test's [`TestOutcome`], producing a suitable
@@ -58,36 +241,295 @@ impl TestOutcome {
     ///
     /// Otherwise, this returns `Result::Err` precisely when a test failure has
     /// been recorded with
-    /// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure).
+    /// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure), or
+    /// when threads started during the test are still running and
+    /// [`Config::stray_threads`][crate::config::Config::stray_threads] is set
+    /// to fail the test.
     ///
     /// **For internal use only. API stablility is not guaranteed!**
     #[doc(hidden)]
+    #[allow(clippy::result_unit_err)]
     pub fn close_current_test_outcome<E: Display>(result: Result<(), E>) -> Result<(), ()> {
-        TestOutcome::with_current_test_outcome(|outcome| match &*outcome {
-            TestOutcome::Success => match result {
-                Ok(()) => Ok(()),
-                Err(f) => {
-                    print!("{}", f);
-                    Err(())
+        let stray_threads = CURRENT_TEST
+            .with(|current_test| current_test.borrow_mut().threads_at_start.take())
+            .map(|threads_at_start| threads::threads_started_since(&threads_at_start))
+            .filter(|stray_threads| !stray_threads.is_empty());
+        let (passed, record, fixtures) = CURRENT_TEST.with(|current_test| {
+            let mut current_test = current_test.borrow_mut();
+            if let Err(f) = &result {
+                if let TestOutcome::Success = current_test.outcome {
+                    if !current_test.prints_failures_when_ending() {
+                        print!("{}", console::paint_failure(&format!("{f}")));
+                    }
+                }
+                current_test.outcome = TestOutcome::Failure;
+                current_test.failures.push(format!("{f}"));
+            }
+            if let Some(stray_threads) = stray_threads {
+                let description = threads::describe_stray_threads(&stray_threads);
+                if config().stray_threads == StrayThreads::Fail {
+                    if !current_test.prints_failures_when_ending() {
+                        print!("{}", console::paint_failure(&format!("{description}\n")));
+                    }
+                    current_test.outcome = TestOutcome::Failure;
+                    current_test.failures.push(description);
+                } else {
+                    println!("Warning: {description}");
+                }
+            }
+            if let Some(inbox) = current_test.failure_inbox.take() {
+                let forwarded = std::mem::take(
+                    &mut *inbox.failures.lock().unwrap_or_else(PoisonError::into_inner),
+                );
+                if !forwarded.is_empty() {
+                    current_test.outcome = TestOutcome::Failure;
+                    current_test.failures.extend(forwarded);
                 }
-            },
-            TestOutcome::Failure => Err(()),
+            }
+            for location in std::mem::take(&mut current_test.ignored_results) {
+                println!("Warning: assertion result ignored at {location}");
+            }
+            if current_test.suppressed_failures > 0 {
+                println!(
+                    "...and {} further failures which were not printed",
+                    std::mem::take(&mut current_test.suppressed_failures)
+                );
+            }
+            let passed = matches!(current_test.outcome, TestOutcome::Success);
+            let record = current_test.info.take().map(|info| TestRecord {
+                info,
+                passed,
+                duration: current_test.started_at.take().map(|t| t.elapsed()).unwrap_or_default(),
+                failures: std::mem::take(&mut current_test.failures),
+                assertions: current_test.assertions,
+                skipped: None,
+            });
+            (passed, record, std::mem::take(&mut current_test.fixtures))
+        });
+        if let Some(record) = record {
+            console::report_test_finished(&record);
+            dependencies::record_test_result(&record);
+            bazel::record_test_result(record);
+        }
+        // Tearing the fixtures down may record failures, so they are released
+        // outside the borrow.
+        fixtures.into_iter().for_each(|fixture| fixture.release());
+        if passed { Ok(()) } else { Err(()) }
+    }
+
+    /// Ends the current test, which panicked with the given description
+    /// without the panic being caught, and reports it as failed.
+    ///
+    /// This runs while the panic unwinds, so it must not panic itself.
+    pub(crate) fn close_panicked_test_outcome(description: String) {
+        let closed = CURRENT_TEST
+            .try_with(|current_test| {
+                let mut current_test = current_test.try_borrow_mut().ok()?;
+                let info = current_test.info.take()?;
+                current_test.outcome = TestOutcome::Failure;
+                current_test.failures.push(description);
+                let record = TestRecord {
+                    info,
+                    passed: false,
+                    duration: current_test
+                        .started_at
+                        .take()
+                        .map(|t| t.elapsed())
+                        .unwrap_or_default(),
+                    failures: std::mem::take(&mut current_test.failures),
+                    assertions: current_test.assertions,
+                    skipped: None,
+                };
+                Some((record, std::mem::take(&mut current_test.fixtures)))
+            })
+            .ok()
+            .flatten();
+        if let Some((record, fixtures)) = closed {
+            console::report_test_finished(&record);
+            dependencies::record_test_result(&record);
+            bazel::record_test_result(record);
+            fixtures.into_iter().for_each(|fixture| fixture.release());
+        }
+    }
+
+    /// Reports that the test `info` was skipped for the given reason.
+    ///
+    /// A skipped test is announced in the console output and marked as such
+    /// in the XML report, but the Rust test harness still counts it as
+    /// passed.
+    pub(crate) fn skip_test(info: TestInfo, reason: String) {
+        console::report_test_skipped(&info, &reason);
+        bazel::record_test_result(TestRecord::skipped(info, reason));
+    }
+
+    /// Reports that the test `info` failed with the given failure message
+    /// without running.
+    pub(crate) fn fail_test(info: TestInfo, failure: String) {
+        console::report_test_started(&info);
+        if !console::prints_failures_of_finished_tests() {
+            print!("{}", console::paint_failure(&failure));
+        }
+        let record = TestRecord {
+            info,
+            passed: false,
+            duration: Duration::ZERO,
+            failures: vec![failure],
+            assertions: AssertionCounts::default(),
+            skipped: None,
+        };
+        console::report_test_finished(&record);
+        dependencies::record_test_result(&record);
+        bazel::record_test_result(record);
+    }
+
+    /// Records that the test annotated with `#[googletest::test]` which is
+    /// running on this thread uses `fixture`, which it releases when it ends.
+    ///
+    /// Returns whether this is the first use of `fixture` by the test. Uses
+    /// outside of such a test are not recorded.
+    pub(crate) fn use_fixture(fixture: &'static (dyn Fixture + Sync)) -> bool {
+        CURRENT_TEST.with(|current_test| {
+            let mut current_test = current_test.borrow_mut();
+            let address = fixture as *const _ as *const ();
+            if current_test.info.is_none()
+                || current_test
+                    .fixtures
+                    .iter()
+                    .any(|used| *used as *const _ as *const () == address)
+            {
+                return false;
+            }
+            current_test.fixtures.push(fixture);
+            true
         })
     }
 
-    /// Records that the currently running test has failed.
-    fn fail_current_test() {
-        TestOutcome::with_current_test_outcome(|mut outcome| {
-            *outcome = TestOutcome::Failure;
+    /// Records that the failing result of the assertion at `location` was
+    /// dropped without being handled, to be reported when the test ends.
+    fn record_ignored_result(location: String) {
+        // The result may be dropped while the current test is borrowed or
+        // after the thread-local storage has been torn down, in which case
+        // there is nothing to report it to.
+        let _ = CURRENT_TEST.try_with(|current_test| {
+            if let Ok(mut current_test) = current_test.try_borrow_mut() {
+                if current_test.info.is_some() {
+                    current_test.ignored_results.push(location);
+                }
+            }
+        });
+    }
+
+    /// Records that the current thread evaluated an assertion with the given
+    /// outcome.
+    ///
+    /// This is intended only for use by the assertion macros.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn record_assertion(passed: bool) {
+        CURRENT_TEST.with(|current_test| {
+            let assertions = &mut current_test.borrow_mut().assertions;
+            if passed {
+                assertions.passed += 1;
+            } else {
+                assertions.failed += 1;
+            }
+        })
+    }
+
+    /// Returns the number of assertions evaluated on the current thread since
+    /// the current test started.
+    ///
+    /// Failures captured while running the inputs of a property test are not
+    /// counted as non-fatal failures, since only the failures of the reported
+    /// input fail the test.
+    pub fn assertion_counts() -> AssertionCounts {
+        CURRENT_TEST.with(|current_test| current_test.borrow().assertions)
+    }
+
+    /// Runs `f`, capturing the descriptions of the non-fatal failures it
+    /// records instead of printing them and failing the current test.
+    ///
+    /// Returns the result of `f` together with the captured descriptions.
+    pub(crate) fn capture_failures<R>(f: impl FnOnce() -> R) -> (R, Vec<String>) {
+        let previous = CURRENT_TEST
+            .with(|current_test| current_test.borrow_mut().captured_failures.replace(vec![]));
+        let result = f();
+        let captured = CURRENT_TEST.with(|current_test| {
+            std::mem::replace(&mut current_test.borrow_mut().captured_failures, previous)
+        });
+        (result, captured.unwrap_or_default())
+    }
+
+    /// Returns the inbox collecting the failures recorded on other threads on
+    /// behalf of the test running on the current thread.
+    ///
+    /// If the current thread itself runs on behalf of a test on another
+    /// thread, this is the inbox of that test.
+    pub(crate) fn failure_inbox() -> FailureInbox {
+        CURRENT_TEST.with(|current_test| {
+            let mut current_test = current_test.borrow_mut();
+            if let Some(inbox) = &current_test.forward_failures_to {
+                return inbox.clone();
+            }
+            let printed_when_test_ends = current_test.prints_failures_when_ending();
+            current_test
+                .failure_inbox
+                .get_or_insert_with(|| FailureInbox {
+                    failures: Default::default(),
+                    printed_when_test_ends,
+                })
+                .clone()
         })
     }
 
-    /// Runs `action` with the [`TestOutcome`] for the currently running test.
+    /// Forwards the non-fatal failures recorded on the current thread to
+    /// `inbox` rather than failing the thread's own test.
     ///
-    /// This is primarily intended for use by assertion macros like
-    /// `verify_that!`.
-    fn with_current_test_outcome<T>(action: impl FnOnce(RefMut<TestOutcome>) -> T) -> T {
-        CURRENT_TEST_OUTCOME.with(|current_test_outcome| action(current_test_outcome.borrow_mut()))
+    /// Returns the inbox to which failures were forwarded before.
+    pub(crate) fn forward_failures_to(inbox: Option<FailureInbox>) -> Option<FailureInbox> {
+        CURRENT_TEST.with(|current_test| {
+            std::mem::replace(&mut current_test.borrow_mut().forward_failures_to, inbox)
+        })
+    }
+
+    /// Records that the currently running test has failed with the given
+    /// failure description.
+    ///
+    /// Returns the description to print, if any. Nothing is printed once the
+    /// test has exceeded the configured maximum number of failures, nor if
+    /// the failures are printed when the test ends.
+    fn fail_current_test(description: String) -> Option<String> {
+        CURRENT_TEST.with(|current_test| {
+            let mut current_test = current_test.borrow_mut();
+            if let Some(captured_failures) = &mut current_test.captured_failures {
+                captured_failures.push(description);
+                return None;
+            }
+            current_test.assertions.non_fatal_failed += 1;
+            if let Some(inbox) = &current_test.forward_failures_to {
+                let description = format!(
+                    "{description}  on thread {}\n",
+                    std::thread::current().name().unwrap_or("<unnamed>")
+                );
+                inbox
+                    .failures
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(description.clone());
+                return (!inbox.printed_when_test_ends).then_some(description);
+            }
+            current_test.outcome = TestOutcome::Failure;
+            if current_test.info.is_none() {
+                return Some(description);
+            }
+            if config().max_failures.map_or(false, |max| current_test.failures.len() >= max) {
+                current_test.suppressed_failures += 1;
+                return None;
+            }
+            current_test.failures.push(description.clone());
+            (!current_test.prints_failures_when_ending()).then_some(description)
+        })
     }
 }
 
@@ -100,6 +542,10 @@ pub struct TestAssertionFailure {
     /// A human-readable formatted string describing the error.
     pub description: String,
     pub custom_message: Option<String>,
+    /// The structured explanation of the mismatch, if the failure came from
+    /// a matcher.
+    pub explanation: Option<Box<MatchExplanation>>,
+    ignored_result_tracker: Option<Arc<IgnoredResultTracker>>,
 }
 
 impl TestAssertionFailure {
@@ -107,17 +553,45 @@ impl TestAssertionFailure {
     ///
     /// **For internal use only. API stablility is not guaranteed!**
     pub fn create(description: String) -> Self {
-        Self { description, custom_message: None }
+        Self { description, custom_message: None, explanation: None, ignored_result_tracker: None }
+    }
+
+    /// Arranges for the failure to be reported as ignored at the end of the
+    /// test if it is dropped without ever having been displayed.
+    ///
+    /// A failure is displayed when it is logged with
+    /// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure) or
+    /// propagated out of the test function, so this catches assertions whose
+    /// `Result` was silently discarded. The tracking is only done in debug
+    /// builds.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn track_if_ignored(self, location: SourceLocation) -> Self {
+        if !cfg!(debug_assertions) {
+            return self;
+        }
+        Self {
+            ignored_result_tracker: Some(Arc::new(IgnoredResultTracker {
+                location: location.file_and_line(),
+                displayed: AtomicBool::new(false),
+            })),
+            ..self
+        }
     }
 
     pub(crate) fn log(&self) {
-        TestOutcome::fail_current_test();
-        print!("{}", self);
+        if let Some(description) = TestOutcome::fail_current_test(format!("{self}")) {
+            print!("{}", console::paint_failure(&description));
+        }
     }
 }
 
 impl Display for TestAssertionFailure {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if let Some(tracker) = &self.ignored_result_tracker {
+            tracker.displayed.store(true, Ordering::Relaxed);
+        }
         writeln!(f, "{}", self.description)?;
         if let Some(custom_message) = &self.custom_message {
             writeln!(f, "{}", custom_message)?;
@@ -126,6 +600,21 @@ impl Display for TestAssertionFailure {
     }
 }
 
+/// Reports a tracked failure as ignored when the last copy of it is dropped
+/// without the failure having been displayed.
+struct IgnoredResultTracker {
+    location: String,
+    displayed: AtomicBool,
+}
+
+impl Drop for IgnoredResultTracker {
+    fn drop(&mut self) {
+        if !self.displayed.load(Ordering::Relaxed) {
+            TestOutcome::record_ignored_result(std::mem::take(&mut self.location));
+        }
+    }
+}
+
 // The standard Rust test harness outputs the TestAssertionFailure with the
 // Debug trait. We want the output to be formatted, so we use a custom Debug
 // implementation which defers to Display.
@@ -140,3 +629,51 @@ impl<T: std::error::Error> From<T> for TestAssertionFailure {
         TestAssertionFailure::create(format!("{value}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AssertionCounts, TestOutcome};
+    use crate::prelude::*;
+
+    #[test]
+    fn counts_passed_and_failed_assertions() -> Result<()> {
+        let _ = verify_that!(1, eq(1));
+        let _ = verify_that!(1, eq(2));
+        let _ = verify_pred!(str::is_empty(""));
+        let _ = fail!("Failed");
+
+        let counts = TestOutcome::assertion_counts();
+
+        verify_that!(counts, eq(AssertionCounts { passed: 2, failed: 2, non_fatal_failed: 0 }))
+    }
+
+    #[test]
+    fn distinguishes_fatal_and_non_fatal_failures() -> Result<()> {
+        let _ = verify_that!(1, eq(2));
+        verify_that!(1, eq(2)).and_log_failure();
+
+        let counts = TestOutcome::assertion_counts();
+
+        expect_that!(counts.total(), eq(2));
+        expect_that!(counts.non_fatal_failed, eq(1));
+        verify_that!(counts.fatal_failed(), eq(1))
+    }
+
+    #[test]
+    fn captures_non_fatal_failures_without_printing_them() -> Result<()> {
+        let ((), captured) = TestOutcome::capture_failures(|| {
+            verify_that!(1, eq(2)).and_log_failure();
+        });
+
+        verify_that!(captured, elements_are![starts_with("Value of: 1\n")])
+    }
+
+    #[test]
+    fn does_not_count_captured_failures_as_non_fatal() -> Result<()> {
+        let _ = TestOutcome::capture_failures(|| {
+            verify_that!(1, eq(2)).and_log_failure();
+        });
+
+        verify_that!(TestOutcome::assertion_counts().non_fatal_failed, eq(0))
+    }
+}
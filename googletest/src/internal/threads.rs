@@ -0,0 +1,129 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Detection of threads which a test leaves running.
+//
+// The threads of the process are listed through `/proc/self/task`, so the
+// detection only works on Linux. Elsewhere, no threads are ever reported.
+
+use std::time::{Duration, Instant};
+
+/// How long to wait for threads started during a test to finish on their
+/// own before reporting them.
+const GRACE_PERIOD: Duration = Duration::from_millis(100);
+
+/// A thread of the current process.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ThreadId {
+    pub(crate) id: u64,
+    pub(crate) name: String,
+}
+
+/// Returns the IDs of the threads currently running in the process, or `None`
+/// if they cannot be determined on this platform.
+pub(crate) fn running_threads() -> Option<Vec<u64>> {
+    let entries = std::fs::read_dir("/proc/self/task").ok()?;
+    Some(entries.filter_map(|e| e.ok()?.file_name().to_str()?.parse().ok()).collect())
+}
+
+/// Returns the threads which are running but were not among `before`, after
+/// allowing them a short grace period to finish.
+pub(crate) fn threads_started_since(before: &[u64]) -> Vec<ThreadId> {
+    let deadline = Instant::now() + GRACE_PERIOD;
+    loop {
+        let started: Vec<u64> = running_threads()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| !before.contains(id))
+            .collect();
+        if started.is_empty() || Instant::now() >= deadline {
+            return started
+                .into_iter()
+                .map(|id| ThreadId {
+                    id,
+                    name: std::fs::read_to_string(format!("/proc/self/task/{id}/comm"))
+                        .map(|name| name.trim_end().to_string())
+                        .unwrap_or_default(),
+                })
+                .collect();
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+/// Describes the threads in `stray_threads` for a failure message or
+/// warning.
+pub(crate) fn describe_stray_threads(stray_threads: &[ThreadId]) -> String {
+    let threads = stray_threads
+        .iter()
+        .map(|thread| format!("{} ({})", thread.id, thread.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "{} thread{} started during the test {} still running at its end: {threads}",
+        stray_threads.len(),
+        if stray_threads.len() == 1 { "" } else { "s" },
+        if stray_threads.len() == 1 { "is" } else { "are" },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe_stray_threads, running_threads, threads_started_since, ThreadId};
+    use crate::prelude::*;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn describes_stray_threads() -> Result<()> {
+        let threads =
+            [ThreadId { id: 12, name: "worker".into() }, ThreadId { id: 13, name: "timer".into() }];
+
+        verify_that!(
+            describe_stray_threads(&threads),
+            eq("2 threads started during the test are still running at its end: 12 (worker), \
+                13 (timer)")
+        )
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn detects_thread_still_running() -> Result<()> {
+        let before = running_threads().unwrap();
+        let (sender, receiver) = channel::<()>();
+        let thread = std::thread::Builder::new()
+            .name("stray".into())
+            .spawn(move || {
+                let _ = receiver.recv();
+            })
+            .unwrap();
+
+        let started = threads_started_since(&before);
+        drop(sender);
+        thread.join().unwrap();
+
+        verify_that!(started, contains(field!(ThreadId.name, eq("stray"))))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn ignores_thread_which_finished() -> Result<()> {
+        let before = running_threads().unwrap();
+        std::thread::Builder::new().name("finished".into()).spawn(|| {}).unwrap().join().unwrap();
+
+        verify_that!(
+            threads_started_since(&before),
+            not(contains(field!(ThreadId.name, eq("finished"))))
+        )
+    }
+}
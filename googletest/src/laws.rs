@@ -0,0 +1,344 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks that implementations of [`Eq`], [`Ord`], and [`Hash`] uphold the
+//! laws which the standard library expects of them.
+//!
+//! Each check exercises the implementations on every pair, or triple, of the
+//! given sample values and fails with a description of the law and the
+//! samples which violate it:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::laws::{hash_consistent_with_eq, upholds_eq_laws, upholds_ord_laws};
+//! # fn should_pass() -> Result<()> {
+//! let samples = ["", "a", "A", "ab"];
+//!
+//! upholds_eq_laws(&samples)?;
+//! upholds_ord_laws(&samples)?;
+//! hash_consistent_with_eq(&samples)
+//! # }
+//! # should_pass().unwrap();
+//! ```
+//!
+//! The checks can only find violations among the samples, so these should
+//! include values which are equal without being identical, e.g., strings
+//! differing only in case for a case-insensitive comparison.
+
+use crate::Result;
+use crate::internal::source_location::SourceLocation;
+use crate::internal::test_outcome::{TestAssertionFailure, TestOutcome};
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::panic::Location;
+
+/// Checks that `==` and `!=` are reflexive, symmetric, transitive, and
+/// consistent with each other on `samples`.
+#[track_caller]
+pub fn upholds_eq_laws<T: Eq + Debug>(samples: &[T]) -> Result<()> {
+    let violation = find_eq_violation(samples);
+    report("Eq", samples, violation, Location::caller())
+}
+
+/// Checks that [`Ord::cmp`] is a total order on `samples` which agrees with
+/// [`PartialOrd`], the comparison operators, and `==`.
+#[track_caller]
+pub fn upholds_ord_laws<T: Ord + Debug>(samples: &[T]) -> Result<()> {
+    let violation = find_ord_violation(samples);
+    report("Ord", samples, violation, Location::caller())
+}
+
+/// Checks that samples which are equal also have equal hashes, as [`Hash`]
+/// requires.
+#[track_caller]
+pub fn hash_consistent_with_eq<T: Hash + Eq + Debug>(samples: &[T]) -> Result<()> {
+    let violation = find_hash_violation(samples);
+    report("Hash", samples, violation, Location::caller())
+}
+
+/// A law which the samples with the given indices violate.
+struct Violation {
+    law: String,
+    samples: Vec<usize>,
+}
+
+/// Describes the violation of `law` by the samples with the given indices.
+///
+/// The indices may repeat, but each is listed once, in ascending order.
+fn violation(law: String, samples: &[usize]) -> Option<Violation> {
+    let mut indices = samples.to_vec();
+    indices.sort_unstable();
+    indices.dedup();
+    Some(Violation { law, samples: indices })
+}
+
+fn find_eq_violation<T: Eq>(samples: &[T]) -> Option<Violation> {
+    let n = samples.len();
+    for (i, sample) in samples.iter().enumerate() {
+        #[allow(clippy::eq_op)] // Reflexivity is what is being checked.
+        if sample != sample {
+            return violation(format!("#{i} is not equal to itself"), &[i]);
+        }
+    }
+    for i in 0..n {
+        for j in 0..n {
+            let (a, b) = (&samples[i], &samples[j]);
+            if (a == b) == (a != b) {
+                return violation(
+                    format!("#{i} == #{j} is {} but #{i} != #{j} is {}", a == b, a != b),
+                    &[i, j],
+                );
+            }
+            if a == b && b != a {
+                return violation(format!("#{i} == #{j} but #{j} != #{i}"), &[i, j]);
+            }
+        }
+    }
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                if samples[i] == samples[j] && samples[j] == samples[k] && samples[i] != samples[k]
+                {
+                    return violation(
+                        format!("#{i} == #{j} and #{j} == #{k} but #{i} != #{k}"),
+                        &[i, j, k],
+                    );
+                }
+            }
+        }
+    }
+    None
+}
+
+fn find_ord_violation<T: Ord>(samples: &[T]) -> Option<Violation> {
+    let n = samples.len();
+    for i in 0..n {
+        for j in 0..n {
+            let (a, b) = (&samples[i], &samples[j]);
+            let ordering = a.cmp(b);
+            if a.partial_cmp(b) != Some(ordering) {
+                return violation(
+                    format!(
+                        "#{i}.cmp(#{j}) is {ordering:?} but #{i}.partial_cmp(#{j}) is {:?}",
+                        a.partial_cmp(b)
+                    ),
+                    &[i, j],
+                );
+            }
+            if b.cmp(a) != ordering.reverse() {
+                return violation(
+                    format!("#{i}.cmp(#{j}) is {ordering:?} but #{j}.cmp(#{i}) is {:?}", b.cmp(a)),
+                    &[i, j],
+                );
+            }
+            if (ordering == Ordering::Equal) != (a == b) {
+                return violation(
+                    format!("#{i}.cmp(#{j}) is {ordering:?} but #{i} == #{j} is {}", a == b),
+                    &[i, j],
+                );
+            }
+            for (operator, result, expected) in [
+                ("<", a < b, ordering == Ordering::Less),
+                ("<=", a <= b, ordering != Ordering::Greater),
+                (">", a > b, ordering == Ordering::Greater),
+                (">=", a >= b, ordering != Ordering::Less),
+            ] {
+                if result != expected {
+                    return violation(
+                        format!(
+                            "#{i}.cmp(#{j}) is {ordering:?} but #{i} {operator} #{j} is {result}"
+                        ),
+                        &[i, j],
+                    );
+                }
+            }
+        }
+    }
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let ordering = samples[i].cmp(&samples[j]);
+                if samples[j].cmp(&samples[k]) == ordering
+                    && samples[i].cmp(&samples[k]) != ordering
+                {
+                    return violation(
+                        format!(
+                            "#{i}.cmp(#{j}) and #{j}.cmp(#{k}) are {ordering:?} but #{i}.cmp(#{k}) \
+                             is {:?}",
+                            samples[i].cmp(&samples[k])
+                        ),
+                        &[i, j, k],
+                    );
+                }
+            }
+        }
+    }
+    None
+}
+
+fn find_hash_violation<T: Hash + Eq>(samples: &[T]) -> Option<Violation> {
+    let hashes: Vec<u64> = samples
+        .iter()
+        .map(|sample| {
+            let mut hasher = DefaultHasher::new();
+            sample.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect();
+    for i in 0..samples.len() {
+        for j in i + 1..samples.len() {
+            if samples[i] == samples[j] && hashes[i] != hashes[j] {
+                return violation(
+                    format!("#{i} == #{j} but their hashes {} and {} differ", hashes[i], hashes[j]),
+                    &[i, j],
+                );
+            }
+        }
+    }
+    None
+}
+
+fn report<T: Debug>(
+    trait_name: &str,
+    samples: &[T],
+    violation: Option<Violation>,
+    caller: &'static Location<'static>,
+) -> Result<()> {
+    let Some(violation) = violation else {
+        TestOutcome::record_assertion(true);
+        return Ok(());
+    };
+    TestOutcome::record_assertion(false);
+    let location = SourceLocation::new(caller.file(), caller.line(), caller.column());
+    let values = violation
+        .samples
+        .iter()
+        .map(|index| format!("  #{index} = {:?}", samples[*index]))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Err(TestAssertionFailure::create(format!(
+        "The {trait_name} implementation violates a law: {}\n{values}\n{location}",
+        violation.law
+    ))
+    .track_if_ignored(location))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_consistent_with_eq, upholds_eq_laws, upholds_ord_laws};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::cmp::Ordering;
+    use std::hash::{Hash, Hasher};
+
+    /// Numbers which compare equal when they differ by at most one, which is
+    /// not transitive.
+    #[derive(Debug)]
+    struct Approximate(i32);
+
+    impl PartialEq for Approximate {
+        fn eq(&self, other: &Self) -> bool {
+            (self.0 - other.0).abs() <= 1
+        }
+    }
+
+    impl Eq for Approximate {}
+
+    /// A name which compares case-insensitively but hashes case-sensitively.
+    #[derive(Debug)]
+    struct Name(&'static str);
+
+    impl PartialEq for Name {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.eq_ignore_ascii_case(other.0)
+        }
+    }
+
+    impl Eq for Name {}
+
+    impl Hash for Name {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+        }
+    }
+
+    /// A value whose ordering ignores its payload, unlike its equality.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Keyed(u8, &'static str);
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn passes_for_lawful_implementations() -> Result<()> {
+        let samples = [0, 1, 1, -5];
+
+        upholds_eq_laws(&samples)?;
+        upholds_ord_laws(&samples)?;
+        hash_consistent_with_eq(&samples)
+    }
+
+    #[test]
+    fn reports_non_transitive_equality() -> Result<()> {
+        let result = upholds_eq_laws(&[Approximate(0), Approximate(1), Approximate(2)]);
+
+        verify_that!(
+            result,
+            err(displays_as(starts_with(indoc! {"
+                The Eq implementation violates a law: #0 == #1 and #1 == #2 but #0 != #2
+                  #0 = Approximate(0)
+                  #1 = Approximate(1)
+                  #2 = Approximate(2)
+            "})))
+        )
+    }
+
+    #[test]
+    fn reports_hash_inconsistent_with_equality() -> Result<()> {
+        let result = hash_consistent_with_eq(&[Name("alice"), Name("bob"), Name("Alice")]);
+
+        verify_that!(
+            result,
+            err(displays_as(all!(
+                starts_with("The Hash implementation violates a law: #0 == #2 but their hashes"),
+                contains_substring("  #0 = Name(\"alice\")\n  #2 = Name(\"Alice\")\n")
+            )))
+        )
+    }
+
+    #[test]
+    fn reports_ordering_inconsistent_with_equality() -> Result<()> {
+        let result = upholds_ord_laws(&[Keyed(1, "a"), Keyed(1, "b")]);
+
+        verify_that!(
+            result,
+            err(displays_as(starts_with(indoc! {r#"
+                The Ord implementation violates a law: #0.cmp(#1) is Equal but #0 == #1 is false
+                  #0 = Keyed(1, "a")
+                  #1 = Keyed(1, "b")
+            "#})))
+        )
+    }
+}
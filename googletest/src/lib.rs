@@ -16,28 +16,48 @@
 
 extern crate googletest_macro;
 
+pub mod allocation;
 #[macro_use]
 pub mod assertions;
+pub mod clock;
+pub mod config;
+pub mod fixture;
+pub mod formatters;
+pub mod generators;
 pub mod internal;
+pub mod laws;
 pub mod matcher;
 pub mod matcher_support;
 pub mod matchers;
+pub mod opaque;
+pub mod poll;
+pub mod test_fs;
+pub mod test_scope;
 
 pub mod prelude {
     pub use super::matchers::*;
     pub use super::GoogleTestSupport;
     pub use super::IntoTestResult;
     pub use super::Result;
+    pub use super::opaque::opaque;
     // Assert macros
-    pub use super::{assert_that, expect_pred, expect_that, fail, verify_pred, verify_that};
+    #[cfg(feature = "tokio")]
+    pub use super::expect_that_within;
+    pub use super::{
+        assert_that, expect_pred, expect_that, fail, verify_assertion_count, verify_pred,
+        verify_that,
+    };
     // Matcher macros
     pub use super::{
-        all, contains_each, elements_are, field, is_contained_in, matches_pattern, pat, pointwise,
-        property, tuple, unordered_elements_are,
+        all, any, contains_each, contains_run, contains_subsequence, elements_are,
+        ends_with_elements, field, is_contained_in, iterates_as, matches_pattern, pat, pointwise,
+        property, result_of, starts_with_elements, tuple, unordered_elements_are,
+        unordered_entries_are,
     };
 }
 
-pub use googletest_macro::test;
+pub use config::config;
+pub use googletest_macro::{matcher_fn, property_test, test};
 
 // For backwards compatibility.
 #[deprecated(since = "0.5.0", note = "Use googletest::test instead")]
@@ -12,14 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::config::config;
+use crate::formatters::{format_value, non_static_type_id};
 use crate::internal::source_location::SourceLocation;
 use crate::internal::test_outcome::TestAssertionFailure;
+use crate::matcher_support::debug_layout::layout_debug;
+use crate::matcher_support::truncation::{truncate_bytes, truncate_containers, truncate_lines};
+use crate::matchers::eq_deref_of_matcher::{eq_deref_of, EqDerefOfMatcher};
+use std::any::TypeId;
+use std::cell::RefCell;
 use std::fmt::{Debug, Display, Formatter, Result};
+use std::sync::Arc;
 
 /// An interface for checking an arbitrary condition on a datum.
 pub trait Matcher {
     /// The type against which this matcher matches.
-    type ActualT: Debug + ?Sized;
+    type ActualT: TestDebug + ?Sized;
 
     /// Returns whether the condition matches the datum `actual`.
     ///
@@ -99,11 +107,13 @@ pub trait Matcher {
     /// the expected value and vice versa.
     ///
     /// This implementation should be overridden in any matcher which contains
-    /// one or more inner matchers. The implementation should invoke
-    /// `explain_match` on the inner matchers, so that the generated match
-    /// explanation also reflects their implementation. Without this, the match
-    /// explanation of the inner matchers will not be able to make use of the
-    /// actual value at all.
+    /// one or more inner matchers. The implementation should obtain the
+    /// explanations of the inner matchers with [`explain`], so that the
+    /// generated match explanation also reflects their implementation, and
+    /// attach them with [`MatchExplanation::with_child`], so that the
+    /// explanation tree records which inner matchers were consulted. Without
+    /// this, the match explanation of the inner matchers will not be able to
+    /// make use of the actual value at all.
     ///
     /// For example, the `explain_match` implementation of the matcher
     /// [`points_to`][crate::matchers::points_to] defers immediately to the
@@ -111,25 +121,201 @@ pub trait Matcher {
     ///
     /// ```ignore
     /// fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
-    ///     self.expected.explain_match(actual.deref())
+    ///     let inner = explain(&self.expected, actual.deref());
+    ///     MatchExplanation::create(inner.to_string()).with_child(inner)
     /// }
     /// ```
     ///
     /// The matcher can also provide some additional context before deferring to
-    /// an inner matcher. In that case it should invoke `explain_match` on the
-    /// inner matcher at a point where a relative clause would fit. For example:
+    /// an inner matcher. In that case it should place the inner explanation at
+    /// a point where a relative clause would fit. For example:
     ///
     /// ```ignore
     /// fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
-    ///     MatchExplanation::create(
-    ///         format!("which points to a value {}", self.expected.explain_match(actual.deref()))
-    ///             //   ^^^^^^^^^^^^^^^^^^^^ Expands to "points to a value which ..."
-    ///     )
+    ///     let inner = explain(&self.expected, actual.deref());
+    ///     MatchExplanation::create(format!("which points to a value {inner}"))
+    ///         //                            ^^^^^^^^^^^^^^^^^^^^ Expands to "points to a value which ..."
+    ///         .with_child(inner)
     /// }
     /// ```
     fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
         MatchExplanation::create(format!("which {}", self.describe(self.matches(actual))))
     }
+
+    /// The name under which this matcher appears in a [`MatchExplanation`].
+    ///
+    /// The default implementation returns the name of the implementing type
+    /// without its module path or generic parameters, such as `EqMatcher`.
+    fn matcher_name(&self) -> &'static str {
+        short_type_name(std::any::type_name::<Self>())
+    }
+}
+
+/// A boxed matcher against `ActualT` whose concrete type is erased.
+///
+/// Such matchers can be stored in collections, returned from trait methods,
+/// and chosen at runtime. Use
+/// [`boxed`][crate::matchers::BoxedMatcherExt::boxed] to construct one. The
+/// lifetime `'a` bounds any data the matcher borrows; it is `'static` for
+/// matchers owning their expected values.
+///
+/// ```
+/// # use googletest::matcher::BoxMatcher;
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let checks: Vec<BoxMatcher<'static, i32>> = vec![gt(0).boxed(), lt(10).boxed()];
+/// for check in &checks {
+///     verify_that!(5, check)?;
+/// }
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+pub type BoxMatcher<'a, ActualT> = Box<dyn Matcher<ActualT = ActualT> + 'a>;
+
+/// A boxed matcher matches exactly what the matcher it contains matches.
+///
+/// This allows a matcher to be chosen at runtime or its concrete type to be
+/// erased:
+///
+/// ```
+/// # use googletest::matcher::Matcher;
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// # let exact = false;
+/// let matcher: Box<dyn Matcher<ActualT = i32>> =
+///     if exact { Box::new(eq(1)) } else { Box::new(gt(0)) };
+/// verify_that!(2, matcher)
+/// # }
+/// # should_pass().unwrap();
+/// ```
+impl<M: Matcher + ?Sized> Matcher for Box<M> {
+    type ActualT = M::ActualT;
+
+    fn matches(&self, actual: &Self::ActualT) -> MatcherResult {
+        (**self).matches(actual)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        (**self).describe(matcher_result)
+    }
+
+    fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
+        (**self).explain_match(actual)
+    }
+
+    fn matcher_name(&self) -> &'static str {
+        (**self).matcher_name()
+    }
+}
+
+/// A reference to a matcher matches exactly what the matcher matches.
+impl<M: Matcher + ?Sized> Matcher for &M {
+    type ActualT = M::ActualT;
+
+    fn matches(&self, actual: &Self::ActualT) -> MatcherResult {
+        (**self).matches(actual)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        (**self).describe(matcher_result)
+    }
+
+    fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
+        (**self).explain_match(actual)
+    }
+
+    fn matcher_name(&self) -> &'static str {
+        (**self).matcher_name()
+    }
+}
+
+/// A [`Matcher`] against `ActualT`.
+///
+/// This is implemented for every matcher against `ActualT`. Functions which
+/// take a matcher against a particular type bound it by this trait rather than
+/// by `Matcher<ActualT = ...>`, so that a matcher against the wrong type is
+/// reported with a message naming both types at the matcher argument.
+#[rustversion::attr(
+    since(1.78),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not a matcher against `{ActualT}`",
+        label = "expected a matcher against `{ActualT}`"
+    )
+)]
+pub trait MatcherOf<ActualT: TestDebug + ?Sized>: Matcher<ActualT = ActualT> {}
+
+impl<M: Matcher + ?Sized> MatcherOf<M::ActualT> for M {}
+
+/// A reference to a value which can be converted into a [`Matcher`] against
+/// `ActualT`.
+///
+/// A reference to a matcher converts into that reference, which matches what
+/// the matcher matches. A reference to any other value which can be compared
+/// with `ActualT` via [`PartialEq`] converts into the matcher
+/// [`eq_deref_of`][crate::matchers::eq_deref_of] of the reference. Container
+/// macros such as [`elements_are!`][crate::elements_are] accept their
+/// arguments through this trait, so that plain values can be used in place of
+/// explicit `eq(...)` matchers:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], elements_are![1, gt(1), 3])?;
+/// verify_that!(vec!["a".to_string()], unordered_elements_are!["a"])?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// Since only references are converted, the matchers and values given to the
+/// macros are borrowed rather than moved.
+///
+/// The parameter `KindT` is either [`MatcherKind`] or [`ValueKind`]. It only
+/// serves to keep the two implementations apart, is inferred, and never needs
+/// to be named.
+#[rustversion::attr(
+    since(1.78),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can neither match nor be compared with a value of type `{ActualT}`",
+        label = "expected a matcher of `{ActualT}` or a value comparable with it",
+        note = "if `{Self}` is a matcher, it matches a different type, or one of the matchers \
+                it contains does not match the type to which it is applied",
+        note = "matchers of the elements of a container in order, such as `elements_are!`, \
+                require the container to implement `OrderedContainer`; use \
+                `unordered_elements_are!` for containers such as `HashMap` and `HashSet`, \
+                whose iteration order is unspecified"
+    )
+)]
+pub trait IntoMatcher<ActualT: ?Sized, KindT> {
+    /// The matcher into which this reference converts.
+    type Matcher: Matcher<ActualT = ActualT>;
+
+    /// Converts this reference into a matcher.
+    fn into_matcher(self) -> Self::Matcher;
+}
+
+/// The [`IntoMatcher`] kind of a reference to a [`Matcher`].
+pub enum MatcherKind {}
+
+/// The [`IntoMatcher`] kind of a reference to a value which is compared with
+/// the actual value for equality.
+pub enum ValueKind {}
+
+impl<'a, M: Matcher + ?Sized> IntoMatcher<M::ActualT, MatcherKind> for &'a M {
+    type Matcher = &'a M;
+
+    fn into_matcher(self) -> &'a M {
+        self
+    }
+}
+
+impl<'a, A: Debug + ?Sized, T: PartialEq<A> + Debug> IntoMatcher<A, ValueKind> for &'a T {
+    type Matcher = EqDerefOfMatcher<A, &'a T>;
+
+    fn into_matcher(self) -> EqDerefOfMatcher<A, &'a T> {
+        eq_deref_of(self)
+    }
 }
 
 /// Constructs a [`TestAssertionFailure`] reporting that the given `matcher`
@@ -137,23 +323,96 @@ pub trait Matcher {
 ///
 /// The parameter `actual_expr` contains the expression which was evaluated to
 /// obtain `actual`.
-pub(crate) fn create_assertion_failure<T: Debug + ?Sized>(
+pub(crate) fn create_assertion_failure<T: TestDebug + ?Sized>(
     matcher: &impl Matcher<ActualT = T>,
     actual: &T,
     actual_expr: &'static str,
     source_location: SourceLocation,
 ) -> TestAssertionFailure {
-    TestAssertionFailure::create(format!(
+    let explanation = explain(matcher, actual);
+    let mut failure = TestAssertionFailure::create(format!(
         "Value of: {}\n\
              Expected: {}\n\
-             Actual: {:#?}, {}\n\
+             Actual: {}, {}\n\
              {}",
         actual_expr,
         matcher.describe(MatcherResult::Matches),
-        actual,
-        matcher.explain_match(actual),
+        explanation.actual().unwrap_or_default(),
+        explanation,
         source_location,
-    ))
+    ));
+    failure.explanation = Some(Box::new(explanation));
+    failure
+}
+
+/// Renders `actual` as it appears in failure messages: with the formatter
+/// registered for its type, if any, laid out and truncated as configured.
+pub(crate) fn render_actual<T: TestDebug + ?Sized>(actual: &T) -> String {
+    let actual_debug = format_value::<T>(format!("{:#?}", AsDebug(actual)));
+    let config = config();
+    let actual_debug = match config.max_container_elements {
+        Some(max_elements) => truncate_containers(&actual_debug, max_elements),
+        None => actual_debug,
+    };
+    let actual_debug = layout_debug(actual_debug);
+    let actual_debug = match config.max_actual_lines {
+        Some(max_lines) => truncate_lines(&actual_debug, max_lines),
+        None => actual_debug,
+    };
+    truncate_bytes(&actual_debug, config.max_actual_bytes)
+}
+
+/// Renders actual values in failure messages.
+///
+/// This is implemented for every type implementing [`Debug`], whose output
+/// is used. A type which does not implement [`Debug`] can still be matched
+/// if it implements this trait instead, for example with a placeholder
+/// rendering or one showing only the relevant parts of the value:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use googletest::matcher::TestDebug;
+/// # use std::fmt::{Formatter, Result as FmtResult};
+/// struct Connection {
+///     // Some fields which do not implement Debug...
+///     peer: String,
+/// }
+///
+/// impl TestDebug for Connection {
+///     fn test_fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+///         write!(f, "Connection to {}", self.peer)
+///     }
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// let connection = Connection { peer: "example.com".into() };
+/// verify_that!(connection, predicate(|c: &Connection| c.peer.ends_with(".com")))
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// Values of third-party types which implement neither trait can be wrapped
+/// with [`opaque`][crate::opaque::opaque] to be matched.
+pub trait TestDebug {
+    /// Formats the value like [`Debug::fmt`], respecting the alternate flag
+    /// `{:#?}` for pretty-printing if sensible.
+    fn test_fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
+impl<T: Debug + ?Sized> TestDebug for T {
+    fn test_fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Adapts a reference to a [`TestDebug`] value to [`Debug`], so that it can
+/// be formatted with `{:?}`.
+pub(crate) struct AsDebug<'a, T: ?Sized>(pub(crate) &'a T);
+
+impl<T: TestDebug + ?Sized> Debug for AsDebug<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.test_fmt(f)
+    }
 }
 
 /// The result of applying a [`Matcher`] on an actual value.
@@ -195,19 +454,352 @@ impl MatcherResult {
 /// Human-readable explanation of why a value was matched or not matched by a
 /// matcher.
 ///
-/// This is formatted into an assertion failure message.
+/// This is formatted into an assertion failure message. Its [`Display`]
+/// output is the text which the matcher produced, which already includes the
+/// explanations of any inner matchers.
+///
+/// Beyond that text, an explanation forms a tree mirroring the structure of
+/// the matcher: each node records the name of the matcher which produced it,
+/// the rendering of the value it was given, and the explanations of the
+/// inner matchers it consulted. Tooling can walk this tree, which a failed
+/// assertion carries in the `explanation` field of its error, to find out
+/// which nested matcher failed. With the `serde` feature enabled, the tree implements
+/// `serde::Serialize`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use googletest::matcher::explain;
+/// let matcher = some(eq(2));
+/// let explanation = explain(&matcher, &Some(3));
+///
+/// assert_eq!(explanation.to_string(), "which has a value which isn't equal to 2");
+/// assert_eq!(explanation.matcher(), Some("SomeMatcher"));
+/// assert_eq!(explanation.children()[0].matcher(), Some("EqMatcher"));
+/// assert_eq!(explanation.children()[0].actual(), Some("3"));
+/// ```
 #[derive(Debug, Clone)]
 #[non_exhaustive]
-pub struct MatchExplanation(String);
+pub struct MatchExplanation {
+    text: String,
+    matcher: Option<String>,
+    actual: Option<Arc<str>>,
+    children: Vec<MatchExplanation>,
+}
+
+/// Identifies a borrowed value by its address, its pointer metadata (the
+/// length of a slice or the vtable of a trait object), and its type.
+///
+/// While a value is borrowed, no other value of the same type can have the
+/// same identity, except for zero-sized values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ValueId {
+    pointer: [usize; 2],
+    type_id: TypeId,
+}
+
+impl ValueId {
+    fn of<T: ?Sized>(value: &T) -> Self {
+        let pointer: *const T = value;
+        let mut words = [0usize; 2];
+        assert!(std::mem::size_of::<*const T>() <= std::mem::size_of_val(&words));
+        // SAFETY: The destination is large enough, as asserted, and any bit
+        // pattern is a valid `usize`.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &pointer as *const *const T as *const u8,
+                words.as_mut_ptr() as *mut u8,
+                std::mem::size_of::<*const T>(),
+            );
+        }
+        Self { pointer: words, type_id: non_static_type_id::<T>() }
+    }
+}
 
 impl MatchExplanation {
+    /// Creates an explanation with the given text and no children.
+    ///
+    /// The matcher name and actual value are filled in by [`explain`].
     pub fn create(explanation: String) -> Self {
-        Self(explanation)
+        Self { text: explanation, matcher: None, actual: None, children: vec![] }
+    }
+
+    /// Adds `child` as the explanation of an inner matcher.
+    ///
+    /// This does not change the text of `self`, which should already
+    /// incorporate the text of `child` where appropriate.
+    pub fn with_child(mut self, child: MatchExplanation) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Adds each of `children` as the explanation of an inner matcher.
+    pub fn with_children(mut self, children: impl IntoIterator<Item = MatchExplanation>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// The text of this explanation, as it appears in a failure message.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The name of the matcher type which produced this explanation, without
+    /// its module path or generic parameters.
+    pub fn matcher(&self) -> Option<&str> {
+        self.matcher.as_deref()
+    }
+
+    /// The value this explanation is about, rendered like the actual value in
+    /// failure messages.
+    ///
+    /// This is the `Debug` output of the value, rewritten by the formatter
+    /// registered for its type, if any, and laid out and truncated as
+    /// configured.
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_deref()
+    }
+
+    /// The explanations of the inner matchers, in the order they were
+    /// consulted.
+    pub fn children(&self) -> &[MatchExplanation] {
+        &self.children
     }
 }
 
 impl Display for MatchExplanation {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.text)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MatchExplanation {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MatchExplanation", 4)?;
+        state.serialize_field("matcher", &self.matcher)?;
+        state.serialize_field("actual", &self.actual())?;
+        state.serialize_field("explanation", &self.text)?;
+        state.serialize_field("children", &self.children)?;
+        state.end()
+    }
+}
+
+/// Invokes [`Matcher::explain_match`] and records in the result the name of
+/// `matcher` and the rendering of `actual`.
+///
+/// Matchers such as [`not`][crate::matchers::not] and
+/// [`any_of`][crate::matchers::any_of] explain the same value with their inner
+/// matchers. It is then rendered only once and the rendering is shared.
+///
+/// Matchers with inner matchers should use this rather than calling
+/// `explain_match` on them directly, and attach the result with
+/// [`MatchExplanation::with_child`], so that the explanation tree reflects
+/// which inner matcher was consulted.
+pub fn explain<MatcherT: Matcher + ?Sized>(
+    matcher: &MatcherT,
+    actual: &MatcherT::ActualT,
+) -> MatchExplanation {
+    let actual_id = ValueId::of(actual);
+    EXPLAINED_VALUES.with(|values| values.borrow_mut().push((actual_id, None)));
+    let _frame = ExplainedValueFrame;
+    let mut explanation = matcher.explain_match(actual);
+    explanation.matcher = Some(matcher.matcher_name().to_string());
+    explanation.actual = Some(shared_rendering(actual_id, actual));
+    explanation
+}
+
+thread_local! {
+    /// The values of the ongoing invocations of [`explain`] on this thread,
+    /// outermost first, each with its rendering once it has been made.
+    ///
+    /// Each of these values stays borrowed until its invocation returns, so
+    /// another value with the same [`ValueId`] cannot appear in the meantime.
+    static EXPLAINED_VALUES: RefCell<Vec<(ValueId, Option<Arc<str>>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Removes the innermost entry of [`EXPLAINED_VALUES`] when the invocation of
+/// [`explain`] which added it ends, even by a panic.
+struct ExplainedValueFrame;
+
+impl Drop for ExplainedValueFrame {
+    fn drop(&mut self) {
+        EXPLAINED_VALUES.with(|values| values.borrow_mut().pop());
+    }
+}
+
+/// Returns the rendering of `actual`, identified by `actual_id`, reusing the
+/// one made by an ongoing invocation of [`explain`] on the same value.
+///
+/// A new rendering is kept with the outermost such invocation, so that it is
+/// shared with every other one until that returns.
+fn shared_rendering<T: TestDebug + ?Sized>(actual_id: ValueId, actual: &T) -> Arc<str> {
+    let existing = EXPLAINED_VALUES.with(|values| {
+        values
+            .borrow()
+            .iter()
+            .find(|(id, _)| *id == actual_id)
+            .and_then(|(_, rendering)| rendering.clone())
+    });
+    if let Some(rendering) = existing {
+        return rendering;
+    }
+    // The values are not borrowed while rendering, since a formatter may
+    // itself explain values.
+    let rendering: Arc<str> = render_actual(actual).into();
+    EXPLAINED_VALUES.with(|values| {
+        if let Some((_, slot)) = values.borrow_mut().iter_mut().find(|(id, _)| *id == actual_id) {
+            *slot = Some(rendering.clone());
+        }
+    });
+    rendering
+}
+
+/// Strips references, the module path, and generic parameters from a type
+/// name, leaving for example `EqMatcher`.
+fn short_type_name(type_name: &'static str) -> &'static str {
+    let path = type_name.split('<').next().unwrap_or(type_name);
+    let path = path.trim_start_matches('&').trim_start_matches("mut ").trim_start_matches("dyn ");
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn verify_that_accepts_plain_value() -> Result<()> {
+        verify_that!(42, 42)
+    }
+
+    #[test]
+    fn verify_that_accepts_plain_value_comparable_with_actual() -> Result<()> {
+        verify_that!("A string".to_string(), "A string")
+    }
+
+    #[test]
+    fn verify_that_explains_mismatch_with_plain_value() -> Result<()> {
+        let result = verify_that!(42, 123);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Value of: 42\nExpected: is equal to 123\nActual: 42,"
+            )))
+        )
+    }
+
+    #[test]
+    fn verify_that_matches_value_behind_references() -> Result<()> {
+        let value = 42;
+        let reference = &value;
+
+        verify_that!(reference, eq(42))?;
+        verify_that!(&reference, gt(41))?;
+        verify_that!(&"A string".to_string(), "A string")
+    }
+
+    #[test]
+    fn verify_that_prefers_matcher_of_reference() -> Result<()> {
+        let value = 42;
+
+        verify_that!(&value, eq(&42))
+    }
+
+    #[test]
+    fn verify_that_explains_mismatch_of_value_behind_references() -> Result<()> {
+        let result = verify_that!(&&42, eq(123));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Value of: &&42\nExpected: is equal to 123\nActual: 42,"
+            )))
+        )
+    }
+
+    #[test]
+    fn expect_that_accepts_plain_value() {
+        expect_that!(vec![1, 2], vec![1, 2]);
+    }
+
+    #[test]
+    fn verify_assertion_count_accepts_plain_value() -> Result<()> {
+        verify_assertion_count!(2, {
+            expect_that!(1, 1);
+            expect_that!(2, 2);
+        })
+    }
+
+    #[test]
+    fn failure_records_explanation_tree() -> Result<()> {
+        let failure =
+            verify_that!(Some(vec![1, 5]), some(elements_are![eq(1), gt(7)])).unwrap_err();
+        let explanation = failure.explanation.unwrap();
+
+        verify_that!(explanation.matcher(), some(eq("SomeMatcher")))?;
+        verify_that!(explanation.actual(), some(eq(format!("{:#?}", Some(vec![1, 5])))))?;
+        let elements = &explanation.children()[0];
+        verify_that!(elements.matcher(), some(eq("ElementsAre")))?;
+        verify_that!(elements.actual(), some(eq(format!("{:#?}", vec![1, 5]))))?;
+        verify_that!(elements.children().len(), eq(1))?;
+        verify_that!(elements.children()[0].matcher(), some(eq("GtMatcher")))?;
+        verify_that!(elements.children()[0].actual(), some(eq("5")))?;
+        verify_that!(elements.children()[0].text(), eq("which is less than or equal to 7"))
+    }
+
+    #[test]
+    fn explanation_renders_actual_with_registered_formatter() -> Result<()> {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Celsius(f64);
+        crate::formatters::register_formatter::<Celsius>(|debug| {
+            debug.replace("Celsius(\n    ", "").replace(",\n)", " °C")
+        });
+
+        let explanation = super::explain(&anything(), &Celsius(21.5));
+
+        verify_that!(explanation.actual(), some(eq("21.5 °C")))
+    }
+
+    #[test]
+    fn failure_renders_value_explained_by_several_matchers_once() -> Result<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static RENDERINGS: AtomicUsize = AtomicUsize::new(0);
+        struct Reading(u32);
+        impl std::fmt::Debug for Reading {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                RENDERINGS.fetch_add(1, Ordering::SeqCst);
+                write!(f, "Reading({})", self.0)
+            }
+        }
+        let is_three = || predicate(|reading: &Reading| reading.0 == 3);
+
+        let result =
+            verify_that!(Reading(3), not(any_of(vec![Box::new(is_three()), Box::new(is_three())])));
+
+        verify_that!(result, err(displays_as(contains_substring("Actual: Reading(3),"))))?;
+        verify_that!(RENDERINGS.load(Ordering::SeqCst), eq(1))
+    }
+
+    #[test]
+    fn explanation_tree_displays_as_flat_text() -> Result<()> {
+        let explanation = super::explain(&not(some(eq(2))), &Some(2));
+
+        verify_that!(explanation.to_string(), eq("which has a value which is equal to 2"))?;
+        verify_that!(explanation.matcher(), some(eq("NotMatcher")))?;
+        verify_that!(explanation.children()[0].to_string(), eq(explanation.to_string()))?;
+        verify_that!(explanation.children()[0].matcher(), some(eq("SomeMatcher")))
+    }
+
+    #[test]
+    fn explanation_of_leaf_matcher_has_no_children() -> Result<()> {
+        let explanation = super::explain(&eq(2), &3);
+
+        verify_that!(explanation.children().len(), eq(0))
     }
 }
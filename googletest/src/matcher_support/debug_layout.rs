@@ -0,0 +1,230 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{config, DebugStyle};
+use crate::matcher::{AsDebug, TestDebug};
+
+/// The number of spaces by which `{:#?}` indents each nesting level.
+const DEBUG_INDENTATION: usize = 4;
+
+/// Renders the `Debug` output of `value` for a failure message, laid out as
+/// configured through [`Config::debug_style`][crate::config::Config::debug_style],
+/// [`Config::max_debug_depth`][crate::config::Config::max_debug_depth], and
+/// [`Config::max_debug_width`][crate::config::Config::max_debug_width].
+pub(crate) fn debug_string<T: TestDebug + ?Sized>(value: &T) -> String {
+    layout_debug(format!("{:#?}", AsDebug(value)))
+}
+
+/// Lays out `debug`, the pretty-printed (`{:#?}`) `Debug` output of a value,
+/// as configured.
+pub(crate) fn layout_debug(debug: String) -> String {
+    let config = config();
+    let max_width = match config.debug_style {
+        DebugStyle::Compact => Some(usize::MAX),
+        DebugStyle::Pretty => config.max_debug_width,
+    };
+    let debug = match config.max_debug_depth {
+        Some(max_depth) => limit_depth(&debug, max_depth),
+        None => debug,
+    };
+    match max_width {
+        Some(max_width) => join_narrow_blocks(&debug, max_width),
+        None => debug,
+    }
+}
+
+/// Replaces the contents of every nesting level below `max_depth` in
+/// `debug`, which must be pretty-printed `Debug` output, with `...`.
+///
+/// The fields or elements of the value itself are at depth 1.
+fn limit_depth(debug: &str, max_depth: usize) -> String {
+    let mut output = vec![];
+    let mut open_indents: Vec<usize> = vec![];
+    for line in debug.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if open_indents.last() == Some(&indent) && trimmed.starts_with([']', '}', ')']) {
+            open_indents.pop();
+        }
+        if open_indents.len() <= max_depth {
+            output.push(line.to_string());
+        }
+        if trimmed.ends_with(['[', '{', '(']) {
+            open_indents.push(indent);
+            if open_indents.len() == max_depth + 1 {
+                output.push(format!("{:indent$}...", "", indent = indent + DEBUG_INDENTATION));
+            }
+        }
+    }
+    output.join("\n")
+}
+
+/// Joins every nesting level of `debug`, which must be pretty-printed
+/// `Debug` output, onto a single line if it fits into `max_width`
+/// characters, including its indentation, as it would be rendered by `{:?}`.
+fn join_narrow_blocks(debug: &str, max_width: usize) -> String {
+    struct Block {
+        indent: usize,
+        start: usize,
+        joinable: bool,
+    }
+
+    let mut output: Vec<String> = vec![];
+    let mut blocks: Vec<Block> = vec![];
+    for line in debug.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if blocks.last().map_or(false, |block| block.indent == indent)
+            && trimmed.starts_with([']', '}', ')'])
+        {
+            let block = blocks.pop().unwrap();
+            let joined = block.joinable.then(|| join_block(&output[block.start..], trimmed));
+            match joined {
+                Some(joined) if joined.chars().count() <= max_width => {
+                    output.truncate(block.start);
+                    output.push(joined);
+                }
+                _ => {
+                    if let Some(parent) = blocks.last_mut() {
+                        parent.joinable = false;
+                    }
+                    output.push(line.to_string());
+                }
+            }
+            continue;
+        }
+        if trimmed.ends_with(['[', '{', '(']) {
+            blocks.push(Block { indent, start: output.len(), joinable: true });
+        }
+        output.push(line.to_string());
+    }
+    output.join("\n")
+}
+
+/// Joins the `lines` of a nesting level, starting with its opening line, and
+/// its closing line `close` onto a single line.
+fn join_block(lines: &[String], close: &str) -> String {
+    let open = lines[0].trim_end();
+    let items = lines[1..].iter().map(|line| line.trim()).collect::<Vec<_>>().join(" ");
+    let items = items.strip_suffix(',').unwrap_or(&items);
+    let opens_struct = open.ends_with('{') && !is_map_opening(open.trim_start());
+    if opens_struct { format!("{open} {items} {close}") } else { format!("{open}{items}{close}") }
+}
+
+/// Returns whether `line`, which ends with `{`, opens a map rather than a
+/// struct.
+fn is_map_opening(line: &str) -> bool {
+    line == "{" || line.ends_with(": {")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join_narrow_blocks, layout_debug, limit_depth};
+    use crate::config::DebugStyle;
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Outer {
+        name: &'static str,
+        inner: Inner,
+        values: Vec<i32>,
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Inner {
+        flag: bool,
+        pair: (i32, i32),
+    }
+
+    fn outer() -> Outer {
+        Outer { name: "outer", inner: Inner { flag: true, pair: (1, 2) }, values: vec![3, 4] }
+    }
+
+    #[test]
+    fn limits_depth_of_nested_values() -> Result<()> {
+        verify_that!(
+            limit_depth(&format!("{:#?}", outer()), 1),
+            eq(indoc!(
+                r#"
+                Outer {
+                    name: "outer",
+                    inner: Inner {
+                        ...
+                    },
+                    values: [
+                        ...
+                    ],
+                }"#
+            ))
+        )
+    }
+
+    #[test]
+    fn limits_depth_to_top_level() -> Result<()> {
+        verify_that!(limit_depth(&format!("{:#?}", outer()), 0), eq("Outer {\n    ...\n}"))
+    }
+
+    #[test]
+    fn joins_blocks_which_fit_into_width() -> Result<()> {
+        verify_that!(
+            join_narrow_blocks(&format!("{:#?}", outer()), 50),
+            eq(indoc!(
+                r#"
+                Outer {
+                    name: "outer",
+                    inner: Inner { flag: true, pair: (1, 2) },
+                    values: [3, 4],
+                }"#
+            ))
+        )
+    }
+
+    #[test]
+    fn joins_blocks_like_compact_debug_output() -> Result<()> {
+        let value = (outer(), BTreeMap::from([(1, "a"), (2, "b")]), vec![Some(1), None]);
+
+        verify_that!(
+            join_narrow_blocks(&format!("{value:#?}"), usize::MAX),
+            eq(format!("{value:?}"))
+        )
+    }
+
+    #[test]
+    fn leaves_output_unchanged_by_default() -> Result<()> {
+        let debug = format!("{:#?}", outer());
+
+        verify_that!(layout_debug(debug.clone()), eq(debug))
+    }
+
+    #[test]
+    fn compact_style_renders_on_single_line() -> Result<()> {
+        let _guard = crate::config().debug_style(DebugStyle::Compact).apply();
+
+        verify_that!(layout_debug(format!("{:#?}", outer())), eq(format!("{:?}", outer())))
+    }
+
+    #[test]
+    fn compact_style_respects_depth() -> Result<()> {
+        let _guard = crate::config().debug_style(DebugStyle::Compact).max_debug_depth(1).apply();
+
+        verify_that!(
+            layout_debug(format!("{:#?}", outer())),
+            eq(r#"Outer { name: "outer", inner: Inner { ... }, values: [...] }"#)
+        )
+    }
+}
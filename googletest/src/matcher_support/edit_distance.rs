@@ -15,15 +15,117 @@
 use std::fmt::Debug;
 use std::ops::Index;
 
+/// The largest number of pairs of elements for which [`edit_list`] computes a
+/// minimal edit list with the weighted dynamic programming algorithm.
+const MAX_TABLE_CELLS: usize = 1 << 16;
+
+/// The largest number of comparisons [`edit_list`] spends on aligning larger
+/// sequences, beyond which it gives up on diffing them.
+const MAX_DIFF_WORK: usize = 10_000_000;
+
 /// Compute the edit list of `left` and `right`.
 ///
-/// See <https://en.wikipedia.org/wiki/Edit_distance>
+/// Short sequences are compared pairwise, weighting each pair by
+/// [`Distance::distance`], so that similar elements are preferably paired as
+/// [`Edit::Both`]. Longer sequences are first aligned on their equal
+/// elements with Myers' O(ND) diff algorithm in linear space, and then only
+/// the stretches between those are compared pairwise, if they are short.
+///
+/// Returns `None` if the sequences are too large and too different to diff
+/// within a reasonable time.
+///
+/// See <https://en.wikipedia.org/wiki/Edit_distance> and
+/// <http://www.xmailserver.org/diff2.pdf>
 pub(crate) fn edit_list<T: Distance + Copy>(
     left: impl IntoIterator<Item = T>,
     right: impl IntoIterator<Item = T>,
-) -> Vec<Edit<T>> {
+) -> Option<Vec<Edit<T>>> {
     let left: Vec<_> = left.into_iter().collect();
     let right: Vec<_> = right.into_iter().collect();
+    if left.len().saturating_mul(right.len()) <= MAX_TABLE_CELLS {
+        return Some(weighted_edit_list(&left, &right));
+    }
+
+    align_on_equal_elements(&left, &right, |path, left_gap, right_gap| {
+        if left_gap.len() * right_gap.len() <= MAX_TABLE_CELLS {
+            path.extend(weighted_edit_list(left_gap, right_gap));
+        } else {
+            path.extend(right_gap.iter().map(|&right| Edit::ExtraRight { right }));
+            path.extend(left_gap.iter().map(|&left| Edit::ExtraLeft { left }));
+        }
+    })
+}
+
+/// Compute the edit list of the lines `left` and `right`.
+///
+/// The lines are first aligned on those which are equal. The lines which
+/// differ between two equal ones are then paired in order, and only paired
+/// lines are compared character by character through
+/// [`Distance::distance`]. They are listed as an [`Edit::Both`] if they have
+/// anything in common, and otherwise as lines on one side only. Unlike
+/// [`edit_list`], this does not compare every line with every other, so that
+/// the cost grows with the number of lines which differ rather than with the
+/// product of the numbers and lengths of all lines.
+///
+/// Returns `None` if the sequences are too large and too different to diff
+/// within a reasonable time.
+pub(crate) fn line_edit_list<T: Distance + Copy>(
+    left: impl IntoIterator<Item = T>,
+    right: impl IntoIterator<Item = T>,
+) -> Option<Vec<Edit<T>>> {
+    let left: Vec<_> = left.into_iter().collect();
+    let right: Vec<_> = right.into_iter().collect();
+    align_on_equal_elements(&left, &right, |path, left_gap, right_gap| {
+        for (&left, &right) in left_gap.iter().zip(right_gap) {
+            let distance = T::distance(left, right);
+            if distance < 2.0 {
+                path.push(Edit::Both { left, right, distance });
+            } else {
+                path.push(Edit::ExtraRight { right });
+                path.push(Edit::ExtraLeft { left });
+            }
+        }
+        let paired = left_gap.len().min(right_gap.len());
+        path.extend(right_gap[paired..].iter().map(|&right| Edit::ExtraRight { right }));
+        path.extend(left_gap[paired..].iter().map(|&left| Edit::ExtraLeft { left }));
+    })
+}
+
+/// Aligns `left` and `right` on a longest common subsequence of equal
+/// elements, and lets `diff_gap` append the edits for each stretch of
+/// differing elements before, between, and after those to the edit list.
+fn align_on_equal_elements<T: Distance + Copy>(
+    left: &[T],
+    right: &[T],
+    mut diff_gap: impl FnMut(&mut Vec<Edit<T>>, &[T], &[T]),
+) -> Option<Vec<Edit<T>>> {
+    let mut matches = vec![];
+    let mut budget = MAX_DIFF_WORK;
+    common_subsequence(left, right, (0, 0), &mut matches, &mut budget)?;
+
+    let mut path = Vec::with_capacity(left.len() + right.len());
+    let mut current = (0, 0);
+    for (idx, idy) in matches.into_iter().chain([(left.len(), right.len())]) {
+        diff_gap(&mut path, &left[current.0..idx], &right[current.1..idy]);
+        if idx < left.len() {
+            path.push(Edit::Both { left: left[idx], right: right[idy], distance: 0.0 });
+        }
+        current = (idx + 1, idy + 1);
+    }
+    Some(path)
+}
+
+/// Computes a minimal edit list of `left` and `right` with a dynamic
+/// programming algorithm, taking time and space proportional to the product
+/// of their lengths.
+fn weighted_edit_list<T: Distance + Copy>(left: &[T], right: &[T]) -> Vec<Edit<T>> {
+    if left.is_empty() || right.is_empty() {
+        return right
+            .iter()
+            .map(|&right| Edit::ExtraRight { right })
+            .chain(left.iter().map(|&left| Edit::ExtraLeft { left }))
+            .collect();
+    }
 
     struct TableElement<U> {
         cost: f64,
@@ -87,6 +189,128 @@ pub(crate) fn edit_list<T: Distance + Copy>(
     path
 }
 
+/// Appends the index pairs of the elements of a longest common subsequence of
+/// `left` and `right`, offset by `offset`, to `matches`, in order.
+///
+/// This is the linear space variant of Myers' algorithm, which recursively
+/// splits the sequences at the middle snake of an optimal alignment. Each
+/// comparison of elements is charged to `budget`; returns `None` once it is
+/// exhausted.
+fn common_subsequence<T: Distance + Copy>(
+    left: &[T],
+    right: &[T],
+    offset: (usize, usize),
+    matches: &mut Vec<(usize, usize)>,
+    budget: &mut usize,
+) -> Option<()> {
+    let prefix = left.iter().zip(right).take_while(|(&l, &r)| T::equals(l, r)).count();
+    let (left_rest, right_rest) = (&left[prefix..], &right[prefix..]);
+    let suffix = left_rest
+        .iter()
+        .rev()
+        .zip(right_rest.iter().rev())
+        .take_while(|(&l, &r)| T::equals(l, r))
+        .count();
+    let left_rest = &left_rest[..left_rest.len() - suffix];
+    let right_rest = &right_rest[..right_rest.len() - suffix];
+    *budget = budget.checked_sub(prefix + suffix)?;
+
+    matches.extend((0..prefix).map(|i| (offset.0 + i, offset.1 + i)));
+    if !left_rest.is_empty() && !right_rest.is_empty() {
+        let offset = (offset.0 + prefix, offset.1 + prefix);
+        let (start, end) = middle_snake(left_rest, right_rest, budget)?;
+        common_subsequence(&left_rest[..start.0], &right_rest[..start.1], offset, matches, budget)?;
+        matches
+            .extend((0..end.0 - start.0).map(|i| (offset.0 + start.0 + i, offset.1 + start.1 + i)));
+        common_subsequence(
+            &left_rest[end.0..],
+            &right_rest[end.1..],
+            (offset.0 + end.0, offset.1 + end.1),
+            matches,
+            budget,
+        )?;
+    }
+    let (left_end, right_end) = (offset.0 + left.len(), offset.1 + right.len());
+    matches.extend((0..suffix).rev().map(|i| (left_end - 1 - i, right_end - 1 - i)));
+    Some(())
+}
+
+/// Finds the middle snake of an optimal alignment of `left` and `right`,
+/// which must both be non-empty: the run of equal elements, from a start to
+/// an end position, which the alignment passes halfway through its edits.
+///
+/// The search advances from the start and from the end of both sequences
+/// alternately until the two searches meet, charging each step to `budget`.
+fn middle_snake<T: Distance + Copy>(
+    left: &[T],
+    right: &[T],
+    budget: &mut usize,
+) -> Option<((usize, usize), (usize, usize))> {
+    let (n, m) = (left.len() as isize, right.len() as isize);
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+    let max = (n + m + 1) / 2;
+    let offset = max + 1;
+    // The furthest position along each diagonal k = x - y reached from the
+    // start, respectively, with reversed coordinates, from the end.
+    let mut forward = vec![0isize; 2 * offset as usize + 1];
+    let mut backward = vec![0isize; 2 * offset as usize + 1];
+    let index = |k: isize| (k + offset) as usize;
+
+    for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && forward[index(k - 1)] < forward[index(k + 1)]) {
+                forward[index(k + 1)]
+            } else {
+                forward[index(k - 1)] + 1
+            };
+            let start = (x, x - k);
+            while x < n && x - k < m && T::equals(left[x as usize], right[(x - k) as usize]) {
+                x += 1;
+            }
+            *budget = budget.checked_sub(1 + (x - start.0) as usize)?;
+            forward[index(k)] = x;
+            let reversed_k = delta - k;
+            if odd
+                && (-(d - 1)..=d - 1).contains(&reversed_k)
+                && x + backward[index(reversed_k)] >= n
+            {
+                return Some((
+                    (start.0 as usize, start.1 as usize),
+                    (x as usize, (x - k) as usize),
+                ));
+            }
+        }
+        for reversed_k in (-d..=d).step_by(2) {
+            let mut x = if reversed_k == -d
+                || (reversed_k != d
+                    && backward[index(reversed_k - 1)] < backward[index(reversed_k + 1)])
+            {
+                backward[index(reversed_k + 1)]
+            } else {
+                backward[index(reversed_k - 1)] + 1
+            };
+            let start = (x, x - reversed_k);
+            while x < n
+                && x - reversed_k < m
+                && T::equals(left[(n - 1 - x) as usize], right[(m - 1 - x + reversed_k) as usize])
+            {
+                x += 1;
+            }
+            *budget = budget.checked_sub(1 + (x - start.0) as usize)?;
+            backward[index(reversed_k)] = x;
+            let k = delta - reversed_k;
+            if !odd && (-d..=d).contains(&k) && x + forward[index(k)] >= n {
+                return Some((
+                    ((n - x) as usize, (m - x + reversed_k) as usize),
+                    ((n - start.0) as usize, (m - start.1) as usize),
+                ));
+            }
+        }
+    }
+    unreachable!("Searches from both ends of the sequences always meet")
+}
+
 /// An edit operation on two sequences of `T`.
 #[derive(Debug, Clone)]
 pub(crate) enum Edit<T> {
@@ -98,12 +322,228 @@ pub(crate) enum Edit<T> {
     Both { left: T, right: T, distance: f64 },
 }
 
+/// Renders `edit_list`, comparing the lines of an actual (left) with those of
+/// an expected (right) value, as the hunks of a unified diff.
+///
+/// Each hunk is headed by `@@ -l,s +l,s @@`, giving the first line and the
+/// number of lines of the expected and then the actual value it covers. Lines
+/// only in the actual value are marked with `+`, those only in the expected
+/// value with `-`, and up to `context` unchanged lines before and after each
+/// change are shown with a leading space. Every line, including the first, is
+/// preceded by a newline.
+pub(crate) fn unified_diff(edit_list: &[Edit<&str>], context: usize) -> String {
+    let is_change =
+        |edit: &Edit<&str>| !matches!(edit, Edit::Both { distance, .. } if *distance == 0.0);
+    let changes: Vec<usize> = (0..edit_list.len()).filter(|&i| is_change(&edit_list[i])).collect();
+    let is_shown = |index: usize| {
+        let next_change = changes.partition_point(|&change| change + context < index);
+        next_change < changes.len() && changes[next_change] <= index + context
+    };
+
+    let mut summary = String::new();
+    // The number of lines of the actual and the expected value consumed by
+    // the edits preceding the current one.
+    let (mut actual_line, mut expected_line) = (0, 0);
+    let mut index = 0;
+    while index < edit_list.len() {
+        if !is_shown(index) {
+            let (actual_lines, expected_lines) = lines_consumed(&edit_list[index]);
+            actual_line += actual_lines;
+            expected_line += expected_lines;
+            index += 1;
+            continue;
+        }
+        let hunk_end = (index..edit_list.len()).find(|&i| !is_shown(i)).unwrap_or(edit_list.len());
+        let hunk = &edit_list[index..hunk_end];
+        let (actual_lines, expected_lines) = hunk
+            .iter()
+            .map(lines_consumed)
+            .fold((0, 0), |(actual, expected), (a, e)| (actual + a, expected + e));
+        // As in the unified format, an empty range starts at the line before it.
+        let start = |line: usize, count: usize| if count == 0 { line } else { line + 1 };
+        summary.push_str(&format!(
+            "\n@@ -{},{expected_lines} +{},{actual_lines} @@",
+            start(expected_line, expected_lines),
+            start(actual_line, actual_lines),
+        ));
+        for edit in hunk {
+            match edit {
+                Edit::Both { left, distance, .. } if *distance == 0.0 => {
+                    summary.push_str(&format!("\n {left}"));
+                }
+                Edit::Both { left, right, .. } => {
+                    let (left_markers, right_markers) =
+                        changed_spans(left, right).unwrap_or((None, None));
+                    summary.push_str(&format!("\n+{left}"));
+                    push_changed_spans(&mut summary, left_markers);
+                    summary.push_str(&format!("\n-{right}"));
+                    push_changed_spans(&mut summary, right_markers);
+                }
+                Edit::ExtraLeft { left } => summary.push_str(&format!("\n+{left}")),
+                Edit::ExtraRight { right } => summary.push_str(&format!("\n-{right}")),
+            }
+        }
+        actual_line += actual_lines;
+        expected_line += expected_lines;
+        index = hunk_end;
+    }
+    summary
+}
+
+/// Renders `edit_list`, comparing the lines of an actual (left) with those of
+/// an expected (right) value, as two columns side by side.
+///
+/// The columns are headed by `(actual)` and `(expected)`. The gutter between
+/// them holds `|` for a changed line, `<` for a line only in the actual value,
+/// and `>` for a line only in the expected value. Every row, including the
+/// first, is preceded by a newline.
+pub(crate) fn side_by_side_diff(edit_list: &[Edit<&str>]) -> String {
+    const ACTUAL_TITLE: &str = "(actual)";
+    let width = edit_list
+        .iter()
+        .filter_map(|edit| match edit {
+            Edit::Both { left, .. } | Edit::ExtraLeft { left } => Some(left.chars().count()),
+            Edit::ExtraRight { .. } => None,
+        })
+        .fold(ACTUAL_TITLE.len(), usize::max);
+    let row = |left: &str, gutter: char, right: &str| {
+        let padding = width - left.chars().count();
+        let row = format!("\n{left}{:padding$} {gutter} {right}", "");
+        row.trim_end().to_string()
+    };
+    let mut summary = row(ACTUAL_TITLE, ' ', "(expected)");
+    for edit in edit_list {
+        summary.push_str(&match edit {
+            Edit::Both { left, right, distance } if *distance == 0.0 => row(left, ' ', right),
+            Edit::Both { left, right, .. } => row(left, '|', right),
+            Edit::ExtraLeft { left } => row(left, '<', ""),
+            Edit::ExtraRight { right } => row("", '>', right),
+        });
+    }
+    summary
+}
+
+/// Marks the spans in which the lines `left` and `right` differ, to be shown
+/// below each of them when a diff lists them as a changed line.
+///
+/// The lines are compared word by word, where a word is a run of alphanumeric
+/// characters or underscores, a run of whitespace, or any other single
+/// character. Returns, for each of the two lines, a string with a `^` below
+/// each character of a word not in the other line, or `None` if that line has
+/// no such word. Returns `None` altogether if the lines have no alphanumeric
+/// word in common, since marking the changes then hardly helps.
+pub(crate) fn changed_spans(left: &str, right: &str) -> Option<(Option<String>, Option<String>)> {
+    let left_words = words(left);
+    let right_words = words(right);
+    if left_words.is_empty() || right_words.is_empty() {
+        return None;
+    }
+    let mut left_markers = String::new();
+    let mut right_markers = String::new();
+    let mut has_common_word = false;
+    let mark = |markers: &mut String, word: Word, marker: char| {
+        markers.extend(std::iter::repeat(marker).take(word.0.chars().count()))
+    };
+    for edit in edit_list(left_words, right_words)? {
+        match edit {
+            Edit::Both { left, right, distance: 0.0 } => {
+                has_common_word |= left.0.contains(|c: char| c.is_alphanumeric());
+                mark(&mut left_markers, left, ' ');
+                mark(&mut right_markers, right, ' ');
+            }
+            Edit::Both { left, right, .. } => {
+                mark(&mut left_markers, left, '^');
+                mark(&mut right_markers, right, '^');
+            }
+            Edit::ExtraLeft { left } => mark(&mut left_markers, left, '^'),
+            Edit::ExtraRight { right } => mark(&mut right_markers, right, '^'),
+        }
+    }
+    if !has_common_word {
+        return None;
+    }
+    let markers = |markers: String| {
+        let markers = markers.trim_end().to_string();
+        if markers.is_empty() { None } else { Some(markers) }
+    };
+    Some((markers(left_markers), markers(right_markers)))
+}
+
+/// A word of a line, as compared by [`changed_spans`].
+#[derive(Clone, Copy, PartialEq)]
+struct Word<'a>(&'a str);
+
+impl Distance for Word<'_> {
+    fn distance(left: Self, right: Self) -> f64 {
+        if left == right { 0.0 } else { 1.0 }
+    }
+}
+
+fn words(line: &str) -> Vec<Word<'_>> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Whitespace,
+        Other,
+    }
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Whitespace
+        } else {
+            Class::Other
+        }
+    };
+    let mut words = vec![];
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let ends_word = match chars.peek() {
+            Some(&(_, next)) => class(c) == Class::Other || class(next) != class(c),
+            None => true,
+        };
+        if ends_word {
+            let end = index + c.len_utf8();
+            words.push(Word(&line[start..end]));
+            start = end;
+        }
+    }
+    words
+}
+
+/// Appends the markers of one line returned by [`changed_spans`], if any, to
+/// `summary` as a line of its own, aligned with the changed line above it.
+pub(crate) fn push_changed_spans(summary: &mut String, markers: Option<String>) {
+    if let Some(markers) = markers {
+        summary.push_str("\n ");
+        summary.push_str(&markers);
+    }
+}
+
+// The numbers of lines of the left and the right sequence which the given
+// edit consumes.
+fn lines_consumed<T>(edit: &Edit<T>) -> (usize, usize) {
+    match edit {
+        Edit::ExtraLeft { .. } => (1, 0),
+        Edit::ExtraRight { .. } => (0, 1),
+        Edit::Both { .. } => (1, 1),
+    }
+}
+
 /// Trait to implement the distance between two objects.
 ///
 /// This allows to control the behavior of [`edit_list`] notably when two prefer
 /// one [`Edit::Both`] or one [`Edit::ExtraRight`] and [`Edit::ExtraLeft`].
-pub(crate) trait Distance {
+pub(crate) trait Distance: Sized {
     fn distance(left: Self, right: Self) -> f64;
+
+    /// Whether `left` and `right` are equal, i.e., have a distance of 0.
+    ///
+    /// Override this if it can be decided faster than the distance.
+    fn equals(left: Self, right: Self) -> bool {
+        Self::distance(left, right) == 0.0
+    }
 }
 
 impl Distance for char {
@@ -120,7 +560,11 @@ impl Distance for &str {
         if left == right {
             return 0.0;
         }
-        let edits: f64 = edit_list(left.chars(), right.chars())
+        let Some(edits) = edit_list(left.chars(), right.chars()) else {
+            // The strings are too different to diff.
+            return 2.0;
+        };
+        let edits: f64 = edits
             .into_iter()
             .map(|edit| match edit {
                 Edit::Both { distance, .. } => distance,
@@ -129,6 +573,10 @@ impl Distance for &str {
             .sum();
         1. + edits / (left.chars().count().max(right.chars().count()) as f64)
     }
+
+    fn equals(left: Self, right: Self) -> bool {
+        left == right
+    }
 }
 
 /// 2D Table implemented with a Vec<_>.
@@ -168,7 +616,11 @@ impl<T> Index<(usize, usize)> for Table<T> {
 mod tests {
     use super::*;
     use crate::elements_are;
-    use crate::{matcher::Matcher, matchers::predicate, verify_that, Result};
+    use crate::{
+        Result,
+        matcher::Matcher,
+        matchers::{eq, none, predicate, some},
+    };
     use indoc::indoc;
 
     fn is_both<E: PartialEq + Debug>(
@@ -195,9 +647,106 @@ mod tests {
         })
     }
 
+    #[test]
+    fn side_by_side_diff_aligns_columns_and_marks_changes() -> Result<()> {
+        let actual = "first\nsecond line\nthird\nonly actual";
+        let expected = "first\nsecond line!\nthird\nonly expected";
+        let edits = line_edit_list(actual.lines(), expected.lines()).unwrap();
+
+        verify_that!(
+            side_by_side_diff(&edits),
+            eq(indoc!(
+                "
+
+                (actual)      (expected)
+                first         first
+                second line | second line!
+                third         third
+                only actual | only expected"
+            ))
+        )
+    }
+
+    #[test]
+    fn side_by_side_diff_marks_lines_on_one_side_only() -> Result<()> {
+        let edits = line_edit_list("a\nb".lines(), "b\nc".lines()).unwrap();
+
+        verify_that!(
+            side_by_side_diff(&edits),
+            eq("\n(actual)   (expected)\na        <\nb          b\n         > c")
+        )
+    }
+
+    #[test]
+    fn changed_spans_marks_differing_words() -> Result<()> {
+        verify_that!(
+            changed_spans("let total = price * quantity;", "let total = cost * quantity;"),
+            some(eq((Some("            ^^^^^".to_string()), Some("            ^^^^".to_string()))))
+        )
+    }
+
+    #[test]
+    fn changed_spans_marks_words_only_on_one_side() -> Result<()> {
+        verify_that!(
+            changed_spans("GET /index.html HTTP/1.1", "GET /index.html"),
+            some(eq((Some("               ^^^^^^^^^".to_string()), None)))
+        )
+    }
+
+    #[test]
+    fn changed_spans_counts_characters_rather_than_bytes() -> Result<()> {
+        verify_that!(
+            changed_spans("größe: 10", "größe: 12"),
+            some(eq((Some("       ^^".to_string()), Some("       ^^".to_string()))))
+        )
+    }
+
+    #[test]
+    fn changed_spans_is_none_without_common_alphanumeric_word() -> Result<()> {
+        verify_that!(changed_spans("    1,", "    2,"), none())?;
+        verify_that!(changed_spans("", "something"), none())
+    }
+
+    #[test]
+    fn unified_diff_groups_changes_into_hunks_with_context() -> Result<()> {
+        let actual = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj";
+        let expected = "a\nb\nC\nd\ne\nf\ng\nh\nj\nk";
+        let edits = line_edit_list(actual.lines(), expected.lines()).unwrap();
+
+        verify_that!(
+            unified_diff(&edits, 1),
+            eq("\n@@ -2,3 +2,3 @@\n b\n-C\n+c\n d\n@@ -8,3 +8,3 @@\n h\n+i\n j\n-k")
+        )
+    }
+
+    #[test]
+    fn unified_diff_merges_hunks_with_overlapping_context() -> Result<()> {
+        let actual = "a\nb\nc\nd\ne";
+        let expected = "A\nb\nc\nD\ne";
+        let edits = line_edit_list(actual.lines(), expected.lines()).unwrap();
+
+        verify_that!(unified_diff(&edits, 1), eq("\n@@ -1,5 +1,5 @@\n-A\n+a\n b\n c\n-D\n+d\n e"))
+    }
+
+    #[test]
+    fn unified_diff_counts_empty_range_from_preceding_line() -> Result<()> {
+        let actual = "a\nb\nc";
+        let expected = "a\nb\nc\nd";
+        let edits = line_edit_list(actual.lines(), expected.lines()).unwrap();
+
+        verify_that!(unified_diff(&edits, 0), eq("\n@@ -4,1 +3,0 @@\n-d"))
+    }
+
+    #[test]
+    fn unified_diff_is_empty_without_changes() -> Result<()> {
+        let edits = line_edit_list("a\nb".lines(), "a\nb".lines()).unwrap();
+
+        verify_that!(unified_diff(&edits, 3), eq(""))
+    }
+
     #[test]
     fn exact_match() -> Result<()> {
-        let edits = edit_list("hello".chars(), "hello".chars());
+        let edits = edit_list("hello".chars(), "hello".chars()).unwrap();
         verify_that!(
             edits,
             elements_are![
@@ -212,7 +761,7 @@ mod tests {
 
     #[test]
     fn completely_different() -> Result<()> {
-        let edits = edit_list("goodbye".chars(), "hello".chars());
+        let edits = edit_list("goodbye".chars(), "hello".chars()).unwrap();
         verify_that!(
             edits,
             elements_are![
@@ -229,7 +778,7 @@ mod tests {
 
     #[test]
     fn slightly_different() -> Result<()> {
-        let edits = edit_list("floor".chars(), "flower".chars());
+        let edits = edit_list("floor".chars(), "flower".chars()).unwrap();
         verify_that!(
             edits,
             elements_are![
@@ -257,13 +806,143 @@ mod tests {
             string: "someone"
         "#
         );
-        let edits = edit_list(left.lines(), right.lines());
+        let edits = edit_list(left.lines(), right.lines()).unwrap();
+        verify_that!(
+            edits,
+            elements_are![
+                is_both("int: 123", "int: 321"),
+                is_both(r#"string: "something""#, r#"string: "someone""#),
+            ]
+        )
+    }
+
+    #[test]
+    fn line_edit_list_pairs_changed_lines_in_order() -> Result<()> {
+        let left = "header\nint: 123\nstring: \"something\"\nfooter";
+        let right = "header\nint: 321\nstring: \"someone\"\nextra\nfooter";
+
+        let edits = line_edit_list(left.lines(), right.lines()).unwrap();
+
         verify_that!(
             edits,
             elements_are![
+                is_both("header", "header"),
                 is_both("int: 123", "int: 321"),
                 is_both(r#"string: "something""#, r#"string: "someone""#),
+                is_extra_right("extra"),
+                is_both("footer", "footer"),
             ]
         )
     }
+
+    #[test]
+    fn line_edit_list_does_not_pair_completely_different_lines() -> Result<()> {
+        let edits = line_edit_list("abc".lines(), "xyz".lines()).unwrap();
+
+        verify_that!(edits, elements_are![is_extra_right("xyz"), is_extra_left("abc")])
+    }
+
+    #[test]
+    fn line_edit_list_diffs_long_documents() -> Result<()> {
+        let left: String =
+            (0..5000).map(|i| format!("line {i} of a rather long document\n")).collect();
+        let right: String = (0..5000)
+            .map(|i| {
+                let word = if i % 50 == 0 { "Line" } else { "line" };
+                format!("{word} {i} of a rather long document\n")
+            })
+            .collect();
+
+        let edits = line_edit_list(left.lines(), right.lines()).unwrap();
+
+        verify_that!(
+            edits
+                .iter()
+                .filter(|edit| matches!(edit, Edit::Both { distance, .. } if *distance > 0.0))
+                .count(),
+            eq(100)
+        )
+    }
+
+    #[test]
+    fn empty_left_sequence() -> Result<()> {
+        let edits = edit_list("".chars(), "ab".chars()).unwrap();
+        verify_that!(edits, elements_are![is_extra_right('a'), is_extra_right('b')])
+    }
+
+    #[test]
+    fn long_sequences_are_aligned_on_equal_elements() -> Result<()> {
+        let left: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+        let mut right = left.clone();
+        right.remove(500);
+        right.insert(100, "inserted".into());
+        let edits =
+            edit_list(left.iter().map(String::as_str), right.iter().map(String::as_str)).unwrap();
+        let changes: Vec<_> = edits
+            .into_iter()
+            .filter(|edit| !matches!(edit, Edit::Both { distance, .. } if *distance == 0.0))
+            .collect();
+        let result =
+            verify_that!(changes, elements_are![is_extra_right("inserted"), is_extra_left("500")]);
+        result
+    }
+
+    #[test]
+    fn long_sequences_keep_a_longest_common_subsequence() -> Result<()> {
+        // A simple linear congruential generator, for reproducible sequences
+        // with many partial matches.
+        let mut state = 12345u32;
+        let mut next = || {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            char::from(b'a' + (state >> 16) as u8 % 4)
+        };
+        let left: Vec<char> = (0..300).map(|_| next()).collect();
+        let right: Vec<char> = (0..400).map(|_| next()).collect();
+        let mut lcs = vec![vec![0; right.len() + 1]; left.len() + 1];
+        for i in 1..=left.len() {
+            for j in 1..=right.len() {
+                lcs[i][j] = if left[i - 1] == right[j - 1] {
+                    lcs[i - 1][j - 1] + 1
+                } else {
+                    lcs[i - 1][j].max(lcs[i][j - 1])
+                };
+            }
+        }
+
+        let edits = edit_list(left.iter().copied(), right.iter().copied()).unwrap();
+
+        let (mut actual_left, mut actual_right, mut common) = (vec![], vec![], 0);
+        for edit in edits {
+            match edit {
+                Edit::Both { left, right, distance } => {
+                    actual_left.push(left);
+                    actual_right.push(right);
+                    common += usize::from(distance == 0.0);
+                }
+                Edit::ExtraLeft { left } => actual_left.push(left),
+                Edit::ExtraRight { right } => actual_right.push(right),
+            }
+        }
+        verify_that!(actual_left, eq(left))?;
+        verify_that!(actual_right, eq(right))?;
+        verify_that!(common, eq(lcs[300][400]))
+    }
+
+    #[test]
+    fn long_strings_with_few_differences_are_diffed() -> Result<()> {
+        let left = "a".repeat(100_000);
+        let right = format!("{}b{}", &left[..50_000], &left[50_000..]);
+
+        let edits = edit_list(left.chars(), right.chars()).unwrap();
+
+        verify_that!(edits.len(), eq(100_001))
+    }
+
+    #[test]
+    fn long_and_completely_different_strings_are_too_large_to_diff() -> Result<()> {
+        let left = "a".repeat(100_000);
+        let right = "b".repeat(100_000);
+
+        verify_that!(edit_list(left.chars(), right.chars()), none())
+    }
 }
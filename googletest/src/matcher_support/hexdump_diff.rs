@@ -0,0 +1,205 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::DiffStyle;
+use regex::Regex;
+
+/// The number of bytes shown per row of a hexdump.
+const BYTES_PER_ROW: usize = 8;
+
+/// Returns whether `type_name`, as given by [`std::any::type_name`], denotes a
+/// container of bytes whose `Debug` output lists the bytes as numbers.
+///
+/// This covers byte slices and arrays, as well as `Vec`, `VecDeque`, and the
+/// standard smart pointers to byte slices, all possibly behind references.
+pub(crate) fn is_byte_container(type_name: &str) -> bool {
+    let pattern = Regex::new(
+        r"^(&(mut )?)*(\[u8(; \d+)?\]|alloc::vec::Vec<u8>|alloc::collections::vec_deque::VecDeque<u8>|alloc::(boxed::Box|rc::Rc|sync::Arc|borrow::Cow)<\[u8\]>)$",
+    )
+    .unwrap();
+    pattern.is_match(type_name)
+}
+
+/// Parses the compact (`{:?}`) `Debug` output of a container of bytes, such as
+/// `[72, 105]`, back into the bytes.
+pub(crate) fn parse_bytes(debug: &str) -> Option<Vec<u8>> {
+    let elements = debug.strip_prefix('[')?.strip_suffix(']')?;
+    if elements.is_empty() {
+        return Some(vec![]);
+    }
+    elements.split(", ").map(|element| element.parse().ok()).collect()
+}
+
+/// Renders a side-by-side hexdump of `actual` and `expected`, marking each
+/// byte which differs from the one at the same offset on the other side with
+/// a `*`.
+///
+/// Returns `None` if the two are equal. With [`DiffStyle::ChangesOnly`], each
+/// run of rows without differences is replaced by `...`. With
+/// [`DiffStyle::Unified`], so is each such run except for up to `context` rows
+/// next to a row with differences.
+pub(crate) fn hexdump_diff(
+    actual: &[u8],
+    expected: &[u8],
+    diff_style: DiffStyle,
+) -> Option<String> {
+    let first_difference = (0..actual.len().max(expected.len()))
+        .find(|&offset| actual.get(offset) != expected.get(offset))?;
+    let mut summary = format!(
+        "Hexdump diff (actual | expected), first difference at byte offset {first_difference}:"
+    );
+    if actual.len() != expected.len() {
+        summary.push_str(&format!(
+            "\nThe actual value has {} bytes, the expected value {}.",
+            actual.len(),
+            expected.len()
+        ));
+    }
+    let rows = (actual.len().max(expected.len()) + BYTES_PER_ROW - 1) / BYTES_PER_ROW;
+    let row_differs = |row: usize| {
+        (row * BYTES_PER_ROW..(row + 1) * BYTES_PER_ROW)
+            .any(|offset| actual.get(offset) != expected.get(offset))
+    };
+    let context = match diff_style {
+        DiffStyle::ChangesOnly => Some(0),
+        DiffStyle::Unified { context } => Some(context),
+        DiffStyle::Full | DiffStyle::SideBySide | DiffStyle::Disabled => None,
+    };
+    // Whether the previous row was elided.
+    let mut eliding = false;
+    for row in 0..rows {
+        let row_start = row * BYTES_PER_ROW;
+        let offsets = row_start..row_start + BYTES_PER_ROW;
+        let differs = row_differs(row);
+        let shown = match context {
+            Some(context) => (row.saturating_sub(context)..=row + context)
+                .any(|other| other < rows && row_differs(other)),
+            None => true,
+        };
+        if !shown {
+            if !eliding {
+                summary.push_str("\n ...");
+            }
+            eliding = true;
+            continue;
+        }
+        eliding = false;
+        let actual_row = render_row(actual, expected, offsets.clone());
+        let expected_row = render_row(expected, actual, offsets);
+        let row = format!(
+            "\n{}{row_start:08x}: {actual_row} | {expected_row}",
+            if differs { '!' } else { ' ' }
+        );
+        summary.push_str(row.trim_end());
+    }
+    Some(summary)
+}
+
+/// Renders the bytes of `bytes` at `offsets` in hexadecimal, each followed by
+/// `*` if it differs from the byte of `other` at the same offset.
+fn render_row(bytes: &[u8], other: &[u8], offsets: std::ops::Range<usize>) -> String {
+    offsets
+        .map(|offset| match bytes.get(offset) {
+            Some(byte) if other.get(offset) == Some(byte) => format!("{byte:02x} "),
+            Some(byte) => format!("{byte:02x}*"),
+            None => "   ".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hexdump_diff, is_byte_container, parse_bytes};
+    use crate::config::DiffStyle;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn recognises_byte_containers() -> Result<()> {
+        verify_that!(is_byte_container(std::any::type_name::<Vec<u8>>()), eq(true))?;
+        verify_that!(is_byte_container(std::any::type_name::<&[u8]>()), eq(true))?;
+        verify_that!(is_byte_container(std::any::type_name::<&&mut [u8; 4]>()), eq(true))?;
+        verify_that!(is_byte_container(std::any::type_name::<Box<[u8]>>()), eq(true))?;
+        verify_that!(is_byte_container(std::any::type_name::<Vec<i8>>()), eq(false))?;
+        verify_that!(is_byte_container(std::any::type_name::<Vec<Vec<u8>>>()), eq(false))
+    }
+
+    #[test]
+    fn parses_byte_debug_output() -> Result<()> {
+        verify_that!(parse_bytes("[0, 72, 255]"), some(eq(vec![0, 72, 255])))?;
+        verify_that!(parse_bytes("[]"), some(empty()))?;
+        verify_that!(parse_bytes("[256]"), none())?;
+        verify_that!(parse_bytes("b\"ab\""), none())
+    }
+
+    #[test]
+    fn returns_none_for_equal_bytes() -> Result<()> {
+        verify_that!(hexdump_diff(b"abc", b"abc", DiffStyle::Full), none())
+    }
+
+    #[test]
+    fn renders_side_by_side_rows_marking_differences() -> Result<()> {
+        verify_that!(
+            hexdump_diff(b"Hello, world!", b"Hello, World.\n", DiffStyle::Full),
+            some(eq(indoc!(
+                "
+                Hexdump diff (actual | expected), first difference at byte offset 7:
+                The actual value has 13 bytes, the expected value 14.
+                !00000000: 48 65 6c 6c 6f 2c 20 77* | 48 65 6c 6c 6f 2c 20 57*
+                !00000008: 6f 72 6c 64 21*          | 6f 72 6c 64 2e*0a*"
+            )
+            .trim_start()))
+        )
+    }
+
+    #[test]
+    fn shows_context_rows_with_unified_diff_style() -> Result<()> {
+        let actual = [0u8; 40];
+        let mut expected = actual;
+        expected[20] = 1;
+
+        verify_that!(
+            hexdump_diff(&actual, &expected, DiffStyle::Unified { context: 1 }),
+            some(eq(indoc!(
+                "
+                Hexdump diff (actual | expected), first difference at byte offset 20:
+                 ...
+                 00000008: 00 00 00 00 00 00 00 00  | 00 00 00 00 00 00 00 00
+                !00000010: 00 00 00 00 00*00 00 00  | 00 00 00 00 01*00 00 00
+                 00000018: 00 00 00 00 00 00 00 00  | 00 00 00 00 00 00 00 00
+                 ..."
+            )
+            .trim_start()))
+        )
+    }
+
+    #[test]
+    fn elides_unchanged_rows_with_changes_only() -> Result<()> {
+        let actual = [0u8; 32];
+        let mut expected = actual;
+        expected[12] = 1;
+
+        verify_that!(
+            hexdump_diff(&actual, &expected, DiffStyle::ChangesOnly),
+            some(eq(indoc!(
+                "
+                Hexdump diff (actual | expected), first difference at byte offset 12:
+                 ...
+                !00000008: 00 00 00 00 00*00 00 00  | 00 00 00 00 01*00 00 00
+                 ..."
+            )
+            .trim_start()))
+        )
+    }
+}
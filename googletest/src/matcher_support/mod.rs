@@ -13,6 +13,10 @@
 // limitations under the License.
 
 pub(crate) mod count_elements;
+pub(crate) mod debug_layout;
 pub mod description;
 pub(crate) mod edit_distance;
-pub(crate) mod zipped_iterator;
\ No newline at end of file
+pub(crate) mod hexdump_diff;
+pub mod ordered_container;
+pub(crate) mod truncation;
+pub(crate) mod zipped_iterator;
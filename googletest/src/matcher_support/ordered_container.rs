@@ -0,0 +1,61 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matchers::yielded::Yielded;
+use std::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+
+/// A container which iterates over its elements in a specified order.
+///
+/// The matchers which match the elements of a container in order, namely
+/// [`elements_are!`][crate::elements_are], [`pointwise!`][crate::pointwise]
+/// and [`elements_are_from`][crate::matchers::elements_are_from], only accept
+/// containers implementing this trait. Matching a container whose iteration
+/// order is unspecified, such as a [`HashMap`][std::collections::HashMap] or
+/// a [`HashSet`][std::collections::HashSet], with them would lead to a flaky
+/// test, so it is rejected at compile time:
+///
+/// ```compile_fail
+/// # use googletest::prelude::*;
+/// # use std::collections::HashSet;
+/// verify_that!(HashSet::from([1, 2]), elements_are![eq(1), eq(2)])
+/// # .unwrap();
+/// ```
+///
+/// Such containers can be matched with
+/// [`unordered_elements_are!`][crate::unordered_elements_are] instead.
+///
+/// Implement this trait for a custom container which iterates in a specified
+/// order so that it can be matched with the matchers above.
+#[rustversion::attr(
+    since(1.78),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not iterate in a specified order",
+        label = "matched in order here",
+        note = "use `unordered_elements_are!` for containers such as `HashMap` and `HashSet`, \
+                whose iteration order is unspecified",
+        note = "implement `OrderedContainer` for a custom container which iterates in a \
+                specified order"
+    )
+)]
+pub trait OrderedContainer {}
+
+impl<T> OrderedContainer for [T] {}
+impl<T, const N: usize> OrderedContainer for [T; N] {}
+impl<T> OrderedContainer for Vec<T> {}
+impl<T> OrderedContainer for VecDeque<T> {}
+impl<T> OrderedContainer for LinkedList<T> {}
+impl<T> OrderedContainer for BTreeSet<T> {}
+impl<K, V> OrderedContainer for BTreeMap<K, V> {}
+impl<T> OrderedContainer for Option<T> {}
+impl<T> OrderedContainer for Yielded<T> {}
@@ -0,0 +1,233 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The number of spaces by which `{:#?}` indents each nesting level.
+const DEBUG_INDENTATION: usize = 4;
+
+/// Elides all but the first `max_elements` elements of every container in
+/// `debug`, which must be the pretty-printed (`{:#?}`) `Debug` output of a
+/// value.
+///
+/// Lists, sets, and maps are truncated at every nesting level. The elided
+/// elements are replaced with a line stating how many were omitted. Structs
+/// and tuples are left intact.
+pub(crate) fn truncate_containers(debug: &str, max_elements: usize) -> String {
+    struct Frame {
+        indent: usize,
+        is_container: bool,
+        elements: usize,
+    }
+    impl Frame {
+        fn hides_elements(&self, max_elements: usize) -> bool {
+            self.is_container && self.elements > max_elements
+        }
+    }
+
+    let mut output = Vec::new();
+    let mut frames: Vec<Frame> = Vec::new();
+    for line in debug.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if let Some(frame) = frames.last_mut() {
+            if indent == frame.indent && trimmed.starts_with([']', '}', ')']) {
+                let frame = frames.pop().unwrap();
+                if !frames.iter().any(|f| f.hides_elements(max_elements)) {
+                    if frame.hides_elements(max_elements) {
+                        output.push(format!(
+                            "{:indent$}... and {} more",
+                            "",
+                            frame.elements - max_elements,
+                            indent = indent + DEBUG_INDENTATION
+                        ));
+                    }
+                    output.push(line.to_string());
+                }
+                continue;
+            }
+            if indent == frame.indent + DEBUG_INDENTATION {
+                frame.elements += 1;
+            }
+        }
+        if !frames.iter().any(|f| f.hides_elements(max_elements)) {
+            output.push(line.to_string());
+        }
+        if trimmed.ends_with(['[', '{', '(']) {
+            frames.push(Frame { indent, is_container: is_container_opening(trimmed), elements: 0 });
+        }
+    }
+    output.join("\n")
+}
+
+/// Keeps only the first `max_lines` lines of `text`, replacing the rest with
+/// a line stating how many were truncated.
+pub(crate) fn truncate_lines(text: &str, max_lines: usize) -> String {
+    let lines = text.lines().count();
+    if lines <= max_lines {
+        return text.to_string();
+    }
+    let mut output: Vec<&str> = text.lines().take(max_lines).collect();
+    let marker = format!("... {} more lines truncated", lines - max_lines);
+    output.push(&marker);
+    output.join("\n")
+}
+
+/// Keeps only the first `max_bytes` bytes of `text`, replacing the rest with
+/// a note stating how many were truncated.
+///
+/// The cut is moved back to the nearest character boundary, so that slightly
+/// fewer than `max_bytes` bytes may be kept.
+pub(crate) fn truncate_bytes(text: &str, max_bytes: usize) -> String {
+    if text.len() <= max_bytes {
+        return text.to_string();
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}... {} more bytes truncated", &text[..end], text.len() - end)
+}
+
+/// Returns whether `line`, which opens a nesting level, opens a list, set, or
+/// map rather than a struct or a tuple.
+fn is_container_opening(line: &str) -> bool {
+    line.ends_with('[') || line == "{" || line.ends_with(": {")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{truncate_bytes, truncate_containers, truncate_lines};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn leaves_short_list_unchanged() -> Result<()> {
+        let debug = format!("{:#?}", vec![1, 2]);
+
+        verify_that!(truncate_containers(&debug, 2), eq(debug))
+    }
+
+    #[test]
+    fn truncates_long_list() -> Result<()> {
+        let debug = format!("{:#?}", vec![1, 2, 3, 4, 5]);
+
+        verify_that!(
+            truncate_containers(&debug, 2),
+            eq(indoc!(
+                "
+                [
+                    1,
+                    2,
+                    ... and 3 more
+                ]"
+            ))
+        )
+    }
+
+    #[test]
+    fn truncates_nested_lists_independently() -> Result<()> {
+        let debug = format!("{:#?}", vec![vec![1, 2, 3], vec![4], vec![5]]);
+
+        verify_that!(
+            truncate_containers(&debug, 2),
+            eq(indoc!(
+                "
+                [
+                    [
+                        1,
+                        2,
+                        ... and 1 more
+                    ],
+                    [
+                        4,
+                    ],
+                    ... and 1 more
+                ]"
+            ))
+        )
+    }
+
+    #[test]
+    fn truncates_map() -> Result<()> {
+        let debug = format!("{:#?}", BTreeMap::from([(1, "a"), (2, "b"), (3, "c")]));
+
+        verify_that!(
+            truncate_containers(&debug, 1),
+            eq(indoc!(
+                r#"
+                {
+                    1: "a",
+                    ... and 2 more
+                }"#
+            ))
+        )
+    }
+
+    #[test]
+    fn does_not_truncate_struct_fields() -> Result<()> {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Strukt {
+            a: i32,
+            b: i32,
+            c: Vec<i32>,
+        }
+        let debug = format!("{:#?}", Strukt { a: 1, b: 2, c: vec![3, 4] });
+
+        verify_that!(
+            truncate_containers(&debug, 1),
+            eq(indoc!(
+                "
+                Strukt {
+                    a: 1,
+                    b: 2,
+                    c: [
+                        3,
+                        ... and 1 more
+                    ],
+                }"
+            ))
+        )
+    }
+
+    #[test]
+    fn leaves_single_line_output_unchanged() -> Result<()> {
+        verify_that!(truncate_containers("[]", 0), eq("[]"))
+    }
+
+    #[test]
+    fn truncates_lines_beyond_limit() -> Result<()> {
+        verify_that!(truncate_lines("a\nb\nc\nd", 2), eq("a\nb\n... 2 more lines truncated"))
+    }
+
+    #[test]
+    fn leaves_text_within_line_limit_unchanged() -> Result<()> {
+        verify_that!(truncate_lines("a\nb", 2), eq("a\nb"))
+    }
+
+    #[test]
+    fn truncates_bytes_beyond_limit() -> Result<()> {
+        verify_that!(truncate_bytes("abcdef", 4), eq("abcd... 2 more bytes truncated"))
+    }
+
+    #[test]
+    fn truncates_bytes_at_character_boundary() -> Result<()> {
+        verify_that!(truncate_bytes("aéb", 2), eq("a... 3 more bytes truncated"))
+    }
+
+    #[test]
+    fn leaves_text_within_byte_limit_unchanged() -> Result<()> {
+        verify_that!(truncate_bytes("abc", 3), eq("abc"))
+    }
+}
@@ -64,21 +64,20 @@ macro_rules! all {
 /// For internal use only. API stablility is not guaranteed!
 #[doc(hidden)]
 pub mod internal {
-    use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult, TestDebug};
     use crate::matcher_support::description::Description;
     use crate::matchers::anything;
-    use std::fmt::Debug;
 
     /// A matcher which matches an input value matched by all matchers in the
     /// array `components`.
     ///
     /// For internal use only. API stablility is not guaranteed!
     #[doc(hidden)]
-    pub struct AllMatcher<'a, T: Debug + ?Sized, const N: usize> {
+    pub struct AllMatcher<'a, T: TestDebug + ?Sized, const N: usize> {
         components: [&'a dyn Matcher<ActualT = T>; N],
     }
 
-    impl<'a, T: Debug + ?Sized, const N: usize> AllMatcher<'a, T, N> {
+    impl<'a, T: TestDebug + ?Sized, const N: usize> AllMatcher<'a, T, N> {
         /// Constructs an [`AllMatcher`] with the given component matchers.
         ///
         /// Intended for use only by the [`all`] macro.
@@ -87,7 +86,7 @@ pub mod internal {
         }
     }
 
-    impl<'a, T: Debug + ?Sized, const N: usize> Matcher for AllMatcher<'a, T, N> {
+    impl<'a, T: TestDebug + ?Sized, const N: usize> Matcher for AllMatcher<'a, T, N> {
         type ActualT = T;
 
         fn matches(&self, actual: &Self::ActualT) -> MatcherResult {
@@ -105,19 +104,25 @@ pub mod internal {
         fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
             match N {
                 0 => anything::<T>().explain_match(actual),
-                1 => self.components[0].explain_match(actual),
+                1 => {
+                    let inner = explain(self.components[0], actual);
+                    MatchExplanation::create(inner.to_string()).with_child(inner)
+                }
                 _ => {
                     let failures = self
                         .components
                         .iter()
                         .filter(|component| !component.matches(actual).into_bool())
-                        .map(|component| format!("{}", component.explain_match(actual)))
-                        .collect::<Description>();
-                    if failures.len() == 1 {
-                        MatchExplanation::create(format!("{}", failures))
+                        .map(|component| explain(*component, actual))
+                        .collect::<Vec<_>>();
+                    let description =
+                        failures.iter().map(|failure| failure.to_string()).collect::<Description>();
+                    let text = if description.len() == 1 {
+                        format!("{}", description)
                     } else {
-                        MatchExplanation::create(format!("\n{}", failures.bullet_list().indent()))
-                    }
+                        format!("\n{}", description.bullet_list().indent())
+                    };
+                    MatchExplanation::create(text).with_children(failures)
                 }
             }
         }
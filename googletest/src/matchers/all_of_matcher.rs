@@ -0,0 +1,176 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult, TestDebug};
+use crate::matcher_support::description::Description;
+
+/// Matches a value which all of the matchers in `components` match.
+///
+/// This is the runtime counterpart of the [`all!`][crate::all] macro. Since
+/// the matchers are supplied as a `Vec` of boxed trait objects, the number of
+/// clauses and their kinds can be determined at runtime, e.g., one clause per
+/// entry of some configuration.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use googletest::matcher::Matcher;
+/// # fn should_pass() -> Result<()> {
+/// let forbidden_words = ["error", "panic"];
+/// let clauses = forbidden_words
+///     .iter()
+///     .map(|word| Box::new(not(contains_substring(*word))) as Box<dyn Matcher<ActualT = &str>>)
+///     .collect();
+/// verify_that!("All is well", all_of(clauses))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(
+///     "A string",
+///     all_of(vec![Box::new(starts_with("A")), Box::new(ends_with("not a string"))])
+/// )?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On a mismatch, the explanation lists each clause which did not match
+/// together with its index in `components`.
+///
+/// An empty `components` matches any value.
+pub fn all_of<'a, T: TestDebug + ?Sized + 'a>(
+    components: Vec<Box<dyn Matcher<ActualT = T> + 'a>>,
+) -> impl Matcher<ActualT = T> + 'a {
+    AllOfMatcher { components }
+}
+
+struct AllOfMatcher<'a, T: ?Sized> {
+    components: Vec<Box<dyn Matcher<ActualT = T> + 'a>>,
+}
+
+impl<'a, T: TestDebug + ?Sized> Matcher for AllOfMatcher<'a, T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        self.components.iter().all(|component| component.matches(actual).into_bool()).into()
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let failures = self
+            .components
+            .iter()
+            .enumerate()
+            .filter(|(_, component)| !component.matches(actual).into_bool())
+            .map(|(idx, component)| (idx, explain(component, actual)))
+            .collect::<Vec<_>>();
+        if failures.is_empty() {
+            MatchExplanation::create("which matches all clauses".to_string())
+        } else {
+            let description = failures
+                .iter()
+                .map(|(idx, explanation)| format!("{idx}. {explanation}"))
+                .collect::<Description>();
+            MatchExplanation::create(format!(
+                "which fails the following clauses:\n{}",
+                description.indent()
+            ))
+            .with_children(failures.into_iter().map(|(_, explanation)| explanation))
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        if self.components.is_empty() {
+            return match matcher_result {
+                MatcherResult::Matches => "is anything".to_string(),
+                MatcherResult::DoesNotMatch => "never matches".to_string(),
+            };
+        }
+        let clauses = self
+            .components
+            .iter()
+            .map(|m| m.describe(matcher_result))
+            .collect::<Description>()
+            .enumerate()
+            .indent();
+        format!(
+            "{}:\n{clauses}",
+            if matcher_result.into() {
+                "has all the following properties"
+            } else {
+                "has at least one of the following properties"
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::all_of;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn all_of_matches_when_all_clauses_match() -> Result<()> {
+        verify_that!(5, all_of(vec![Box::new(gt(1)), Box::new(lt(10))]))
+    }
+
+    #[test]
+    fn all_of_does_not_match_when_one_clause_does_not_match() -> Result<()> {
+        verify_that!(5, not(all_of(vec![Box::new(gt(1)), Box::new(lt(3))])))
+    }
+
+    #[test]
+    fn all_of_matches_anything_when_empty() -> Result<()> {
+        verify_that!(5, all_of(vec![]))
+    }
+
+    #[test]
+    fn all_of_describes_enumerated_clauses() -> Result<()> {
+        let matcher = all_of::<i32>(vec![Box::new(gt(1)), Box::new(lt(10))]);
+
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq(indoc!(
+                "
+                has all the following properties:
+                  0. is greater than 1
+                  1. is less than 10"
+            ))
+        )
+    }
+
+    #[test]
+    fn all_of_explains_each_failing_clause_with_its_index() -> Result<()> {
+        let result = verify_that!(
+            "A string",
+            all_of(vec![
+                Box::new(starts_with("Another")),
+                Box::new(contains_substring("str")),
+                Box::new(ends_with("strings")),
+            ])
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: "A string", which fails the following clauses:
+                  0. which does not start with "Another"
+                  2. which does not end with "strings"
+                "#
+            ))))
+        )
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Matches a container whose elements are pairwise distinct.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], all_unique())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 1], all_unique())?; // Fails: 1 occurs twice
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// `ContainerT` can be any container such that `&ContainerT` implements
+/// `IntoIterator` and whose elements implement [`Eq`] and [`Hash`]. On a
+/// mismatch, the explanation lists each duplicated value together with the
+/// indices at which it occurs.
+pub fn all_unique<ElementT: Debug + Eq + Hash, ContainerT: Debug + ?Sized>()
+-> impl Matcher<ActualT = ContainerT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    AllUniqueMatcher { identity: Identity, phantom: Default::default() }
+}
+
+/// Matches a container in which no two elements have the same key, as
+/// extracted by `key`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec!["a", "bb", "ccc"], all_unique_by_key(|s: &&str| s.len()))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![-1, 1], all_unique_by_key(|n: &i32| n.abs()))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn all_unique_by_key<ElementT, ContainerT, KeyT, KeyFnT>(
+    key: KeyFnT,
+) -> impl Matcher<ActualT = ContainerT>
+where
+    ElementT: Debug,
+    ContainerT: Debug + ?Sized,
+    KeyT: Debug + Eq + Hash,
+    KeyFnT: Fn(&ElementT) -> KeyT,
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    AllUniqueMatcher { identity: ByKey(key), phantom: Default::default() }
+}
+
+/// How [`AllUniqueMatcher`] tells whether two elements are the same.
+trait ElementIdentity<ElementT> {
+    /// Returns the groups of indices of elements in `elements` which are the
+    /// same, together with a description of what they have in common. Groups
+    /// are ordered by their first index.
+    fn duplicates<'a>(
+        &self,
+        elements: impl Iterator<Item = &'a ElementT>,
+    ) -> Vec<(String, Vec<usize>)>
+    where
+        ElementT: 'a;
+
+    /// What the elements are distinguished by, e.g., ` by key`.
+    fn unique_by(&self) -> &'static str;
+}
+
+struct Identity;
+
+impl<ElementT: Debug + Eq + Hash> ElementIdentity<ElementT> for Identity {
+    fn duplicates<'a>(
+        &self,
+        elements: impl Iterator<Item = &'a ElementT>,
+    ) -> Vec<(String, Vec<usize>)>
+    where
+        ElementT: 'a,
+    {
+        group_duplicates(elements.enumerate())
+            .into_iter()
+            .map(|(element, indices)| (format!("{element:?}"), indices))
+            .collect()
+    }
+
+    fn unique_by(&self) -> &'static str {
+        ""
+    }
+}
+
+struct ByKey<KeyFnT>(KeyFnT);
+
+impl<ElementT, KeyT: Debug + Eq + Hash, KeyFnT: Fn(&ElementT) -> KeyT> ElementIdentity<ElementT>
+    for ByKey<KeyFnT>
+{
+    fn duplicates<'a>(
+        &self,
+        elements: impl Iterator<Item = &'a ElementT>,
+    ) -> Vec<(String, Vec<usize>)>
+    where
+        ElementT: 'a,
+    {
+        group_duplicates(elements.map(&self.0).enumerate())
+            .into_iter()
+            .map(|(key, indices)| (format!("key {key:?}"), indices))
+            .collect()
+    }
+
+    fn unique_by(&self) -> &'static str {
+        " by key"
+    }
+}
+
+/// Groups the indices of equal values, keeping only the groups with more than
+/// one index, in the order of their first index.
+fn group_duplicates<T: Eq + Hash>(
+    values: impl Iterator<Item = (usize, T)>,
+) -> Vec<(T, Vec<usize>)> {
+    let mut groups: HashMap<T, Vec<usize>> = HashMap::new();
+    for (index, value) in values {
+        groups.entry(value).or_default().push(index);
+    }
+    let mut duplicates: Vec<_> =
+        groups.into_iter().filter(|(_, indices)| indices.len() > 1).collect();
+    duplicates.sort_by_key(|(_, indices)| indices[0]);
+    duplicates
+}
+
+struct AllUniqueMatcher<ContainerT: ?Sized, IdentityT> {
+    identity: IdentityT,
+    phantom: PhantomData<ContainerT>,
+}
+
+impl<ElementT: Debug, ContainerT: Debug + ?Sized, IdentityT: ElementIdentity<ElementT>> Matcher
+    for AllUniqueMatcher<ContainerT, IdentityT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    type ActualT = ContainerT;
+
+    fn matches(&self, actual: &ContainerT) -> MatcherResult {
+        self.identity.duplicates(actual.into_iter()).is_empty().into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("has pairwise distinct elements{}", self.identity.unique_by())
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("has duplicate elements{}", self.identity.unique_by())
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+        let duplicates = self.identity.duplicates(actual.into_iter());
+        if duplicates.is_empty() {
+            return MatchExplanation::create(format!(
+                "whose elements are pairwise distinct{}",
+                self.identity.unique_by()
+            ));
+        }
+        let duplicates = duplicates
+            .into_iter()
+            .map(|(value, indices)| {
+                let indices =
+                    indices.iter().map(|index| format!("#{index}")).collect::<Vec<_>>().join(", ");
+                format!("{value} at {indices}")
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        MatchExplanation::create(format!("which contains duplicates: {duplicates}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_unique, all_unique_by_key};
+    use crate::prelude::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn all_unique_matches_distinct_elements() -> Result<()> {
+        expect_that!(vec![1, 2, 3], all_unique());
+        expect_that!(Vec::<i32>::new(), all_unique());
+        verify_that!(VecDeque::from(["a", "b"]), all_unique())
+    }
+
+    #[test]
+    fn all_unique_does_not_match_duplicates() -> Result<()> {
+        verify_that!(vec![1, 2, 1], not(all_unique()))
+    }
+
+    #[test]
+    fn all_unique_by_key_uses_key() -> Result<()> {
+        expect_that!(vec![1, -2, 3], all_unique_by_key(|n: &i32| n.abs()));
+        verify_that!(vec![1, -1], not(all_unique_by_key(|n: &i32| n.abs())))
+    }
+
+    #[test]
+    fn all_unique_explains_duplicates_with_indices() -> Result<()> {
+        let result = verify_that!(vec![5, 2, 7, 2, 5, 2], all_unique());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "which contains duplicates: 5 at #0, #4; 2 at #1, #3, #5"
+            )))
+        )
+    }
+
+    #[test]
+    fn all_unique_by_key_explains_duplicate_keys() -> Result<()> {
+        let result = verify_that!(vec!["a", "bb", "c"], all_unique_by_key(|s: &&str| s.len()));
+
+        verify_that!(
+            result,
+            err(displays_as(
+                contains_substring("Expected: has pairwise distinct elements by key\n")
+                    .and(contains_substring("which contains duplicates: key 1 at #0, #2"))
+            ))
+        )
+    }
+}
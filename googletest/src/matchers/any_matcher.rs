@@ -0,0 +1,235 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macro is documented at the top level.
+#![doc(hidden)]
+
+/// Matches a value which at least one of the given matchers matches.
+///
+/// Each argument is a [`Matcher`][crate::matcher::Matcher] which matches
+/// against the actual value.
+///
+/// For example:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("A string", any!(starts_with("A"), ends_with("not a string")))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("A string", any!(starts_with("B"), ends_with("not a string")))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// Using this macro is equivalent to using the
+/// [`or`][crate::matchers::disjunction_matcher::OrMatcherExt::or] extension
+/// method:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(10, lt(9).or(gt(9)))?; // Also passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// On a mismatch, the explanation gives the reason why each of the matchers
+/// failed. Without arguments, `any!()` matches no value.
+#[macro_export]
+macro_rules! any {
+    ($($matcher:expr),* $(,)?) => {{
+        use $crate::matchers::any_matcher::internal::AnyMatcher;
+        AnyMatcher::new([$(&$matcher),*])
+    }}
+}
+
+/// Functionality needed by the [`any`] macro.
+///
+/// For internal use only. API stablility is not guaranteed!
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult, TestDebug};
+    use crate::matcher_support::description::Description;
+
+    /// A matcher which matches an input value matched by at least one of the
+    /// matchers in the array `components`.
+    ///
+    /// For internal use only. API stablility is not guaranteed!
+    #[doc(hidden)]
+    pub struct AnyMatcher<'a, T: TestDebug + ?Sized, const N: usize> {
+        components: [&'a dyn Matcher<ActualT = T>; N],
+    }
+
+    impl<'a, T: TestDebug + ?Sized, const N: usize> AnyMatcher<'a, T, N> {
+        /// Constructs an [`AnyMatcher`] with the given component matchers.
+        ///
+        /// Intended for use only by the [`any`] macro.
+        pub fn new(components: [&'a dyn Matcher<ActualT = T>; N]) -> Self {
+            Self { components }
+        }
+    }
+
+    impl<'a, T: TestDebug + ?Sized, const N: usize> Matcher for AnyMatcher<'a, T, N> {
+        type ActualT = T;
+
+        fn matches(&self, actual: &Self::ActualT) -> MatcherResult {
+            self.components.iter().any(|component| component.matches(actual).into_bool()).into()
+        }
+
+        fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
+            match N {
+                0 => MatchExplanation::create("which matches no alternative".to_string()),
+                1 => {
+                    let inner = explain(self.components[0], actual);
+                    MatchExplanation::create(inner.to_string()).with_child(inner)
+                }
+                _ => {
+                    let successes = self
+                        .components
+                        .iter()
+                        .filter(|component| component.matches(actual).into_bool())
+                        .map(|component| explain(*component, actual))
+                        .collect::<Vec<_>>();
+                    let explanations = if successes.is_empty() {
+                        self.components
+                            .iter()
+                            .map(|component| explain(*component, actual))
+                            .collect::<Vec<_>>()
+                    } else {
+                        successes
+                    };
+                    let description = explanations
+                        .iter()
+                        .map(|explanation| explanation.to_string())
+                        .collect::<Description>();
+                    let text = if description.len() == 1 {
+                        format!("{}", description)
+                    } else {
+                        format!("\n{}", description.bullet_list().indent())
+                    };
+                    MatchExplanation::create(text).with_children(explanations)
+                }
+            }
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            match N {
+                0 => match matcher_result {
+                    MatcherResult::Matches => "never matches".to_string(),
+                    MatcherResult::DoesNotMatch => "is anything".to_string(),
+                },
+                1 => self.components[0].describe(matcher_result),
+                _ => {
+                    let properties = self
+                        .components
+                        .iter()
+                        .map(|m| m.describe(matcher_result))
+                        .collect::<Description>()
+                        .bullet_list()
+                        .indent();
+                    format!(
+                        "{}:\n{properties}",
+                        if matcher_result.into() {
+                            "has at least one of the following properties"
+                        } else {
+                            "has all the following properties"
+                        }
+                    )
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::internal;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_when_one_matcher_matches() -> Result<()> {
+        verify_that!("A string", any!(starts_with("B"), ends_with("string")))
+    }
+
+    #[test]
+    fn does_not_match_when_no_matcher_matches() -> Result<()> {
+        verify_that!("A string", not(any!(starts_with("B"), ends_with("strings"))))
+    }
+
+    #[test]
+    fn matches_nothing_without_matchers() -> Result<()> {
+        let matcher: internal::AnyMatcher<i32, 0> = any!();
+
+        verify_that!(5, not(matcher))
+    }
+
+    #[test]
+    fn description_shows_more_than_one_matcher() -> Result<()> {
+        let first_matcher = starts_with("A");
+        let second_matcher = ends_with("string");
+        let matcher: internal::AnyMatcher<String, 2> = any!(first_matcher, second_matcher);
+
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq(indoc!(
+                "
+                has at least one of the following properties:
+                  * starts with prefix \"A\"
+                  * ends with suffix \"string\""
+            ))
+        )
+    }
+
+    #[test]
+    fn description_shows_one_matcher_directly() -> Result<()> {
+        let first_matcher = starts_with("A");
+        let matcher: internal::AnyMatcher<String, 1> = any!(first_matcher);
+
+        verify_that!(matcher.describe(MatcherResult::Matches), eq("starts with prefix \"A\""))
+    }
+
+    #[test]
+    fn mismatch_description_explains_every_failed_matcher() -> Result<()> {
+        let first_matcher = starts_with("Another");
+        let second_matcher = ends_with("strings");
+        let matcher: internal::AnyMatcher<str, 2> = any!(first_matcher, second_matcher);
+
+        verify_that!(
+            matcher.explain_match("A string"),
+            displays_as(eq(
+                "\n  * which does not start with \"Another\"\n  * which does not end with \"strings\""
+            ))
+        )
+    }
+
+    #[test]
+    fn match_description_shows_only_matching_matcher_when_negated() -> Result<()> {
+        let first_matcher = starts_with("A");
+        let second_matcher = ends_with("strings");
+        let matcher: internal::AnyMatcher<str, 2> = any!(first_matcher, second_matcher);
+
+        verify_that!(
+            matcher.explain_match("A string"),
+            displays_as(eq("which starts with prefix \"A\""))
+        )
+    }
+}
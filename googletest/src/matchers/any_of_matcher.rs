@@ -0,0 +1,202 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult, TestDebug};
+use crate::matcher_support::description::Description;
+
+/// Matches a value which at least one of the matchers in `components`
+/// matches.
+///
+/// This is the runtime counterpart of the
+/// [`or`][crate::matchers::disjunction_matcher::OrMatcherExt::or] extension
+/// method. Since the matchers are supplied as a `Vec` of boxed trait objects,
+/// the number of clauses and their kinds can be determined at runtime, e.g.,
+/// one clause per entry of some configuration.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use googletest::matcher::Matcher;
+/// # fn should_pass() -> Result<()> {
+/// let allowed_prefixes = ["http://", "https://"];
+/// let clauses = allowed_prefixes
+///     .iter()
+///     .map(|prefix| Box::new(starts_with(*prefix)) as Box<dyn Matcher<ActualT = &str>>)
+///     .collect();
+/// verify_that!("https://example.com", any_of(clauses))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(
+///     "A string",
+///     any_of(vec![Box::new(starts_with("B")), Box::new(ends_with("C"))])
+/// )?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On a mismatch, the explanation lists each clause together with its index in
+/// `components` and the reason it did not match.
+///
+/// An empty `components` matches no value.
+pub fn any_of<'a, T: TestDebug + ?Sized + 'a>(
+    components: Vec<Box<dyn Matcher<ActualT = T> + 'a>>,
+) -> impl Matcher<ActualT = T> + 'a {
+    AnyOfMatcher { components }
+}
+
+struct AnyOfMatcher<'a, T: ?Sized> {
+    components: Vec<Box<dyn Matcher<ActualT = T> + 'a>>,
+}
+
+impl<'a, T: TestDebug + ?Sized> Matcher for AnyOfMatcher<'a, T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        self.components.iter().any(|component| component.matches(actual).into_bool()).into()
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let successes = self
+            .components
+            .iter()
+            .enumerate()
+            .filter(|(_, component)| component.matches(actual).into_bool())
+            .map(|(idx, component)| (idx, explain(component, actual)))
+            .collect::<Vec<_>>();
+        if !successes.is_empty() {
+            return MatchExplanation::create(format!(
+                "which matches the following clauses:\n{}",
+                numbered(&successes).indent()
+            ))
+            .with_children(successes.into_iter().map(|(_, explanation)| explanation));
+        }
+        if self.components.is_empty() {
+            return MatchExplanation::create("which matches no clauses".to_string());
+        }
+        let failures = self
+            .components
+            .iter()
+            .enumerate()
+            .map(|(idx, component)| (idx, explain(component, actual)))
+            .collect::<Vec<_>>();
+        MatchExplanation::create(format!(
+            "which fails all clauses:\n{}",
+            numbered(&failures).indent()
+        ))
+        .with_children(failures.into_iter().map(|(_, explanation)| explanation))
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        if self.components.is_empty() {
+            return match matcher_result {
+                MatcherResult::Matches => "never matches".to_string(),
+                MatcherResult::DoesNotMatch => "is anything".to_string(),
+            };
+        }
+        let clauses = self
+            .components
+            .iter()
+            .map(|m| m.describe(matcher_result))
+            .collect::<Description>()
+            .enumerate()
+            .indent();
+        format!(
+            "{}:\n{clauses}",
+            if matcher_result.into() {
+                "has at least one of the following properties"
+            } else {
+                "has all the following properties"
+            }
+        )
+    }
+}
+
+/// Lists the given explanations, each preceded by the index of its clause.
+fn numbered(explanations: &[(usize, MatchExplanation)]) -> Description {
+    explanations.iter().map(|(idx, explanation)| format!("{idx}. {explanation}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::any_of;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn any_of_matches_when_one_clause_matches() -> Result<()> {
+        verify_that!(5, any_of(vec![Box::new(lt(1)), Box::new(eq(5))]))
+    }
+
+    #[test]
+    fn any_of_does_not_match_when_no_clause_matches() -> Result<()> {
+        verify_that!(5, not(any_of(vec![Box::new(lt(1)), Box::new(gt(10))])))
+    }
+
+    #[test]
+    fn any_of_matches_nothing_when_empty() -> Result<()> {
+        verify_that!(5, not(any_of(vec![])))
+    }
+
+    #[test]
+    fn any_of_describes_enumerated_clauses() -> Result<()> {
+        let matcher = any_of::<i32>(vec![Box::new(lt(1)), Box::new(gt(10))]);
+
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq(indoc!(
+                "
+                has at least one of the following properties:
+                  0. is less than 1
+                  1. is greater than 10"
+            ))
+        )
+    }
+
+    #[test]
+    fn any_of_explains_every_clause_with_its_index() -> Result<()> {
+        let result = verify_that!(
+            "A string",
+            any_of(vec![Box::new(starts_with("Another")), Box::new(ends_with("strings"))])
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: "A string", which fails all clauses:
+                  0. which does not start with "Another"
+                  1. which does not end with "strings"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn any_of_explains_matching_clauses_when_negated() -> Result<()> {
+        let result = verify_that!(5, not(any_of(vec![Box::new(lt(10)), Box::new(gt(10))])));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: 5, which matches the following clauses:
+                  0. which is less than 10
+                "
+            ))))
+        )
+    }
+}
@@ -12,8 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{Matcher, MatcherResult};
-use std::{fmt::Debug, marker::PhantomData};
+use crate::matcher::{Matcher, MatcherResult, TestDebug};
+use std::marker::PhantomData;
 
 /// Matches anything. This matcher always succeeds.
 ///
@@ -29,13 +29,21 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # }
 /// # should_pass().unwrap();
 /// ```
-pub fn anything<T: Debug + ?Sized>() -> impl Matcher<ActualT = T> {
+pub fn anything<T: TestDebug + ?Sized>() -> impl Matcher<ActualT = T> + Copy {
     Anything::<T>(Default::default())
 }
 
 struct Anything<T: ?Sized>(PhantomData<T>);
 
-impl<T: Debug + ?Sized> Matcher for Anything<T> {
+impl<T: ?Sized> Clone for Anything<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Anything<T> {}
+
+impl<T: TestDebug + ?Sized> Matcher for Anything<T> {
     type ActualT = T;
 
     fn matches(&self, _: &T) -> MatcherResult {
@@ -75,4 +83,11 @@ mod tests {
         verify_that!(value, some(anything()))?;
         Ok(())
     }
+
+    #[test]
+    fn anything_matcher_can_be_copied_and_reused() -> Result<()> {
+        let matcher = anything();
+        verify_that!(32, matcher)?;
+        verify_that!(64, matcher)
+    }
 }
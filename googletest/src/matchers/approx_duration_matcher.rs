@@ -0,0 +1,166 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::time::Duration;
+
+/// Matches a [`Duration`] within `tolerance` of `expected`.
+///
+/// This is the analogue of [`near`][crate::matchers::near] for durations, which
+/// are typically measured and hence never exact:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::time::Duration;
+/// # fn should_pass() -> Result<()> {
+/// let elapsed = Duration::from_millis(110);
+/// verify_that!(elapsed, approx_duration(Duration::from_millis(100), Duration::from_millis(20)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// let elapsed = Duration::from_millis(135);
+/// verify_that!(elapsed, approx_duration(Duration::from_millis(100), Duration::from_millis(20)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// Durations are printed with human-readable units, so the failure of the
+/// last assertion above reads:
+///
+/// ```text
+/// Value of: elapsed
+/// Expected: is within 20ms of 100ms
+/// Actual: 135ms, which is 35ms longer than 100ms
+/// ```
+pub fn approx_duration(expected: Duration, tolerance: Duration) -> ApproxDurationMatcher {
+    ApproxDurationMatcher { expected, tolerance }
+}
+
+/// A matcher which matches durations within a tolerance of an expected
+/// duration.
+///
+/// See [`approx_duration`].
+#[derive(Clone, Copy)]
+pub struct ApproxDurationMatcher {
+    expected: Duration,
+    tolerance: Duration,
+}
+
+impl Matcher for ApproxDurationMatcher {
+    type ActualT = Duration;
+
+    fn matches(&self, actual: &Duration) -> MatcherResult {
+        (abs_diff(*actual, self.expected) <= self.tolerance).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is within {:?} of {:?}", self.tolerance, self.expected)
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't within {:?} of {:?}", self.tolerance, self.expected)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &Duration) -> MatchExplanation {
+        let difference = abs_diff(*actual, self.expected);
+        if *actual > self.expected {
+            MatchExplanation::create(format!(
+                "which is {:?} longer than {:?}",
+                difference, self.expected
+            ))
+        } else if *actual < self.expected {
+            MatchExplanation::create(format!(
+                "which is {:?} shorter than {:?}",
+                difference, self.expected
+            ))
+        } else {
+            MatchExplanation::create(format!("which is exactly {:?}", self.expected))
+        }
+    }
+}
+
+fn abs_diff(a: Duration, b: Duration) -> Duration {
+    if a > b { a - b } else { b - a }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::approx_duration;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use std::time::Duration;
+
+    const MS_100: Duration = Duration::from_millis(100);
+    const MS_20: Duration = Duration::from_millis(20);
+
+    #[test]
+    fn approx_duration_matches_duration_within_tolerance() -> Result<()> {
+        verify_that!(Duration::from_millis(80), approx_duration(MS_100, MS_20))?;
+        verify_that!(Duration::from_millis(100), approx_duration(MS_100, MS_20))?;
+        verify_that!(Duration::from_millis(120), approx_duration(MS_100, MS_20))
+    }
+
+    #[test]
+    fn approx_duration_does_not_match_duration_outside_tolerance() -> Result<()> {
+        verify_that!(Duration::from_micros(79_999), not(approx_duration(MS_100, MS_20)))?;
+        verify_that!(Duration::from_micros(120_001), not(approx_duration(MS_100, MS_20)))
+    }
+
+    #[test]
+    fn approx_duration_handles_tolerance_larger_than_expected() -> Result<()> {
+        verify_that!(Duration::ZERO, approx_duration(MS_20, MS_100))
+    }
+
+    #[test]
+    fn approx_duration_describes_itself_with_units() -> Result<()> {
+        verify_that!(
+            approx_duration(Duration::from_secs(2), Duration::from_micros(500))
+                .describe(MatcherResult::Matches),
+            eq("is within 500µs of 2s")
+        )?;
+        verify_that!(
+            approx_duration(MS_100, MS_20).describe(MatcherResult::DoesNotMatch),
+            eq("isn't within 20ms of 100ms")
+        )
+    }
+
+    #[test]
+    fn approx_duration_explains_difference() -> Result<()> {
+        let matcher = approx_duration(MS_100, MS_20);
+        verify_that!(
+            matcher.explain_match(&Duration::from_millis(135)),
+            displays_as(eq("which is 35ms longer than 100ms"))
+        )?;
+        verify_that!(
+            matcher.explain_match(&Duration::from_millis(50)),
+            displays_as(eq("which is 50ms shorter than 100ms"))
+        )?;
+        verify_that!(matcher.explain_match(&MS_100), displays_as(eq("which is exactly 100ms")))
+    }
+
+    #[test]
+    fn approx_duration_failure_message_shows_actual_duration() -> Result<()> {
+        let result = verify_that!(Duration::from_millis(135), approx_duration(MS_100, MS_20));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("Actual: 135ms, which is 35ms longer than 100ms")))
+        )
+    }
+}
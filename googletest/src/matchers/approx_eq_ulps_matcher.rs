@@ -0,0 +1,258 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::fmt::Debug;
+
+/// Matches a floating point value at most `max_ulps` units in the last place
+/// (ULPs) away from `expected`.
+///
+/// The distance is the number of representable values of the type between the
+/// actual and expected values, as computed from their bit representations.
+/// Unlike [`near`][crate::matchers::near], the tolerance thus scales with the
+/// magnitude of the values compared. Positive and negative zero are 0 ULPs
+/// apart.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(0.1 + 0.2, approx_eq_ulps(0.3, 1))?; // Passes
+/// verify_that!(1e300 * 3.0, approx_eq_ulps(3e300, 4))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(1.0f32 + f32::EPSILON * 4.0, approx_eq_ulps(1.0, 2))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// By default, consistent with the IEEE floating point standard, `NaN` is
+/// infinitely far away from any value, including `NaN`. To treat two `NaN`
+/// values as equal, use the method [`UlpsMatcher::nans_are_equal`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(f64::NAN, not(approx_eq_ulps(f64::NAN, 4)))?; // Passes
+/// verify_that!(f64::NAN, approx_eq_ulps(f64::NAN, 4).nans_are_equal())?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// Infinities are one ULP away from the largest finite value of the same
+/// sign.
+pub fn approx_eq_ulps<T: UlpsFloat>(expected: T, max_ulps: u64) -> UlpsMatcher<T> {
+    UlpsMatcher { expected, max_ulps, nans_are_equal: false }
+}
+
+/// A matcher which matches floating-point numbers at most a given number of
+/// ULPs away from its expected value.
+///
+/// See [`approx_eq_ulps`].
+#[derive(Clone, Copy)]
+pub struct UlpsMatcher<T> {
+    expected: T,
+    max_ulps: u64,
+    nans_are_equal: bool,
+}
+
+impl<T> UlpsMatcher<T> {
+    /// Configures this instance to treat two NaNs as equal.
+    ///
+    /// This behaviour differs from the IEEE standard for floating point which
+    /// treats two NaNs as infinitely far apart.
+    pub fn nans_are_equal(mut self) -> Self {
+        self.nans_are_equal = true;
+        self
+    }
+
+    /// Configures this instance to treat two NaNs as not equal.
+    ///
+    /// This behaviour complies with the IEEE standard for floating point. It is
+    /// the default behaviour for this matcher, so invoking this method is
+    /// usually redundant.
+    pub fn nans_are_not_equal(mut self) -> Self {
+        self.nans_are_equal = false;
+        self
+    }
+}
+
+impl<T: UlpsFloat> Matcher for UlpsMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        match actual.ulps_from(self.expected) {
+            Some(ulps) => (ulps <= self.max_ulps).into(),
+            None => (self.nans_are_equal && actual.is_nan() && self.expected.is_nan()).into(),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is within {} ULPs of {:?}", self.max_ulps, self.expected)
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't within {} ULPs of {:?}", self.max_ulps, self.expected)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        match actual.ulps_from(self.expected) {
+            Some(ulps) => MatchExplanation::create(format!("which is {} ULPs away", ulps)),
+            None => {
+                MatchExplanation::create(format!("which {}", self.describe(self.matches(actual))))
+            }
+        }
+    }
+}
+
+/// A floating point type whose values can be compared by ULPs.
+///
+/// This is implemented for [`f32`] and [`f64`].
+pub trait UlpsFloat: Copy + Debug {
+    /// Returns the number of representable values between `self` and `other`,
+    /// or `None` if either is NaN.
+    fn ulps_from(self, other: Self) -> Option<u64>;
+
+    /// Returns whether `self` is NaN.
+    fn is_nan(self) -> bool;
+}
+
+impl UlpsFloat for f32 {
+    fn ulps_from(self, other: Self) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        // Maps the sign-magnitude bit representation onto a scale on which
+        // adjacent values differ by one and both zeros are zero. Since NaNs are
+        // excluded, this cannot overflow.
+        fn ordered(x: f32) -> i32 {
+            let bits = x.to_bits() as i32;
+            if bits < 0 { i32::MIN - bits } else { bits }
+        }
+        Some(ordered(self).abs_diff(ordered(other)).into())
+    }
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+}
+
+impl UlpsFloat for f64 {
+    fn ulps_from(self, other: Self) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        // See the implementation for f32.
+        fn ordered(x: f64) -> i64 {
+            let bits = x.to_bits() as i64;
+            if bits < 0 { i64::MIN - bits } else { bits }
+        }
+        Some(ordered(self).abs_diff(ordered(other)))
+    }
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::approx_eq_ulps;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+
+    #[test]
+    fn matches_equal_f64() -> Result<()> {
+        verify_that!(1.5f64, approx_eq_ulps(1.5, 0))
+    }
+
+    #[test]
+    fn matches_adjacent_f64_within_tolerance() -> Result<()> {
+        let next = f64::from_bits(1.0f64.to_bits() + 1);
+        verify_that!(next, approx_eq_ulps(1.0, 1))?;
+        verify_that!(next, not(approx_eq_ulps(1.0, 0)))
+    }
+
+    #[test]
+    fn matches_adjacent_f32_within_tolerance() -> Result<()> {
+        let next = f32::from_bits(1.0f32.to_bits() + 3);
+        verify_that!(next, approx_eq_ulps(1.0, 3))?;
+        verify_that!(next, not(approx_eq_ulps(1.0, 2)))
+    }
+
+    #[test]
+    fn zeros_of_either_sign_are_equal() -> Result<()> {
+        verify_that!(-0.0f64, approx_eq_ulps(0.0, 0))?;
+        verify_that!(0.0f32, approx_eq_ulps(-0.0, 0))
+    }
+
+    #[test]
+    fn counts_ulps_across_zero() -> Result<()> {
+        let smallest = f64::from_bits(1);
+        verify_that!(-smallest, approx_eq_ulps(smallest, 2))?;
+        verify_that!(-smallest, not(approx_eq_ulps(smallest, 1)))
+    }
+
+    #[test]
+    fn counts_ulps_between_extremes() -> Result<()> {
+        let ulps = 2 * 0x7FEF_FFFF_FFFF_FFFF;
+        verify_that!(f64::MAX, approx_eq_ulps(f64::MIN, ulps))?;
+        verify_that!(f64::MAX, not(approx_eq_ulps(f64::MIN, ulps - 1)))?;
+        verify_that!(f64::INFINITY, approx_eq_ulps(f64::NEG_INFINITY, ulps + 2))
+    }
+
+    #[test]
+    fn nan_is_not_equal_to_nan_by_default() -> Result<()> {
+        verify_that!(f64::NAN, not(approx_eq_ulps(f64::NAN, u64::MAX)))
+    }
+
+    #[test]
+    fn nan_is_equal_to_nan_if_nans_are_equal() -> Result<()> {
+        verify_that!(f32::NAN, approx_eq_ulps(f32::NAN, 0).nans_are_equal())
+    }
+
+    #[test]
+    fn nan_is_not_equal_to_number_if_nans_are_equal() -> Result<()> {
+        verify_that!(f32::NAN, not(approx_eq_ulps(1.0, u64::MAX).nans_are_equal()))?;
+        verify_that!(1.0f32, not(approx_eq_ulps(f32::NAN, u64::MAX).nans_are_equal()))
+    }
+
+    #[test]
+    fn infinity_is_one_ulp_from_max() -> Result<()> {
+        verify_that!(f64::INFINITY, approx_eq_ulps(f64::MAX, 1))
+    }
+
+    #[test]
+    fn describes_tolerance() -> Result<()> {
+        verify_that!(
+            approx_eq_ulps(1.0f64, 4).describe(MatcherResult::Matches),
+            eq("is within 4 ULPs of 1.0")
+        )
+    }
+
+    #[test]
+    fn explains_distance() -> Result<()> {
+        let actual = f64::from_bits(1.0f64.to_bits() + 10);
+        verify_that!(
+            approx_eq_ulps(1.0f64, 4).explain_match(&actual),
+            displays_as(eq("which is 10 ULPs away"))
+        )
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::{
+    fmt::Debug,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Matches a timestamp, either a [`SystemTime`] or an [`Instant`], at most
+/// `tolerance` before or after `expected`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::time::{Duration, SystemTime};
+/// # fn should_pass() -> Result<()> {
+/// let start = SystemTime::now();
+/// let recorded = start + Duration::from_millis(5);
+/// verify_that!(recorded, approx_time(start, Duration::from_secs(1)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let start = SystemTime::now();
+/// let recorded = start - Duration::from_secs(3);
+/// verify_that!(recorded, approx_time(start, Duration::from_secs(1)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On mismatch, the explanation gives the distance between the timestamps and
+/// its direction, for example "which is 3s before the expected time".
+pub fn approx_time<T: Timestamp>(expected: T, tolerance: Duration) -> ApproxTimeMatcher<T> {
+    ApproxTimeMatcher { expected, tolerance }
+}
+
+/// A matcher which matches timestamps within a tolerance of an expected
+/// timestamp.
+///
+/// See [`approx_time`].
+#[derive(Clone, Copy)]
+pub struct ApproxTimeMatcher<T> {
+    expected: T,
+    tolerance: Duration,
+}
+
+impl<T: Timestamp> Matcher for ApproxTimeMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        (distance(*actual, self.expected) <= self.tolerance).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is within {:?} of {:?}", self.tolerance, self.expected)
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't within {:?} of {:?}", self.tolerance, self.expected)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let delta = distance(*actual, self.expected);
+        if *actual > self.expected {
+            MatchExplanation::create(format!("which is {:?} after the expected time", delta))
+        } else if *actual < self.expected {
+            MatchExplanation::create(format!("which is {:?} before the expected time", delta))
+        } else {
+            MatchExplanation::create("which is exactly the expected time".to_string())
+        }
+    }
+}
+
+fn distance<T: Timestamp>(a: T, b: T) -> Duration {
+    if a > b { a.duration_since(b) } else { b.duration_since(a) }
+}
+
+/// A point in time which [`approx_time`] can compare.
+///
+/// This is implemented for [`SystemTime`] and [`Instant`].
+pub trait Timestamp: Ord + Copy + Debug {
+    /// Returns the time elapsed from `earlier` to `self`, which must not be
+    /// later than `self`.
+    fn duration_since(self, earlier: Self) -> Duration;
+}
+
+impl Timestamp for SystemTime {
+    fn duration_since(self, earlier: Self) -> Duration {
+        SystemTime::duration_since(&self, earlier).unwrap_or_default()
+    }
+}
+
+impl Timestamp for Instant {
+    fn duration_since(self, earlier: Self) -> Duration {
+        Instant::duration_since(&self, earlier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::approx_time;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use std::time::{Duration, Instant, SystemTime};
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn approx_time_matches_system_time_within_tolerance() -> Result<()> {
+        let expected = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        verify_that!(expected + SECOND, approx_time(expected, SECOND))?;
+        verify_that!(expected - SECOND, approx_time(expected, SECOND))?;
+        verify_that!(expected, approx_time(expected, Duration::ZERO))
+    }
+
+    #[test]
+    fn approx_time_does_not_match_system_time_outside_tolerance() -> Result<()> {
+        let expected = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        verify_that!(expected + 2 * SECOND, not(approx_time(expected, SECOND)))?;
+        verify_that!(expected - 2 * SECOND, not(approx_time(expected, SECOND)))
+    }
+
+    #[test]
+    fn approx_time_matches_instant_within_tolerance() -> Result<()> {
+        let expected = Instant::now();
+        verify_that!(expected + SECOND, approx_time(expected, SECOND))?;
+        verify_that!(expected + 2 * SECOND, not(approx_time(expected, SECOND)))
+    }
+
+    #[test]
+    fn approx_time_describes_tolerance() -> Result<()> {
+        let expected = SystemTime::UNIX_EPOCH;
+        verify_that!(
+            approx_time(expected, SECOND).describe(MatcherResult::Matches),
+            starts_with("is within 1s of ")
+        )
+    }
+
+    #[test]
+    fn approx_time_explains_direction_and_delta() -> Result<()> {
+        let expected = Instant::now();
+        let matcher = approx_time(expected, SECOND);
+        verify_that!(
+            matcher.explain_match(&(expected + Duration::from_millis(1500))),
+            displays_as(eq("which is 1.5s after the expected time"))
+        )?;
+        verify_that!(
+            matcher.explain_match(&(expected - Duration::from_millis(250))),
+            displays_as(eq("which is 250ms before the expected time"))
+        )?;
+        verify_that!(
+            matcher.explain_match(&expected),
+            displays_as(eq("which is exactly the expected time"))
+        )
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{BoxMatcher, Matcher};
+
+/// Extension trait providing the [`boxed`][BoxedMatcherExt::boxed] method.
+pub trait BoxedMatcherExt: Matcher {
+    /// Boxes this matcher, erasing its concrete type.
+    ///
+    /// This is useful to store matchers of different types together or to
+    /// choose a matcher at runtime:
+    ///
+    /// ```
+    /// # use googletest::matcher::BoxMatcher;
+    /// # use googletest::prelude::*;
+    /// fn status_matcher(expect_success: bool) -> BoxMatcher<'static, u16> {
+    ///     if expect_success { lt(300).boxed() } else { ge(400).boxed() }
+    /// }
+    ///
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!(204, status_matcher(true))?; // Passes
+    /// verify_that!(404, status_matcher(false))?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    fn boxed<'a>(self) -> BoxMatcher<'a, Self::ActualT>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+}
+
+impl<M> BoxedMatcherExt for M where M: Matcher {}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxedMatcherExt;
+    use crate::matcher::{BoxMatcher, Matcher, MatcherResult};
+    use crate::prelude::*;
+
+    #[test]
+    fn boxed_matcher_matches_what_inner_matcher_matches() -> Result<()> {
+        verify_that!(123, eq(123).boxed())?;
+        verify_that!(123, not(eq(234).boxed()))
+    }
+
+    #[test]
+    fn boxed_matchers_of_different_types_can_be_stored_together() -> Result<()> {
+        let matchers: Vec<BoxMatcher<'static, String>> =
+            vec![starts_with("A").boxed(), ends_with("string").boxed(), eq("A string").boxed()];
+
+        for matcher in &matchers {
+            verify_that!("A string".to_string(), matcher)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn boxed_matcher_can_borrow_expected_value() -> Result<()> {
+        let expected = vec![1, 2, 3];
+        let matcher: BoxMatcher<'_, Vec<i32>> = eq_deref_of(&expected).boxed();
+
+        verify_that!(vec![1, 2, 3], matcher)
+    }
+
+    #[test]
+    fn boxed_matcher_can_be_returned_from_trait_object() -> Result<()> {
+        trait Expectation {
+            fn matcher(&self) -> BoxMatcher<'static, i32>;
+        }
+        struct Positive;
+        impl Expectation for Positive {
+            fn matcher(&self) -> BoxMatcher<'static, i32> {
+                gt(0).boxed()
+            }
+        }
+        let expectation: Box<dyn Expectation> = Box::new(Positive);
+
+        verify_that!(5, expectation.matcher())
+    }
+
+    #[test]
+    fn boxed_matcher_describes_itself_as_inner_matcher() -> Result<()> {
+        let matcher: BoxMatcher<'static, i32> = gt(0).boxed();
+
+        verify_that!(matcher.describe(MatcherResult::Matches), eq("is greater than 0"))
+    }
+}
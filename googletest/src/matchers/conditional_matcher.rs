@@ -0,0 +1,145 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::fmt::Debug;
+
+/// Matches a value with `if_true` when `condition` holds and with `if_false`
+/// otherwise.
+///
+/// This lets parameterised test helpers vary their expectations without
+/// duplicating whole assertions:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// fn check_response(status: u16, expect_success: bool) -> Result<()> {
+///     verify_that!(status, conditional(expect_success, lt(400), ge(400)))
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// check_response(200, true)?; // Passes
+/// check_response(404, false)?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// check_response(500, true)?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The condition is evaluated once, when the matcher is constructed. The
+/// description names the branch which was selected, so the failure of the
+/// last assertion above reads:
+///
+/// ```text
+/// Value of: status
+/// Expected: is less than 400 (since the condition is true)
+/// Actual: 500, which is greater than or equal to 400
+/// ```
+pub fn conditional<ActualT: Debug + ?Sized>(
+    condition: bool,
+    if_true: impl Matcher<ActualT = ActualT>,
+    if_false: impl Matcher<ActualT = ActualT>,
+) -> impl Matcher<ActualT = ActualT> {
+    ConditionalMatcher { condition, if_true, if_false }
+}
+
+struct ConditionalMatcher<IfTrueT, IfFalseT> {
+    condition: bool,
+    if_true: IfTrueT,
+    if_false: IfFalseT,
+}
+
+impl<ActualT, IfTrueT, IfFalseT> Matcher for ConditionalMatcher<IfTrueT, IfFalseT>
+where
+    ActualT: Debug + ?Sized,
+    IfTrueT: Matcher<ActualT = ActualT>,
+    IfFalseT: Matcher<ActualT = ActualT>,
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        if self.condition { self.if_true.matches(actual) } else { self.if_false.matches(actual) }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        if self.condition {
+            format!("{} (since the condition is true)", self.if_true.describe(matcher_result))
+        } else {
+            format!("{} (since the condition is false)", self.if_false.describe(matcher_result))
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let inner = if self.condition {
+            explain(&self.if_true, actual)
+        } else {
+            explain(&self.if_false, actual)
+        };
+        MatchExplanation::create(inner.to_string()).with_child(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::conditional;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn conditional_uses_first_matcher_when_condition_is_true() -> Result<()> {
+        verify_that!(1, conditional(true, eq(1), eq(2)))?;
+        verify_that!(2, not(conditional(true, eq(1), eq(2))))
+    }
+
+    #[test]
+    fn conditional_uses_second_matcher_when_condition_is_false() -> Result<()> {
+        verify_that!(2, conditional(false, eq(1), eq(2)))?;
+        verify_that!(1, not(conditional(false, eq(1), eq(2))))
+    }
+
+    #[test]
+    fn conditional_describes_selected_branch() -> Result<()> {
+        verify_that!(
+            conditional(true, eq(1), eq(2)).describe(MatcherResult::Matches),
+            eq("is equal to 1 (since the condition is true)")
+        )?;
+        verify_that!(
+            conditional(false, eq(1), eq(2)).describe(MatcherResult::DoesNotMatch),
+            eq("isn't equal to 2 (since the condition is false)")
+        )
+    }
+
+    #[test]
+    fn conditional_explains_mismatch_with_selected_branch() -> Result<()> {
+        let result =
+            verify_that!(vec![1, 2], conditional(false, contains(eq(1)), not(contains(eq(1)))));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: contains no element which is equal to 1 (since the condition is false)
+                Actual: [
+                    1,
+                    2,
+                ], which contains a matching element
+                "
+            ))))
+        )
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Extension trait providing the [`and`][AndMatcherExt::and] method.
@@ -77,16 +77,18 @@ impl<T: Debug + ?Sized, M1: Matcher<ActualT = T>, M2: Matcher<ActualT = T>> Matc
 
     fn explain_match(&self, actual: &T) -> MatchExplanation {
         match (self.m1.matches(actual), self.m2.matches(actual)) {
-            (MatcherResult::Matches, MatcherResult::Matches) => MatchExplanation::create(format!(
-                "{} and\n{}",
-                self.m1.explain_match(actual),
-                self.m2.explain_match(actual)
-            )),
-            (MatcherResult::DoesNotMatch, MatcherResult::Matches) => self.m1.explain_match(actual),
-            (MatcherResult::Matches, MatcherResult::DoesNotMatch) => self.m2.explain_match(actual),
-            (MatcherResult::DoesNotMatch, MatcherResult::DoesNotMatch) => MatchExplanation::create(
-                format!("{} and\n{}", self.m1.explain_match(actual), self.m2.explain_match(actual)),
-            ),
+            (MatcherResult::DoesNotMatch, MatcherResult::Matches) => {
+                let explanation = explain(&self.m1, actual);
+                MatchExplanation::create(explanation.to_string()).with_child(explanation)
+            }
+            (MatcherResult::Matches, MatcherResult::DoesNotMatch) => {
+                let explanation = explain(&self.m2, actual);
+                MatchExplanation::create(explanation.to_string()).with_child(explanation)
+            }
+            _ => {
+                let (e1, e2) = (explain(&self.m1, actual), explain(&self.m2, actual));
+                MatchExplanation::create(format!("{e1} and\n{e2}")).with_children([e1, e2])
+            }
         }
     }
 
@@ -98,6 +98,14 @@ pub struct ContainerEqMatcher<ActualContainerT: ?Sized, ExpectedContainerT> {
     phantom: PhantomData<ActualContainerT>,
 }
 
+impl<ActualContainerT: ?Sized, ExpectedContainerT: Clone> Clone
+    for ContainerEqMatcher<ActualContainerT, ExpectedContainerT>
+{
+    fn clone(&self) -> Self {
+        Self { expected: self.expected.clone(), phantom: PhantomData }
+    }
+}
+
 impl<ActualElementT, ActualContainerT, ExpectedElementT, ExpectedContainerT> Matcher
     for ContainerEqMatcher<ActualContainerT, ExpectedContainerT>
 where
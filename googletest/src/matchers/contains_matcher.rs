@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Matches an iterable type whose elements contain a value matched by `inner`.
@@ -40,17 +40,19 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # should_fail_1().unwrap_err();
 /// # should_fail_2().unwrap_err();
 /// ```
-pub fn contains<T, InnerMatcherT>(inner: InnerMatcherT) -> ContainsMatcher<T, InnerMatcherT> {
+pub fn contains<T: ?Sized, InnerMatcherT>(
+    inner: InnerMatcherT,
+) -> ContainsMatcher<T, InnerMatcherT> {
     ContainsMatcher { inner, count: None, phantom: Default::default() }
 }
 
-pub struct ContainsMatcher<T, InnerMatcherT> {
+pub struct ContainsMatcher<T: ?Sized, InnerMatcherT> {
     inner: InnerMatcherT,
     count: Option<Box<dyn Matcher<ActualT = usize>>>,
     phantom: PhantomData<T>,
 }
 
-impl<T, InnerMatcherT> ContainsMatcher<T, InnerMatcherT> {
+impl<T: ?Sized, InnerMatcherT> ContainsMatcher<T, InnerMatcherT> {
     /// Configures this instance to match containers which contain a number of
     /// matching items matched by `count`.
     ///
@@ -79,7 +81,7 @@ impl<T, InnerMatcherT> ContainsMatcher<T, InnerMatcherT> {
 //  because val is dropped before matcher but the trait bound requires that
 //  the argument to matches outlive the matcher. It works fine if one defines
 //  val before matcher.
-impl<T: Debug, InnerMatcherT: Matcher<ActualT = T>, ContainerT: Debug> Matcher
+impl<T: Debug, InnerMatcherT: Matcher<ActualT = T>, ContainerT: Debug + ?Sized> Matcher
     for ContainsMatcher<ContainerT, InnerMatcherT>
 where
     for<'a> &'a ContainerT: IntoIterator<Item = &'a T>,
@@ -102,8 +104,9 @@ where
     fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
         let count = self.count_matches(actual);
         match (count, &self.count) {
-            (_, Some(_)) => {
-                MatchExplanation::create(format!("which contains {} matching elements", count))
+            (_, Some(count_matcher)) => {
+                MatchExplanation::create(format!("which contains {count} matching elements"))
+                    .with_child(explain(count_matcher.as_ref(), &count))
             }
             (0, None) => {
                 MatchExplanation::create("which does not contain a matching element".to_string())
@@ -135,8 +138,8 @@ where
     }
 }
 
-impl<ActualT, InnerMatcherT> ContainsMatcher<ActualT, InnerMatcherT> {
-    fn count_matches<T: Debug, ContainerT>(&self, actual: &ContainerT) -> usize
+impl<ActualT: ?Sized, InnerMatcherT> ContainsMatcher<ActualT, InnerMatcherT> {
+    fn count_matches<T: Debug, ContainerT: ?Sized>(&self, actual: &ContainerT) -> usize
     where
         for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
         InnerMatcherT: Matcher<ActualT = T>,
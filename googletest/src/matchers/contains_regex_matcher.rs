@@ -70,6 +70,12 @@ pub struct ContainsRegexMatcher<ActualT: ?Sized> {
     phantom: PhantomData<ActualT>,
 }
 
+impl<ActualT: ?Sized> Clone for ContainsRegexMatcher<ActualT> {
+    fn clone(&self) -> Self {
+        Self { regex: self.regex.clone(), phantom: PhantomData }
+    }
+}
+
 impl<ActualT: AsRef<str> + Debug + ?Sized> Matcher for ContainsRegexMatcher<ActualT> {
     type ActualT = ActualT;
 
@@ -123,6 +129,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_to_owned)]
     fn contains_regex_matches_string_reference_with_owned_string() -> Result<()> {
         let matcher = contains_regex("value".to_string());
 
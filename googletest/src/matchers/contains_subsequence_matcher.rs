@@ -0,0 +1,381 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macros are documented at the top level.
+#![doc(hidden)]
+
+/// Matches a container containing elements which the given matchers match, in
+/// order but not necessarily adjacent to one another.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4, 5], contains_subsequence![eq(2), gt(3)])?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4, 5], contains_subsequence![eq(4), eq(2)])?; // Fails: wrong order
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// Each matcher is matched against the earliest element after the one which
+/// the previous matcher matched. On a mismatch, the explanation lists the
+/// elements which the leading matchers matched and the first matcher which
+/// no later element matches.
+///
+/// The actual value must be a container implementing [`IntoIterator`]. Use
+/// [`contains_run!`][crate::contains_run] to require the matched elements to
+/// be adjacent.
+///
+/// [`IntoIterator`]: std::iter::IntoIterator
+#[macro_export]
+macro_rules! contains_subsequence {
+    ($($matcher:expr),* $(,)?) => {{
+        use $crate::matchers::contains_subsequence_matcher::internal::ContainsSubsequence;
+        ContainsSubsequence::new(&[$(&$crate::matcher::IntoMatcher::into_matcher(&$matcher)),*])
+    }}
+}
+
+/// Matches a container containing a run of adjacent elements which the given
+/// matchers match in order.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4, 5], contains_run![eq(3), eq(4)])?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4, 5], contains_run![eq(2), eq(4)])?; // Fails: not adjacent
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On a mismatch, the explanation shows where the longest partial run starts
+/// and why the first element after it does not match.
+///
+/// The actual value must be a container implementing [`IntoIterator`]. Use
+/// [`contains_subsequence!`][crate::contains_subsequence] if the matched
+/// elements need not be adjacent.
+///
+/// [`IntoIterator`]: std::iter::IntoIterator
+#[macro_export]
+macro_rules! contains_run {
+    ($($matcher:expr),* $(,)?) => {{
+        use $crate::matchers::contains_subsequence_matcher::internal::ContainsRun;
+        ContainsRun::new(&[$(&$crate::matcher::IntoMatcher::into_matcher(&$matcher)),*])
+    }}
+}
+
+/// Module for use only by the macros in this module.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+    use crate::matcher_support::description::Description;
+    use crate::matcher_support::zipped_iterator::zip;
+    use std::{fmt::Debug, marker::PhantomData};
+
+    /// This struct is meant to be used only by the macro
+    /// `contains_subsequence!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct ContainsSubsequence<'a, ContainerT: ?Sized, T: Debug> {
+        elements: &'a [&'a dyn Matcher<ActualT = T>],
+        phantom: PhantomData<ContainerT>,
+    }
+
+    impl<'a, ContainerT: ?Sized, T: Debug> ContainsSubsequence<'a, ContainerT, T> {
+        /// Factory only intended for use in the macro `contains_subsequence!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        pub fn new(elements: &'a [&'a dyn Matcher<ActualT = T>]) -> Self {
+            Self { elements, phantom: Default::default() }
+        }
+
+        /// Returns the indices of the elements of `actual` which the leading
+        /// matchers match, choosing the earliest element for each matcher.
+        fn matched_indices(&self, actual: &ContainerT) -> Vec<usize>
+        where
+            for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
+        {
+            let mut matched = vec![];
+            let mut matchers = self.elements.iter().peekable();
+            for (index, element) in actual.into_iter().enumerate() {
+                let Some(matcher) = matchers.peek() else {
+                    break;
+                };
+                if matcher.matches(element).into_bool() {
+                    matched.push(index);
+                    matchers.next();
+                }
+            }
+            matched
+        }
+    }
+
+    impl<'a, T: Debug, ContainerT: Debug + ?Sized> Matcher for ContainsSubsequence<'a, ContainerT, T>
+    where
+        for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
+    {
+        type ActualT = ContainerT;
+
+        fn matches(&self, actual: &ContainerT) -> MatcherResult {
+            (self.matched_indices(actual).len() == self.elements.len()).into()
+        }
+
+        fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+            let matched = self.matched_indices(actual);
+            let positions = describe_positions(&matched);
+            if matched.len() == self.elements.len() {
+                return MatchExplanation::create(format!(
+                    "which contains the subsequence at {positions}"
+                ));
+            }
+            let unmatched = self.elements[matched.len()].describe(MatcherResult::Matches);
+            match matched.last() {
+                None => {
+                    MatchExplanation::create(format!("which contains no element which {unmatched}"))
+                }
+                Some(last) => MatchExplanation::create(format!(
+                    "which contains elements matching the first {} matchers at {positions}, but \
+                     no element after #{last} which {unmatched}",
+                    matched.len()
+                )),
+            }
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            format!(
+                "{} a subsequence of elements which:\n{}",
+                if matcher_result.into() { "contains" } else { "doesn't contain" },
+                describe_matchers(self.elements)
+            )
+        }
+    }
+
+    /// This struct is meant to be used only by the macro `contains_run!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct ContainsRun<'a, ContainerT: ?Sized, T: Debug> {
+        elements: &'a [&'a dyn Matcher<ActualT = T>],
+        phantom: PhantomData<ContainerT>,
+    }
+
+    impl<'a, ContainerT: ?Sized, T: Debug> ContainsRun<'a, ContainerT, T> {
+        /// Factory only intended for use in the macro `contains_run!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        pub fn new(elements: &'a [&'a dyn Matcher<ActualT = T>]) -> Self {
+            Self { elements, phantom: Default::default() }
+        }
+
+        /// Returns the number of leading matchers which match the elements of
+        /// `run` in order, and whether `run` ended before a matcher failed to
+        /// match.
+        fn matching_prefix(&self, run: &[&T]) -> (usize, bool) {
+            let mut zipped_iterator = zip(run.iter(), self.elements.iter());
+            let length =
+                zipped_iterator.by_ref().take_while(|(a, e)| e.matches(a).into_bool()).count();
+            let run_ended = length < self.elements.len() && length == run.len();
+            (length, run_ended)
+        }
+    }
+
+    impl<'a, T: Debug, ContainerT: Debug + ?Sized> Matcher for ContainsRun<'a, ContainerT, T>
+    where
+        for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
+    {
+        type ActualT = ContainerT;
+
+        fn matches(&self, actual: &ContainerT) -> MatcherResult {
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            (self.elements.is_empty()
+                || (0..actual.len())
+                    .any(|start| self.matching_prefix(&actual[start..]).0 == self.elements.len()))
+            .into()
+        }
+
+        fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            // The start of the longest partial run, preferring the earliest one.
+            let Some((start, (length, run_ended))) = (0..actual.len())
+                .map(|start| (start, self.matching_prefix(&actual[start..])))
+                .rev()
+                .max_by_key(|(_, (length, _))| *length)
+            else {
+                return if self.elements.is_empty() {
+                    MatchExplanation::create("which contains the empty run".to_string())
+                } else {
+                    MatchExplanation::create("which is empty".to_string())
+                };
+            };
+            if length == self.elements.len() {
+                MatchExplanation::create(format!(
+                    "which contains the run at elements #{start} to #{}",
+                    start + length - 1
+                ))
+            } else if length == 0 {
+                MatchExplanation::create(format!(
+                    "which contains no element which {}",
+                    self.elements[0].describe(MatcherResult::Matches)
+                ))
+            } else if run_ended {
+                MatchExplanation::create(format!(
+                    "where the longest partial run starts at element #{start} and ends with the \
+                     container after {length} of {} elements",
+                    self.elements.len()
+                ))
+            } else {
+                let mismatch = start + length;
+                let element = actual[mismatch];
+                let inner = explain(self.elements[length], element);
+                MatchExplanation::create(format!(
+                    "where the longest partial run starts at element #{start}, but element \
+                     #{mismatch} is {element:?}, {inner}"
+                ))
+                .with_child(inner)
+            }
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            format!(
+                "{} a run of adjacent elements which:\n{}",
+                if matcher_result.into() { "contains" } else { "doesn't contain" },
+                describe_matchers(self.elements)
+            )
+        }
+    }
+
+    fn describe_matchers<T: Debug>(elements: &[&dyn Matcher<ActualT = T>]) -> Description {
+        elements
+            .iter()
+            .map(|matcher| matcher.describe(MatcherResult::Matches))
+            .collect::<Description>()
+            .enumerate()
+            .indent()
+    }
+
+    fn describe_positions(indices: &[usize]) -> String {
+        let positions = indices.iter().map(|index| format!("#{index}")).collect::<Vec<_>>();
+        format!("element{} {}", if indices.len() == 1 { "" } else { "s" }, positions.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn contains_subsequence_matches_elements_in_order() -> Result<()> {
+        expect_that!(vec![1, 2, 3, 4, 5], contains_subsequence![eq(1), eq(3), eq(5)]);
+        expect_that!(vec![1, 2, 3], contains_subsequence![]);
+        verify_that!(vec![1, 2, 3], contains_subsequence![eq(1), eq(2), eq(3)])
+    }
+
+    #[test]
+    fn contains_subsequence_does_not_match_elements_out_of_order() -> Result<()> {
+        verify_that!(vec![1, 2, 3], not(contains_subsequence![eq(3), eq(1)]))
+    }
+
+    #[test]
+    fn contains_subsequence_explains_first_unmatched_matcher() -> Result<()> {
+        let first = eq(1);
+        let second = eq(3);
+        let third = eq(2);
+        let result = verify_that!(vec![1, 2, 3, 4], contains_subsequence![first, second, third]);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: contains a subsequence of elements which:
+                  0. is equal to 1
+                  1. is equal to 3
+                  2. is equal to 2
+                Actual: [
+                    1,
+                    2,
+                    3,
+                    4,
+                ], which contains elements matching the first 2 matchers at elements #0, #2, but no element after #2 which is equal to 2
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn contains_subsequence_explains_no_matching_element() -> Result<()> {
+        let result = verify_that!(vec![1, 2], contains_subsequence![eq(3)]);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("which contains no element which is equal to 3")))
+        )
+    }
+
+    #[test]
+    fn contains_run_matches_adjacent_elements() -> Result<()> {
+        expect_that!(vec![1, 2, 3, 4], contains_run![eq(2), eq(3)]);
+        expect_that!(vec![1, 2, 3, 4], contains_run![eq(3), eq(4)]);
+        expect_that!(Vec::<i32>::new(), contains_run![]);
+        verify_that!(vec![1, 2, 3, 4], not(contains_run![eq(2), eq(4)]))
+    }
+
+    #[test]
+    fn contains_run_explains_longest_partial_run() -> Result<()> {
+        let first = eq(2);
+        let second = eq(3);
+        let third = eq(5);
+        let result = verify_that!(vec![2, 1, 2, 3, 4], contains_run![first, second, third]);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "where the longest partial run starts at element #2, but element #4 is 4, which \
+                 isn't equal to 5"
+            )))
+        )
+    }
+
+    #[test]
+    fn contains_run_explains_run_cut_off_by_end() -> Result<()> {
+        let result = verify_that!(vec![1, 2], contains_run![eq(2), eq(3)]);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "where the longest partial run starts at element #1 and ends with the container \
+                 after 1 of 2 elements"
+            )))
+        )
+    }
+
+    #[test]
+    fn contains_run_explains_empty_container() -> Result<()> {
+        let result = verify_that!(Vec::<i32>::new(), contains_run![eq(1)]);
+
+        verify_that!(result, err(displays_as(contains_substring("which is empty"))))
+    }
+}
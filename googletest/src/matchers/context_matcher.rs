@@ -0,0 +1,180 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::description::Description;
+use std::fmt::Debug;
+
+/// Extension trait providing the [`with_context`][ContextMatcherExt::with_context]
+/// method.
+pub trait ContextMatcherExt: Matcher {
+    /// Constructs a matcher which matches exactly when `self` does, but which
+    /// renders its description and explanation as an indented layer below
+    /// the label `context`.
+    ///
+    /// This helps to navigate the failure messages of deeply nested composite
+    /// matchers, since each labeled layer states which part of the actual
+    /// value it concerns.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// #[derive(Debug)]
+    /// struct Response {
+    ///     status: u32,
+    ///     content_type: String,
+    /// }
+    ///
+    /// # fn should_pass() -> Result<()> {
+    /// let response = Response { status: 200, content_type: "text/html".into() };
+    /// verify_that!(
+    ///     response,
+    ///     field!(Response.content_type, starts_with("text/"))
+    ///         .with_context("in the response header section")
+    /// )?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// let response = Response { status: 404, content_type: "text/html".into() };
+    /// verify_that!(response, field!(Response.status, eq(200)).with_context("in the status line"))?;
+    /// // Fails with:
+    /// //   Expected: in the status line:
+    /// //     has field `status`, which is equal to 200
+    /// //   Actual: Response { status: 404, ... }, in the status line:
+    /// //     which has field `status`, which isn't equal to 200
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    // TODO(b/264518763): Replace the return type with impl Matcher and reduce
+    // visibility of ContextMatcher once impl in return position in trait
+    // methods is stable.
+    fn with_context(self, context: impl Into<String>) -> ContextMatcher<Self>
+    where
+        Self: Sized,
+    {
+        ContextMatcher { inner: self, context: context.into() }
+    }
+}
+
+impl<M> ContextMatcherExt for M where M: Matcher {}
+
+/// Matcher created by [`ContextMatcherExt::with_context`].
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub struct ContextMatcher<InnerMatcherT> {
+    inner: InnerMatcherT,
+    context: String,
+}
+
+impl<InnerMatcherT> ContextMatcher<InnerMatcherT> {
+    fn render(&self, content: String) -> String {
+        format!("{}:\n{}", self.context, std::iter::once(content).collect::<Description>().indent())
+    }
+}
+
+impl<InnerMatcherT: Matcher> Matcher for ContextMatcher<InnerMatcherT>
+where
+    InnerMatcherT::ActualT: Debug,
+{
+    type ActualT = InnerMatcherT::ActualT;
+
+    fn matches(&self, actual: &Self::ActualT) -> MatcherResult {
+        self.inner.matches(actual)
+    }
+
+    fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
+        let inner = explain(&self.inner, actual);
+        MatchExplanation::create(self.render(inner.to_string())).with_child(inner)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        self.render(self.inner.describe(matcher_result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContextMatcherExt;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn with_context_matches_when_inner_matches() -> Result<()> {
+        verify_that!(1, eq(1).with_context("in the counter"))
+    }
+
+    #[test]
+    fn with_context_does_not_match_when_inner_does_not_match() -> Result<()> {
+        verify_that!(1, not(eq(2).with_context("in the counter")))
+    }
+
+    #[test]
+    fn with_context_describes_inner_matcher_indented_below_label() -> Result<()> {
+        verify_that!(
+            eq(1).with_context("in the counter").describe(MatcherResult::DoesNotMatch),
+            eq(indoc!(
+                "
+                in the counter:
+                  isn't equal to 1"
+            ))
+        )
+    }
+
+    #[test]
+    fn with_context_full_error_message() -> Result<()> {
+        let result = verify_that!(2, eq(1).with_context("in the counter"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: in the counter:
+                  is equal to 1
+                Actual: 2, in the counter:
+                  which isn't equal to 1
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn with_context_nests_layers() -> Result<()> {
+        #[derive(Debug)]
+        struct Header {
+            name: String,
+        }
+        #[derive(Debug)]
+        struct Response {
+            header: Header,
+        }
+        let matcher = field!(
+            Response.header,
+            field!(Header.name, eq("Content-Type")).with_context("in the header name")
+        )
+        .with_context("in the response header section");
+
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq(indoc!(
+                "
+                in the response header section:
+                  has field `header`, which in the header name:
+                    has field `name`, which is equal to \"Content-Type\""
+            ))
+        )
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::ops::ControlFlow;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a `ControlFlow` which is `Continue` with a value matched by `inner`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::ops::ControlFlow;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(ControlFlow::<(), _>::Continue("Some value"), continues_with(eq("Some value")))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail_1() -> Result<()> {
+/// verify_that!(ControlFlow::<_, &str>::Break("Some value"), continues_with(eq("Some value")))?;   // Fails
+/// #     Ok(())
+/// # }
+/// # fn should_fail_2() -> Result<()> {
+/// verify_that!(ControlFlow::<(), _>::Continue("Other value"), continues_with(eq("Some value")))?;   // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail_1().unwrap_err();
+/// # should_fail_2().unwrap_err();
+/// ```
+pub fn continues_with<B: Debug, C: Debug>(
+    inner: impl Matcher<ActualT = C>,
+) -> impl Matcher<ActualT = ControlFlow<B, C>> {
+    ContinuesWithMatcher::<B, C, _> {
+        inner,
+        phantom_b: Default::default(),
+        phantom_c: Default::default(),
+    }
+}
+
+struct ContinuesWithMatcher<B, C, InnerMatcherT> {
+    inner: InnerMatcherT,
+    phantom_b: PhantomData<B>,
+    phantom_c: PhantomData<C>,
+}
+
+impl<B: Debug, C: Debug, InnerMatcherT: Matcher<ActualT = C>> Matcher
+    for ContinuesWithMatcher<B, C, InnerMatcherT>
+{
+    type ActualT = ControlFlow<B, C>;
+
+    fn matches(&self, actual: &Self::ActualT) -> MatcherResult {
+        match actual {
+            ControlFlow::Continue(v) => self.inner.matches(v),
+            ControlFlow::Break(_) => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
+        match actual {
+            ControlFlow::Continue(v) => {
+                let inner = explain(&self.inner, v);
+                MatchExplanation::create(format!("which continues with a value {inner}"))
+                    .with_child(inner)
+            }
+            ControlFlow::Break(_) => MatchExplanation::create("which breaks".to_string()),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!(
+                    "continues with a value which {}",
+                    self.inner.describe(MatcherResult::Matches)
+                )
+            }
+            MatcherResult::DoesNotMatch => {
+                format!(
+                    "breaks or continues with a value which {}",
+                    self.inner.describe(MatcherResult::DoesNotMatch)
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::continues_with;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::ops::ControlFlow;
+
+    #[test]
+    fn continues_with_matches_continue_with_matching_value() -> Result<()> {
+        verify_that!(ControlFlow::<(), i32>::Continue(1), continues_with(eq(1)))
+    }
+
+    #[test]
+    fn continues_with_does_not_match_continue_with_wrong_value() -> Result<()> {
+        verify_that!(ControlFlow::<(), i32>::Continue(0), not(continues_with(eq(1))))
+    }
+
+    #[test]
+    fn continues_with_does_not_match_break() -> Result<()> {
+        verify_that!(ControlFlow::<i32, i32>::Break(1), not(continues_with(eq(1))))
+    }
+
+    #[test]
+    fn continues_with_full_error_message() -> Result<()> {
+        let result = verify_that!(ControlFlow::<&str, i32>::Break("done"), continues_with(eq(1)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: continues with a value which is equal to 1
+                Actual: Break(
+                    "done",
+                ), which breaks
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn continues_with_describe_does_not_match() -> Result<()> {
+        verify_that!(
+            continues_with::<(), i32>(eq(1)).describe(MatcherResult::DoesNotMatch),
+            eq("breaks or continues with a value which isn't equal to 1")
+        )
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::{fmt::Debug, marker::PhantomData};
+
+pub use googletest_macro::EnumVariants;
+
+/// An enum whose variants can be enumerated, as needed by
+/// [`covers_all_variants`].
+///
+/// Derive this with `#[derive(EnumVariants)]` rather than implementing it by
+/// hand.
+pub trait EnumVariants {
+    /// The names of all variants of the enum, in declaration order.
+    const VARIANTS: &'static [&'static str];
+
+    /// The name of the variant of `self`.
+    fn variant(&self) -> &'static str;
+}
+
+/// Matches a container which contains at least one value of each variant of
+/// the enum `E`.
+///
+/// This is useful for checking that a dispatch table or the test cases of a
+/// serialization round trip cover every variant, including ones added later.
+/// The enum must derive [`EnumVariants`]. On a mismatch, the explanation lists
+/// the missing variants.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// #[derive(Debug, EnumVariants)]
+/// enum Shape {
+///     Circle(f64),
+///     Square { side: f64 },
+///     Point,
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// let shapes = vec![Shape::Point, Shape::Circle(1.0), Shape::Square { side: 2.0 }];
+/// verify_that!(shapes, covers_all_variants())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// let shapes = vec![Shape::Circle(1.0), Shape::Circle(2.0)];
+/// verify_that!(shapes, covers_all_variants())?; // Fails: Square and Point are missing
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn covers_all_variants<E: EnumVariants + Debug, ContainerT: Debug + ?Sized>()
+-> impl Matcher<ActualT = ContainerT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a E>,
+{
+    CoversAllVariantsMatcher::<E, ContainerT> { phantom: Default::default() }
+}
+
+struct CoversAllVariantsMatcher<E, ContainerT: ?Sized> {
+    phantom: PhantomData<(fn() -> E, ContainerT)>,
+}
+
+impl<E: EnumVariants, ContainerT: ?Sized> CoversAllVariantsMatcher<E, ContainerT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a E>,
+{
+    /// Returns the variants of which `actual` contains no value, in
+    /// declaration order.
+    fn missing_variants(actual: &ContainerT) -> Vec<&'static str> {
+        let present: Vec<&'static str> = actual.into_iter().map(EnumVariants::variant).collect();
+        E::VARIANTS.iter().copied().filter(|variant| !present.contains(variant)).collect()
+    }
+}
+
+impl<E: EnumVariants + Debug, ContainerT: Debug + ?Sized> Matcher
+    for CoversAllVariantsMatcher<E, ContainerT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a E>,
+{
+    type ActualT = ContainerT;
+
+    fn matches(&self, actual: &ContainerT) -> MatcherResult {
+        if Self::missing_variants(actual).is_empty() {
+            MatcherResult::Matches
+        } else {
+            MatcherResult::DoesNotMatch
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("contains every variant of {}", std::any::type_name::<E>())
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("doesn't contain every variant of {}", std::any::type_name::<E>())
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+        let missing = Self::missing_variants(actual);
+        if missing.is_empty() {
+            MatchExplanation::create("which contains every variant".to_string())
+        } else {
+            MatchExplanation::create(format!(
+                "which is missing the variant{} {}",
+                if missing.len() == 1 { "" } else { "s" },
+                missing.join(", ")
+            ))
+        }
+    }
+}
@@ -0,0 +1,456 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Matchers for date-time values of the `chrono` and `time` crates.
+//!
+//! These matchers are available with the feature `chrono`, the feature `time`,
+//! or both. They apply to any [`DateTimeValue`], which is implemented for
+//! `chrono::DateTime` with the former and `time::OffsetDateTime` with the
+//! latter.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::{fmt::Debug, time::Duration};
+
+/// Matches a date-time denoting the same instant as `expected`, regardless of
+/// the timezones of either.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # #[cfg(feature = "chrono")] {
+/// use chrono::{FixedOffset, TimeZone, Utc};
+/// # fn should_pass() -> Result<()> {
+/// let noon_utc = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+/// let two_pm_cest = FixedOffset::east_opt(2 * 3600).unwrap().from_utc_datetime(&noon_utc.naive_utc());
+/// verify_that!(two_pm_cest, same_instant_as(noon_utc.fixed_offset()))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # }
+/// ```
+///
+/// When the instants differ, the explanation states by how much and in which
+/// direction. When they agree but the values are in different timezones, the
+/// explanation of a match names both offsets.
+pub fn same_instant_as<T: DateTimeValue>(expected: T) -> SameInstantMatcher<T> {
+    SameInstantMatcher { expected }
+}
+
+/// Starts a matcher for a date-time at most `tolerance` before or after a
+/// given date-time.
+///
+/// Complete the matcher by calling [`Within::of`]:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # #[cfg(feature = "chrono")] {
+/// use chrono::{TimeZone, Utc};
+/// use std::time::Duration;
+/// # fn should_pass() -> Result<()> {
+/// let expected = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+/// let actual = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 3).unwrap();
+/// verify_that!(actual, within(Duration::from_secs(5)).of(expected))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let expected = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+/// let actual = Utc.with_ymd_and_hms(2023, 6, 1, 11, 59, 50).unwrap();
+/// verify_that!(actual, within(Duration::from_secs(5)).of(expected))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// # }
+/// ```
+///
+/// The failure of the second assertion above reads:
+///
+/// ```text
+/// Value of: actual
+/// Expected: is within 5s of 2023-06-01T12:00:00Z
+/// Actual: 2023-06-01T11:59:50Z, which is 10s before 2023-06-01T12:00:00Z
+/// ```
+pub fn within(tolerance: Duration) -> Within {
+    Within { tolerance }
+}
+
+/// A tolerance awaiting the date-time to which it applies.
+///
+/// See [`within`].
+#[derive(Clone, Copy)]
+pub struct Within {
+    tolerance: Duration,
+}
+
+impl Within {
+    /// Completes the matcher with the date-time `expected`.
+    pub fn of<T: DateTimeValue>(self, expected: T) -> WithinMatcher<T> {
+        WithinMatcher { expected, tolerance: self.tolerance }
+    }
+}
+
+/// Matches a date-time whose calendar date in its own timezone is `date`.
+///
+/// The type of `date` is the date type of the respective crate, so
+/// `chrono::NaiveDate` or `time::Date`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # #[cfg(feature = "chrono")] {
+/// use chrono::{FixedOffset, NaiveDate, TimeZone};
+/// # fn should_pass() -> Result<()> {
+/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+/// let actual = tokyo.with_ymd_and_hms(2023, 6, 2, 1, 0, 0).unwrap();
+/// verify_that!(actual, on_date(NaiveDate::from_ymd_opt(2023, 6, 2).unwrap()))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # }
+/// ```
+///
+/// On mismatch, the explanation states the date of the actual value together
+/// with its timezone, since the same instant may fall on different dates in
+/// different timezones.
+pub fn on_date<T: DateTimeValue>(date: T::Date) -> OnDateMatcher<T> {
+    OnDateMatcher { date }
+}
+
+/// A date-time value of an external crate which the matchers in this module
+/// can inspect.
+pub trait DateTimeValue: Debug {
+    /// The calendar date type of the crate.
+    type Date: PartialEq + Debug;
+
+    /// Returns the number of nanoseconds since the Unix epoch.
+    fn unix_timestamp_nanos(&self) -> i128;
+
+    /// Returns the calendar date of this value in its own timezone.
+    fn local_date(&self) -> Self::Date;
+
+    /// Returns a description of the UTC offset of this value, such as
+    /// `+02:00`.
+    fn offset_description(&self) -> String;
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> DateTimeValue for chrono::DateTime<Tz> {
+    type Date = chrono::NaiveDate;
+
+    fn unix_timestamp_nanos(&self) -> i128 {
+        self.timestamp() as i128 * 1_000_000_000 + self.timestamp_subsec_nanos() as i128
+    }
+
+    fn local_date(&self) -> chrono::NaiveDate {
+        self.date_naive()
+    }
+
+    fn offset_description(&self) -> String {
+        use chrono::Offset;
+        self.offset().fix().to_string()
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimeValue for time::OffsetDateTime {
+    type Date = time::Date;
+
+    fn unix_timestamp_nanos(&self) -> i128 {
+        time::OffsetDateTime::unix_timestamp_nanos(*self)
+    }
+
+    fn local_date(&self) -> time::Date {
+        self.date()
+    }
+
+    fn offset_description(&self) -> String {
+        self.offset().to_string()
+    }
+}
+
+/// A matcher which matches date-times denoting the same instant as an
+/// expected date-time.
+///
+/// See [`same_instant_as`].
+#[derive(Clone, Copy)]
+pub struct SameInstantMatcher<T> {
+    expected: T,
+}
+
+impl<T: DateTimeValue> Matcher for SameInstantMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        (actual.unix_timestamp_nanos() == self.expected.unix_timestamp_nanos()).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("is the same instant as {:?}", self.expected),
+            MatcherResult::DoesNotMatch => {
+                format!("isn't the same instant as {:?}", self.expected)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        MatchExplanation::create(explain_distance(actual, &self.expected))
+    }
+}
+
+/// A matcher which matches date-times within a tolerance of an expected
+/// date-time.
+///
+/// See [`within`].
+#[derive(Clone, Copy)]
+pub struct WithinMatcher<T> {
+    expected: T,
+    tolerance: Duration,
+}
+
+impl<T: DateTimeValue> Matcher for WithinMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        let distance = actual.unix_timestamp_nanos().abs_diff(self.expected.unix_timestamp_nanos());
+        (distance <= self.tolerance.as_nanos()).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is within {:?} of {:?}", self.tolerance, self.expected)
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't within {:?} of {:?}", self.tolerance, self.expected)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        MatchExplanation::create(explain_distance(actual, &self.expected))
+    }
+}
+
+/// A matcher which matches date-times on a given calendar date.
+///
+/// See [`on_date`].
+pub struct OnDateMatcher<T: DateTimeValue> {
+    date: T::Date,
+}
+
+impl<T: DateTimeValue> Clone for OnDateMatcher<T>
+where
+    T::Date: Clone,
+{
+    fn clone(&self) -> Self {
+        Self { date: self.date.clone() }
+    }
+}
+
+impl<T: DateTimeValue> Matcher for OnDateMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        (actual.local_date() == self.date).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("is on {:?}", self.date),
+            MatcherResult::DoesNotMatch => format!("isn't on {:?}", self.date),
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        MatchExplanation::create(format!(
+            "which is on {:?} in timezone {}",
+            actual.local_date(),
+            actual.offset_description()
+        ))
+    }
+}
+
+fn explain_distance<T: DateTimeValue>(actual: &T, expected: &T) -> String {
+    let actual_nanos = actual.unix_timestamp_nanos();
+    let expected_nanos = expected.unix_timestamp_nanos();
+    let distance = actual_nanos.abs_diff(expected_nanos);
+    let distance = Duration::new(
+        u64::try_from(distance / 1_000_000_000).unwrap_or(u64::MAX),
+        (distance % 1_000_000_000) as u32,
+    );
+    if actual_nanos > expected_nanos {
+        format!("which is {:?} after {:?}", distance, expected)
+    } else if actual_nanos < expected_nanos {
+        format!("which is {:?} before {:?}", distance, expected)
+    } else if actual.offset_description() != expected.offset_description() {
+        format!(
+            "which is the same instant in timezone {} rather than {}",
+            actual.offset_description(),
+            expected.offset_description()
+        )
+    } else {
+        "which is the same instant".to_string()
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod chrono_tests {
+    use super::{on_date, same_instant_as, within};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+    use std::time::Duration;
+
+    fn noon_utc() -> DateTime<FixedOffset> {
+        Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap().fixed_offset()
+    }
+
+    fn in_offset(value: DateTime<FixedOffset>, hours: i32) -> DateTime<FixedOffset> {
+        value.with_timezone(&FixedOffset::east_opt(hours * 3600).unwrap())
+    }
+
+    #[test]
+    fn same_instant_as_matches_across_timezones() -> Result<()> {
+        verify_that!(in_offset(noon_utc(), 2), same_instant_as(noon_utc()))
+    }
+
+    #[test]
+    fn same_instant_as_does_not_match_different_instant() -> Result<()> {
+        verify_that!(
+            noon_utc() + chrono::Duration::nanoseconds(1),
+            not(same_instant_as(noon_utc()))
+        )
+    }
+
+    #[test]
+    fn same_instant_as_explains_timezone_difference() -> Result<()> {
+        verify_that!(
+            same_instant_as(noon_utc()).explain_match(&in_offset(noon_utc(), -5)),
+            displays_as(eq("which is the same instant in timezone -05:00 rather than +00:00"))
+        )
+    }
+
+    #[test]
+    fn same_instant_as_explains_distance() -> Result<()> {
+        verify_that!(
+            same_instant_as(noon_utc())
+                .explain_match(&(noon_utc() + chrono::Duration::minutes(90))),
+            displays_as(eq("which is 5400s after 2023-06-01T12:00:00+00:00"))
+        )
+    }
+
+    #[test]
+    fn within_matches_date_time_within_tolerance() -> Result<()> {
+        let matcher = within(Duration::from_secs(5)).of(noon_utc());
+        verify_that!(noon_utc() + chrono::Duration::seconds(5), matcher)?;
+        verify_that!(in_offset(noon_utc() - chrono::Duration::seconds(5), 3), matcher)?;
+        verify_that!(noon_utc() + chrono::Duration::seconds(6), not(matcher))
+    }
+
+    #[test]
+    fn within_describes_tolerance() -> Result<()> {
+        verify_that!(
+            within(Duration::from_secs(5)).of(noon_utc()).describe(MatcherResult::Matches),
+            eq("is within 5s of 2023-06-01T12:00:00+00:00")
+        )
+    }
+
+    #[test]
+    fn within_explains_distance_before_expected() -> Result<()> {
+        let result = verify_that!(
+            noon_utc() - chrono::Duration::seconds(10),
+            within(Duration::from_secs(5)).of(noon_utc())
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("which is 10s before 2023-06-01T12:00:00+00:00")))
+        )
+    }
+
+    #[test]
+    fn on_date_uses_timezone_of_actual_value() -> Result<()> {
+        let late_evening = Utc.with_ymd_and_hms(2023, 6, 1, 22, 0, 0).unwrap().fixed_offset();
+        verify_that!(late_evening, on_date(NaiveDate::from_ymd_opt(2023, 6, 1).unwrap()))?;
+        verify_that!(
+            in_offset(late_evening, 9),
+            on_date(NaiveDate::from_ymd_opt(2023, 6, 2).unwrap())
+        )
+    }
+
+    #[test]
+    fn on_date_explains_date_and_timezone() -> Result<()> {
+        let late_evening = Utc.with_ymd_and_hms(2023, 6, 1, 22, 0, 0).unwrap().fixed_offset();
+        verify_that!(
+            on_date(NaiveDate::from_ymd_opt(2023, 6, 1).unwrap())
+                .explain_match(&in_offset(late_evening, 9)),
+            displays_as(eq("which is on 2023-06-02 in timezone +09:00"))
+        )
+    }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod time_tests {
+    use super::{on_date, same_instant_as, within};
+    use crate::matcher::Matcher;
+    use crate::prelude::*;
+    use std::time::Duration;
+    use time::{Date, Month, OffsetDateTime, UtcOffset};
+
+    fn noon_utc() -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_685_620_800).unwrap()
+    }
+
+    fn in_offset(value: OffsetDateTime, hours: i8) -> OffsetDateTime {
+        value.to_offset(UtcOffset::from_hms(hours, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn same_instant_as_matches_across_timezones() -> Result<()> {
+        verify_that!(in_offset(noon_utc(), 2), same_instant_as(noon_utc()))?;
+        verify_that!(noon_utc() + time::Duration::nanoseconds(1), not(same_instant_as(noon_utc())))
+    }
+
+    #[test]
+    fn same_instant_as_explains_timezone_difference() -> Result<()> {
+        verify_that!(
+            same_instant_as(noon_utc()).explain_match(&in_offset(noon_utc(), 2)),
+            displays_as(eq(
+                "which is the same instant in timezone +02:00:00 rather than +00:00:00"
+            ))
+        )
+    }
+
+    #[test]
+    fn within_matches_date_time_within_tolerance() -> Result<()> {
+        let matcher = within(Duration::from_millis(500)).of(noon_utc());
+        verify_that!(noon_utc() - time::Duration::milliseconds(500), matcher)?;
+        verify_that!(noon_utc() + time::Duration::milliseconds(501), not(matcher))
+    }
+
+    #[test]
+    fn on_date_uses_timezone_of_actual_value() -> Result<()> {
+        let june_1 = Date::from_calendar_date(2023, Month::June, 1).unwrap();
+        verify_that!(noon_utc(), on_date(june_1))?;
+        verify_that!(in_offset(noon_utc(), -13), not(on_date(june_1)))
+    }
+
+    #[test]
+    fn on_date_explains_date_and_timezone() -> Result<()> {
+        let june_1 = Date::from_calendar_date(2023, Month::June, 1).unwrap();
+        verify_that!(
+            on_date(june_1).explain_match(&in_offset(noon_utc(), -13)),
+            displays_as(eq("which is on 2023-05-31 in timezone -13:00:00"))
+        )
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::debug_layout::debug_string;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// Matches a value which, after being mapped through `map`, is matched by
+/// `inner`.
+///
+/// The `label` names the derived value in the description and explanation of
+/// the matcher, so it should be a noun phrase such as "normalized path". This
+/// is the general escape hatch for matching a value computed from the actual
+/// value when neither [`field!`][crate::field] nor
+/// [`property!`][crate::property] fits, for example because the computation is
+/// not a method of the actual value.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("  Hello  ", derived("trimmed text", |s: &&str| s.trim(), eq("Hello")))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(-3, derived("absolute value", |n: &i32| n.abs(), lt(3)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The failure of the second assertion above reads:
+///
+/// ```text
+/// Value of: -3
+/// Expected: whose absolute value is less than 3
+/// Actual: -3, whose absolute value is 3, which is greater than or equal to 3
+/// ```
+///
+/// As with [`predicate`][crate::matchers::predicate], the type of the closure
+/// argument usually has to be given explicitly.
+pub fn derived<ActualT, DerivedT, MapT, InnerMatcherT>(
+    label: impl Into<String>,
+    map: MapT,
+    inner: InnerMatcherT,
+) -> impl Matcher<ActualT = ActualT>
+where
+    ActualT: Debug + ?Sized,
+    DerivedT: Debug,
+    MapT: Fn(&ActualT) -> DerivedT,
+    InnerMatcherT: Matcher<ActualT = DerivedT>,
+{
+    DerivedMatcher { label: label.into(), map, inner, phantom: Default::default() }
+}
+
+struct DerivedMatcher<ActualT: ?Sized, MapT, InnerMatcherT> {
+    label: String,
+    map: MapT,
+    inner: InnerMatcherT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT, DerivedT, MapT, InnerMatcherT> Matcher
+    for DerivedMatcher<ActualT, MapT, InnerMatcherT>
+where
+    ActualT: Debug + ?Sized,
+    DerivedT: Debug,
+    MapT: Fn(&ActualT) -> DerivedT,
+    InnerMatcherT: Matcher<ActualT = DerivedT>,
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        self.inner.matches(&(self.map)(actual))
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!("whose {} {}", self.label, self.inner.describe(matcher_result))
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let derived = (self.map)(actual);
+        let inner = explain(&self.inner, &derived);
+        MatchExplanation::create(format!(
+            "whose {} is {}, {inner}",
+            self.label,
+            debug_string(&derived)
+        ))
+        .with_child(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derived;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn derived_matches_when_inner_matcher_matches_mapped_value() -> Result<()> {
+        verify_that!(String::from("ABC"), derived("length", |s: &String| s.len(), eq(3)))
+    }
+
+    #[test]
+    fn derived_does_not_match_when_inner_matcher_does_not_match_mapped_value() -> Result<()> {
+        verify_that!(String::from("ABC"), not(derived("length", |s: &String| s.len(), eq(4))))
+    }
+
+    #[test]
+    fn derived_matches_unsized_value() -> Result<()> {
+        verify_that!(*"ABC", derived("lowercase form", |s: &str| s.to_lowercase(), eq("abc")))
+    }
+
+    #[test]
+    fn derived_describes_itself_with_label() -> Result<()> {
+        let matcher = derived("length", |s: &String| s.len(), eq(3));
+
+        verify_that!(matcher.describe(MatcherResult::Matches), eq("whose length is equal to 3"))?;
+        verify_that!(
+            matcher.describe(MatcherResult::DoesNotMatch),
+            eq("whose length isn't equal to 3")
+        )
+    }
+
+    #[test]
+    fn derived_explains_mismatch_with_mapped_value() -> Result<()> {
+        let result =
+            verify_that!(String::from("ABC"), derived("length", |s: &String| s.len(), eq(4)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: whose length is equal to 4
+                Actual: \"ABC\", whose length is 3, which isn't equal to 4
+                "
+            ))))
+        )
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{config, DiffStyle};
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::edit_distance;
+use crate::matchers::eq_matcher::edit_list_summary;
+use crate::matchers::matches_glob_matcher::{matches_glob, MatchesGlobMatcher};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+/// Matches a path to a directory whose tree is equal to that at `expected`.
+///
+/// Two trees are equal if they contain the same relative paths, each of
+/// which is a directory in both or a file with the same contents in both.
+/// Symbolic links are followed.
+///
+/// On a mismatch, the explanation lists each differing path: entries missing
+/// from the actual tree, unexpected entries in it, entries which are a file
+/// in one tree and a directory in the other, and files whose contents differ.
+/// The contents of differing text files are shown as a line diff.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use googletest::test_fs::TestFs;
+/// # fn should_pass() -> Result<()> {
+/// let expected = TestFs::create([("a.txt", "hello"), ("b/", "")])?;
+/// let actual = TestFs::create([("a.txt", "hello"), ("b/", ""), ("build.log", "done")])?;
+/// verify_that!(actual.root(), dir_eq(expected.root()).ignoring("*.log"))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// let expected = TestFs::create([("a.txt", "hello")])?;
+/// let actual = TestFs::create([("a.txt", "goodbye")])?;
+/// verify_that!(actual.root(), dir_eq(expected.root()))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The actual value may be of any type implementing `AsRef<Path>`, such as
+/// [`Path`], [`PathBuf`] or a string.
+// N.B. This returns the concrete type rather than an impl Matcher so that the
+// ignored paths can be configured with DirEqMatcher::ignoring.
+pub fn dir_eq<ActualT: AsRef<Path> + Debug + ?Sized>(
+    expected: impl AsRef<Path>,
+) -> DirEqMatcher<ActualT> {
+    DirEqMatcher {
+        expected: expected.as_ref().to_path_buf(),
+        ignored: vec![],
+        phantom: Default::default(),
+    }
+}
+
+/// A matcher which matches a directory tree equal to an expected one.
+///
+/// Intended only to be used from the function [`dir_eq`] only.
+/// Should not be referenced by code outside this library.
+pub struct DirEqMatcher<ActualT: ?Sized> {
+    expected: PathBuf,
+    ignored: Vec<MatchesGlobMatcher<str, String>>,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: ?Sized> DirEqMatcher<ActualT> {
+    /// Configures this instance to ignore the entries whose paths relative to
+    /// the roots of the trees match the glob `pattern` in both trees.
+    ///
+    /// The pattern has the syntax of [`matches_glob`] and is matched against
+    /// paths with components separated by `/`, e.g., `"target"` or
+    /// `"src/*.bak"`. The contents of an ignored directory are ignored as
+    /// well.
+    ///
+    /// Panics if `pattern` is not a syntactically valid glob pattern.
+    pub fn ignoring(mut self, pattern: impl Into<String>) -> Self {
+        self.ignored.push(matches_glob(pattern.into()));
+        self
+    }
+
+    fn is_ignored(&self, relative: &str) -> bool {
+        self.ignored.iter().any(|pattern| pattern.matches(relative).into())
+    }
+
+    /// Reads the entries of the tree at `root`, keyed by their paths relative
+    /// to `root`.
+    fn read_tree(&self, root: &Path) -> std::io::Result<BTreeMap<String, Entry>> {
+        let mut entries = BTreeMap::new();
+        self.read_entries(root, "", &mut entries)?;
+        Ok(entries)
+    }
+
+    fn read_entries(
+        &self,
+        directory: &Path,
+        prefix: &str,
+        entries: &mut BTreeMap<String, Entry>,
+    ) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(directory)? {
+            let path = entry?.path();
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            let relative =
+                if prefix.is_empty() { name.to_string() } else { format!("{prefix}/{name}") };
+            if self.is_ignored(&relative) {
+                continue;
+            }
+            if std::fs::metadata(&path)?.is_dir() {
+                self.read_entries(&path, &relative, entries)?;
+                entries.insert(relative, Entry::Directory);
+            } else {
+                entries.insert(relative, Entry::File(std::fs::read(&path)?));
+            }
+        }
+        Ok(())
+    }
+
+    /// Compares the tree at `actual` with the expected one, returning a
+    /// description of each differing path or of the error which prevented
+    /// the comparison.
+    fn differences(&self, actual: &Path) -> Result<Vec<String>, String> {
+        let expected_entries = self
+            .read_tree(&self.expected)
+            .map_err(|e| format!("whose expected tree {:?} cannot be read: {e}", self.expected))?;
+        let actual_entries =
+            self.read_tree(actual).map_err(|e| format!("which cannot be read: {e}"))?;
+        let paths: BTreeSet<&String> =
+            expected_entries.keys().chain(actual_entries.keys()).collect();
+        // Directories reported as missing or unexpected, whose contents need
+        // not be reported individually.
+        let mut reported_directories: Vec<&str> = vec![];
+        let mut differences = vec![];
+        for path in paths {
+            if reported_directories.iter().any(|directory| {
+                path.strip_prefix(directory).map_or(false, |rest| rest.starts_with('/'))
+            }) {
+                continue;
+            }
+            match (expected_entries.get(path), actual_entries.get(path)) {
+                (Some(expected), None) => {
+                    if let Entry::Directory = expected {
+                        reported_directories.push(path);
+                    }
+                    differences.push(format!("{path}: missing {}", expected.kind()));
+                }
+                (None, Some(actual)) => {
+                    if let Entry::Directory = actual {
+                        reported_directories.push(path);
+                    }
+                    differences.push(format!("{path}: unexpected {}", actual.kind()));
+                }
+                (Some(Entry::File(expected)), Some(Entry::File(actual))) if expected != actual => {
+                    differences.push(format!("{path}: {}", describe_contents(expected, actual)));
+                }
+                (Some(expected), Some(actual)) if expected.kind() != actual.kind() => {
+                    if let Entry::Directory = expected {
+                        reported_directories.push(path);
+                    }
+                    differences.push(format!(
+                        "{path}: expected a {} but was a {}",
+                        expected.kind(),
+                        actual.kind()
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(differences)
+    }
+}
+
+/// An entry of a directory tree.
+enum Entry {
+    Directory,
+    File(Vec<u8>),
+}
+
+impl Entry {
+    fn kind(&self) -> &'static str {
+        match self {
+            Entry::Directory => "directory",
+            Entry::File(_) => "file",
+        }
+    }
+}
+
+/// Describes how the contents `actual` of a file differ from `expected`,
+/// with a line diff if both are text.
+fn describe_contents(expected: &[u8], actual: &[u8]) -> String {
+    let summary = format!(
+        "contents differ (expected {} bytes, actual {} bytes)",
+        expected.len(),
+        actual.len()
+    );
+    let diff_style = config().diff_style;
+    let (Ok(expected), Ok(actual)) = (std::str::from_utf8(expected), std::str::from_utf8(actual))
+    else {
+        return summary;
+    };
+    if expected.is_empty() || actual.is_empty() || diff_style == DiffStyle::Disabled {
+        return summary;
+    }
+    let Some(edit_list) = edit_distance::line_edit_list(actual.lines(), expected.lines()) else {
+        return summary;
+    };
+    format!("{summary}{}", edit_list_summary(&edit_list, diff_style)).replace('\n', "\n  ")
+}
+
+impl<ActualT: AsRef<Path> + Debug + ?Sized> Matcher for DirEqMatcher<ActualT> {
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        matches!(self.differences(actual.as_ref()), Ok(differences) if differences.is_empty())
+            .into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is a directory tree equal to {:?}", self.expected)
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't a directory tree equal to {:?}", self.expected)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        match self.differences(actual.as_ref()) {
+            Ok(differences) if differences.is_empty() => {
+                MatchExplanation::create("which is an equal directory tree".to_string())
+            }
+            Ok(differences) => MatchExplanation::create(format!(
+                "which differs at {} path{}:\n  {}",
+                differences.len(),
+                if differences.len() == 1 { "" } else { "s" },
+                differences.join("\n").replace('\n', "\n  ")
+            )),
+            Err(error) => MatchExplanation::create(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dir_eq;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use crate::test_fs::TestFs;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_equal_trees() -> Result<()> {
+        let expected = TestFs::create([("a.txt", "a"), ("b/c.txt", "c"), ("d/", "")])?;
+        let actual = TestFs::create([("a.txt", "a"), ("b/c.txt", "c"), ("d/", "")])?;
+
+        verify_that!(actual.root(), dir_eq(expected.root()))
+    }
+
+    #[test]
+    fn does_not_match_tree_with_different_contents() -> Result<()> {
+        let expected = TestFs::create([("a.txt", "a")])?;
+        let actual = TestFs::create([("a.txt", "b")])?;
+
+        verify_that!(
+            dir_eq(expected.root()).matches(actual.root()),
+            eq(MatcherResult::DoesNotMatch)
+        )
+    }
+
+    #[test]
+    fn matches_trees_differing_only_in_ignored_paths() -> Result<()> {
+        let expected = TestFs::create([("a.txt", "a")])?;
+        let actual =
+            TestFs::create([("a.txt", "a"), ("target/debug/out", "binary"), ("b.log", "log")])?;
+
+        verify_that!(actual.root(), dir_eq(expected.root()).ignoring("target").ignoring("*.log"))
+    }
+
+    #[test]
+    fn explains_missing_and_unexpected_entries() -> Result<()> {
+        let expected = TestFs::create([("a.txt", "a"), ("gone/x.txt", "x"), ("kind", "file")])?;
+        let actual = TestFs::create([("a.txt", "a"), ("new.txt", "new"), ("kind/", "")])?;
+
+        verify_that!(
+            dir_eq(expected.root()).explain_match(actual.root()),
+            displays_as(eq(indoc!(
+                "
+                which differs at 3 paths:
+                  gone: missing directory
+                  kind: expected a file but was a directory
+                  new.txt: unexpected file"
+            )))
+        )
+    }
+
+    #[test]
+    fn explains_differing_text_contents_with_diff() -> Result<()> {
+        let expected = TestFs::create([("a.txt", "one\ntwo\nthree\n")])?;
+        let actual = TestFs::create([("a.txt", "one\n2\nthree\n")])?;
+
+        verify_that!(
+            dir_eq(expected.root()).explain_match(actual.root()),
+            displays_as(eq(indoc!(
+                "
+                which differs at 1 path:
+                  a.txt: contents differ (expected 14 bytes, actual 12 bytes)
+                     one
+                    -two
+                    +2
+                     three"
+            )))
+        )
+    }
+
+    #[test]
+    fn explains_differing_binary_contents_with_sizes() -> Result<()> {
+        let expected = TestFs::create([])?;
+        let actual = TestFs::create([])?;
+        expected.write("a.bin", [0xff, 0x00])?;
+        actual.write("a.bin", [0xff, 0x01, 0x02])?;
+
+        verify_that!(
+            dir_eq(expected.root()).explain_match(actual.root()),
+            displays_as(eq(indoc!(
+                "
+                which differs at 1 path:
+                  a.bin: contents differ (expected 2 bytes, actual 3 bytes)"
+            )))
+        )
+    }
+
+    #[test]
+    fn explains_unreadable_actual_tree() -> Result<()> {
+        let expected = TestFs::create([])?;
+
+        verify_that!(
+            dir_eq(expected.root()).explain_match(expected.path("missing").as_path()),
+            displays_as(starts_with("which cannot be read: "))
+        )
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Extension trait providing the [`or`][OrMatcherExt::or] method.
@@ -75,11 +75,8 @@ impl<T: Debug + ?Sized, M1: Matcher<ActualT = T>, M2: Matcher<ActualT = T>> Matc
     }
 
     fn explain_match(&self, actual: &T) -> MatchExplanation {
-        MatchExplanation::create(format!(
-            "{} and\n{}",
-            self.m1.explain_match(actual),
-            self.m2.explain_match(actual)
-        ))
+        let (e1, e2) = (explain(&self.m1, actual), explain(&self.m2, actual));
+        MatchExplanation::create(format!("{e1} and\n{e2}")).with_children([e1, e2])
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 
@@ -43,7 +43,9 @@ impl<T: Debug + Display, InnerMatcher: Matcher<ActualT = String>> Matcher
     }
 
     fn explain_match(&self, actual: &T) -> MatchExplanation {
-        MatchExplanation::create(format!("which displays as \"{}\"", actual))
+        let displayed = format!("{actual}");
+        let inner = explain(&self.inner, &displayed);
+        MatchExplanation::create(format!("which displays as \"{displayed}\"")).with_child(inner)
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use crate::matcher_support::description::Description;
 use std::{fmt::Debug, marker::PhantomData};
 
@@ -91,7 +91,7 @@ where
         let mut non_matching_elements = Vec::new();
         for (index, element) in actual.into_iter().enumerate() {
             if !self.inner.matches(element).into_bool() {
-                non_matching_elements.push((index, element, self.inner.explain_match(element)));
+                non_matching_elements.push((index, element, explain(&self.inner, element)));
             }
         }
         if non_matching_elements.is_empty() {
@@ -104,7 +104,8 @@ where
             let (idx, element, explanation) = non_matching_elements.remove(0);
             return MatchExplanation::create(format!(
                 "whose element #{idx} is {element:?}, {explanation}"
-            ));
+            ))
+            .with_child(explanation);
         }
 
         let failed_indexes = non_matching_elements
@@ -114,12 +115,13 @@ where
             .join(", ");
         let element_explanations = non_matching_elements
             .iter()
-            .map(|&(_, element, ref explanation)| format!("{element:?}, {explanation}"))
+            .map(|&(idx, element, ref explanation)| format!("#{idx}: {element:?}, {explanation}"))
             .collect::<Description>()
             .indent();
         MatchExplanation::create(format!(
             "whose elements {failed_indexes} don't match\n{element_explanations}"
         ))
+        .with_children(non_matching_elements.into_iter().map(|(_, _, explanation)| explanation))
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
@@ -239,8 +241,8 @@ mod tests {
                     1,
                     3,
                 ], whose elements #0, #1 don't match
-                  0, which is less than or equal to 1
-                  1, which is less than or equal to 1"
+                  #0: 0, which is less than or equal to 1
+                  #1: 1, which is less than or equal to 1"
             ))))
         )
     }
@@ -252,7 +254,7 @@ mod tests {
             result,
             err(displays_as(contains_substring(indoc!(
                 "
-                Value of: vec![vec! [1, 2], vec! [1]]
+                Value of: vec![vec![1, 2], vec![1]]
                 Expected: only contains elements that only contains elements that is equal to 1
                 Actual: [
                     [
@@ -0,0 +1,140 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{BoxMatcher, MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::ordered_container::OrderedContainer;
+use crate::matchers::elements_are_matcher::internal::{
+    describe_elements, explain_elements, match_elements,
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a container whose elements the matchers in `elements` match, in
+/// order.
+///
+/// This is the runtime counterpart of [`elements_are!`][crate::elements_are].
+/// Since the matchers are supplied as a `Vec` of boxed trait objects, they can
+/// be built at runtime, e.g., one matcher per entry of some test data.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let expected = [1.0, 2.0, 3.0];
+/// let matchers = expected.iter().map(|e| near(*e, 0.01).boxed()).collect();
+/// verify_that!(vec![1.001, 1.999, 3.0], elements_are_from(matchers))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2], elements_are_from(vec![eq(1).boxed(), eq(3).boxed()]))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The failure messages are the same as those of `elements_are!`. As with
+/// that macro, the actual value must implement [`OrderedContainer`], so that a
+/// [`HashMap`][std::collections::HashMap] or
+/// [`HashSet`][std::collections::HashSet] is rejected at compile time.
+pub fn elements_are_from<'a, T: Debug + 'a, ContainerT: Debug + OrderedContainer + ?Sized + 'a>(
+    elements: Vec<BoxMatcher<'a, T>>,
+) -> impl Matcher<ActualT = ContainerT> + 'a
+where
+    for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
+{
+    ElementsAreFromMatcher { elements, phantom: PhantomData }
+}
+
+struct ElementsAreFromMatcher<'a, T, ContainerT: ?Sized> {
+    elements: Vec<BoxMatcher<'a, T>>,
+    phantom: PhantomData<ContainerT>,
+}
+
+impl<'a, T, ContainerT: ?Sized> ElementsAreFromMatcher<'a, T, ContainerT> {
+    fn matcher_refs(&self) -> Vec<&dyn Matcher<ActualT = T>> {
+        self.elements.iter().map(|matcher| matcher.as_ref() as &dyn Matcher<ActualT = T>).collect()
+    }
+}
+
+impl<'a, T: Debug, ContainerT: Debug + OrderedContainer + ?Sized> Matcher
+    for ElementsAreFromMatcher<'a, T, ContainerT>
+where
+    for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
+{
+    type ActualT = ContainerT;
+
+    fn matches(&self, actual: &ContainerT) -> MatcherResult {
+        match_elements(actual.into_iter(), &self.matcher_refs())
+    }
+
+    fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+        explain_elements(actual.into_iter(), &self.matcher_refs())
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!(
+            "{} elements:\n{}",
+            if matcher_result.into() { "has" } else { "doesn't have" },
+            describe_elements(&self.matcher_refs())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::elements_are_from;
+    use crate::matcher::Matcher;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn elements_are_from_matches_matchers_built_at_runtime() -> Result<()> {
+        let matchers: Vec<Box<dyn Matcher<ActualT = i32>>> =
+            (1..=3).map(|i| Box::new(eq(i)) as Box<dyn Matcher<ActualT = i32>>).collect();
+        verify_that!(vec![1, 2, 3], elements_are_from(matchers))
+    }
+
+    #[test]
+    fn elements_are_from_matches_empty_container_with_no_matchers() -> Result<()> {
+        verify_that!(Vec::<i32>::new(), elements_are_from(vec![]))
+    }
+
+    #[test]
+    fn elements_are_from_does_not_match_when_sizes_differ() -> Result<()> {
+        verify_that!(vec![1, 2], not(elements_are_from(vec![Box::new(eq(1))])))
+    }
+
+    #[test]
+    fn elements_are_from_produces_correct_failure_message() -> Result<()> {
+        let result = verify_that!(
+            vec![1, 4, 3],
+            elements_are_from(vec![Box::new(eq(1)), Box::new(eq(2)), Box::new(eq(3))])
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: has elements:
+                  0. is equal to 1
+                  1. is equal to 2
+                  2. is equal to 3
+                Actual: [
+                    1,
+                    4,
+                    3,
+                ], where element #1 is 4, which isn't equal to 2"
+            ))))
+        )
+    }
+}
@@ -30,6 +30,15 @@
 /// #    .unwrap();
 /// ```
 ///
+/// A plain value can be given in place of a matcher, in which case the
+/// corresponding element must be equal to it, as with [`eq`]:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// verify_that!(vec![1, 2, 3], elements_are![1, anything(), 3])
+/// #    .unwrap();
+/// ```
+///
 /// The actual value must be a container implementing [`IntoIterator`]. This
 /// includes standard containers, slices (when dereferenced) and arrays.
 ///
@@ -44,21 +53,63 @@
 /// ```
 ///
 /// This matcher does not support matching directly against an [`Iterator`]. To
-/// match against an iterator, use [`Iterator::collect`] to build a [`Vec`].
+/// match against an iterator which implements [`Clone`], use
+/// [`iterates_as!`][crate::iterates_as], which does not collect its items.
+/// Otherwise, wrap it with [`yielded`][crate::matchers::yielded] or use
+/// [`Iterator::collect`] to build a [`Vec`].
 ///
-/// Do not use this with unordered containers, since that will lead to flaky
-/// tests. Use [`unordered_elements_are!`][crate::unordered_elements_are]
-/// instead.
+/// The container must implement [`OrderedContainer`], since matching an
+/// unordered container such as a [`HashMap`][std::collections::HashMap] or
+/// [`HashSet`][std::collections::HashSet] in order would lead to flaky tests.
+/// Such containers are rejected at compile time; use
+/// [`unordered_elements_are!`][crate::unordered_elements_are] for them instead.
 ///
+/// [`eq`]: crate::matchers::eq
 /// [`IntoIterator`]: std::iter::IntoIterator
 /// [`Iterator`]: std::iter::Iterator
 /// [`Iterator::collect`]: std::iter::Iterator::collect
+/// [`OrderedContainer`]: crate::matcher_support::ordered_container::OrderedContainer
 /// [`Vec`]: std::vec::Vec
 #[macro_export]
 macro_rules! elements_are {
     ($($matcher:expr),* $(,)?) => {{
         use $crate::matchers::elements_are_matcher::internal::ElementsAre;
-        ElementsAre::new(&[$(&$matcher),*])
+        ElementsAre::new(&[$(&$crate::matcher::IntoMatcher::into_matcher(&$matcher)),*])
+    }}
+}
+
+/// Matches an iterator whose items each matcher matches, in order.
+///
+/// This is the counterpart of [`elements_are!`][crate::elements_are] for
+/// iterators. It walks a fresh clone of the actual iterator each time it
+/// matches, so the iterator must implement [`Clone`], but its items are never
+/// collected. The items are owned, so the matchers match the items themselves
+/// rather than references to them.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let words = ["apple", "banana"];
+/// verify_that!(words.iter().map(|w| w.len()), iterates_as![eq(5), eq(6)])?; // Passes
+/// verify_that!((1..=3).rev(), iterates_as![eq(3), eq(2), eq(1)])?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!((1..4).map(|i| i * 2), iterates_as![eq(2), eq(4)])?; // Fails: wrong size
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The iterator must be finite unless it diverges from the matchers. To match
+/// an iterator which cannot be cloned, such as the result of `drain()`, wrap
+/// it with [`yielded`][crate::matchers::yielded] and use `elements_are!`.
+#[macro_export]
+macro_rules! iterates_as {
+    ($($matcher:expr),* $(,)?) => {{
+        use $crate::matchers::elements_are_matcher::internal::IteratesAs;
+        IteratesAs::new(&[$(&$crate::matcher::IntoMatcher::into_matcher(&$matcher)),*])
     }}
 }
 
@@ -67,10 +118,11 @@ macro_rules! elements_are {
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
 pub mod internal {
-    use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
     use crate::matcher_support::description::Description;
+    use crate::matcher_support::ordered_container::OrderedContainer;
     use crate::matcher_support::zipped_iterator::zip;
-    use std::{fmt::Debug, marker::PhantomData};
+    use std::{borrow::Borrow, fmt::Debug, marker::PhantomData};
 
     /// This struct is meant to be used only by the macro `elements_are!`.
     ///
@@ -91,65 +143,129 @@ pub mod internal {
         }
     }
 
-    impl<'a, T: Debug, ContainerT: Debug + ?Sized> Matcher for ElementsAre<'a, ContainerT, T>
+    impl<'a, T: Debug, ContainerT: Debug + OrderedContainer + ?Sized> Matcher
+        for ElementsAre<'a, ContainerT, T>
     where
         for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
     {
         type ActualT = ContainerT;
 
         fn matches(&self, actual: &ContainerT) -> MatcherResult {
-            let mut zipped_iterator = zip(actual.into_iter(), self.elements.iter());
-            for (a, e) in zipped_iterator.by_ref() {
-                if !e.matches(a).into_bool() {
-                    return MatcherResult::DoesNotMatch;
-                }
-            }
-            if !zipped_iterator.has_size_mismatch() {
-                MatcherResult::Matches
-            } else {
-                MatcherResult::DoesNotMatch
-            }
+            match_elements(actual.into_iter(), self.elements)
         }
 
         fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
-            let actual_iterator = actual.into_iter();
-            let mut zipped_iterator = zip(actual_iterator, self.elements.iter());
-            let mut mismatches = Vec::new();
-            for (idx, (a, e)) in zipped_iterator.by_ref().enumerate() {
-                if !e.matches(a).into_bool() {
-                    mismatches.push(format!("element #{idx} is {a:?}, {}", e.explain_match(a)));
-                }
-            }
-            if mismatches.is_empty() {
-                if !zipped_iterator.has_size_mismatch() {
-                    MatchExplanation::create("whose elements all match".to_string())
-                } else {
-                    MatchExplanation::create(format!(
-                        "whose size is {}",
-                        zipped_iterator.left_size()
-                    ))
-                }
-            } else if mismatches.len() == 1 {
-                let mismatches = mismatches.into_iter().collect::<Description>();
-                MatchExplanation::create(format!("where {mismatches}"))
-            } else {
-                let mismatches = mismatches.into_iter().collect::<Description>();
-                MatchExplanation::create(format!("where:\n{}", mismatches.bullet_list().indent()))
-            }
+            explain_elements(actual.into_iter(), self.elements)
         }
 
         fn describe(&self, matcher_result: MatcherResult) -> String {
             format!(
                 "{} elements:\n{}",
                 if matcher_result.into() { "has" } else { "doesn't have" },
-                &self
-                    .elements
-                    .iter()
-                    .map(|matcher| matcher.describe(MatcherResult::Matches))
-                    .collect::<Description>()
-                    .enumerate()
-                    .indent()
+                describe_elements(self.elements)
             )
         }
     }
+
+    /// This struct is meant to be used only by the macro `iterates_as!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct IteratesAs<'a, IteratorT, T: Debug> {
+        elements: &'a [&'a dyn Matcher<ActualT = T>],
+        phantom: PhantomData<IteratorT>,
+    }
+
+    impl<'a, IteratorT, T: Debug> IteratesAs<'a, IteratorT, T> {
+        /// Factory only intended for use in the macro `iterates_as!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        #[doc(hidden)]
+        pub fn new(elements: &'a [&'a dyn Matcher<ActualT = T>]) -> Self {
+            Self { elements, phantom: Default::default() }
+        }
+    }
+
+    impl<'a, T: Debug, IteratorT: Iterator<Item = T> + Clone + Debug> Matcher
+        for IteratesAs<'a, IteratorT, T>
+    {
+        type ActualT = IteratorT;
+
+        fn matches(&self, actual: &IteratorT) -> MatcherResult {
+            match_elements(actual.clone(), self.elements)
+        }
+
+        fn explain_match(&self, actual: &IteratorT) -> MatchExplanation {
+            explain_elements(actual.clone(), self.elements)
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            format!(
+                "{} elements:\n{}",
+                if matcher_result.into() { "yields" } else { "doesn't yield" },
+                describe_elements(self.elements)
+            )
+        }
+    }
+
+    pub(crate) fn match_elements<T: Debug, ItemT: Borrow<T>>(
+        actual: impl Iterator<Item = ItemT>,
+        elements: &[&dyn Matcher<ActualT = T>],
+    ) -> MatcherResult {
+        let mut zipped_iterator = zip(actual, elements.iter());
+        for (a, e) in zipped_iterator.by_ref() {
+            if !e.matches(a.borrow()).into_bool() {
+                return MatcherResult::DoesNotMatch;
+            }
+        }
+        if !zipped_iterator.has_size_mismatch() {
+            MatcherResult::Matches
+        } else {
+            MatcherResult::DoesNotMatch
+        }
+    }
+
+    pub(crate) fn explain_elements<T: Debug, ItemT: Borrow<T>>(
+        actual: impl Iterator<Item = ItemT>,
+        elements: &[&dyn Matcher<ActualT = T>],
+    ) -> MatchExplanation {
+        let mut zipped_iterator = zip(actual, elements.iter());
+        let mut mismatches = Vec::new();
+        for (idx, (a, e)) in zipped_iterator.by_ref().enumerate() {
+            let a = a.borrow();
+            if !e.matches(a).into_bool() {
+                mismatches.push((idx, format!("{a:?}"), explain(*e, a)));
+            }
+        }
+        if mismatches.is_empty() {
+            if !zipped_iterator.has_size_mismatch() {
+                MatchExplanation::create("whose elements all match".to_string())
+            } else {
+                MatchExplanation::create(format!("whose size is {}", zipped_iterator.left_size()))
+            }
+        } else {
+            let description = mismatches
+                .iter()
+                .map(|(idx, a, explanation)| format!("element #{idx} is {a}, {explanation}"))
+                .collect::<Description>();
+            let text = if mismatches.len() == 1 {
+                format!("where {description}")
+            } else {
+                format!("where:\n{}", description.bullet_list().indent())
+            };
+            MatchExplanation::create(text)
+                .with_children(mismatches.into_iter().map(|(_, _, explanation)| explanation))
+        }
+    }
+
+    pub(crate) fn describe_elements<T: Debug>(
+        elements: &[&dyn Matcher<ActualT = T>],
+    ) -> Description {
+        elements
+            .iter()
+            .map(|matcher| matcher.describe(MatcherResult::Matches))
+            .collect::<Description>()
+            .enumerate()
+            .indent()
+    }
 }
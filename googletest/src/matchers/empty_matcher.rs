@@ -44,8 +44,7 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # }
 /// # should_pass().unwrap();
 /// ```
-
-pub fn empty<T: Debug + ?Sized>() -> impl Matcher<ActualT = T>
+pub fn empty<T: Debug + ?Sized>() -> impl Matcher<ActualT = T> + Copy
 where
     for<'a> &'a T: IntoIterator,
 {
@@ -56,6 +55,14 @@ struct EmptyMatcher<T: ?Sized> {
     phantom: PhantomData<T>,
 }
 
+impl<T: ?Sized> Clone for EmptyMatcher<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for EmptyMatcher<T> {}
+
 impl<T: Debug + ?Sized> Matcher for EmptyMatcher<T>
 where
     for<'a> &'a T: IntoIterator,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::debug_layout::debug_string;
 use crate::matchers::eq_matcher::create_diff;
 use std::{fmt::Debug, marker::PhantomData, ops::Deref};
 
@@ -62,6 +63,14 @@ pub struct EqDerefOfMatcher<ActualT: ?Sized, ExpectedRefT> {
     phantom: PhantomData<ActualT>,
 }
 
+impl<ActualT: ?Sized, ExpectedRefT: Clone> Clone for EqDerefOfMatcher<ActualT, ExpectedRefT> {
+    fn clone(&self) -> Self {
+        Self { expected: self.expected.clone(), phantom: PhantomData }
+    }
+}
+
+impl<ActualT: ?Sized, ExpectedRefT: Copy> Copy for EqDerefOfMatcher<ActualT, ExpectedRefT> {}
+
 impl<ActualT, ExpectedRefT, ExpectedT> Matcher for EqDerefOfMatcher<ActualT, ExpectedRefT>
 where
     ActualT: Debug + ?Sized,
@@ -83,8 +92,8 @@ where
 
     fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
         create_diff(
-            &format!("{:#?}", self.expected.deref()),
-            &format!("{:#?}", actual),
+            &debug_string(self.expected.deref()),
+            &debug_string(actual),
             &self.describe(self.matches(actual)),
         )
     }
@@ -139,9 +148,13 @@ mod tests {
             Debug diff:
              Strukt {
             +    int: 123,
+                      ^^^
             -    int: 321,
+                      ^^^
             +    string: "something",
+                          ^^^^^^^^^
             -    string: "someone",
+                          ^^^^^^^
              }
             "#})))
         )
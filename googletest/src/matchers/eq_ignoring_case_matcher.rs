@@ -0,0 +1,257 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{config, DiffStyle};
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::edit_distance::{self, Distance, Edit};
+use crate::matchers::eq_matcher::edit_list_summary;
+use std::{fmt::Debug, marker::PhantomData, ops::Deref};
+
+/// Matches a string equal to `expected` when differences of case are ignored.
+///
+/// Unlike
+/// [`ignoring_ascii_case`][crate::matchers::str_matcher::StrMatcherConfigurator::ignoring_ascii_case],
+/// this is Unicode-aware: two strings are considered equal if they agree after
+/// mapping them to lower and then to upper case, which approximates Unicode
+/// case folding.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("Hello World", eq_ignoring_case("hello world"))?; // Passes
+/// verify_that!("STRASSE", eq_ignoring_case("straße"))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("Hello World", eq_ignoring_case("hello there"))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On mismatch, the explanation points out where the strings first differ
+/// beyond case. For multi-line strings, it includes a line diff in which lines
+/// differing only in case are shown as unchanged:
+///
+/// ```text
+/// Value of: "Hello World"
+/// Expected: is equal to "hello there" ignoring case
+/// Actual: "Hello World", which differs beyond case from the expected value starting at character 6
+/// ```
+pub fn eq_ignoring_case<A: ?Sized, T>(expected: T) -> EqIgnoringCaseMatcher<A, T> {
+    EqIgnoringCaseMatcher { expected, phantom: Default::default() }
+}
+
+/// A matcher which matches strings equal to an expected string, ignoring
+/// case.
+///
+/// See [`eq_ignoring_case`].
+pub struct EqIgnoringCaseMatcher<A: ?Sized, T> {
+    expected: T,
+    phantom: PhantomData<A>,
+}
+
+impl<A: ?Sized, T: Clone> Clone for EqIgnoringCaseMatcher<A, T> {
+    fn clone(&self) -> Self {
+        Self { expected: self.expected.clone(), phantom: PhantomData }
+    }
+}
+
+impl<A: ?Sized, T: Copy> Copy for EqIgnoringCaseMatcher<A, T> {}
+
+impl<A, T> Matcher for EqIgnoringCaseMatcher<A, T>
+where
+    A: AsRef<str> + Debug + ?Sized,
+    T: Deref<Target = str> + Debug,
+{
+    type ActualT = A;
+
+    fn matches(&self, actual: &A) -> MatcherResult {
+        (fold_case(actual.as_ref()) == fold_case(&self.expected)).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("is equal to {:?} ignoring case", self.expected),
+            MatcherResult::DoesNotMatch => {
+                format!("isn't equal to {:?} ignoring case", self.expected)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &A) -> MatchExplanation {
+        if self.matches(actual).into() {
+            return MatchExplanation::create(format!(
+                "which {}",
+                self.describe(MatcherResult::Matches)
+            ));
+        }
+        let actual = actual.as_ref();
+        let expected: &str = &self.expected;
+        let diff_style = config().diff_style;
+        if actual.lines().count() < 2 || expected.is_empty() || diff_style == DiffStyle::Disabled {
+            let position = actual
+                .chars()
+                .zip(expected.chars())
+                .position(|(a, e)| fold_char(a) != fold_char(e))
+                .unwrap_or_else(|| actual.chars().count().min(expected.chars().count()));
+            return MatchExplanation::create(format!(
+                "which differs beyond case from the expected value starting at character {}",
+                position
+            ));
+        }
+        let Some(edit_list) = edit_distance::line_edit_list(
+            actual.lines().map(CaselessLine),
+            expected.lines().map(CaselessLine),
+        ) else {
+            return MatchExplanation::create(
+                "which differs beyond case from the expected value\nDifference ignoring case \
+                omitted: the strings are too large and too different to diff."
+                    .to_string(),
+            );
+        };
+        let edit_list: Vec<_> = edit_list
+            .into_iter()
+            .map(|edit| match edit {
+                Edit::ExtraLeft { left } => Edit::ExtraLeft { left: left.0 },
+                Edit::ExtraRight { right } => Edit::ExtraRight { right: right.0 },
+                Edit::Both { left, right, distance } => {
+                    Edit::Both { left: left.0, right: right.0, distance }
+                }
+            })
+            .collect();
+        MatchExplanation::create(format!(
+            "which differs beyond case from the expected value\nDifference ignoring case:{}",
+            edit_list_summary(&edit_list, diff_style)
+        ))
+    }
+}
+
+pub(super) fn fold_case(value: &str) -> String {
+    value.to_lowercase().to_uppercase()
+}
+
+fn fold_char(value: char) -> String {
+    value.to_lowercase().flat_map(char::to_uppercase).collect()
+}
+
+/// A line which the edit distance compares without regard to case.
+#[derive(Clone, Copy)]
+struct CaselessLine<'a>(&'a str);
+
+impl Distance for CaselessLine<'_> {
+    fn distance(left: Self, right: Self) -> f64 {
+        let left = fold_case(left.0);
+        let right = fold_case(right.0);
+        <&str as Distance>::distance(&left, &right)
+    }
+
+    fn equals(left: Self, right: Self) -> bool {
+        fold_case(left.0) == fold_case(right.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eq_ignoring_case;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_string_differing_in_ascii_case() -> Result<()> {
+        verify_that!("Hello World", eq_ignoring_case("hELLO wORLD"))
+    }
+
+    #[test]
+    fn matches_string_differing_in_unicode_case() -> Result<()> {
+        verify_that!("ÄÖÜ", eq_ignoring_case("äöü"))?;
+        verify_that!(String::from("STRASSE"), eq_ignoring_case("straße"))?;
+        verify_that!("ΣΊΣΥΦΟΣ", eq_ignoring_case("σίσυφος"))
+    }
+
+    #[test]
+    fn does_not_match_string_differing_beyond_case() -> Result<()> {
+        verify_that!("Hello", not(eq_ignoring_case("Help")))?;
+        verify_that!("Hello", not(eq_ignoring_case("Hello!")))
+    }
+
+    #[test]
+    fn describes_itself() -> Result<()> {
+        let matcher = eq_ignoring_case::<str, _>("abc");
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq("is equal to \"abc\" ignoring case")
+        )?;
+        verify_that!(
+            matcher.describe(MatcherResult::DoesNotMatch),
+            eq("isn't equal to \"abc\" ignoring case")
+        )
+    }
+
+    #[test]
+    fn explains_position_of_first_difference() -> Result<()> {
+        verify_that!(
+            eq_ignoring_case("hello there").explain_match("Hello World"),
+            displays_as(eq(
+                "which differs beyond case from the expected value starting at character 6"
+            ))
+        )
+    }
+
+    #[test]
+    fn explains_position_when_one_string_is_prefix_of_other() -> Result<()> {
+        verify_that!(
+            eq_ignoring_case("HELLO").explain_match("hello!"),
+            displays_as(eq(
+                "which differs beyond case from the expected value starting at character 5"
+            ))
+        )
+    }
+
+    #[test]
+    fn explains_multiline_mismatch_with_caseless_diff() -> Result<()> {
+        let result = verify_that!(
+            indoc!(
+                "
+                First Line
+                Second Line
+                Third Line
+                "
+            ),
+            eq_ignoring_case(indoc!(
+                "
+                FIRST LINE
+                SECOND ROW
+                THIRD LINE
+                "
+            ))
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                  which differs beyond case from the expected value
+                  Difference ignoring case:
+                   First Line
+                  +Second Line
+                  -SECOND ROW
+                   Third Line
+                "
+            ))))
+        )
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{config, DiffStyle};
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::edit_distance;
+use crate::matchers::eq_matcher::edit_list_summary;
+use std::{fmt::Debug, marker::PhantomData, ops::Deref};
+
+/// Matches a string equal to `expected` after normalizing whitespace.
+///
+/// Within each line of either string, every run of whitespace characters is
+/// replaced by a single space. Line breaks are preserved, so this is suited to
+/// comparing generated code or formatted output whose layout matters but whose
+/// exact spacing does not.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("fn  main() {\t}", eq_ignoring_whitespace("fn main() { }"))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("fn main(){}", eq_ignoring_whitespace("fn main() { }"))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// Whitespace is only collapsed, never removed, so the failing assertion above
+/// distinguishes `(){}` from `() { }`. Leading and trailing whitespace of each
+/// line, such as indentation, can additionally be ignored with
+/// [`EqIgnoringWhitespaceMatcher::trimming_lines`].
+///
+/// On mismatch, the explanation shows the normalized actual value or, for
+/// multi-line strings, a line diff of the normalized strings.
+pub fn eq_ignoring_whitespace<A: ?Sized, T>(expected: T) -> EqIgnoringWhitespaceMatcher<A, T> {
+    EqIgnoringWhitespaceMatcher { expected, trim_lines: false, phantom: Default::default() }
+}
+
+/// A matcher which matches strings equal to an expected string after
+/// normalizing whitespace.
+///
+/// See [`eq_ignoring_whitespace`].
+pub struct EqIgnoringWhitespaceMatcher<A: ?Sized, T> {
+    expected: T,
+    trim_lines: bool,
+    phantom: PhantomData<A>,
+}
+
+impl<A: ?Sized, T> EqIgnoringWhitespaceMatcher<A, T> {
+    /// Configures this instance to also ignore leading and trailing whitespace
+    /// on each line.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!(
+    ///     "if x {\n        y();\n}\n",
+    ///     eq_ignoring_whitespace("if x {\n  y();  \n}\n").trimming_lines()
+    /// )?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    pub fn trimming_lines(mut self) -> Self {
+        self.trim_lines = true;
+        self
+    }
+}
+
+impl<A: ?Sized, T: Clone> Clone for EqIgnoringWhitespaceMatcher<A, T> {
+    fn clone(&self) -> Self {
+        Self { expected: self.expected.clone(), trim_lines: self.trim_lines, phantom: PhantomData }
+    }
+}
+
+impl<A: ?Sized, T: Copy> Copy for EqIgnoringWhitespaceMatcher<A, T> {}
+
+impl<A: ?Sized, T> EqIgnoringWhitespaceMatcher<A, T> {
+    fn normalize(&self, value: &str) -> String {
+        value.lines().map(|line| self.normalize_line(line)).collect::<Vec<_>>().join("\n")
+    }
+
+    fn normalize_line(&self, line: &str) -> String {
+        if self.trim_lines {
+            return line.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+        let mut normalized = String::with_capacity(line.len());
+        let mut in_whitespace = false;
+        for c in line.chars() {
+            if c.is_whitespace() {
+                if !in_whitespace {
+                    normalized.push(' ');
+                }
+                in_whitespace = true;
+            } else {
+                normalized.push(c);
+                in_whitespace = false;
+            }
+        }
+        normalized
+    }
+}
+
+impl<A, T> Matcher for EqIgnoringWhitespaceMatcher<A, T>
+where
+    A: AsRef<str> + Debug + ?Sized,
+    T: Deref<Target = str> + Debug,
+{
+    type ActualT = A;
+
+    fn matches(&self, actual: &A) -> MatcherResult {
+        (self.normalize(actual.as_ref()) == self.normalize(&self.expected)).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        let qualifier = if self.trim_lines { " and line ends" } else { "" };
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is equal to {:?} ignoring whitespace{qualifier}", self.expected)
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't equal to {:?} ignoring whitespace{qualifier}", self.expected)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &A) -> MatchExplanation {
+        if self.matches(actual).into() {
+            return MatchExplanation::create(format!(
+                "which {}",
+                self.describe(MatcherResult::Matches)
+            ));
+        }
+        let actual = self.normalize(actual.as_ref());
+        let expected = self.normalize(&self.expected);
+        let diff_style = config().diff_style;
+        if actual.lines().count() < 2 || expected.is_empty() || diff_style == DiffStyle::Disabled {
+            return MatchExplanation::create(format!(
+                "which is {:?} after normalizing whitespace",
+                actual
+            ));
+        }
+        let Some(edit_list) = edit_distance::line_edit_list(actual.lines(), expected.lines())
+        else {
+            return MatchExplanation::create(
+                "which differs after normalizing whitespace\nNormalized diff omitted: the strings \
+                are too large and too different to diff."
+                    .to_string(),
+            );
+        };
+        MatchExplanation::create(format!(
+            "which differs after normalizing whitespace\nNormalized diff:{}",
+            edit_list_summary(&edit_list, diff_style)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eq_ignoring_whitespace;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_string_with_different_runs_of_whitespace() -> Result<()> {
+        verify_that!("a  b\t\tc", eq_ignoring_whitespace("a b c"))?;
+        verify_that!(String::from("a b c"), eq_ignoring_whitespace("a \t b  c"))
+    }
+
+    #[test]
+    fn does_not_match_string_with_missing_whitespace() -> Result<()> {
+        verify_that!("ab c", not(eq_ignoring_whitespace("a b c")))
+    }
+
+    #[test]
+    fn preserves_line_breaks() -> Result<()> {
+        verify_that!("a\nb", not(eq_ignoring_whitespace("a b")))
+    }
+
+    #[test]
+    fn respects_indentation_by_default() -> Result<()> {
+        verify_that!("  a\n    b", eq_ignoring_whitespace(" a\n b"))?;
+        verify_that!("  a", not(eq_ignoring_whitespace("a")))
+    }
+
+    #[test]
+    fn ignores_indentation_when_trimming_lines() -> Result<()> {
+        verify_that!("  a  \n    b", eq_ignoring_whitespace("a\nb").trimming_lines())
+    }
+
+    #[test]
+    fn describes_itself() -> Result<()> {
+        verify_that!(
+            eq_ignoring_whitespace::<str, _>("a b").describe(MatcherResult::Matches),
+            eq("is equal to \"a b\" ignoring whitespace")
+        )?;
+        verify_that!(
+            eq_ignoring_whitespace::<str, _>("a b")
+                .trimming_lines()
+                .describe(MatcherResult::DoesNotMatch),
+            eq("isn't equal to \"a b\" ignoring whitespace and line ends")
+        )
+    }
+
+    #[test]
+    fn explains_single_line_mismatch_with_normalized_actual() -> Result<()> {
+        verify_that!(
+            eq_ignoring_whitespace("fn main() { }").explain_match("fn   main(){}"),
+            displays_as(eq("which is \"fn main(){}\" after normalizing whitespace"))
+        )
+    }
+
+    #[test]
+    fn explains_multiline_mismatch_with_normalized_diff() -> Result<()> {
+        let result = verify_that!(
+            "fn main() {\n    let  x = 1;\n    let y   = 3;\n}",
+            eq_ignoring_whitespace("fn main() {\n  let x = 1;\n  let y = 2;\n}")
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                  which differs after normalizing whitespace
+                  Normalized diff:
+                   fn main() {
+                    let x = 1;
+                  + let y = 3;
+                            ^
+                  - let y = 2;
+                            ^
+                   }
+                "
+            ))))
+        )
+    }
+}
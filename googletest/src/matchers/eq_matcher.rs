@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::config::{config, DiffStyle};
+use crate::formatters::format_value;
 use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
-use crate::matcher_support::edit_distance;
+use crate::matcher_support::debug_layout::layout_debug;
+use crate::matcher_support::{edit_distance, hexdump_diff};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Matches a value equal (in the sense of `==`) to `expected`.
@@ -50,24 +53,35 @@ use std::{fmt::Debug, marker::PhantomData};
 /// ```
 ///
 /// In most cases however, one must convert one of the arguments explicitly.
-/// This can be surprising when comparing integer types or references.
+/// This can be surprising when comparing integer types.
 ///
 /// ```compile_fail
 /// verify_that!(123u32, eq(123u64))?; // Does not compile
 /// verify_that!(123u32 as u64, eq(123u64))?; // Passes
 /// ```
 ///
+/// A reference to the actual value can be compared with a value or with a
+/// reference, since the assertion macros dereference the actual value when
+/// the matcher expects the value behind the reference:
+///
 /// ```ignore
 /// let actual: &T = ...;
 /// let expected: T = T{...};
-/// verify_that(actual, eq(expected))?; // Does not compile
-/// verify_that(actual, eq(&expected))?; // Compiles
+/// verify_that!(actual, eq(expected))?; // Passes
+/// verify_that!(actual, eq(&expected))?; // Passes
 /// ```
 ///
 /// When matching with string types (`&str` and `String`), one can set more
 /// options on how equality is checked through the
 /// [`StrMatcherConfigurator`][crate::matchers::str_matcher::StrMatcherConfigurator]
 /// extension trait, which is implemented for this matcher.
+///
+/// When the actual value is a container of bytes, such as `Vec<u8>` or
+/// `&[u8]`, a failure shows the actual and expected bytes as side-by-side
+/// hexdumps with their offsets, marking each differing byte with `*`, instead
+/// of a diff of their `Debug` output. The
+/// [`diff_style`][crate::config::Config::diff_style] applies to the hexdump as
+/// well.
 pub fn eq<A: ?Sized, T>(expected: T) -> EqMatcher<A, T> {
     EqMatcher { expected, phantom: Default::default() }
 }
@@ -80,6 +94,14 @@ pub struct EqMatcher<A: ?Sized, T> {
     phantom: PhantomData<A>,
 }
 
+impl<A: ?Sized, T: Clone> Clone for EqMatcher<A, T> {
+    fn clone(&self) -> Self {
+        Self { expected: self.expected.clone(), phantom: PhantomData }
+    }
+}
+
+impl<A: ?Sized, T: Copy> Copy for EqMatcher<A, T> {}
+
 impl<A: Debug + ?Sized, T: PartialEq<A> + Debug> Matcher for EqMatcher<A, T> {
     type ActualT = A;
 
@@ -89,31 +111,72 @@ impl<A: Debug + ?Sized, T: PartialEq<A> + Debug> Matcher for EqMatcher<A, T> {
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
         match matcher_result {
-            MatcherResult::Matches => format!("is equal to {:?}", self.expected),
-            MatcherResult::DoesNotMatch => format!("isn't equal to {:?}", self.expected),
+            MatcherResult::Matches => {
+                format!("is equal to {}", format_value::<T>(format!("{:?}", self.expected)))
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't equal to {}", format_value::<T>(format!("{:?}", self.expected)))
+            }
         }
     }
 
     fn explain_match(&self, actual: &A) -> MatchExplanation {
+        if let Some(explanation) = create_hexdump_diff::<A>(
+            &format!("{:?}", self.expected),
+            &format!("{:?}", actual),
+            &self.describe(self.matches(actual)),
+        ) {
+            return explanation;
+        }
         create_diff(
-            &format!("{:#?}", self.expected),
-            &format!("{:#?}", actual),
+            &layout_debug(format_value::<T>(format!("{:#?}", self.expected))),
+            &layout_debug(format_value::<A>(format!("{:#?}", actual))),
             &self.describe(self.matches(actual)),
         )
     }
 }
 
+// Renders the difference between byte containers as a hexdump, since their
+// Debug output of one number per line is hard to compare. Returns None if
+// the actual value is not a container of bytes or the values do not differ.
+fn create_hexdump_diff<A: ?Sized>(
+    expected_debug: &str,
+    actual_debug: &str,
+    description: &str,
+) -> Option<MatchExplanation> {
+    let diff_style = config().diff_style;
+    if diff_style == DiffStyle::Disabled
+        || !hexdump_diff::is_byte_container(std::any::type_name::<A>())
+    {
+        return None;
+    }
+    let diff = hexdump_diff::hexdump_diff(
+        &hexdump_diff::parse_bytes(actual_debug)?,
+        &hexdump_diff::parse_bytes(expected_debug)?,
+        diff_style,
+    )?;
+    Some(MatchExplanation::create(format!("which {description}\n{diff}")))
+}
+
 pub(super) fn create_diff(
     expected_debug: &str,
     actual_debug: &str,
     description: &str,
 ) -> MatchExplanation {
-    if actual_debug.lines().count() < 2 {
+    let diff_style = config().diff_style;
+    if actual_debug.lines().count() < 2 || diff_style == DiffStyle::Disabled {
         // If the actual debug is only one line, then there is no point in doing a
         // line-by-line diff.
         return MatchExplanation::create(format!("which {description}",));
     }
-    let edit_list = edit_distance::edit_list(actual_debug.lines(), expected_debug.lines());
+    let Some(edit_list) =
+        edit_distance::line_edit_list(actual_debug.lines(), expected_debug.lines())
+    else {
+        return MatchExplanation::create(format!(
+            "which {description}\nDebug diff omitted: the values are too large and too different \
+            to diff."
+        ));
+    };
 
     if edit_list.is_empty() {
         return MatchExplanation::create(format!(
@@ -123,13 +186,36 @@ pub(super) fn create_diff(
 
     MatchExplanation::create(format!(
         "which {description}\nDebug diff:{}",
-        edit_list_summary(&edit_list)
+        edit_list_summary(&edit_list, diff_style)
     ))
 }
 
-fn edit_list_summary(edit_list: &[edit_distance::Edit<&str>]) -> String {
+pub(super) fn edit_list_summary(
+    edit_list: &[edit_distance::Edit<&str>],
+    diff_style: DiffStyle,
+) -> String {
+    match diff_style {
+        DiffStyle::Unified { context } => return edit_distance::unified_diff(edit_list, context),
+        DiffStyle::SideBySide => return edit_distance::side_by_side_diff(edit_list),
+        DiffStyle::Full | DiffStyle::ChangesOnly | DiffStyle::Disabled => {}
+    }
     let mut summary = String::new();
+    // Whether the previous edit was an unchanged line elided by
+    // DiffStyle::ChangesOnly.
+    let mut eliding = false;
     for edit in edit_list {
+        match edit {
+            edit_distance::Edit::Both { distance, .. }
+                if *distance == 0.0 && diff_style == DiffStyle::ChangesOnly =>
+            {
+                if !eliding {
+                    summary.push_str("\n ...");
+                }
+                eliding = true;
+                continue;
+            }
+            _ => eliding = false,
+        }
         summary.push('\n');
         match edit {
             edit_distance::Edit::Both { left, distance, .. } if *distance == 0.0 => {
@@ -137,11 +223,15 @@ fn edit_list_summary(edit_list: &[edit_distance::Edit<&str>]) -> String {
                 summary.push_str(left);
             }
             edit_distance::Edit::Both { left, right, .. } => {
+                let (left_markers, right_markers) =
+                    edit_distance::changed_spans(left, right).unwrap_or((None, None));
                 summary.push('+');
                 summary.push_str(left);
+                edit_distance::push_changed_spans(&mut summary, left_markers);
                 summary.push('\n');
                 summary.push('-');
                 summary.push_str(right);
+                edit_distance::push_changed_spans(&mut summary, right_markers);
             }
             edit_distance::Edit::ExtraLeft { left } => {
                 summary.push('+');
@@ -159,6 +249,7 @@ fn edit_list_summary(edit_list: &[edit_distance::Edit<&str>]) -> String {
 #[cfg(test)]
 mod tests {
     use super::eq;
+    use crate::config::DiffStyle;
     use crate::prelude::*;
     use indoc::indoc;
 
@@ -209,9 +300,13 @@ mod tests {
             Debug diff:
              Strukt {
             +    int: 123,
+                      ^^^
             -    int: 321,
+                      ^^^
             +    string: "something",
+                          ^^^^^^^^^
             -    string: "someone",
+                          ^^^^^^^
              }
             "#})))
         )
@@ -285,4 +380,219 @@ mod tests {
             "#})))
         )
     }
+
+    #[test]
+    fn eq_vec_debug_diff_with_changes_only() -> Result<()> {
+        let _guard = crate::config().diff_style(DiffStyle::ChangesOnly).apply();
+
+        let result = verify_that!(vec![1, 2, 3, 4, 5], eq(vec![1, 2, 3, 4, 6]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {
+            r#"
+            Debug diff:
+             ...
+            +    5,
+            -    6,
+             ...
+            "#})))
+        )
+    }
+
+    #[test]
+    fn eq_vec_debug_diff_unified() -> Result<()> {
+        let _guard = crate::config().diff_style(DiffStyle::Unified { context: 1 }).apply();
+
+        let result = verify_that!(vec![1, 2, 3, 4, 5, 6], eq(vec![1, 2, 3, 4, 7, 6]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {
+            r#"
+            Debug diff:
+            @@ -5,3 +5,3 @@
+                 4,
+            +    5,
+            -    7,
+                 6,
+            "#})))
+        )
+    }
+
+    #[test]
+    fn eq_struct_debug_diff_side_by_side() -> Result<()> {
+        #[derive(Debug, PartialEq)]
+        struct Strukt {
+            int: i32,
+            string: String,
+        }
+        let _guard = crate::config().diff_style(DiffStyle::SideBySide).apply();
+
+        let result = verify_that!(
+            Strukt { int: 123, string: "something".into() },
+            eq(Strukt { int: 321, string: "something".into() })
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {
+            r#"
+            Debug diff:
+            (actual)                   (expected)
+            Strukt {                   Strukt {
+                int: 123,            |     int: 321,
+                string: "something",       string: "something",
+            }                          }
+            "#})))
+        )
+    }
+
+    #[test]
+    fn eq_vec_without_debug_diff_when_disabled() -> Result<()> {
+        let _guard = crate::config().diff_style(DiffStyle::Disabled).apply();
+
+        let result = verify_that!(vec![1, 2], eq(vec![1, 3]));
+
+        verify_that!(result, err(displays_as(not(contains_substring("Debug diff:")))))
+    }
+
+    #[test]
+    fn eq_truncates_actual_value_when_configured() -> Result<()> {
+        let _guard = crate::config().max_container_elements(2).apply();
+
+        let result = verify_that!(vec![1, 2, 3, 4], eq(vec![1]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {
+            "
+            Actual: [
+                1,
+                2,
+                ... and 2 more
+            ], which isn't equal to [1]
+            "})))
+        )
+    }
+
+    #[test]
+    fn eq_truncates_lines_and_bytes_of_actual_value_when_configured() -> Result<()> {
+        let _guard = crate::config().max_actual_lines(3).max_actual_bytes(12).apply();
+
+        let result = verify_that!(vec![1, 2, 3, 4], eq(vec![1]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {
+            "
+            Actual: [
+                1,
+               ... 30 more bytes truncated, which isn't equal to [1]
+            "})))
+        )
+    }
+
+    #[test]
+    fn eq_omits_diff_of_values_too_large_and_too_different_to_diff() -> Result<()> {
+        let result = verify_that!(
+            (0..50_000).collect::<Vec<_>>(),
+            eq((50_000..100_000).collect::<Vec<_>>())
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Debug diff omitted: the values are too large and too different to diff."
+            )))
+        )
+    }
+
+    #[test]
+    fn eq_renders_values_compactly_when_configured() -> Result<()> {
+        let _guard = crate::config().debug_style(crate::config::DebugStyle::Compact).apply();
+
+        let result = verify_that!(vec![1, 2], eq(vec![1, 3]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("Actual: [1, 2], which isn't equal to [1, 3]\n")))
+        )
+    }
+
+    #[test]
+    fn eq_diffs_joined_lines_when_width_is_configured() -> Result<()> {
+        let _guard = crate::config().max_debug_width(12).apply();
+
+        let result = verify_that!(vec![(1, 2), (3, 4)], eq(vec![(1, 2), (3, 5)]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {
+            "
+            Debug diff:
+             [
+                 (1, 2),
+            +    (3, 4),
+                     ^
+            -    (3, 5),
+                     ^
+             ]
+            "})))
+        )
+    }
+
+    #[test]
+    fn eq_matcher_can_be_cloned_and_reused() -> Result<()> {
+        let matcher = eq(String::from("A string"));
+        verify_that!(String::from("A string"), matcher.clone())?;
+        verify_that!(vec![String::from("A string")], each(matcher))
+    }
+
+    #[test]
+    fn eq_byte_vec_hexdump_diff() -> Result<()> {
+        let result = verify_that!(b"GET /index.html".to_vec(), eq(b"GET /indeX.htm".to_vec()));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc! {
+            "
+            Hexdump diff (actual | expected), first difference at byte offset 9:
+            The actual value has 15 bytes, the expected value 14.
+             00000000: 47 45 54 20 2f 69 6e 64  | 47 45 54 20 2f 69 6e 64
+            !00000008: 65 78*2e 68 74 6d 6c*    | 65 58*2e 68 74 6d
+            "})))
+        )
+    }
+
+    #[test]
+    fn eq_byte_slice_hexdump_diff() -> Result<()> {
+        let actual: &[u8] = &[0, 1, 2];
+
+        verify_that!(
+            verify_that!(actual, eq(&[0, 1, 3][..])),
+            err(displays_as(contains_substring("!00000000: 00 01 02*                | 00 01 03*")))
+        )
+    }
+
+    #[test]
+    fn eq_byte_vec_without_hexdump_diff_when_disabled() -> Result<()> {
+        let _guard = crate::config().diff_style(DiffStyle::Disabled).apply();
+
+        let result = verify_that!(vec![1u8, 2], eq(vec![1u8, 3]));
+
+        verify_that!(result, err(displays_as(not(contains_substring("Hexdump diff")))))
+    }
+
+    #[test]
+    fn eq_non_byte_vec_has_debug_diff_rather_than_hexdump_diff() -> Result<()> {
+        let result = verify_that!(vec![1u16, 2], eq(vec![1u16, 3]));
+
+        verify_that!(
+            result,
+            err(displays_as(
+                contains_substring("Debug diff:").and(not(contains_substring("Hexdump diff")))
+            ))
+        )
+    }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Matches a `Result` containing `Err` with a value matched by `inner`.
@@ -58,10 +58,10 @@ impl<T: Debug, E: Debug, InnerMatcherT: Matcher<ActualT = E>> Matcher
 
     fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
         match actual {
-            Err(e) => MatchExplanation::create(format!(
-                "which is an error {}",
-                self.inner.explain_match(e)
-            )),
+            Err(e) => {
+                let inner = explain(&self.inner, e);
+                MatchExplanation::create(format!("which is an error {inner}")).with_child(inner)
+            }
             Ok(_) => MatchExplanation::create("which is a success".to_string()),
         }
     }
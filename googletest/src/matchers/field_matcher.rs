@@ -39,6 +39,19 @@
 /// # should_pass().unwrap();
 /// ```
 ///
+/// The inner matcher must match the type of the field. Otherwise, compilation
+/// fails with an error at the inner matcher:
+///
+/// ```compile_fail
+/// # use googletest::prelude::*;
+/// #[derive(Debug)]
+/// struct IntField {
+///   int: i32
+/// }
+/// verify_that!(IntField{int: 32}, field!(IntField.int, starts_with("3")))
+/// #    .unwrap();
+/// ```
+///
 /// Tuple structs are also supported via the index syntax:
 ///
 /// ```
@@ -89,22 +102,32 @@
 /// # should_pass().unwrap();
 /// ```
 ///
-/// Nested structures are *not supported*, however:
+/// Nested structures can be matched by giving a dotted path of fields.
+/// References and smart pointers such as [`Box`] along the path are
+/// dereferenced automatically, just as with ordinary field access:
 ///
-/// ```compile_fail
+/// ```
 /// # use googletest::prelude::*;
 /// #[derive(Debug)]
 /// struct InnerStruct(i32);
 /// #[derive(Debug)]
+/// struct MiddleStruct {
+///     inner: Box<InnerStruct>,
+/// }
+/// #[derive(Debug)]
 /// struct OuterStruct {
-///     inner: InnerStruct,
+///     middle: MiddleStruct,
 /// }
-/// # fn should_not_compile() -> Result<()> {
-/// verify_that!(value, field!(OuterStruct.inner.0, eq(32)))?; // Does not compile
+/// # fn should_pass() -> Result<()> {
+/// let value = OuterStruct { middle: MiddleStruct { inner: Box::new(InnerStruct(32)) } };
+/// verify_that!(value, field!(OuterStruct.middle.inner.0, eq(32)))?;
 /// #     Ok(())
 /// # }
+/// # should_pass().unwrap();
 /// ```
 ///
+/// Only the first element of the path may name an enum variant.
+///
 /// See also the macro [`property`][crate::property] for an analogous mechanism
 /// to extract a datum by invoking a method.
 #[macro_export]
@@ -133,6 +156,22 @@ macro_rules! field_internal {
             &stringify!($field),
             $m)
     }};
+
+    ($($t:ident)::+.$field:tt $(.$subfield:tt)+, $m:expr) => {{
+        use $crate::matchers::field_matcher::internal::field_matcher;
+        field_matcher(
+            |o| {
+                match o {
+                    // Field access auto-dereferences, so this also traverses references and smart
+                    // pointers such as Box along the path.
+                    $($t)::* { $field: value, .. } => Some(&value$(.$subfield)+),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            },
+            &concat!(stringify!($field) $(, ".", stringify!($subfield))+),
+            $m)
+    }};
 }
 
 /// Functions for use only by the declarative macros in this module.
@@ -140,29 +179,32 @@ macro_rules! field_internal {
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
 pub mod internal {
-    use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
-    use std::fmt::Debug;
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherOf, MatcherResult, TestDebug};
 
     /// Creates a matcher to verify a specific field of the actual struct using
     /// the provided inner matcher.
     ///
     /// **For internal use only. API stablility is not guaranteed!**
     #[doc(hidden)]
-    pub fn field_matcher<OuterT: Debug, InnerT: Debug, InnerMatcher: Matcher<ActualT = InnerT>>(
+    pub fn field_matcher<OuterT: TestDebug, InnerT: TestDebug, InnerMatcher: MatcherOf<InnerT>>(
         field_accessor: fn(&OuterT) -> Option<&InnerT>,
         field_path: &'static str,
         inner: InnerMatcher,
-    ) -> impl Matcher<ActualT = OuterT> {
+    ) -> FieldMatcher<OuterT, InnerT, InnerMatcher> {
         FieldMatcher { field_accessor, field_path, inner }
     }
 
-    struct FieldMatcher<OuterT, InnerT, InnerMatcher> {
+    /// The matcher created by [`field!`][crate::field].
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct FieldMatcher<OuterT, InnerT, InnerMatcher> {
         field_accessor: fn(&OuterT) -> Option<&InnerT>,
         field_path: &'static str,
         inner: InnerMatcher,
     }
 
-    impl<OuterT: Debug, InnerT: Debug, InnerMatcher: Matcher<ActualT = InnerT>> Matcher
+    impl<OuterT: TestDebug, InnerT: TestDebug, InnerMatcher: Matcher<ActualT = InnerT>> Matcher
         for FieldMatcher<OuterT, InnerT, InnerMatcher>
     {
         type ActualT = OuterT;
@@ -177,11 +219,9 @@ pub mod internal {
 
         fn explain_match(&self, actual: &OuterT) -> MatchExplanation {
             if let Some(actual) = (self.field_accessor)(actual) {
-                MatchExplanation::create(format!(
-                    "which has field `{}`, {}",
-                    self.field_path,
-                    self.inner.explain_match(actual)
-                ))
+                let inner = explain(&self.inner, actual);
+                MatchExplanation::create(format!("which has field `{}`, {inner}", self.field_path))
+                    .with_child(inner)
             } else {
                 // TODO(hovinen): This message could be misinterpreted to mean that there were a
                 // typo in the field, when it actually means that the actual value uses the
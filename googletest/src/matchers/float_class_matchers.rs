@@ -0,0 +1,232 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Matchers for the classification of floating point values.
+//!
+//! These match [`f32`] and [`f64`] values as well as references to them, so
+//! that they apply directly to the items of an iterator over floats.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::{fmt::Debug, marker::PhantomData, num::FpCategory};
+
+/// Matches a floating point value which is neither infinite nor NaN.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(1.5, is_finite())?; // Passes
+/// verify_that!(vec![0.0f32, -2.0], each(is_finite()))?; // Passes
+/// verify_that!(&f64::MIN_POSITIVE, is_finite())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(f64::NAN, is_finite())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On mismatch, the explanation states the classification of the actual
+/// value, such as "which is NaN".
+pub fn is_finite<T: FloatValue>() -> impl Matcher<ActualT = T> + Copy {
+    FloatClassMatcher::new(FloatClass::Finite)
+}
+
+/// Matches a floating point value which is positive or negative infinity.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(f64::INFINITY, is_infinite())?; // Passes
+/// verify_that!(f32::NEG_INFINITY, is_infinite())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(f64::MAX, is_infinite())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_infinite<T: FloatValue>() -> impl Matcher<ActualT = T> + Copy {
+    FloatClassMatcher::new(FloatClass::Infinite)
+}
+
+/// A floating point value, or a reference to one, which the matchers in this
+/// module can classify.
+///
+/// This is implemented for [`f32`], [`f64`], and references to them.
+pub trait FloatValue: Debug {
+    /// Returns the category of this value.
+    fn category(&self) -> FpCategory;
+}
+
+macro_rules! impl_float_value {
+    ($($t:ty),*) => {
+        $(
+            impl FloatValue for $t {
+                fn category(&self) -> FpCategory {
+                    self.classify()
+                }
+            }
+
+            impl FloatValue for &$t {
+                fn category(&self) -> FpCategory {
+                    self.classify()
+                }
+            }
+        )*
+    };
+}
+
+impl_float_value!(f32, f64);
+
+#[derive(Clone, Copy)]
+pub(crate) enum FloatClass {
+    Finite,
+    Infinite,
+    Nan,
+}
+
+impl FloatClass {
+    fn contains(self, category: FpCategory) -> bool {
+        match self {
+            FloatClass::Finite => !matches!(category, FpCategory::Infinite | FpCategory::Nan),
+            FloatClass::Infinite => category == FpCategory::Infinite,
+            FloatClass::Nan => category == FpCategory::Nan,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            FloatClass::Finite => "finite",
+            FloatClass::Infinite => "infinite",
+            FloatClass::Nan => "NaN",
+        }
+    }
+}
+
+pub(crate) struct FloatClassMatcher<T> {
+    class: FloatClass,
+    phantom: PhantomData<T>,
+}
+
+impl<T> FloatClassMatcher<T> {
+    pub(crate) fn new(class: FloatClass) -> Self {
+        Self { class, phantom: PhantomData }
+    }
+}
+
+impl<T> Clone for FloatClassMatcher<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FloatClassMatcher<T> {}
+
+impl<T: FloatValue> Matcher for FloatClassMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        self.class.contains(actual.category()).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("is {}", self.class.name()),
+            MatcherResult::DoesNotMatch => format!("isn't {}", self.class.name()),
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let classification = match actual.category() {
+            FpCategory::Nan => "NaN",
+            FpCategory::Infinite => "infinite",
+            FpCategory::Zero => "zero",
+            FpCategory::Subnormal => "a subnormal number",
+            FpCategory::Normal => "a normal number",
+        };
+        MatchExplanation::create(format!("which is {}", classification))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_finite, is_infinite};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+
+    #[test]
+    fn is_finite_matches_finite_values() -> Result<()> {
+        verify_that!(0.0f32, is_finite())?;
+        verify_that!(-1.5f64, is_finite())?;
+        verify_that!(f64::MIN_POSITIVE / 2.0, is_finite())
+    }
+
+    #[test]
+    fn is_finite_does_not_match_infinity_or_nan() -> Result<()> {
+        verify_that!(f32::INFINITY, not(is_finite()))?;
+        verify_that!(f64::NEG_INFINITY, not(is_finite()))?;
+        verify_that!(f64::NAN, not(is_finite()))
+    }
+
+    #[test]
+    fn is_infinite_matches_infinities() -> Result<()> {
+        verify_that!(f32::INFINITY, is_infinite())?;
+        verify_that!(f64::NEG_INFINITY, is_infinite())
+    }
+
+    #[test]
+    fn is_infinite_does_not_match_finite_values_or_nan() -> Result<()> {
+        verify_that!(f64::MAX, not(is_infinite()))?;
+        verify_that!(f32::NAN, not(is_infinite()))
+    }
+
+    #[test]
+    fn matches_references_to_floats() -> Result<()> {
+        let values = [1.0f64, 2.0];
+        verify_that!(values.iter().max_by(|a, b| a.total_cmp(b)).unwrap(), is_finite())?;
+        verify_that!(&f32::INFINITY, is_infinite())
+    }
+
+    #[test]
+    fn describes_classification() -> Result<()> {
+        let matcher = is_infinite::<f64>();
+        verify_that!(matcher.describe(MatcherResult::Matches), eq("is infinite"))?;
+        verify_that!(matcher.describe(MatcherResult::DoesNotMatch), eq("isn't infinite"))
+    }
+
+    #[test]
+    fn explains_classification_of_actual_value() -> Result<()> {
+        verify_that!(is_finite().explain_match(&f64::NAN), displays_as(eq("which is NaN")))?;
+        verify_that!(is_infinite().explain_match(&0.0f64), displays_as(eq("which is zero")))?;
+        verify_that!(
+            is_infinite().explain_match(&f32::MIN_POSITIVE),
+            displays_as(eq("which is a normal number"))
+        )?;
+        verify_that!(
+            is_finite().explain_match(&f64::NEG_INFINITY),
+            displays_as(eq("which is infinite"))
+        )
+    }
+
+    #[test]
+    fn mismatch_message_contains_classification() -> Result<()> {
+        let result = verify_that!(f64::NAN, is_finite());
+
+        verify_that!(result, err(displays_as(contains_substring("Actual: NaN, which is NaN"))))
+    }
+}
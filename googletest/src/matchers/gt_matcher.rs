@@ -36,8 +36,7 @@ use std::{fmt::Debug, marker::PhantomData};
 /// ```
 ///
 /// In most cases the params neeed to be the same type or they need to be cast
-/// explicitly. This can be surprising when comparing integer types or
-/// references:
+/// explicitly. This can be surprising when comparing integer types:
 ///
 /// ```compile_fail
 /// # use googletest::prelude::*;
@@ -48,22 +47,17 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # }
 /// ```
 ///
-/// ```compile_fail
-/// # use googletest::prelude::*;
-/// # fn should_not_compile() -> Result<()> {
-/// let actual: &u32 = &2;
-/// let expected: u32 = 1;
-/// verify_that!(actual, gt(expected))?; // Does not compile
-/// #     Ok(())
-/// # }
-/// ```
+/// A reference to the actual value, on the other hand, can be compared with
+/// a value or with a reference, since the assertion macros dereference the
+/// actual value when the matcher expects the value behind the reference:
 ///
 /// ```
 /// # use googletest::prelude::*;
 /// # fn should_pass() -> Result<()> {
 /// let actual: &u32 = &2;
 /// let expected: u32 = 1;
-/// verify_that!(actual, gt(&expected))?; // Compiles and passes
+/// verify_that!(actual, gt(expected))?; // Passes
+/// verify_that!(actual, gt(&expected))?; // Passes
 /// #     Ok(())
 /// # }
 /// # should_pass().unwrap();
@@ -73,15 +67,23 @@ use std::{fmt::Debug, marker::PhantomData};
 /// <https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#implementors>
 pub fn gt<ActualT: Debug + PartialOrd<ExpectedT>, ExpectedT: Debug>(
     expected: ExpectedT,
-) -> impl Matcher<ActualT = ActualT> {
+) -> GtMatcher<ActualT, ExpectedT> {
     GtMatcher::<ActualT, _> { expected, phantom: Default::default() }
 }
 
-struct GtMatcher<ActualT, ExpectedT> {
+pub struct GtMatcher<ActualT, ExpectedT> {
     expected: ExpectedT,
     phantom: PhantomData<ActualT>,
 }
 
+impl<ActualT, ExpectedT: Clone> Clone for GtMatcher<ActualT, ExpectedT> {
+    fn clone(&self) -> Self {
+        Self { expected: self.expected.clone(), phantom: PhantomData }
+    }
+}
+
+impl<ActualT, ExpectedT: Copy> Copy for GtMatcher<ActualT, ExpectedT> {}
+
 impl<ActualT: Debug + PartialOrd<ExpectedT>, ExpectedT: Debug> Matcher
     for GtMatcher<ActualT, ExpectedT>
 {
@@ -255,4 +257,12 @@ Actual: [
 
         verify_that!(actual, gt(expected))
     }
+
+    #[test]
+    fn gt_matcher_can_be_copied_and_reused() -> Result<()> {
+        let matcher = gt(10);
+        let copy = matcher;
+        verify_that!(11, matcher)?;
+        verify_that!(vec![12, 13], each(copy))
+    }
 }
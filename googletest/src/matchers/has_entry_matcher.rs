@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::debug_layout::debug_string;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -61,6 +62,10 @@ use std::marker::PhantomData;
 /// However, `has_entry` will offer somewhat better diagnostic messages in the
 /// case of assertion failure. And it avoid the extra allocation hidden in the
 /// code above.
+///
+/// To match the key with a matcher, or to match a
+/// [`BTreeMap`][std::collections::BTreeMap], use
+/// [`map_matchers::has_entry`][crate::matchers::map_matchers::has_entry].
 pub fn has_entry<KeyT: Debug + Eq + Hash, ValueT: Debug, MatcherT: Matcher<ActualT = ValueT>>(
     key: KeyT,
     inner: MatcherT,
@@ -89,12 +94,13 @@ impl<KeyT: Debug + Eq + Hash, ValueT: Debug, MatcherT: Matcher<ActualT = ValueT>
 
     fn explain_match(&self, actual: &HashMap<KeyT, ValueT>) -> MatchExplanation {
         if let Some(value) = actual.get(&self.key) {
+            let inner = explain(&self.inner, value);
             MatchExplanation::create(format!(
-                "which contains key {:?}, but is mapped to value {:#?}, {}",
+                "which contains key {:?}, but is mapped to value {}, {inner}",
                 self.key,
-                value,
-                self.inner.explain_match(value)
+                debug_string(value)
             ))
+            .with_child(inner)
         } else {
             MatchExplanation::create(format!("which doesn't contain key {:?}", self.key))
         }
@@ -0,0 +1,209 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+/// Matches a value contained in `range`.
+///
+/// The range may be of any type implementing [`RangeBounds`], such as `a..b`,
+/// `a..=b`, `a..`, or `..b`, over any [`PartialOrd`] type.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(42, in_range(0..100))?; // Passes
+/// verify_that!(100, in_range(0..=100))?; // Passes
+/// verify_that!(-5.0, in_range(..0.0))?; // Passes
+/// verify_that!("banana", in_range("apple".."cherry"))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(100, in_range(0..100))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On mismatch, the explanation names the bound which was violated. The
+/// failure of the last assertion above reads:
+///
+/// ```text
+/// Value of: 100
+/// Expected: is in range 0..100
+/// Actual: 100, which is not below the exclusive upper bound 100
+/// ```
+pub fn in_range<T: PartialOrd + Debug, RangeT: RangeBounds<T> + Debug>(
+    range: RangeT,
+) -> InRangeMatcher<T, RangeT> {
+    InRangeMatcher { range, phantom: Default::default() }
+}
+
+/// A matcher which matches a value contained in a range.
+///
+/// See [`in_range`].
+pub struct InRangeMatcher<T, RangeT> {
+    range: RangeT,
+    phantom: PhantomData<T>,
+}
+
+impl<T, RangeT: Clone> Clone for InRangeMatcher<T, RangeT> {
+    fn clone(&self) -> Self {
+        Self { range: self.range.clone(), phantom: PhantomData }
+    }
+}
+
+impl<T: PartialOrd + Debug, RangeT: RangeBounds<T> + Debug> InRangeMatcher<T, RangeT> {
+    fn violated_bound(&self, actual: &T) -> Option<String> {
+        let violation = match self.range.start_bound() {
+            Bound::Included(start) => match actual.partial_cmp(start) {
+                Some(Ordering::Less) => Some(("below the inclusive lower bound", start)),
+                None => Some(("incomparable with the lower bound", start)),
+                _ => None,
+            },
+            Bound::Excluded(start) => match actual.partial_cmp(start) {
+                Some(Ordering::Less | Ordering::Equal) => {
+                    Some(("not above the exclusive lower bound", start))
+                }
+                None => Some(("incomparable with the lower bound", start)),
+                _ => None,
+            },
+            Bound::Unbounded => None,
+        };
+        let violation = violation.or_else(|| match self.range.end_bound() {
+            Bound::Included(end) => match actual.partial_cmp(end) {
+                Some(Ordering::Greater) => Some(("above the inclusive upper bound", end)),
+                None => Some(("incomparable with the upper bound", end)),
+                _ => None,
+            },
+            Bound::Excluded(end) => match actual.partial_cmp(end) {
+                Some(Ordering::Greater | Ordering::Equal) => {
+                    Some(("not below the exclusive upper bound", end))
+                }
+                None => Some(("incomparable with the upper bound", end)),
+                _ => None,
+            },
+            Bound::Unbounded => None,
+        });
+        violation.map(|(description, bound)| format!("{} {:?}", description, bound))
+    }
+}
+
+impl<T: PartialOrd + Debug, RangeT: RangeBounds<T> + Debug> Matcher for InRangeMatcher<T, RangeT> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        self.range.contains(actual).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("is in range {:?}", self.range),
+            MatcherResult::DoesNotMatch => format!("isn't in range {:?}", self.range),
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        match self.violated_bound(actual) {
+            Some(bound) => MatchExplanation::create(format!("which is {}", bound)),
+            None => MatchExplanation::create(format!("which is in range {:?}", self.range)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::in_range;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use std::ops::Bound;
+
+    #[test]
+    fn in_range_matches_value_in_half_open_range() -> Result<()> {
+        verify_that!(0, in_range(0..10))?;
+        verify_that!(9, in_range(0..10))?;
+        verify_that!(10, not(in_range(0..10)))?;
+        verify_that!(-1, not(in_range(0..10)))
+    }
+
+    #[test]
+    fn in_range_matches_value_in_closed_range() -> Result<()> {
+        verify_that!(10, in_range(0..=10))?;
+        verify_that!(11, not(in_range(0..=10)))
+    }
+
+    #[test]
+    fn in_range_matches_value_in_unbounded_ranges() -> Result<()> {
+        verify_that!(i64::MAX, in_range(0..))?;
+        verify_that!(i64::MIN, in_range(..0))?;
+        verify_that!(0, in_range(..=0))?;
+        verify_that!(0, in_range::<i32, _>(..))
+    }
+
+    #[test]
+    fn in_range_matches_strings() -> Result<()> {
+        verify_that!("banana", in_range("apple".."cherry"))
+    }
+
+    #[test]
+    fn in_range_does_not_match_nan() -> Result<()> {
+        verify_that!(f64::NAN, not(in_range(0.0..1.0)))?;
+        verify_that!(
+            in_range(..1.0).explain_match(&f64::NAN),
+            displays_as(eq("which is incomparable with the upper bound 1.0"))
+        )
+    }
+
+    #[test]
+    fn in_range_describes_range() -> Result<()> {
+        let matcher = in_range(1..=5);
+        verify_that!(matcher.describe(MatcherResult::Matches), eq("is in range 1..=5"))?;
+        verify_that!(matcher.describe(MatcherResult::DoesNotMatch), eq("isn't in range 1..=5"))
+    }
+
+    #[test]
+    fn in_range_explains_violated_lower_bound() -> Result<()> {
+        verify_that!(
+            in_range(1..5).explain_match(&0),
+            displays_as(eq("which is below the inclusive lower bound 1"))
+        )?;
+        verify_that!(
+            in_range((Bound::Excluded(1), Bound::Unbounded)).explain_match(&1),
+            displays_as(eq("which is not above the exclusive lower bound 1"))
+        )
+    }
+
+    #[test]
+    fn in_range_explains_violated_upper_bound() -> Result<()> {
+        verify_that!(
+            in_range(1..5).explain_match(&5),
+            displays_as(eq("which is not below the exclusive upper bound 5"))
+        )?;
+        verify_that!(
+            in_range(1..=5).explain_match(&6),
+            displays_as(eq("which is above the inclusive upper bound 5"))
+        )
+    }
+
+    #[test]
+    fn in_range_explains_match() -> Result<()> {
+        verify_that!(in_range(1..5).explain_match(&3), displays_as(eq("which is in range 1..5")))
+    }
+}
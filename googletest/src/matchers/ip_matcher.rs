@@ -0,0 +1,489 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+/// Matches an IP address or socket address whose IP address is a loopback
+/// address, i.e., in `127.0.0.0/8` or equal to `::1`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(IpAddr::V4(Ipv4Addr::LOCALHOST), is_loopback())?;  // Passes
+/// verify_that!("[::1]:8080".parse::<SocketAddr>().unwrap(), is_loopback())?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(Ipv4Addr::new(10, 0, 0, 1), is_loopback())?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_loopback<ActualT: HasIpAddr + ?Sized>() -> impl Matcher<ActualT = ActualT> {
+    IsLoopbackMatcher { phantom: Default::default() }
+}
+
+/// Matches an IP address or socket address whose IP address lies in the
+/// subnet `subnet`, given in CIDR notation, e.g., `"10.0.0.0/8"` or
+/// `"fd00::/8"`.
+///
+/// An IPv4 address never lies in an IPv6 subnet and vice versa.
+///
+/// If `subnet` is not valid CIDR notation, the matcher matches no value and
+/// the failure message reports why `subnet` is invalid.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::net::{Ipv4Addr, SocketAddr};
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Ipv4Addr::new(10, 1, 2, 3), in_subnet("10.0.0.0/8"))?;  // Passes
+/// verify_that!("192.168.1.20:443".parse::<SocketAddr>().unwrap(), in_subnet("192.168.1.0/24"))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(Ipv4Addr::new(11, 0, 0, 1), in_subnet("10.0.0.0/8"))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn in_subnet<ActualT: HasIpAddr + ?Sized>(subnet: &str) -> impl Matcher<ActualT = ActualT> {
+    InSubnetMatcher {
+        subnet: subnet.to_string(),
+        parsed_subnet: Subnet::parse(subnet),
+        phantom: Default::default(),
+    }
+}
+
+/// Matches a socket address whose port is matched by `inner`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::net::SocketAddr;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("10.0.0.1:443".parse::<SocketAddr>().unwrap(), has_port(eq(443)))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("10.0.0.1:80".parse::<SocketAddr>().unwrap(), has_port(eq(443)))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_port<ActualT: HasPort + ?Sized, InnerMatcherT: Matcher<ActualT = u16>>(
+    inner: InnerMatcherT,
+) -> impl Matcher<ActualT = ActualT> {
+    HasPortMatcher { inner, phantom: Default::default() }
+}
+
+/// A value which has an IP address, for use with [`is_loopback`] and
+/// [`in_subnet`].
+///
+/// This is implemented for the IP address and socket address types of
+/// [`std::net`].
+pub trait HasIpAddr: Debug {
+    /// Returns the IP address of this value.
+    fn ip_addr(&self) -> IpAddr;
+}
+
+impl HasIpAddr for IpAddr {
+    fn ip_addr(&self) -> IpAddr {
+        *self
+    }
+}
+
+impl HasIpAddr for Ipv4Addr {
+    fn ip_addr(&self) -> IpAddr {
+        IpAddr::V4(*self)
+    }
+}
+
+impl HasIpAddr for Ipv6Addr {
+    fn ip_addr(&self) -> IpAddr {
+        IpAddr::V6(*self)
+    }
+}
+
+impl HasIpAddr for SocketAddr {
+    fn ip_addr(&self) -> IpAddr {
+        self.ip()
+    }
+}
+
+impl HasIpAddr for SocketAddrV4 {
+    fn ip_addr(&self) -> IpAddr {
+        IpAddr::V4(*self.ip())
+    }
+}
+
+impl HasIpAddr for SocketAddrV6 {
+    fn ip_addr(&self) -> IpAddr {
+        IpAddr::V6(*self.ip())
+    }
+}
+
+/// A value which has a port, for use with [`has_port`].
+///
+/// This is implemented for the socket address types of [`std::net`].
+pub trait HasPort: Debug {
+    /// Returns the port of this value.
+    fn port_number(&self) -> u16;
+}
+
+impl HasPort for SocketAddr {
+    fn port_number(&self) -> u16 {
+        self.port()
+    }
+}
+
+impl HasPort for SocketAddrV4 {
+    fn port_number(&self) -> u16 {
+        self.port()
+    }
+}
+
+impl HasPort for SocketAddrV6 {
+    fn port_number(&self) -> u16 {
+        self.port()
+    }
+}
+
+struct IsLoopbackMatcher<ActualT: ?Sized> {
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: HasIpAddr + ?Sized> Matcher for IsLoopbackMatcher<ActualT> {
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        actual.ip_addr().is_loopback().into()
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let ip_addr = actual.ip_addr();
+        if ip_addr.is_loopback() {
+            MatchExplanation::create(format!("which has loopback address {ip_addr}"))
+        } else {
+            MatchExplanation::create(format!("which has non-loopback address {ip_addr}"))
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => "has a loopback IP address".to_string(),
+            MatcherResult::DoesNotMatch => "doesn't have a loopback IP address".to_string(),
+        }
+    }
+}
+
+/// A subnet parsed from CIDR notation, with the host bits of the network
+/// address cleared.
+#[derive(Debug, Clone, Copy)]
+enum Subnet {
+    V4 { network: u32, prefix_len: u32 },
+    V6 { network: u128, prefix_len: u32 },
+}
+
+impl Subnet {
+    fn parse(subnet: &str) -> Result<Self, String> {
+        let (address, prefix_len) =
+            subnet.split_once('/').ok_or_else(|| "missing prefix length after '/'".to_string())?;
+        let address: IpAddr =
+            address.parse().map_err(|_| format!("{address:?} is not an IP address"))?;
+        let prefix_len: u32 =
+            prefix_len.parse().map_err(|_| format!("{prefix_len:?} is not a prefix length"))?;
+        let max_prefix_len = match address {
+            IpAddr::V4(_) => u32::BITS,
+            IpAddr::V6(_) => u128::BITS,
+        };
+        if prefix_len > max_prefix_len {
+            return Err(format!(
+                "prefix length {prefix_len} exceeds the {max_prefix_len} bits of the address"
+            ));
+        }
+        Ok(match address {
+            IpAddr::V4(address) => {
+                Subnet::V4 { network: u32::from(address) & v4_mask(prefix_len), prefix_len }
+            }
+            IpAddr::V6(address) => {
+                Subnet::V6 { network: u128::from(address) & v6_mask(prefix_len), prefix_len }
+            }
+        })
+    }
+
+    fn contains(&self, ip_addr: IpAddr) -> bool {
+        match (self, ip_addr) {
+            (Subnet::V4 { network, prefix_len }, IpAddr::V4(address)) => {
+                u32::from(address) & v4_mask(*prefix_len) == *network
+            }
+            (Subnet::V6 { network, prefix_len }, IpAddr::V6(address)) => {
+                u128::from(address) & v6_mask(*prefix_len) == *network
+            }
+            _ => false,
+        }
+    }
+
+    fn is_ipv4(&self) -> bool {
+        matches!(self, Subnet::V4 { .. })
+    }
+}
+
+impl Display for Subnet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Subnet::V4 { network, prefix_len } => {
+                write!(f, "{}/{prefix_len}", Ipv4Addr::from(*network))
+            }
+            Subnet::V6 { network, prefix_len } => {
+                write!(f, "{}/{prefix_len}", Ipv6Addr::from(*network))
+            }
+        }
+    }
+}
+
+fn v4_mask(prefix_len: u32) -> u32 {
+    u32::MAX.checked_shl(u32::BITS - prefix_len).unwrap_or(0)
+}
+
+fn v6_mask(prefix_len: u32) -> u128 {
+    u128::MAX.checked_shl(u128::BITS - prefix_len).unwrap_or(0)
+}
+
+struct InSubnetMatcher<ActualT: ?Sized> {
+    subnet: String,
+    parsed_subnet: Result<Subnet, String>,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: HasIpAddr + ?Sized> Matcher for InSubnetMatcher<ActualT> {
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        match &self.parsed_subnet {
+            Ok(subnet) => subnet.contains(actual.ip_addr()).into(),
+            Err(_) => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let subnet = match &self.parsed_subnet {
+            Ok(subnet) => subnet,
+            Err(error) => {
+                return MatchExplanation::create(format!(
+                    "which cannot be checked since the subnet {:?} is invalid: {error}",
+                    self.subnet
+                ));
+            }
+        };
+        let ip_addr = actual.ip_addr();
+        if subnet.contains(ip_addr) {
+            MatchExplanation::create(format!("which has address {ip_addr} in subnet {subnet}"))
+        } else if subnet.is_ipv4() != ip_addr.is_ipv4() {
+            MatchExplanation::create(format!(
+                "which has address {ip_addr}, which is not of the same IP version as subnet \
+                {subnet}"
+            ))
+        } else {
+            MatchExplanation::create(format!("which has address {ip_addr} outside subnet {subnet}"))
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("has an IP address in subnet {}", self.subnet),
+            MatcherResult::DoesNotMatch => {
+                format!("doesn't have an IP address in subnet {}", self.subnet)
+            }
+        }
+    }
+}
+
+struct HasPortMatcher<ActualT: ?Sized, InnerMatcherT> {
+    inner: InnerMatcherT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: HasPort + ?Sized, InnerMatcherT: Matcher<ActualT = u16>> Matcher
+    for HasPortMatcher<ActualT, InnerMatcherT>
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        self.inner.matches(&actual.port_number())
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let port = actual.port_number();
+        let inner = explain(&self.inner, &port);
+        MatchExplanation::create(format!("which has port {port}, {inner}")).with_child(inner)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!("has a port which {}", self.inner.describe(matcher_result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_port, in_subnet, is_loopback};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4};
+
+    #[test]
+    fn is_loopback_matches_ipv4_loopback() -> Result<()> {
+        verify_that!(Ipv4Addr::new(127, 0, 0, 53), is_loopback())
+    }
+
+    #[test]
+    fn is_loopback_matches_ipv6_loopback() -> Result<()> {
+        verify_that!(IpAddr::V6(Ipv6Addr::LOCALHOST), is_loopback())
+    }
+
+    #[test]
+    fn is_loopback_matches_socket_address() -> Result<()> {
+        verify_that!(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080), is_loopback())
+    }
+
+    #[test]
+    fn is_loopback_does_not_match_other_address() -> Result<()> {
+        verify_that!(Ipv4Addr::new(10, 0, 0, 1), not(is_loopback()))
+    }
+
+    #[test]
+    fn is_loopback_explains_mismatch() -> Result<()> {
+        let result = verify_that!(Ipv4Addr::new(10, 0, 0, 1), is_loopback());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: has a loopback IP address
+                Actual: 10.0.0.1, which has non-loopback address 10.0.0.1
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn in_subnet_matches_ipv4_address_in_subnet() -> Result<()> {
+        verify_that!(Ipv4Addr::new(10, 200, 3, 4), in_subnet("10.0.0.0/8"))
+    }
+
+    #[test]
+    fn in_subnet_matches_ipv6_address_in_subnet() -> Result<()> {
+        verify_that!("fd12::1".parse::<Ipv6Addr>().unwrap(), in_subnet("fd00::/8"))
+    }
+
+    #[test]
+    fn in_subnet_matches_everything_with_zero_prefix_length() -> Result<()> {
+        verify_that!(Ipv4Addr::new(203, 0, 113, 7), in_subnet("0.0.0.0/0"))
+    }
+
+    #[test]
+    fn in_subnet_matches_single_address_with_full_prefix_length() -> Result<()> {
+        verify_that!(Ipv4Addr::new(203, 0, 113, 7), in_subnet("203.0.113.7/32"))?;
+        verify_that!(Ipv4Addr::new(203, 0, 113, 8), not(in_subnet("203.0.113.7/32")))
+    }
+
+    #[test]
+    fn in_subnet_ignores_host_bits_of_subnet() -> Result<()> {
+        verify_that!(Ipv4Addr::new(10, 9, 9, 9), in_subnet("10.1.2.3/8"))
+    }
+
+    #[test]
+    fn in_subnet_does_not_match_address_of_other_ip_version() -> Result<()> {
+        verify_that!(IpAddr::V6(Ipv6Addr::UNSPECIFIED), not(in_subnet("0.0.0.0/0")))
+    }
+
+    #[test]
+    fn in_subnet_explains_mismatch() -> Result<()> {
+        let result =
+            verify_that!("11.0.0.1:80".parse::<SocketAddr>().unwrap(), in_subnet("10.0.0.0/8"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: has an IP address in subnet 10.0.0.0/8
+                Actual: 11.0.0.1:80, which has address 11.0.0.1 outside subnet 10.0.0.0/8
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn in_subnet_explains_ip_version_mismatch() -> Result<()> {
+        verify_that!(
+            in_subnet("10.0.0.0/8").explain_match(&IpAddr::V6(Ipv6Addr::LOCALHOST)),
+            displays_as(eq(
+                "which has address ::1, which is not of the same IP version as subnet 10.0.0.0/8"
+            ))
+        )
+    }
+
+    #[test]
+    fn in_subnet_reports_missing_prefix_length() -> Result<()> {
+        verify_that!(
+            in_subnet("10.0.0.0").explain_match(&Ipv4Addr::LOCALHOST),
+            displays_as(eq(
+                "which cannot be checked since the subnet \"10.0.0.0\" is invalid: missing \
+                prefix length after '/'"
+            ))
+        )
+    }
+
+    #[test]
+    fn in_subnet_reports_too_long_prefix_length() -> Result<()> {
+        verify_that!(
+            in_subnet("10.0.0.0/33").explain_match(&Ipv4Addr::LOCALHOST),
+            displays_as(ends_with("prefix length 33 exceeds the 32 bits of the address"))
+        )
+    }
+
+    #[test]
+    fn in_subnet_does_not_match_with_invalid_subnet() -> Result<()> {
+        verify_that!(
+            in_subnet("localhost/8").matches(&Ipv4Addr::LOCALHOST),
+            eq(MatcherResult::DoesNotMatch)
+        )
+    }
+
+    #[test]
+    fn has_port_matches_port() -> Result<()> {
+        verify_that!("[::1]:443".parse::<SocketAddr>().unwrap(), has_port(eq(443)))
+    }
+
+    #[test]
+    fn has_port_explains_mismatch() -> Result<()> {
+        let result = verify_that!(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80), has_port(eq(443)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: has a port which is equal to 443
+                Actual: 127.0.0.1:80, which has port 80, which isn't equal to 443
+                "
+            ))))
+        )
+    }
+}
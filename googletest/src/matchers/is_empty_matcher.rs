@@ -0,0 +1,233 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::count_elements::count_elements;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// The number of elements of a non-empty container listed when explaining a
+/// mismatch.
+const LISTED_ELEMENTS: usize = 3;
+
+/// Matches an empty container or string.
+///
+/// A container is anything over whose reference one can iterate, such as the
+/// standard Rust containers, arrays, and (when dereferenced) slices. A string
+/// is anything which implements `AsRef<str>`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::HashMap;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Vec::<i32>::new(), is_empty())?;
+/// verify_that!(HashMap::<i32, i32>::new(), is_empty())?;
+/// verify_that!("", is_empty())?;
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3, 4], is_empty())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// When a container is unexpectedly non-empty, the explanation lists its first
+/// few elements.
+pub fn is_empty<T: Debug + Emptiness<KindT> + ?Sized, KindT>() -> impl Matcher<ActualT = T> + Copy {
+    IsEmptyMatcher { expect_empty: true, phantom: Default::default() }
+}
+
+/// Matches a non-empty container or string.
+///
+/// This accepts the same values as [`is_empty`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1], is_not_empty())?;
+/// verify_that!(String::from("a"), is_not_empty())?;
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("", is_not_empty())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_not_empty<T: Debug + Emptiness<KindT> + ?Sized, KindT>()
+-> impl Matcher<ActualT = T> + Copy {
+    IsEmptyMatcher { expect_empty: false, phantom: Default::default() }
+}
+
+/// A value which can be empty, as the [`is_empty`] and [`is_not_empty`]
+/// matchers accept it.
+///
+/// The parameter `KindT` distinguishes containers from strings, so that a
+/// type implements this trait in at most one way. It is inferred and never
+/// needs to be named.
+pub trait Emptiness<KindT> {
+    /// Whether `self` is empty.
+    fn is_empty(&self) -> bool;
+
+    /// Describes the contents of `self`, which is not empty.
+    fn describe_contents(&self) -> String;
+}
+
+/// The [`Emptiness`] of a container over whose reference one can iterate.
+pub enum ContainerEmptiness {}
+
+/// The [`Emptiness`] of a string.
+pub enum StrEmptiness {}
+
+impl<T: ?Sized> Emptiness<ContainerEmptiness> for T
+where
+    for<'a> &'a T: IntoIterator,
+    for<'a> <&'a T as IntoIterator>::Item: Debug,
+{
+    fn is_empty(&self) -> bool {
+        self.into_iter().next().is_none()
+    }
+
+    fn describe_contents(&self) -> String {
+        let count = count_elements(self);
+        let listed = self
+            .into_iter()
+            .take(LISTED_ELEMENTS)
+            .map(|element| format!("{element:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match count {
+            1 => format!("which contains 1 element: {listed}"),
+            _ if count <= LISTED_ELEMENTS => format!("which contains {count} elements: {listed}"),
+            _ => format!("which contains {count} elements, starting with {listed}"),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Emptiness<StrEmptiness> for T {
+    fn is_empty(&self) -> bool {
+        self.as_ref().is_empty()
+    }
+
+    fn describe_contents(&self) -> String {
+        format!("which has length {}", self.as_ref().len())
+    }
+}
+
+struct IsEmptyMatcher<T: ?Sized, KindT> {
+    expect_empty: bool,
+    phantom: PhantomData<(KindT, T)>,
+}
+
+impl<T: ?Sized, KindT> Clone for IsEmptyMatcher<T, KindT> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, KindT> Copy for IsEmptyMatcher<T, KindT> {}
+
+impl<T: Debug + Emptiness<KindT> + ?Sized, KindT> Matcher for IsEmptyMatcher<T, KindT> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        (actual.is_empty() == self.expect_empty).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        if matcher_result.into_bool() == self.expect_empty { "is empty" } else { "isn't empty" }
+            .to_string()
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        if actual.is_empty() {
+            MatchExplanation::create("which is empty".to_string())
+        } else {
+            MatchExplanation::create(actual.describe_contents())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_empty, is_not_empty};
+    use crate::matcher::Matcher;
+    use crate::prelude::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn is_empty_matches_empty_containers() -> Result<()> {
+        expect_that!(Vec::<i32>::new(), is_empty());
+        expect_that!(HashMap::<i32, i32>::new(), is_empty());
+        verify_that!(*Vec::<i32>::new().as_slice(), is_empty())
+    }
+
+    #[test]
+    fn is_empty_matches_empty_strings() -> Result<()> {
+        expect_that!("", is_empty());
+        verify_that!(String::new(), is_empty())
+    }
+
+    #[test]
+    fn is_empty_does_not_match_non_empty_values() -> Result<()> {
+        expect_that!(vec![1], not(is_empty()));
+        verify_that!("a", not(is_empty()))
+    }
+
+    #[test]
+    fn is_not_empty_matches_non_empty_values() -> Result<()> {
+        expect_that!(vec![1], is_not_empty());
+        expect_that!("a", is_not_empty());
+        verify_that!(Vec::<i32>::new(), not(is_not_empty()))
+    }
+
+    #[test]
+    fn is_empty_explains_few_elements() -> Result<()> {
+        verify_that!(
+            is_empty().explain_match(&BTreeMap::from([(1, "a")])),
+            displays_as(eq("which contains 1 element: (1, \"a\")"))
+        )
+    }
+
+    #[test]
+    fn is_empty_explains_first_elements_of_long_container() -> Result<()> {
+        let result = verify_that!(vec![1, 2, 3, 4, 5], is_empty());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "which contains 5 elements, starting with 1, 2, 3"
+            )))
+        )
+    }
+
+    #[test]
+    fn is_empty_explains_string_length() -> Result<()> {
+        verify_that!(is_empty().explain_match("abc"), displays_as(eq("which has length 3")))
+    }
+
+    #[test]
+    fn is_not_empty_explains_empty_value() -> Result<()> {
+        let result = verify_that!("", is_not_empty());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Expected: isn't empty\nActual: \"\", which is empty"
+            )))
+        )
+    }
+}
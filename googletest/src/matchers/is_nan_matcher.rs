@@ -12,27 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{Matcher, MatcherResult};
-use num_traits::float::Float;
-use std::{fmt::Debug, marker::PhantomData};
+use crate::matcher::Matcher;
+use crate::matchers::float_class_matchers::{FloatClass, FloatClassMatcher, FloatValue};
 
 /// Matches a floating point value which is NaN.
-pub fn is_nan<T: Float + Debug>() -> impl Matcher<ActualT = T> {
-    IsNanMatcher::<T>(Default::default())
-}
-
-struct IsNanMatcher<T>(PhantomData<T>);
-
-impl<T: Float + Debug> Matcher for IsNanMatcher<T> {
-    type ActualT = T;
-
-    fn matches(&self, actual: &T) -> MatcherResult {
-        actual.is_nan().into()
-    }
-
-    fn describe(&self, matcher_result: MatcherResult) -> String {
-        if matcher_result.into() { "is NaN" } else { "isn't NaN" }.to_string()
-    }
+///
+/// This accepts the same values as
+/// [`is_finite`][crate::matchers::is_finite], including references to floats.
+pub fn is_nan<T: FloatValue>() -> impl Matcher<ActualT = T> + Copy {
+    FloatClassMatcher::new(FloatClass::Nan)
 }
 
 #[cfg(test)]
@@ -59,4 +47,9 @@ mod tests {
     fn does_not_match_f64_number() -> Result<()> {
         verify_that!(0.0f64, not(is_nan()))
     }
+
+    #[test]
+    fn matches_reference_to_nan() -> Result<()> {
+        verify_that!(&f64::NAN, is_nan())
+    }
 }
@@ -0,0 +1,93 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{Matcher, MatcherResult};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::task::Poll;
+
+/// Matches a `Poll` which is `Pending`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::task::Poll;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Poll::<()>::Pending, is_pending())?;   // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(Poll::Ready("Some value"), is_pending())?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_pending<T: Debug>() -> impl Matcher<ActualT = Poll<T>> {
+    IsPendingMatcher::<T> { phantom: Default::default() }
+}
+
+struct IsPendingMatcher<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: Debug> Matcher for IsPendingMatcher<T> {
+    type ActualT = Poll<T>;
+
+    fn matches(&self, actual: &Poll<T>) -> MatcherResult {
+        actual.is_pending().into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => "is pending".to_string(),
+            MatcherResult::DoesNotMatch => "is ready".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_pending;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use std::task::Poll;
+
+    #[test]
+    fn is_pending_matches_pending() -> Result<()> {
+        let matcher = is_pending::<i32>();
+
+        let result = matcher.matches(&Poll::Pending);
+
+        verify_that!(result, eq(MatcherResult::Matches))
+    }
+
+    #[test]
+    fn is_pending_does_not_match_ready() -> Result<()> {
+        let matcher = is_pending();
+
+        let result = matcher.matches(&Poll::Ready(0));
+
+        verify_that!(result, eq(MatcherResult::DoesNotMatch))
+    }
+
+    #[test]
+    fn is_pending_full_error_message() -> Result<()> {
+        let result = verify_that!(Poll::Ready(1), is_pending());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("Expected: is pending\nActual: Ready(\n    1,\n)")))
+        )
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::task::Poll;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a `Poll` which is `Ready` with a value matched by `inner`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::task::Poll;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Poll::Ready(42), is_ready(eq(42)))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail_1() -> Result<()> {
+/// verify_that!(Poll::<i32>::Pending, is_ready(eq(42)))?;   // Fails
+/// #     Ok(())
+/// # }
+/// # fn should_fail_2() -> Result<()> {
+/// verify_that!(Poll::Ready(41), is_ready(eq(42)))?;   // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail_1().unwrap_err();
+/// # should_fail_2().unwrap_err();
+/// ```
+pub fn is_ready<T: Debug>(inner: impl Matcher<ActualT = T>) -> impl Matcher<ActualT = Poll<T>> {
+    IsReadyMatcher { inner, phantom: Default::default() }
+}
+
+struct IsReadyMatcher<T, InnerMatcherT> {
+    inner: InnerMatcherT,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Debug, InnerMatcherT: Matcher<ActualT = T>> Matcher for IsReadyMatcher<T, InnerMatcherT> {
+    type ActualT = Poll<T>;
+
+    fn matches(&self, actual: &Poll<T>) -> MatcherResult {
+        match actual {
+            Poll::Ready(v) => self.inner.matches(v),
+            Poll::Pending => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &Poll<T>) -> MatchExplanation {
+        match actual {
+            Poll::Ready(v) => {
+                let inner = explain(&self.inner, v);
+                MatchExplanation::create(format!("which is ready with a value {inner}"))
+                    .with_child(inner)
+            }
+            Poll::Pending => MatchExplanation::create("which is pending".to_string()),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!(
+                    "is ready with a value which {}",
+                    self.inner.describe(MatcherResult::Matches)
+                )
+            }
+            MatcherResult::DoesNotMatch => {
+                format!(
+                    "is pending or ready with a value which {}",
+                    self.inner.describe(MatcherResult::DoesNotMatch)
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_ready;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::task::Poll;
+
+    #[test]
+    fn is_ready_matches_ready_with_matching_value() -> Result<()> {
+        verify_that!(Poll::Ready(1), is_ready(eq(1)))
+    }
+
+    #[test]
+    fn is_ready_does_not_match_ready_with_wrong_value() -> Result<()> {
+        verify_that!(Poll::Ready(0), not(is_ready(eq(1))))
+    }
+
+    #[test]
+    fn is_ready_does_not_match_pending() -> Result<()> {
+        verify_that!(Poll::Pending, not(is_ready(eq(1))))
+    }
+
+    #[test]
+    fn is_ready_full_error_message() -> Result<()> {
+        let result = verify_that!(Poll::Ready(2), is_ready(eq(1)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Value of: Poll::Ready(2)
+                Expected: is ready with a value which is equal to 1
+                Actual: Ready(
+                    2,
+                ), which is ready with a value which isn't equal to 1
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn is_ready_describe_does_not_match() -> Result<()> {
+        verify_that!(
+            is_ready(eq(1)).describe(MatcherResult::DoesNotMatch),
+            eq("is pending or ready with a value which isn't equal to 1")
+        )
+    }
+
+    #[test]
+    fn is_ready_explain_match_with_pending() -> Result<()> {
+        verify_that!(
+            is_ready(eq(1)).explain_match(&Poll::Pending),
+            displays_as(eq("which is pending"))
+        )
+    }
+}
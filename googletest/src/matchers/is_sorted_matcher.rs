@@ -0,0 +1,275 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// Matches a container whose elements are sorted in ascending order.
+///
+/// Adjacent elements may be equal. Call
+/// [`descending`][IsSortedMatcher::descending] on the result to require a
+/// descending order instead.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 2, 5], is_sorted())?; // Passes
+/// verify_that!(vec![5, 2, 1], is_sorted().descending())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 3, 2], is_sorted())?; // Fails: 3 and 2 are out of order
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// `ContainerT` can be any container such that `&ContainerT` implements
+/// `IntoIterator`. On a mismatch, the explanation points at the first pair of
+/// adjacent elements which are out of order.
+// N.B. This returns the concrete type rather than an impl Matcher so that the
+// order can be reversed with IsSortedMatcher::descending.
+pub fn is_sorted<ElementT: PartialOrd + Debug, ContainerT: Debug + ?Sized>()
+-> IsSortedMatcher<ContainerT, NaturalOrder>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    IsSortedMatcher { order: NaturalOrder, descending: false, phantom: Default::default() }
+}
+
+/// Matches a container whose elements are sorted in ascending order of the
+/// keys which `key` extracts from them.
+///
+/// As for [`is_sorted`], call [`descending`][IsSortedMatcher::descending] on
+/// the result to require a descending order instead.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec!["a", "bb", "ccc"], is_sorted_by_key(|s: &&str| s.len()))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![-3, 1, 2], is_sorted_by_key(|n: &i32| n.abs()))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_sorted_by_key<ElementT, ContainerT, KeyT, KeyFnT>(
+    key: KeyFnT,
+) -> IsSortedMatcher<ContainerT, KeyOrder<KeyFnT>>
+where
+    ElementT: Debug,
+    ContainerT: Debug + ?Sized,
+    KeyT: PartialOrd + Debug,
+    KeyFnT: Fn(&ElementT) -> KeyT,
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    IsSortedMatcher { order: KeyOrder(key), descending: false, phantom: Default::default() }
+}
+
+/// A matcher which matches a sorted container.
+///
+/// Intended only to be used from the functions [`is_sorted`] and
+/// [`is_sorted_by_key`] only. Should not be referenced by code outside this
+/// library.
+pub struct IsSortedMatcher<ContainerT: ?Sized, OrderT> {
+    order: OrderT,
+    descending: bool,
+    phantom: PhantomData<ContainerT>,
+}
+
+impl<ContainerT: ?Sized, OrderT> IsSortedMatcher<ContainerT, OrderT> {
+    /// Requires the elements to be sorted in descending rather than ascending
+    /// order.
+    pub fn descending(self) -> Self {
+        Self { descending: true, ..self }
+    }
+}
+
+/// An order in which [`IsSortedMatcher`] expects elements of type `ElementT`
+/// to be sorted.
+pub trait ElementOrder<ElementT> {
+    /// Whether `first` may precede `second` in ascending order.
+    fn in_order(&self, first: &ElementT, second: &ElementT, descending: bool) -> bool;
+
+    /// Describes the comparison of `first` and `second` beyond their values.
+    fn describe_comparison(&self, first: &ElementT, second: &ElementT) -> String;
+
+    /// What the elements are sorted by, e.g., ` by key`.
+    fn sorted_by(&self) -> &'static str;
+}
+
+/// The order of elements given by their [`PartialOrd`] implementation.
+pub struct NaturalOrder;
+
+impl<ElementT: PartialOrd> ElementOrder<ElementT> for NaturalOrder {
+    fn in_order(&self, first: &ElementT, second: &ElementT, descending: bool) -> bool {
+        if descending { first >= second } else { first <= second }
+    }
+
+    fn describe_comparison(&self, _: &ElementT, _: &ElementT) -> String {
+        String::new()
+    }
+
+    fn sorted_by(&self) -> &'static str {
+        ""
+    }
+}
+
+/// The order of elements given by the keys which a function extracts from
+/// them.
+pub struct KeyOrder<KeyFnT>(KeyFnT);
+
+impl<ElementT, KeyT: PartialOrd + Debug, KeyFnT: Fn(&ElementT) -> KeyT> ElementOrder<ElementT>
+    for KeyOrder<KeyFnT>
+{
+    fn in_order(&self, first: &ElementT, second: &ElementT, descending: bool) -> bool {
+        let (first, second) = ((self.0)(first), (self.0)(second));
+        if descending { first >= second } else { first <= second }
+    }
+
+    fn describe_comparison(&self, first: &ElementT, second: &ElementT) -> String {
+        format!(" with keys {:?} and {:?}", (self.0)(first), (self.0)(second))
+    }
+
+    fn sorted_by(&self) -> &'static str {
+        " by key"
+    }
+}
+
+impl<ContainerT: ?Sized, OrderT> IsSortedMatcher<ContainerT, OrderT> {
+    /// Returns the index of the first element which is out of order with
+    /// respect to its successor, if any.
+    fn first_out_of_order<ElementT>(&self, actual: &ContainerT) -> Option<usize>
+    where
+        OrderT: ElementOrder<ElementT>,
+        for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+    {
+        let mut previous = None;
+        for (index, element) in actual.into_iter().enumerate() {
+            if let Some(previous) = previous {
+                if !self.order.in_order(previous, element, self.descending) {
+                    return Some(index - 1);
+                }
+            }
+            previous = Some(element);
+        }
+        None
+    }
+
+    fn direction(&self) -> &'static str {
+        if self.descending { "descending" } else { "ascending" }
+    }
+}
+
+impl<ElementT: Debug, ContainerT: Debug + ?Sized, OrderT: ElementOrder<ElementT>> Matcher
+    for IsSortedMatcher<ContainerT, OrderT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    type ActualT = ContainerT;
+
+    fn matches(&self, actual: &ContainerT) -> MatcherResult {
+        self.first_out_of_order(actual).is_none().into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!(
+            "{} sorted{} in {} order",
+            if matcher_result.into() { "is" } else { "isn't" },
+            self.order.sorted_by(),
+            self.direction()
+        )
+    }
+
+    fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+        let Some(index) = self.first_out_of_order(actual) else {
+            return MatchExplanation::create(format!(
+                "which is sorted{} in {} order",
+                self.order.sorted_by(),
+                self.direction()
+            ));
+        };
+        let mut elements = actual.into_iter().skip(index);
+        let (Some(first), Some(second)) = (elements.next(), elements.next()) else {
+            unreachable!("first_out_of_order returned the index of an element without successor");
+        };
+        MatchExplanation::create(format!(
+            "whose elements #{index} ({first:?}) and #{} ({second:?}) are out of order{}",
+            index + 1,
+            self.order.describe_comparison(first, second)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_sorted, is_sorted_by_key};
+    use crate::prelude::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn is_sorted_matches_ascending_sequence() -> Result<()> {
+        expect_that!(vec![1, 2, 2, 3], is_sorted());
+        expect_that!(Vec::<i32>::new(), is_sorted());
+        verify_that!(VecDeque::from([1.0, 1.5]), is_sorted())
+    }
+
+    #[test]
+    fn is_sorted_does_not_match_unsorted_sequence() -> Result<()> {
+        verify_that!(vec![1, 3, 2], not(is_sorted()))
+    }
+
+    #[test]
+    fn is_sorted_descending_matches_descending_sequence() -> Result<()> {
+        expect_that!(vec![3, 3, 1], is_sorted().descending());
+        verify_that!(vec![1, 3], not(is_sorted().descending()))
+    }
+
+    #[test]
+    fn is_sorted_by_key_uses_key() -> Result<()> {
+        expect_that!(vec![-1, 2, -3], is_sorted_by_key(|n: &i32| n.abs()));
+        verify_that!(vec![-1, 2, -3], is_sorted_by_key(|n: &i32| -n.abs()).descending())
+    }
+
+    #[test]
+    fn is_sorted_explains_first_out_of_order_pair() -> Result<()> {
+        let result = verify_that!(vec![1, 4, 3, 2], is_sorted());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Expected: is sorted in ascending order\nActual: [\n    1,\n    4,\n    3,\n    2,\n], \
+                 whose elements #1 (4) and #2 (3) are out of order"
+            )))
+        )
+    }
+
+    #[test]
+    fn is_sorted_by_key_explains_keys() -> Result<()> {
+        let result = verify_that!(vec!["bb", "a"], is_sorted_by_key(|s: &&str| s.len()));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Expected: is sorted by key in ascending order\nActual: [\n    \"bb\",\n    \"a\",\n], \
+                 whose elements #0 (\"bb\") and #1 (\"a\") are out of order with keys 2 and 1"
+            )))
+        )
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a sequence of bytes which is valid UTF-8 and whose decoded text
+/// matches `inner`.
+///
+/// The actual value may be anything which can be viewed as a byte slice, such
+/// as `Vec<u8>`, `&[u8]` or a byte array. The matcher `inner` receives the
+/// decoded text as a `String`. Pass [`anything`][crate::matchers::anything]
+/// to check only that the bytes are valid UTF-8.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(b"an error occurred".to_vec(), is_utf8(contains_substring("error")))?; // Passes
+/// verify_that!(&b"caf\xc3\xa9"[..], is_utf8(anything()))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail_1() -> Result<()> {
+/// verify_that!(b"all good".to_vec(), is_utf8(contains_substring("error")))?; // Fails
+/// #     Ok(())
+/// # }
+/// # fn should_fail_2() -> Result<()> {
+/// // Fails: the byte at offset 3 is invalid.
+/// verify_that!(b"caf\xe9".to_vec(), is_utf8(anything()))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail_1().unwrap_err();
+/// # should_fail_2().unwrap_err();
+/// ```
+///
+/// If the bytes are not valid UTF-8, the failure message states the byte
+/// offset at which the first invalid sequence starts.
+pub fn is_utf8<T: AsRef<[u8]> + Debug + ?Sized, InnerMatcherT: Matcher<ActualT = String>>(
+    inner: InnerMatcherT,
+) -> impl Matcher<ActualT = T> {
+    IsUtf8Matcher { inner, phantom: Default::default() }
+}
+
+struct IsUtf8Matcher<T: ?Sized, InnerMatcherT> {
+    inner: InnerMatcherT,
+    phantom: PhantomData<T>,
+}
+
+impl<T: AsRef<[u8]> + Debug + ?Sized, InnerMatcherT: Matcher<ActualT = String>> Matcher
+    for IsUtf8Matcher<T, InnerMatcherT>
+{
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        match std::str::from_utf8(actual.as_ref()) {
+            Ok(text) => self.inner.matches(&text.to_string()),
+            Err(_) => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is valid UTF-8 which {}", self.inner.describe(MatcherResult::Matches))
+            }
+            MatcherResult::DoesNotMatch => format!(
+                "isn't valid UTF-8 or is UTF-8 which {}",
+                self.inner.describe(MatcherResult::DoesNotMatch)
+            ),
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let bytes = actual.as_ref();
+        match std::str::from_utf8(bytes) {
+            Ok(text) => {
+                let inner = explain(&self.inner, &text.to_string());
+                MatchExplanation::create(format!("which decodes to {text:?}, {inner}"))
+                    .with_child(inner)
+            }
+            Err(error) => {
+                let offset = error.valid_up_to();
+                MatchExplanation::create(match error.error_len() {
+                    Some(_) => format!(
+                        "which isn't valid UTF-8: invalid byte 0x{:02x} at offset {offset}",
+                        bytes[offset]
+                    ),
+                    None => format!(
+                        "which isn't valid UTF-8: incomplete sequence at the end, from offset \
+                        {offset}"
+                    ),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_utf8;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn is_utf8_matches_valid_vec_with_matching_text() -> Result<()> {
+        verify_that!(b"an error occurred".to_vec(), is_utf8(contains_substring("error")))
+    }
+
+    #[test]
+    fn is_utf8_matches_byte_slice_and_array() -> Result<()> {
+        verify_that!(&b"caf\xc3\xa9"[..], is_utf8(eq("café")))?;
+        verify_that!(*b"ok", is_utf8(eq("ok")))
+    }
+
+    #[test]
+    fn is_utf8_does_not_match_invalid_bytes() -> Result<()> {
+        verify_that!(vec![0x66, 0xff], not(is_utf8(anything())))
+    }
+
+    #[test]
+    fn is_utf8_does_not_match_when_inner_matcher_does_not_match() -> Result<()> {
+        verify_that!(b"all good".to_vec(), not(is_utf8(contains_substring("error"))))
+    }
+
+    #[test]
+    fn is_utf8_describes_itself() -> Result<()> {
+        let matcher = is_utf8::<Vec<u8>, _>(starts_with("a"));
+
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq("is valid UTF-8 which starts with prefix \"a\"")
+        )?;
+        verify_that!(
+            matcher.describe(MatcherResult::DoesNotMatch),
+            eq("isn't valid UTF-8 or is UTF-8 which does not start with \"a\"")
+        )
+    }
+
+    #[test]
+    fn is_utf8_explains_offset_of_invalid_byte() -> Result<()> {
+        let result = verify_that!(b"caf\xe9!".to_vec(), is_utf8(anything()));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                  99,
+                  97,
+                  102,
+                  233,
+                  33,
+              ], which isn't valid UTF-8: invalid byte 0xe9 at offset 3
+              "
+            ))))
+        )
+    }
+
+    #[test]
+    fn is_utf8_explains_incomplete_sequence_at_end() -> Result<()> {
+        let matcher = is_utf8::<[u8], _>(anything());
+
+        verify_that!(
+            matcher.explain_match(b"ab\xe2\x82").to_string(),
+            eq("which isn't valid UTF-8: incomplete sequence at the end, from offset 2")
+        )
+    }
+
+    #[test]
+    fn is_utf8_explains_inner_mismatch_with_decoded_text() -> Result<()> {
+        let matcher = is_utf8::<[u8], _>(eq("b"));
+
+        verify_that!(
+            matcher.explain_match(b"a").to_string(),
+            eq("which decodes to \"a\", which isn't equal to \"b\"")
+        )
+    }
+}
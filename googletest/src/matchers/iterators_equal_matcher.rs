@@ -0,0 +1,357 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use crate::matchers::eq;
+use std::collections::VecDeque;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// The number of items shown before and after the first difference when
+/// explaining a mismatch.
+const CONTEXT_SIZE: usize = 3;
+
+/// Matches an iterator which yields the same items, in the same order, as the
+/// iterator `expected`.
+///
+/// Unlike [`container_eq`][crate::matchers::container_eq] and
+/// [`elements_are!`][crate::elements_are], this walks both iterators in
+/// lock-step without collecting either of them. It stops at the first item
+/// which differs, so it is suitable for very long generated sequences. On a
+/// mismatch, the explanation shows the position of the first difference
+/// together with a few items on either side of it.
+///
+/// Both the actual iterator and `expected` must implement `Clone`, since the
+/// matcher walks fresh copies of them each time it is invoked. At least one
+/// of them must be finite for a match to terminate.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!((0..1_000_000).map(|i| i * 2), iterators_equal((0..2_000_000).step_by(2)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!((0..1_000_000).map(|i| i * 2), iterators_equal((0..).step_by(2).take(10)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn iterators_equal<ActualT, ExpectedT>(expected: ExpectedT) -> impl Matcher<ActualT = ActualT>
+where
+    ActualT: Iterator + Clone + Debug,
+    ActualT::Item: Debug,
+    ExpectedT: Iterator + Clone + Debug,
+    ExpectedT::Item: PartialEq<ActualT::Item> + Debug,
+{
+    IteratorsPointwiseMatcher {
+        matcher_factory: eq,
+        expected,
+        relation: "equal to",
+        phantom: PhantomData,
+    }
+}
+
+/// Matches an iterator each of whose items is matched by the matcher which
+/// `matcher_factory` creates from the corresponding item of the iterator
+/// `expected`.
+///
+/// This is the lazy counterpart of [`pointwise!`][crate::pointwise], in the
+/// way that [`iterators_equal`] is the lazy counterpart of
+/// [`container_eq`][crate::matchers::container_eq]. In particular, both
+/// iterators must yield the same number of items.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let actual = (1..=1000).map(|i| i as f64 + 0.00001);
+/// verify_that!(actual, iterators_pointwise(|e| near(e, 0.001), (1..=1000).map(f64::from)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(1..=1000, iterators_pointwise(le, 0..1000))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn iterators_pointwise<ActualT, ExpectedT, MatcherFactoryT, MatcherT>(
+    matcher_factory: MatcherFactoryT,
+    expected: ExpectedT,
+) -> impl Matcher<ActualT = ActualT>
+where
+    ActualT: Iterator + Clone + Debug,
+    ActualT::Item: Debug,
+    ExpectedT: Iterator + Clone + Debug,
+    ExpectedT::Item: Debug,
+    MatcherFactoryT: Fn(ExpectedT::Item) -> MatcherT,
+    MatcherT: Matcher<ActualT = ActualT::Item>,
+{
+    IteratorsPointwiseMatcher {
+        matcher_factory,
+        expected,
+        relation: "pointwise matching",
+        phantom: PhantomData,
+    }
+}
+
+struct IteratorsPointwiseMatcher<ActualT, ExpectedT, MatcherFactoryT> {
+    matcher_factory: MatcherFactoryT,
+    expected: ExpectedT,
+    relation: &'static str,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT, ExpectedT, MatcherFactoryT, MatcherT> Matcher
+    for IteratorsPointwiseMatcher<ActualT, ExpectedT, MatcherFactoryT>
+where
+    ActualT: Iterator + Clone + Debug,
+    ActualT::Item: Debug,
+    ExpectedT: Iterator + Clone + Debug,
+    ExpectedT::Item: Debug,
+    MatcherFactoryT: Fn(ExpectedT::Item) -> MatcherT,
+    MatcherT: Matcher<ActualT = ActualT::Item>,
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        let mut actual = actual.clone();
+        let mut expected = self.expected.clone();
+        loop {
+            match (actual.next(), expected.next()) {
+                (Some(actual_item), Some(expected_item)) => {
+                    if !(self.matcher_factory)(expected_item).matches(&actual_item).into_bool() {
+                        return MatcherResult::DoesNotMatch;
+                    }
+                }
+                (None, None) => return MatcherResult::Matches,
+                _ => return MatcherResult::DoesNotMatch,
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let mut actual = actual.clone();
+        let mut expected = self.expected.clone();
+        let mut actual_context = VecDeque::with_capacity(CONTEXT_SIZE);
+        let mut expected_context = VecDeque::with_capacity(CONTEXT_SIZE);
+        let mut idx: usize = 0;
+        let mut inner = None;
+        let difference = loop {
+            match (actual.next(), expected.next()) {
+                (Some(actual_item), Some(expected_item)) => {
+                    let expected_debug = format!("{expected_item:?}");
+                    let matcher = (self.matcher_factory)(expected_item);
+                    if !matcher.matches(&actual_item).into_bool() {
+                        actual_context.push_back(format!("{actual_item:?}"));
+                        expected_context.push_back(expected_debug);
+                        let explanation = explain(&matcher, &actual_item);
+                        let difference =
+                            format!("whose element #{idx} is {actual_item:?}, {explanation}");
+                        inner = Some(explanation);
+                        break difference;
+                    }
+                    push_bounded(&mut actual_context, format!("{actual_item:?}"));
+                    push_bounded(&mut expected_context, expected_debug);
+                }
+                (None, None) => {
+                    return MatchExplanation::create(format!("whose {idx} elements all match"));
+                }
+                (None, Some(expected_item)) => {
+                    expected_context.push_back(format!("{expected_item:?}"));
+                    break format!(
+                        "which ends after {idx} elements, but the expected iterator continues \
+                         with {expected_item:?}"
+                    );
+                }
+                (Some(actual_item), None) => {
+                    actual_context.push_back(format!("{actual_item:?}"));
+                    break format!(
+                        "which continues with {actual_item:?} after the {idx} expected elements"
+                    );
+                }
+            }
+            idx += 1;
+        };
+        let first_context_idx = idx.saturating_sub(CONTEXT_SIZE);
+        actual_context.extend(actual.take(CONTEXT_SIZE).map(|item| format!("{item:?}")));
+        expected_context.extend(expected.take(CONTEXT_SIZE).map(|item| format!("{item:?}")));
+        MatchExplanation::create(format!(
+            "{difference}\n  actual elements from #{first_context_idx}: {}\n  \
+             expected elements from #{first_context_idx}: {}",
+            format_context(actual_context),
+            format_context(expected_context),
+        ))
+        .with_children(inner)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("yields items {} those of {:?}", self.relation, self.expected)
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("doesn't yield items {} those of {:?}", self.relation, self.expected)
+            }
+        }
+    }
+}
+
+fn push_bounded(context: &mut VecDeque<String>, item: String) {
+    if context.len() == CONTEXT_SIZE {
+        context.pop_front();
+    }
+    context.push_back(item);
+}
+
+fn format_context(context: VecDeque<String>) -> String {
+    format!("[{}]", context.into_iter().collect::<Vec<_>>().join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{iterators_equal, iterators_pointwise};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn iterators_equal_matches_equal_iterators() -> Result<()> {
+        verify_that!(0..100, iterators_equal((0..200).step_by(1).take(100)))
+    }
+
+    #[test]
+    fn iterators_equal_matches_empty_iterators() -> Result<()> {
+        verify_that!(0..0, iterators_equal(std::iter::empty::<i32>()))
+    }
+
+    #[test]
+    fn iterators_equal_matches_iterators_over_references() -> Result<()> {
+        let value = [1, 2, 3];
+        verify_that!(value.iter(), iterators_equal([1, 2, 3].iter()))
+    }
+
+    #[test]
+    fn iterators_equal_does_not_match_when_item_differs() -> Result<()> {
+        verify_that!(0..10, not(iterators_equal((0..10).map(|i| if i == 5 { 0 } else { i }))))
+    }
+
+    #[test]
+    fn iterators_equal_does_not_match_when_actual_is_shorter() -> Result<()> {
+        verify_that!(0..10, not(iterators_equal(0..11)))
+    }
+
+    #[test]
+    fn iterators_equal_does_not_match_when_actual_is_longer() -> Result<()> {
+        verify_that!(0..11, not(iterators_equal(0..10)))
+    }
+
+    #[test]
+    fn iterators_equal_terminates_when_one_iterator_is_infinite() -> Result<()> {
+        verify_that!(0..10, not(iterators_equal(0..)))
+    }
+
+    #[test]
+    fn iterators_equal_describes_itself() -> Result<()> {
+        verify_that!(
+            iterators_equal::<std::ops::Range<i32>, _>(0..3).describe(MatcherResult::Matches),
+            eq("yields items equal to those of 0..3")
+        )
+    }
+
+    #[test]
+    fn iterators_equal_shows_context_around_first_difference() -> Result<()> {
+        let result = verify_that!(0..100, iterators_equal((0..100).map(|i| i * i / (i + 1))));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: 0..100, whose element #1 is 1, which isn't equal to 0
+                  actual elements from #0: [0, 1, 2, 3, 4]
+                  expected elements from #0: [0, 0, 1, 2, 3]
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn iterators_equal_shows_bounded_context_deep_into_iterators() -> Result<()> {
+        let result =
+            verify_that!(0..1000, iterators_equal((0..1000).map(|i| if i == 500 { 0 } else { i })));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: 0..1000, whose element #500 is 500, which isn't equal to 0
+                  actual elements from #497: [497, 498, 499, 500, 501, 502, 503]
+                  expected elements from #497: [497, 498, 499, 0, 501, 502, 503]
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn iterators_equal_explains_when_actual_ends_early() -> Result<()> {
+        let result = verify_that!(0..5, iterators_equal(0..10));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: 0..5, which ends after 5 elements, but the expected iterator continues with 5
+                  actual elements from #2: [2, 3, 4]
+                  expected elements from #2: [2, 3, 4, 5, 6, 7, 8]
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn iterators_equal_explains_when_actual_continues() -> Result<()> {
+        let result = verify_that!(0..10, iterators_equal(0..2));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: 0..10, which continues with 2 after the 2 expected elements
+                  actual elements from #0: [0, 1, 2, 3, 4, 5]
+                  expected elements from #0: [0, 1]
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn iterators_pointwise_matches_with_closure() -> Result<()> {
+        verify_that!(
+            (0..10).map(|i| i as f64 + 0.0001),
+            iterators_pointwise(|e| near(e, 0.001), (0..10).map(f64::from))
+        )
+    }
+
+    #[test]
+    fn iterators_pointwise_explains_mismatch_with_inner_matcher() -> Result<()> {
+        let result = verify_that!(2..10, iterators_pointwise(le, 1..9));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Actual: 2..10, whose element #0 is 2, which is greater than 1"
+            )))
+        )
+    }
+}
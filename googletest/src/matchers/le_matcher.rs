@@ -36,8 +36,7 @@ use std::{fmt::Debug, marker::PhantomData};
 /// ```
 ///
 /// In most cases the params neeed to be the same type or they need to be cast
-/// explicitly. This can be surprising when comparing integer types or
-/// references:
+/// explicitly. This can be surprising when comparing integer types:
 ///
 /// ```compile_fail
 /// # use googletest::prelude::*;
@@ -48,22 +47,17 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # }
 /// ```
 ///
-/// ```compile_fail
-/// # use googletest::prelude::*;
-/// # fn should_not_compile() -> Result<()> {
-/// let actual: &u32 = &1;
-/// let expected: u32 = 2;
-/// verify_that!(actual, le(expected))?; // Does not compile
-/// #     Ok(())
-/// # }
-/// ```
+/// A reference to the actual value, on the other hand, can be compared with
+/// a value or with a reference, since the assertion macros dereference the
+/// actual value when the matcher expects the value behind the reference:
 ///
 /// ```
 /// # use googletest::prelude::*;
 /// # fn should_pass() -> Result<()> {
 /// let actual: &u32 = &1;
 /// let expected: u32 = 2;
-/// verify_that!(actual, le(&expected))?; // Compiles and passes
+/// verify_that!(actual, le(expected))?; // Passes
+/// verify_that!(actual, le(&expected))?; // Passes
 /// #     Ok(())
 /// # }
 /// # should_pass().unwrap();
@@ -73,7 +67,7 @@ use std::{fmt::Debug, marker::PhantomData};
 /// <https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#implementors>
 pub fn le<ActualT: Debug + PartialOrd<ExpectedT>, ExpectedT: Debug>(
     expected: ExpectedT,
-) -> impl Matcher<ActualT = ActualT> {
+) -> LeMatcher<ActualT, ExpectedT> {
     LeMatcher::<ActualT, _> { expected, phantom: Default::default() }
 }
 
@@ -82,6 +76,14 @@ pub struct LeMatcher<ActualT, ExpectedT> {
     phantom: PhantomData<ActualT>,
 }
 
+impl<ActualT, ExpectedT: Clone> Clone for LeMatcher<ActualT, ExpectedT> {
+    fn clone(&self) -> Self {
+        Self { expected: self.expected.clone(), phantom: PhantomData }
+    }
+}
+
+impl<ActualT, ExpectedT: Copy> Copy for LeMatcher<ActualT, ExpectedT> {}
+
 impl<ActualT: Debug + PartialOrd<ExpectedT>, ExpectedT: Debug> Matcher
     for LeMatcher<ActualT, ExpectedT>
 {
@@ -0,0 +1,154 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::count_elements::count_elements;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a container or an [`ExactSizeIterator`] whose length matches
+/// `expected`.
+///
+/// A container is anything over whose reference one can iterate, as for
+/// [`size`][crate::matchers::size]. This includes the standard Rust
+/// containers, arrays, and (when dereferenced) slices.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let vec = vec![1, 2, 3];
+/// verify_that!(vec, len(ge(3)))?;
+/// verify_that!(vec.iter().skip(1), len(eq(2)))?;
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2], len(ge(3)))?; // Fails: the length is 2
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn len<T: Debug + Length<KindT> + ?Sized, KindT, E: Matcher<ActualT = usize>>(
+    expected: E,
+) -> impl Matcher<ActualT = T> {
+    LenMatcher { expected, phantom: Default::default() }
+}
+
+/// A value with a length, as the [`len`] matcher accepts it.
+///
+/// The parameter `KindT` distinguishes the ways of determining the length, so
+/// that a type implements this trait in at most one of them. It is inferred
+/// and never needs to be named.
+pub trait Length<KindT> {
+    /// The length of `self`.
+    fn length(&self) -> usize;
+}
+
+/// The [`Length`] of a container over whose reference one can iterate.
+pub enum ContainerLength {}
+
+/// The [`Length`] of an [`ExactSizeIterator`].
+pub enum IteratorLength {}
+
+impl<T: ?Sized> Length<ContainerLength> for T
+where
+    for<'a> &'a T: IntoIterator,
+{
+    fn length(&self) -> usize {
+        count_elements(self)
+    }
+}
+
+impl<T: ExactSizeIterator> Length<IteratorLength> for T {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+struct LenMatcher<T: ?Sized, KindT, E> {
+    expected: E,
+    phantom: PhantomData<(KindT, T)>,
+}
+
+impl<T: Debug + Length<KindT> + ?Sized, KindT, E: Matcher<ActualT = usize>> Matcher
+    for LenMatcher<T, KindT, E>
+{
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        self.expected.matches(&actual.length())
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!("has length, which {}", self.expected.describe(matcher_result))
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let length = actual.length();
+        let inner = explain(&self.expected, &length);
+        MatchExplanation::create(format!("which has length {length}, {inner}")).with_child(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::len;
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{HashMap, VecDeque};
+
+    #[test]
+    fn len_matches_vec() -> Result<()> {
+        verify_that!(vec![1, 2, 3], len(ge(3)))
+    }
+
+    #[test]
+    fn len_matches_dereferenced_slice() -> Result<()> {
+        let value = vec![1, 2, 3];
+        verify_that!(*value.as_slice(), len(eq(3)))
+    }
+
+    #[test]
+    fn len_matches_maps_and_deques() -> Result<()> {
+        expect_that!(HashMap::from([(1, 2), (3, 4)]), len(eq(2)));
+        verify_that!(VecDeque::from([1]), len(eq(1)))
+    }
+
+    #[test]
+    fn len_matches_exact_size_iterator() -> Result<()> {
+        verify_that!([1, 2, 3, 4].iter().step_by(2), len(eq(2)))
+    }
+
+    #[test]
+    fn len_does_not_match_wrong_length() -> Result<()> {
+        verify_that!(vec![1, 2], not(len(ge(3))))
+    }
+
+    #[test]
+    fn len_explains_actual_length_and_inner_matcher() -> Result<()> {
+        let result = verify_that!(vec![1, 2], len(ge(3)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: has length, which is greater than or equal to 3
+                Actual: [
+                    1,
+                    2,
+                ], which has length 2, which is less than 3
+                "
+            ))))
+        )
+    }
+}
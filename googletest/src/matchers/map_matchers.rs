@@ -0,0 +1,326 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Matchers for the entries of maps.
+//!
+//! These match any map over whose reference one can iterate to obtain
+//! `(&key, &value)` pairs, such as [`HashMap`][std::collections::HashMap]
+//! and [`BTreeMap`][std::collections::BTreeMap], and take matchers for the
+//! keys and values.
+//!
+//! [`has_key`] and [`has_value`] are also available from the prelude. Since
+//! the prelude's [`has_entry`][crate::matchers::has_entry] takes a key rather
+//! than a key matcher, [`has_entry`] must be imported from this module.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::description::Description;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// Matches a map containing an entry whose key `key` matches and whose value
+/// `value` matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use googletest::matchers::map_matchers::has_entry;
+/// # use std::collections::BTreeMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = BTreeMap::from([("alice", 31), ("bob", 25)]);
+/// verify_that!(value, has_entry(starts_with("a"), gt(30)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = BTreeMap::from([("alice", 31), ("bob", 25)]);
+/// verify_that!(value, has_entry(eq("bob"), gt(30)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On a mismatch, the explanation lists the near misses, i.e., the entries
+/// whose key matches but whose value does not, together with the reason.
+pub fn has_entry<MapT: Debug + ?Sized, KeyT: Debug, ValueT: Debug>(
+    key: impl Matcher<ActualT = KeyT>,
+    value: impl Matcher<ActualT = ValueT>,
+) -> impl Matcher<ActualT = MapT>
+where
+    for<'a> &'a MapT: IntoIterator<Item = (&'a KeyT, &'a ValueT)>,
+{
+    HasEntryMatcher { key, value, phantom: Default::default() }
+}
+
+/// Matches a map containing a key which `key` matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::HashMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = HashMap::from([("alice", 31), ("bob", 25)]);
+/// verify_that!(value, has_key(eq("bob")))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = HashMap::from([("alice", 31), ("bob", 25)]);
+/// verify_that!(value, has_key(eq("carol")))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_key<MapT: Debug + ?Sized, KeyT: Debug, ValueT>(
+    key: impl Matcher<ActualT = KeyT>,
+) -> impl Matcher<ActualT = MapT>
+where
+    for<'a> &'a MapT: IntoIterator<Item = (&'a KeyT, &'a ValueT)>,
+{
+    HasKeyMatcher { key, phantom: Default::default() }
+}
+
+/// Matches a map containing a value which `value` matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::HashMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = HashMap::from([("alice", 31), ("bob", 25)]);
+/// verify_that!(value, has_value(lt(30)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = HashMap::from([("alice", 31), ("bob", 25)]);
+/// verify_that!(value, has_value(gt(40)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_value<MapT: Debug + ?Sized, KeyT, ValueT: Debug>(
+    value: impl Matcher<ActualT = ValueT>,
+) -> impl Matcher<ActualT = MapT>
+where
+    for<'a> &'a MapT: IntoIterator<Item = (&'a KeyT, &'a ValueT)>,
+{
+    HasValueMatcher { value, phantom: Default::default() }
+}
+
+struct HasEntryMatcher<MapT: ?Sized, KeyMatcherT, ValueMatcherT> {
+    key: KeyMatcherT,
+    value: ValueMatcherT,
+    phantom: PhantomData<MapT>,
+}
+
+impl<MapT: Debug + ?Sized, KeyT: Debug, ValueT: Debug, KeyMatcherT, ValueMatcherT> Matcher
+    for HasEntryMatcher<MapT, KeyMatcherT, ValueMatcherT>
+where
+    for<'a> &'a MapT: IntoIterator<Item = (&'a KeyT, &'a ValueT)>,
+    KeyMatcherT: Matcher<ActualT = KeyT>,
+    ValueMatcherT: Matcher<ActualT = ValueT>,
+{
+    type ActualT = MapT;
+
+    fn matches(&self, actual: &MapT) -> MatcherResult {
+        actual
+            .into_iter()
+            .any(|(key, value)| {
+                self.key.matches(key).into_bool() && self.value.matches(value).into_bool()
+            })
+            .into()
+    }
+
+    fn explain_match(&self, actual: &MapT) -> MatchExplanation {
+        let mut near_misses = vec![];
+        let mut children = vec![];
+        for (key, value) in actual {
+            if !self.key.matches(key).into_bool() {
+                continue;
+            }
+            if self.value.matches(value).into_bool() {
+                return MatchExplanation::create(format!(
+                    "which contains the matching entry {key:?}: {value:?}"
+                ));
+            }
+            let inner = explain(&self.value, value);
+            near_misses.push(format!("{key:?}: {value:?}, {inner}"));
+            children.push(inner);
+        }
+        if near_misses.is_empty() {
+            return MatchExplanation::create(format!(
+                "which contains no key which {}",
+                self.key.describe(MatcherResult::Matches)
+            ));
+        }
+        MatchExplanation::create(format!(
+            "which contains no matching entry, but these entries have a matching key:\n{}",
+            near_misses.into_iter().collect::<Description>().indent()
+        ))
+        .with_children(children)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!(
+            "{} an entry whose key {} and whose value {}",
+            if matcher_result.into() { "contains" } else { "doesn't contain" },
+            self.key.describe(MatcherResult::Matches),
+            self.value.describe(MatcherResult::Matches)
+        )
+    }
+}
+
+struct HasKeyMatcher<MapT: ?Sized, KeyMatcherT> {
+    key: KeyMatcherT,
+    phantom: PhantomData<MapT>,
+}
+
+impl<MapT: Debug + ?Sized, KeyT: Debug, ValueT, KeyMatcherT> Matcher
+    for HasKeyMatcher<MapT, KeyMatcherT>
+where
+    for<'a> &'a MapT: IntoIterator<Item = (&'a KeyT, &'a ValueT)>,
+    KeyMatcherT: Matcher<ActualT = KeyT>,
+{
+    type ActualT = MapT;
+
+    fn matches(&self, actual: &MapT) -> MatcherResult {
+        actual.into_iter().any(|(key, _)| self.key.matches(key).into_bool()).into()
+    }
+
+    fn explain_match(&self, actual: &MapT) -> MatchExplanation {
+        match actual.into_iter().find(|(key, _)| self.key.matches(key).into_bool()) {
+            Some((key, _)) => MatchExplanation::create(format!("which contains the key {key:?}")),
+            None => MatchExplanation::create("which contains no such key".to_string()),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!(
+            "{} a key which {}",
+            if matcher_result.into() { "contains" } else { "doesn't contain" },
+            self.key.describe(MatcherResult::Matches)
+        )
+    }
+}
+
+struct HasValueMatcher<MapT: ?Sized, ValueMatcherT> {
+    value: ValueMatcherT,
+    phantom: PhantomData<MapT>,
+}
+
+impl<MapT: Debug + ?Sized, KeyT, ValueT: Debug, ValueMatcherT> Matcher
+    for HasValueMatcher<MapT, ValueMatcherT>
+where
+    for<'a> &'a MapT: IntoIterator<Item = (&'a KeyT, &'a ValueT)>,
+    ValueMatcherT: Matcher<ActualT = ValueT>,
+{
+    type ActualT = MapT;
+
+    fn matches(&self, actual: &MapT) -> MatcherResult {
+        actual.into_iter().any(|(_, value)| self.value.matches(value).into_bool()).into()
+    }
+
+    fn explain_match(&self, actual: &MapT) -> MatchExplanation {
+        match actual.into_iter().find(|(_, value)| self.value.matches(value).into_bool()) {
+            Some((_, value)) => {
+                MatchExplanation::create(format!("which contains the value {value:?}"))
+            }
+            None => MatchExplanation::create("which contains no such value".to_string()),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!(
+            "{} a value which {}",
+            if matcher_result.into() { "contains" } else { "doesn't contain" },
+            self.value.describe(MatcherResult::Matches)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_entry, has_key, has_value};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn has_entry_matches_entry_of_hash_map() -> Result<()> {
+        verify_that!(HashMap::from([(1, "one"), (2, "two")]), has_entry(gt(1), eq("two")))
+    }
+
+    #[test]
+    fn has_entry_requires_key_and_value_of_same_entry() -> Result<()> {
+        verify_that!(BTreeMap::from([(1, "one"), (2, "two")]), not(has_entry(eq(1), eq("two"))))
+    }
+
+    #[test]
+    fn has_entry_explains_near_misses() -> Result<()> {
+        let result = verify_that!(
+            BTreeMap::from([("alice", 31), ("anna", 25), ("bob", 40)]),
+            has_entry(starts_with("a"), gt(35))
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                }, which contains no matching entry, but these entries have a matching key:
+                  "alice": 31, which is less than or equal to 35
+                  "anna": 25, which is less than or equal to 35
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn has_entry_explains_missing_key() -> Result<()> {
+        let result = verify_that!(BTreeMap::from([(1, 2)]), has_entry(eq(3), anything()));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: contains an entry whose key is equal to 3 and whose value is anything
+                Actual: {
+                    1: 2,
+                }, which contains no key which is equal to 3
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn has_key_matches_key() -> Result<()> {
+        expect_that!(BTreeMap::from([(1, 2)]), has_key(eq(1)));
+        verify_that!(HashMap::from([(1, 2)]), not(has_key(eq(2))))
+    }
+
+    #[test]
+    fn has_value_matches_value() -> Result<()> {
+        expect_that!(HashMap::from([(1, 2)]), has_value(eq(2)));
+        verify_that!(BTreeMap::from([(1, 2)]), not(has_value(eq(1))))
+    }
+
+    #[test]
+    fn has_value_explains_matching_value_when_negated() -> Result<()> {
+        let result = verify_that!(BTreeMap::from([(1, 2)]), not(has_value(eq(2))));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Expected: doesn't contain a value which is equal to 2\nActual: {\n    1: 2,\n}, \
+                 which contains the value 2"
+            )))
+        )
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the derive
+// macro is documented where it is re-exported.
+#![doc(hidden)]
+
+/// Derives a matcher factory for a struct.
+///
+/// For a struct `MyStruct`, this generates a function `my_struct_matcher`
+/// taking one [`Matcher`][crate::matcher::Matcher] per field, in declaration
+/// order, and returning a matcher which matches a `MyStruct` precisely when
+/// every field is matched by the corresponding matcher. The function has the
+/// same visibility as the struct. For tuple structs the parameters are named
+/// `field_0`, `field_1`, and so on.
+///
+/// This is an alternative to repeating the same
+/// [`matches_pattern!`][crate::matches_pattern] invocation for a struct which
+/// is matched in many tests. Unlike `matches_pattern!`, every field must be
+/// given a matcher; use [`anything`][crate::matchers::anything] for fields
+/// which should not be checked.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// #[derive(Debug, MatcherFactory)]
+/// struct HttpResponse {
+///     status: u16,
+///     body: String,
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// let response = HttpResponse { status: 200, body: "All OK".into() };
+/// verify_that!(response, http_response_matcher(eq(200), contains_substring("OK")))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let response = HttpResponse { status: 200, body: "All OK".into() };
+/// verify_that!(response, http_response_matcher(eq(404), anything()))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On a mismatch, the explanation lists only the fields which did not match:
+///
+/// ```text
+/// Value of: response
+/// Expected: is HttpResponse which has all the following properties:
+///   * has field `status`, which is equal to 404
+///   * has field `body`, which is anything
+/// Actual: HttpResponse {
+///     status: 200,
+///     body: "All OK",
+/// }, which has field `status`, which isn't equal to 404
+/// ```
+pub use googletest_macro::MatcherFactory;
+
+/// Functions for use only by the derive macro in this module.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+    use crate::matcher_support::description::Description;
+    use std::fmt::Debug;
+
+    /// A matcher which matches a struct whose fields are matched by the given
+    /// field matchers.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct StructMatcher<'a, T> {
+        type_name: &'static str,
+        fields: Vec<Box<dyn Matcher<ActualT = T> + 'a>>,
+    }
+
+    impl<'a, T> StructMatcher<'a, T> {
+        /// Constructs a [`StructMatcher`] for the struct named `type_name` with
+        /// the given field matchers.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        pub fn new(
+            type_name: &'static str,
+            fields: Vec<Box<dyn Matcher<ActualT = T> + 'a>>,
+        ) -> Self {
+            Self { type_name, fields }
+        }
+    }
+
+    impl<'a, T: Debug> Matcher for StructMatcher<'a, T> {
+        type ActualT = T;
+
+        fn matches(&self, actual: &T) -> MatcherResult {
+            if self.fields.iter().all(|field| field.matches(actual).into_bool()) {
+                MatcherResult::Matches
+            } else {
+                MatcherResult::DoesNotMatch
+            }
+        }
+
+        fn explain_match(&self, actual: &T) -> MatchExplanation {
+            let children = self
+                .fields
+                .iter()
+                .filter(|field| !field.matches(actual).into_bool())
+                .map(|field| explain(field.as_ref(), actual))
+                .collect::<Vec<_>>();
+            let mismatches =
+                children.iter().map(|child| format!("{child}")).collect::<Description>();
+            let explanation = match mismatches.len() {
+                0 => MatchExplanation::create(format!(
+                    "which is {} with matching fields",
+                    self.type_name
+                )),
+                1 => MatchExplanation::create(format!("{mismatches}")),
+                _ => MatchExplanation::create(format!("\n{}", mismatches.bullet_list().indent())),
+            };
+            explanation.with_children(children)
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            let fields = self
+                .fields
+                .iter()
+                .map(|field| field.describe(matcher_result))
+                .collect::<Description>()
+                .bullet_list()
+                .indent();
+            format!(
+                "is {} which has {}:\n{fields}",
+                self.type_name,
+                if matcher_result.into() {
+                    "all the following properties"
+                } else {
+                    "at least one of the following properties"
+                }
+            )
+        }
+    }
+}
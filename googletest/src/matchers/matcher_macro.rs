@@ -0,0 +1,141 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macro is documented at the top level.
+#![doc(hidden)]
+
+/// Defines a matcher factory together with the [`Matcher`] it returns.
+///
+/// This is a lightweight alternative to implementing the trait [`Matcher`] by
+/// hand. The invocation declares the signature of the factory function, the
+/// type of the actual value after `ActualT =`, and then, in order:
+///
+///  * `matches:` a closure taking a reference to the actual value and
+///    returning whether it matches,
+///  * `describe:` a format string describing what the matcher matches,
+///  * `describe_negated:` a format string describing what the matcher does not
+///    match, and
+///  * optionally `explain:` a closure taking a reference to the actual value and
+///    returning a [`String`] explaining why it does or does not match. This
+///    should be a relative clause such as "which is 3 away". If it is omitted,
+///    the explanation is derived from the descriptions as with any other
+///    [`Matcher`].
+///
+/// Within the closures and format strings, each parameter of the factory is
+/// available by name as a reference to its value. The format strings may refer
+/// to parameters with inline arguments such as `{expected:?}`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// googletest::matcher! {
+///     /// Matches a number whose distance to `expected` is at most `tolerance`.
+///     pub fn is_within(expected: f64, tolerance: f64) -> Matcher<ActualT = f64> {
+///         matches: |actual| (actual - expected).abs() <= *tolerance,
+///         describe: "is within {tolerance} of {expected}",
+///         describe_negated: "isn't within {tolerance} of {expected}",
+///         explain: |actual| format!("which is {} away", (actual - expected).abs()),
+///     }
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(1.05, is_within(1.0, 0.1))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(1.5, is_within(1.0, 0.1))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The failure of the second assertion above reads:
+///
+/// ```text
+/// Value of: 1.5
+/// Expected: is within 0.1 of 1
+/// Actual: 1.5, which is 0.5 away
+/// ```
+///
+/// The parameters are stored in the matcher, so their types must not borrow
+/// from the caller. Generic factories are not supported; implement
+/// [`Matcher`] directly for those.
+///
+/// [`Matcher`]: crate::matcher::Matcher
+#[macro_export]
+macro_rules! matcher {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis fn $name:ident($($parameter:ident : $parameter_type:ty),* $(,)?)
+            -> Matcher<ActualT = $actual_type:ty> {
+            matches: |$matches_actual:ident| $matches:expr,
+            describe: $describe:tt,
+            describe_negated: $describe_negated:tt
+            $(, explain: |$explain_actual:ident| $explain:expr)?
+            $(,)?
+        }
+    ) => {
+        $(#[$attribute])*
+        $visibility fn $name($($parameter: $parameter_type),*)
+            -> impl $crate::matcher::Matcher<ActualT = $actual_type>
+        {
+            struct MatcherImpl {
+                $($parameter: $parameter_type),*
+            }
+
+            impl $crate::matcher::Matcher for MatcherImpl {
+                type ActualT = $actual_type;
+
+                fn matches(
+                    &self,
+                    $matches_actual: &$actual_type,
+                ) -> $crate::matcher::MatcherResult {
+                    #[allow(unused_variables)]
+                    let MatcherImpl { $($parameter),* } = self;
+                    $matches.into()
+                }
+
+                fn describe(&self, matcher_result: $crate::matcher::MatcherResult) -> String {
+                    #[allow(unused_variables)]
+                    let MatcherImpl { $($parameter),* } = self;
+                    match matcher_result {
+                        $crate::matcher::MatcherResult::Matches => format!($describe),
+                        $crate::matcher::MatcherResult::DoesNotMatch => format!($describe_negated),
+                    }
+                }
+
+                $crate::matcher!(
+                    @explain_match { $($parameter),* },
+                    $actual_type,
+                    $(|$explain_actual| $explain)?
+                );
+            }
+
+            MatcherImpl { $($parameter),* }
+        }
+    };
+
+    // The parameters are passed as a single token tree since they cannot be repeated inside the
+    // optional `explain` clause.
+    (@explain_match $parameters:tt, $actual_type:ty,) => {};
+
+    (@explain_match $parameters:tt, $actual_type:ty, |$explain_actual:ident| $explain:expr) => {
+        fn explain_match(&self, $explain_actual: &$actual_type) -> $crate::matcher::MatchExplanation {
+            #[allow(unused_variables)]
+            let MatcherImpl $parameters = self;
+            $crate::matcher::MatchExplanation::create($explain)
+        }
+    };
+}
@@ -0,0 +1,403 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// Matches a string the entirety of which matches the given glob pattern.
+///
+/// This is a lighter-weight alternative to
+/// [`matches_regex`][crate::matchers::matches_regex] for log lines, paths and
+/// the like. The pattern supports the following syntax:
+///
+///  * `*` matches any sequence of characters, including the empty one and
+///    including `/`;
+///  * `?` matches any single character;
+///  * `[abc]` matches any one of the characters inside the brackets, `[a-z]`
+///    any character in the given range and `[!abc]` any character not inside
+///    the brackets;
+///  * `\` escapes the following character, so that `\*` matches a literal
+///    `*`;
+///  * every other character matches itself.
+///
+/// Both the actual value and the pattern may be either a `String` or a string
+/// reference.
+///
+/// On a mismatch, the explanation indicates the first position in the actual
+/// string at which it could no longer be matched against the pattern.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("error: file not found", matches_glob("error: * not found"))?;  // Passes
+/// verify_that!("logs/2023-05-01.txt".to_string(), matches_glob("logs/*.txt"))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail_1() -> Result<()> {
+/// verify_that!("error: file missing", matches_glob("error: * not found"))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # fn should_fail_2() -> Result<()> {
+/// verify_that!("warning: file not found", matches_glob("error: *"))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail_1().unwrap_err();
+/// # should_fail_2().unwrap_err();
+/// ```
+///
+/// Panics if the given `pattern` is not a syntactically valid glob pattern,
+/// i.e., if it contains an unterminated `[` or ends with an unescaped `\`.
+// N.B. This returns the concrete type rather than an impl Matcher so that it
+// can act simultaneously as a Matcher<str> and a Matcher<String>, like
+// matches_regex.
+pub fn matches_glob<ActualT: ?Sized, PatternT: Deref<Target = str>>(
+    pattern: PatternT,
+) -> MatchesGlobMatcher<ActualT, PatternT> {
+    let tokens = parse_glob(pattern.deref())
+        .unwrap_or_else(|error| panic!("Invalid glob pattern {:?}: {error}", pattern.deref()));
+    MatchesGlobMatcher { tokens, pattern, phantom: Default::default() }
+}
+
+/// A matcher matching a string-like type matching a given glob pattern.
+///
+/// Intended only to be used from the function [`matches_glob`] only.
+/// Should not be referenced by code outside this library.
+pub struct MatchesGlobMatcher<ActualT: ?Sized, PatternT: Deref<Target = str>> {
+    tokens: Vec<(usize, GlobToken)>,
+    pattern: PatternT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: ?Sized, PatternT: Deref<Target = str> + Clone> Clone
+    for MatchesGlobMatcher<ActualT, PatternT>
+{
+    fn clone(&self) -> Self {
+        Self { tokens: self.tokens.clone(), pattern: self.pattern.clone(), phantom: PhantomData }
+    }
+}
+
+/// A single element of a glob pattern.
+#[derive(Debug, Clone)]
+enum GlobToken {
+    AnySequence,
+    AnyChar,
+    Literal(char),
+    Class { negated: bool, ranges: Vec<(char, char)> },
+}
+
+impl GlobToken {
+    fn matches_char(&self, c: char) -> bool {
+        match self {
+            GlobToken::AnySequence | GlobToken::AnyChar => true,
+            GlobToken::Literal(literal) => *literal == c,
+            GlobToken::Class { negated, ranges } => {
+                ranges.iter().any(|(low, high)| (*low..=*high).contains(&c)) != *negated
+            }
+        }
+    }
+}
+
+/// Splits `pattern` into its tokens, each paired with the byte offset in
+/// `pattern` at which it starts.
+fn parse_glob(pattern: &str) -> Result<Vec<(usize, GlobToken)>, String> {
+    let mut tokens = vec![];
+    let mut chars = pattern.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        let token = match c {
+            '*' => GlobToken::AnySequence,
+            '?' => GlobToken::AnyChar,
+            '\\' => match chars.next() {
+                Some((_, escaped)) => GlobToken::Literal(escaped),
+                None => return Err("trailing unescaped '\\'".to_string()),
+            },
+            '[' => {
+                let negated = chars.next_if(|(_, c)| *c == '!').is_some();
+                let mut ranges = vec![];
+                loop {
+                    match chars.next() {
+                        Some((_, ']')) if !ranges.is_empty() => break,
+                        Some((_, low)) => {
+                            if chars.peek().map(|(_, c)| *c) == Some('-') {
+                                let mut lookahead = chars.clone();
+                                lookahead.next();
+                                if let Some((_, high)) = lookahead.next().filter(|(_, c)| *c != ']')
+                                {
+                                    chars = lookahead;
+                                    ranges.push((low, high));
+                                    continue;
+                                }
+                            }
+                            ranges.push((low, low));
+                        }
+                        None => return Err(format!("unterminated '[' at offset {offset}")),
+                    }
+                }
+                GlobToken::Class { negated, ranges }
+            }
+            c => GlobToken::Literal(c),
+        };
+        tokens.push((offset, token));
+    }
+    Ok(tokens)
+}
+
+/// The outcome of matching a string against a glob pattern.
+struct GlobMatch {
+    /// Whether the whole string matches the whole pattern.
+    is_match: bool,
+    /// The number of pattern tokens in the longest prefix of the pattern which
+    /// matches some prefix of the string.
+    tokens_matched: usize,
+    /// The number of characters in the shortest prefix of the string which
+    /// matches the first `tokens_matched` pattern tokens.
+    chars_matched: usize,
+}
+
+impl<ActualT: ?Sized, PatternT: Deref<Target = str>> MatchesGlobMatcher<ActualT, PatternT> {
+    fn glob_match(&self, actual: &str) -> GlobMatch {
+        let chars = actual.chars().collect::<Vec<_>>();
+        // reachable[j][i] holds whether the first j tokens match the first i
+        // characters.
+        let mut reachable = vec![vec![false; chars.len() + 1]; self.tokens.len() + 1];
+        reachable[0][0] = true;
+        let mut furthest = (0, 0);
+        for j in 0..=self.tokens.len() {
+            for i in 0..=chars.len() {
+                if !reachable[j][i] {
+                    continue;
+                }
+                if j > furthest.0 {
+                    furthest = (j, i);
+                }
+                let Some((_, token)) = self.tokens.get(j) else {
+                    continue;
+                };
+                if let GlobToken::AnySequence = token {
+                    reachable[j + 1][i] = true;
+                    if i < chars.len() {
+                        reachable[j][i + 1] = true;
+                    }
+                } else if i < chars.len() && token.matches_char(chars[i]) {
+                    reachable[j + 1][i + 1] = true;
+                }
+            }
+        }
+        GlobMatch {
+            is_match: reachable[self.tokens.len()][chars.len()],
+            tokens_matched: furthest.0,
+            chars_matched: furthest.1,
+        }
+    }
+}
+
+impl<PatternT, ActualT> Matcher for MatchesGlobMatcher<ActualT, PatternT>
+where
+    PatternT: Deref<Target = str>,
+    ActualT: AsRef<str> + Debug + ?Sized,
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &Self::ActualT) -> MatcherResult {
+        self.glob_match(actual.as_ref()).is_match.into()
+    }
+
+    fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
+        let actual = actual.as_ref();
+        let GlobMatch { is_match, tokens_matched, chars_matched } = self.glob_match(actual);
+        if is_match {
+            return MatchExplanation::create(format!(
+                "which matches the glob pattern {:?}",
+                self.pattern.deref()
+            ));
+        }
+        let actual_rest = actual.chars().skip(chars_matched).collect::<String>();
+        match self.tokens.get(tokens_matched) {
+            Some((offset, _)) => {
+                let pattern_rest = &self.pattern[*offset..];
+                if actual_rest.is_empty() {
+                    MatchExplanation::create(format!(
+                        "which ends where the glob pattern still expects {pattern_rest:?}"
+                    ))
+                } else {
+                    MatchExplanation::create(format!(
+                        "which stops matching the glob pattern at character {chars_matched} \
+                        ({actual_rest:?}), where the pattern expects {pattern_rest:?}"
+                    ))
+                }
+            }
+            None => MatchExplanation::create(format!(
+                "which stops matching the glob pattern at character {chars_matched} \
+                ({actual_rest:?}), after the end of the pattern"
+            )),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("matches the glob pattern {:#?}", self.pattern.deref())
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("doesn't match the glob pattern {:#?}", self.pattern.deref())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches_glob, MatchesGlobMatcher};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_glob_matches_literal_string() -> Result<()> {
+        verify_that!("Some value", matches_glob("Some value"))
+    }
+
+    #[test]
+    fn matches_glob_matches_any_sequence() -> Result<()> {
+        verify_that!("error: file not found", matches_glob("error: * not found"))
+    }
+
+    #[test]
+    fn matches_glob_matches_empty_sequence() -> Result<()> {
+        verify_that!("ab", matches_glob("a*b*"))
+    }
+
+    #[test]
+    fn matches_glob_any_sequence_matches_across_slashes() -> Result<()> {
+        verify_that!("src/matchers/mod.rs", matches_glob("src/*.rs"))
+    }
+
+    #[test]
+    fn matches_glob_matches_any_char() -> Result<()> {
+        verify_that!("v1.2", matches_glob("v?.?"))?;
+        verify_that!("v1.23", not(matches_glob("v?.?")))
+    }
+
+    #[test]
+    fn matches_glob_matches_character_class() -> Result<()> {
+        verify_that!("file-b.txt", matches_glob("file-[abc].txt"))?;
+        verify_that!("file-d.txt", not(matches_glob("file-[abc].txt")))
+    }
+
+    #[test]
+    fn matches_glob_matches_character_range() -> Result<()> {
+        verify_that!("id-7", matches_glob("id-[0-9]"))?;
+        verify_that!("id-x", not(matches_glob("id-[0-9]")))
+    }
+
+    #[test]
+    fn matches_glob_matches_negated_character_class() -> Result<()> {
+        verify_that!("id-x", matches_glob("id-[!0-9]"))?;
+        verify_that!("id-7", not(matches_glob("id-[!0-9]")))
+    }
+
+    #[test]
+    fn matches_glob_treats_leading_bracket_in_class_as_literal() -> Result<()> {
+        verify_that!("]", matches_glob("[]]"))
+    }
+
+    #[test]
+    fn matches_glob_treats_trailing_dash_in_class_as_literal() -> Result<()> {
+        verify_that!("-", matches_glob("[a-]"))
+    }
+
+    #[test]
+    fn matches_glob_matches_escaped_wildcards_literally() -> Result<()> {
+        verify_that!("a*b?", matches_glob(r"a\*b\?"))?;
+        verify_that!("axb?", not(matches_glob(r"a\*b\?")))
+    }
+
+    #[test]
+    fn matches_glob_does_not_match_string_only_beginning_of_which_matches() -> Result<()> {
+        verify_that!("Some value", not(matches_glob("Some")))
+    }
+
+    #[test]
+    fn matches_glob_matches_owned_string_with_owned_pattern() -> Result<()> {
+        let matcher: MatchesGlobMatcher<String, String> = matches_glob("S*e".to_string());
+
+        verify_that!(matcher.matches(&"Some value".to_string()), eq(MatcherResult::Matches))
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid glob pattern \"[abc\": unterminated '[' at offset 0")]
+    fn matches_glob_panics_on_unterminated_class() {
+        let _ = matches_glob::<str, _>("[abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "trailing unescaped '\\'")]
+    fn matches_glob_panics_on_trailing_backslash() {
+        let _ = matches_glob::<str, _>("abc\\");
+    }
+
+    #[test]
+    fn matches_glob_explains_position_of_mismatch() -> Result<()> {
+        let result = verify_that!("error: file missing", matches_glob("error: * not found"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: matches the glob pattern "error: * not found"
+                Actual: "error: file missing", which stops matching the glob pattern at character 12 ("missing"), where the pattern expects "not found"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn matches_glob_explains_mismatch_at_start() -> Result<()> {
+        verify_that!(
+            matches_glob("error: *").explain_match("warning: x"),
+            displays_as(eq(
+                "which stops matching the glob pattern at character 0 (\"warning: x\"), where \
+                the pattern expects \"error: *\""
+            ))
+        )
+    }
+
+    #[test]
+    fn matches_glob_explains_string_ending_early() -> Result<()> {
+        verify_that!(
+            matches_glob("*.txt").explain_match("notes.tx"),
+            displays_as(eq("which ends where the glob pattern still expects \"t\""))
+        )
+    }
+
+    #[test]
+    fn matches_glob_explains_trailing_characters() -> Result<()> {
+        verify_that!(
+            matches_glob("Some").explain_match("Some value"),
+            displays_as(eq(
+                "which stops matching the glob pattern at character 4 (\" value\"), after the \
+                end of the pattern"
+            ))
+        )
+    }
+
+    #[test]
+    fn matches_glob_matcher_can_be_cloned_and_reused() -> Result<()> {
+        let matcher = matches_glob("S*e");
+        verify_that!("Some value", matcher.clone())?;
+        verify_that!("Same", matcher)
+    }
+}
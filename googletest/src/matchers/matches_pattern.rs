@@ -44,6 +44,19 @@
 /// #     .unwrap();
 /// ```
 ///
+/// Each matcher must match the type of its field. Otherwise, compilation fails
+/// with an error at the matcher:
+///
+/// ```compile_fail
+/// # use googletest::prelude::*;
+/// #[derive(Debug)]
+/// struct MyStruct {
+///     a_field: u32,
+/// }
+/// verify_that!(MyStruct { a_field: 1 }, matches_pattern!(MyStruct { a_field: starts_with("1") }))
+/// #    .unwrap();
+/// ```
+///
 /// It is not required to include all named fields in the specification. Omitted
 /// fields have no effect on the output of the matcher.
 ///
@@ -66,6 +79,29 @@
 /// #     .unwrap();
 /// ```
 ///
+/// As in an ordinary Rust pattern, the omitted fields may also be made explicit
+/// with a trailing rest pattern `..`. This is only a matter of style; it does
+/// not change what the matcher checks.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # #[derive(Debug)]
+/// # struct MyStruct {
+/// #     a_field: String,
+/// #     another_field: String,
+/// # }
+/// #
+/// # let my_struct = MyStruct {
+/// #     a_field: "Something to believe in".into(),
+/// #     another_field: "Something else".into()
+/// # };
+/// verify_that!(my_struct, matches_pattern!(MyStruct {
+///     a_field: starts_with("Something"),
+///     ..
+/// }))
+/// #     .unwrap();
+/// ```
+///
 /// One can use it recursively to match nested structures:
 ///
 /// ```
@@ -116,6 +152,34 @@
 /// #     .unwrap();
 /// ```
 ///
+/// A field holding a smart pointer such as [`Box`], [`Rc`][std::rc::Rc] or
+/// [`Arc`][std::sync::Arc] can be matched against the value it points to by
+/// preceding the field name with `*`. This is shorthand for wrapping the
+/// matcher in [`points_to`][crate::matchers::points_to]:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// #[derive(Debug)]
+/// struct MyStruct {
+///     a_boxed_struct: Box<MyInnerStruct>,
+/// }
+///
+/// #[derive(Debug)]
+/// struct MyInnerStruct {
+///     a_field: String,
+/// }
+///
+/// let my_struct = MyStruct {
+///     a_boxed_struct: Box::new(MyInnerStruct { a_field: "Something to believe in".into() }),
+/// };
+/// verify_that!(my_struct, matches_pattern!(MyStruct {
+///     *a_boxed_struct: pat!(MyInnerStruct {
+///         a_field: starts_with("Something"),
+///     }),
+/// }))
+/// #     .unwrap();
+/// ```
+///
 /// In addition to fields, one can match on the outputs of methods
 /// ("properties"):
 ///
@@ -183,7 +247,7 @@
 /// ```
 ///
 /// One can also match tuple structs with up to 10 fields. In this case, all
-/// fields must have matchers:
+/// fields must have matchers, unless the pattern ends with `..`:
 ///
 /// ```
 /// # use googletest::prelude::*;
@@ -220,6 +284,30 @@
 /// # should_fail().unwrap_err();
 /// ```
 ///
+/// A pattern consisting only of `..`, such as `MyEnum::A(..)` or
+/// `MyEnum::B { .. }`, checks only the enum variant:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// #[derive(Debug)]
+/// enum MyEnum {
+///     A(u32, u32),
+///     B { a_field: u32 },
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(MyEnum::A(1, 2), matches_pattern!(MyEnum::A(..)))?; // Passes
+/// verify_that!(MyEnum::A(1, 2), matches_pattern!(MyEnum::A(eq(1), ..)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(MyEnum::A(1, 2), matches_pattern!(MyEnum::B { .. }))?; // Fails - wrong enum variant
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
 /// This macro does not support plain (non-struct) tuples. Use the macro
 /// [`tuple`] for that purpose.
 ///
@@ -262,6 +350,13 @@ macro_rules! matches_pattern_internal {
         all!(field!($($struct_name)*.$field_name, $matcher))
     };
 
+    (
+        [$($struct_name:tt)*],
+        { * $field_name:ident : $matcher:expr $(,)? }
+    ) => {
+        all!(field!($($struct_name)*.$field_name, $crate::matchers::points_to($matcher)))
+    };
+
     (
         [$($struct_name:tt)*],
         { $property_name:ident($($argument:expr),* $(,)?) : $matcher:expr $(,)? }
@@ -287,6 +382,17 @@ macro_rules! matches_pattern_internal {
         )
     };
 
+    (
+        [$($struct_name:tt)*],
+        { * $field_name:ident : $matcher:expr, $($rest:tt)* }
+    ) => {
+        $crate::matches_pattern_internal!(
+            all!(field!($($struct_name)*.$field_name, $crate::matchers::points_to($matcher))),
+            [$($struct_name)*],
+            { $($rest)* }
+        )
+    };
+
     (
         [$($struct_name:tt)*],
         { $property_name:ident($($argument:expr),* $(,)?) : $matcher:expr, $($rest:tt)* }
@@ -320,6 +426,17 @@ macro_rules! matches_pattern_internal {
         )
     };
 
+    (
+        all!($($processed:tt)*),
+        [$($struct_name:tt)*],
+        { * $field_name:ident : $matcher:expr $(,)? }
+    ) => {
+        all!(
+            $($processed)*,
+            field!($($struct_name)*.$field_name, $crate::matchers::points_to($matcher))
+        )
+    };
+
     (
         all!($($processed:tt)*),
         [$($struct_name:tt)*],
@@ -357,6 +474,21 @@ macro_rules! matches_pattern_internal {
         )
     };
 
+    (
+        all!($($processed:tt)*),
+        [$($struct_name:tt)*],
+        { * $field_name:ident : $matcher:expr, $($rest:tt)* }
+    ) => {
+        $crate::matches_pattern_internal!(
+            all!(
+                $($processed)*,
+                field!($($struct_name)*.$field_name, $crate::matchers::points_to($matcher))
+            ),
+            [$($struct_name)*],
+            { $($rest)* }
+        )
+    };
+
     (
         all!($($processed:tt)*),
         [$($struct_name:tt)*],
@@ -387,6 +519,50 @@ macro_rules! matches_pattern_internal {
         )
     };
 
+    // A rest pattern `..` on its own only checks the struct or enum variant. These arms must precede
+    // the tuple struct arms below, since `..` would otherwise be parsed as a (range) expression.
+    (
+        [$($struct_name:tt)*],
+        { .. $(,)? }
+    ) => {
+        $crate::matches_pattern_internal!(@rest [$($struct_name)*], { .. })
+    };
+
+    (
+        [$($struct_name:tt)*],
+        (.. $(,)?)
+    ) => {
+        $crate::matches_pattern_internal!(@rest [$($struct_name)*], (..))
+    };
+
+    (
+        @rest [$($struct_name:tt)*], $rest:tt
+    ) => {
+        $crate::matchers::predicate(|actual| matches!(actual, $($struct_name)* $rest))
+            .with_description(
+                concat!("matches the pattern ", stringify!($($struct_name)* $rest)),
+                concat!("does not match the pattern ", stringify!($($struct_name)* $rest)),
+            )
+    };
+
+    // Omitted fields are not checked anyway, so a trailing `..` after some fields has no effect.
+    (
+        all!($($processed:tt)*),
+        [$($struct_name:tt)*],
+        { .. $(,)? }
+    ) => {
+        all!($($processed)*)
+    };
+
+    (
+        all!($($processed:tt)*),
+        [$($struct_name:tt)*],
+        $field:tt,
+        (.. $(,)?)
+    ) => {
+        all!($($processed)*)
+    };
+
     (
         [$($struct_name:tt)*],
         ($matcher:expr $(,)?)
@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use regex::Captures;
 use regex::Regex;
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -83,6 +84,19 @@ pub struct MatchesRegexMatcher<ActualT: ?Sized, PatternT: Deref<Target = str>> {
     phantom: PhantomData<ActualT>,
 }
 
+impl<ActualT: ?Sized, PatternT: Deref<Target = str> + Clone> Clone
+    for MatchesRegexMatcher<ActualT, PatternT>
+{
+    fn clone(&self) -> Self {
+        Self {
+            regex: self.regex.clone(),
+            pattern: self.pattern.clone(),
+            _adjusted_pattern: self._adjusted_pattern.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
 impl<PatternT, ActualT> Matcher for MatchesRegexMatcher<ActualT, PatternT>
 where
     PatternT: Deref<Target = str>,
@@ -106,11 +120,202 @@ where
     }
 }
 
+/// Matches a string the entirety of which matches the given regular expression
+/// and whose capture groups match the given matchers.
+///
+/// Each entry of `captures` pairs the name of a capture group with a matcher
+/// for the text which that group captured. Unnamed groups may be referred to
+/// by their index, written as a string such as `"1"`. The matcher fails if the
+/// regular expression does not match, if a listed group did not participate in
+/// the match, or if the captured text does not match the corresponding matcher.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(
+///     "id=42",
+///     matches_regex_with_captures(r"id=(?P<id>\d+)", [("id", eq("42"))])
+/// )?;   // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(
+///     "id=41",
+///     matches_regex_with_captures(r"id=(?P<id>\d+)", [("id", eq("42"))])
+/// )?;   // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The matchers are applied to the captured text as a `String`. To use
+/// matchers of different types for different groups, box them with
+/// [`Box<dyn Matcher>`][crate::matcher::Matcher]:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use googletest::matcher::Matcher;
+/// # fn should_pass() -> Result<()> {
+/// let captures: [(&str, Box<dyn Matcher<ActualT = String>>); 2] = [
+///     ("key", Box::new(starts_with("user"))),
+///     ("value", Box::new(eq("admin"))),
+/// ];
+/// verify_that!(
+///     "user.role=admin",
+///     matches_regex_with_captures(r"(?P<key>[\w.]+)=(?P<value>\w+)", captures)
+/// )?;   // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// Panics if the given `pattern` is not a syntactically valid regular
+/// expression or if it has no capture group with one of the given names.
+pub fn matches_regex_with_captures<ActualT, PatternT, NameT, InnerMatcherT>(
+    pattern: PatternT,
+    captures: impl IntoIterator<Item = (NameT, InnerMatcherT)>,
+) -> MatchesRegexWithCapturesMatcher<ActualT, PatternT, InnerMatcherT>
+where
+    ActualT: ?Sized,
+    PatternT: Deref<Target = str>,
+    NameT: AsRef<str>,
+    InnerMatcherT: Matcher<ActualT = String>,
+{
+    let regex = Regex::new(&format!("^(?:{})$", pattern.deref())).unwrap();
+    let captures = captures
+        .into_iter()
+        .map(|(name, matcher)| {
+            let name = name.as_ref().to_string();
+            let group_exists = match name.parse::<usize>() {
+                Ok(index) => index < regex.captures_len(),
+                Err(_) => regex.capture_names().any(|n| n == Some(name.as_str())),
+            };
+            if !group_exists {
+                panic!(
+                    "The regular expression {:#?} has no capture group `{}`",
+                    pattern.deref(),
+                    name
+                );
+            }
+            (name, matcher)
+        })
+        .collect();
+    MatchesRegexWithCapturesMatcher { regex, pattern, captures, phantom: Default::default() }
+}
+
+/// A matcher matching a string-like type matching a given regular expression
+/// whose capture groups match given matchers.
+///
+/// Intended only to be used from the function [`matches_regex_with_captures`]
+/// only. Should not be referenced by code outside this library.
+pub struct MatchesRegexWithCapturesMatcher<ActualT: ?Sized, PatternT, InnerMatcherT> {
+    regex: Regex,
+    pattern: PatternT,
+    captures: Vec<(String, InnerMatcherT)>,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: ?Sized, PatternT, InnerMatcherT>
+    MatchesRegexWithCapturesMatcher<ActualT, PatternT, InnerMatcherT>
+{
+    fn group<'a>(captures: &Captures<'a>, name: &str) -> Option<&'a str> {
+        match name.parse::<usize>() {
+            Ok(index) => captures.get(index),
+            Err(_) => captures.name(name),
+        }
+        .map(|m| m.as_str())
+    }
+}
+
+impl<ActualT, PatternT, InnerMatcherT> Matcher
+    for MatchesRegexWithCapturesMatcher<ActualT, PatternT, InnerMatcherT>
+where
+    ActualT: AsRef<str> + Debug + ?Sized,
+    PatternT: Deref<Target = str>,
+    InnerMatcherT: Matcher<ActualT = String>,
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &Self::ActualT) -> MatcherResult {
+        let Some(captures) = self.regex.captures(actual.as_ref()) else {
+            return MatcherResult::DoesNotMatch;
+        };
+        self.captures
+            .iter()
+            .all(|(name, matcher)| {
+                Self::group(&captures, name)
+                    .map(|text| matcher.matches(&text.to_string()).into_bool())
+                    .unwrap_or(false)
+            })
+            .into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        let groups = self
+            .captures
+            .iter()
+            .map(|(name, matcher)| {
+                format!("capture group `{}` which {}", name, matcher.describe(matcher_result))
+            })
+            .collect::<Vec<_>>();
+        match matcher_result {
+            MatcherResult::Matches => format!(
+                "matches the regular expression {:#?} with {}",
+                self.pattern.deref(),
+                groups.join(" and ")
+            ),
+            MatcherResult::DoesNotMatch => format!(
+                "doesn't match the regular expression {:#?} or has {}",
+                self.pattern.deref(),
+                groups.join(" or ")
+            ),
+        }
+    }
+
+    fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
+        let Some(captures) = self.regex.captures(actual.as_ref()) else {
+            return MatchExplanation::create(format!(
+                "which doesn't match the regular expression {:#?}",
+                self.pattern.deref()
+            ));
+        };
+        let mut children = vec![];
+        let failures = self
+            .captures
+            .iter()
+            .filter_map(|(name, matcher)| match Self::group(&captures, name) {
+                None => Some(format!("whose capture group `{name}` did not participate")),
+                Some(text) => {
+                    let text = text.to_string();
+                    if matcher.matches(&text).into_bool() {
+                        None
+                    } else {
+                        let inner = explain(matcher, &text);
+                        let failure = format!("whose capture group `{name}` is {text:?}, {inner}");
+                        children.push(inner);
+                        Some(failure)
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if failures.is_empty() {
+            MatchExplanation::create(format!(
+                "which matches the regular expression {:#?} with all capture groups matching",
+                self.pattern.deref()
+            ))
+        } else {
+            MatchExplanation::create(failures.join(",\n")).with_children(children)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{matches_regex, MatchesRegexMatcher};
+    use super::{matches_regex, matches_regex_with_captures, MatchesRegexMatcher};
     use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
+    use indoc::indoc;
 
     #[test]
     fn matches_regex_matches_string_reference_with_pattern() -> Result<()> {
@@ -207,4 +412,103 @@ mod tests {
             eq("matches the regular expression \"\\n\"")
         )
     }
+
+    #[test]
+    fn matches_regex_with_captures_matches_named_group() -> Result<()> {
+        verify_that!("id=42", matches_regex_with_captures(r"id=(?P<id>\d+)", [("id", eq("42"))]))
+    }
+
+    #[test]
+    fn matches_regex_with_captures_matches_numbered_group() -> Result<()> {
+        verify_that!(
+            "key=value".to_string(),
+            matches_regex_with_captures(r"(\w+)=(\w+)", [("1", eq("key")), ("2", eq("value"))])
+        )
+    }
+
+    #[test]
+    fn matches_regex_with_captures_does_not_match_when_capture_mismatches() -> Result<()> {
+        let matcher = matches_regex_with_captures(r"id=(?P<id>\d+)", [("id", eq("42"))]);
+
+        verify_that!(matcher.matches("id=41"), eq(MatcherResult::DoesNotMatch))
+    }
+
+    #[test]
+    fn matches_regex_with_captures_does_not_match_partial_string() -> Result<()> {
+        let matcher = matches_regex_with_captures(r"id=(?P<id>\d+)", [("id", eq("42"))]);
+
+        verify_that!(matcher.matches("id=42 and more"), eq(MatcherResult::DoesNotMatch))
+    }
+
+    #[test]
+    fn matches_regex_with_captures_anchors_whole_alternation() -> Result<()> {
+        let matcher = matches_regex_with_captures(r"a|(?P<b>b)", [("b", eq("b"))]);
+
+        verify_that!(matcher.matches("ab"), eq(MatcherResult::DoesNotMatch))
+    }
+
+    #[test]
+    fn matches_regex_with_captures_does_not_match_when_group_does_not_participate() -> Result<()> {
+        let matcher =
+            matches_regex_with_captures(r"(?P<a>a)|(?P<b>b)", [("a", anything::<String>())]);
+
+        verify_that!(matcher.matches("b"), eq(MatcherResult::DoesNotMatch))
+    }
+
+    #[test]
+    #[should_panic(expected = "has no capture group `name`")]
+    fn matches_regex_with_captures_panics_on_unknown_group() {
+        let _ = matches_regex_with_captures::<str, _, _, _>(r"(?P<id>\d+)", [("name", eq("x"))]);
+    }
+
+    #[test]
+    #[should_panic(expected = "has no capture group `2`")]
+    fn matches_regex_with_captures_panics_on_out_of_range_index() {
+        let _ = matches_regex_with_captures::<str, _, _, _>(r"(\d+)", [("2", eq("x"))]);
+    }
+
+    #[test]
+    fn matches_regex_with_captures_describes_groups() -> Result<()> {
+        let matcher: super::MatchesRegexWithCapturesMatcher<str, _, _> =
+            matches_regex_with_captures(r"(?P<a>\w)(?P<b>\w)", [("a", eq("x")), ("b", eq("y"))]);
+
+        verify_that!(
+            Matcher::describe(&matcher, MatcherResult::Matches),
+            eq("matches the regular expression \"(?P<a>\\\\w)(?P<b>\\\\w)\" with capture group \
+                `a` which is equal to \"x\" and capture group `b` which is equal to \"y\"")
+        )?;
+        verify_that!(
+            Matcher::describe(&matcher, MatcherResult::DoesNotMatch),
+            eq("doesn't match the regular expression \"(?P<a>\\\\w)(?P<b>\\\\w)\" or has capture \
+                group `a` which isn't equal to \"x\" or capture group `b` which isn't equal to \"y\"")
+        )
+    }
+
+    #[test]
+    fn matches_regex_with_captures_explains_failing_capture() -> Result<()> {
+        let result = verify_that!(
+            "id=41",
+            matches_regex_with_captures(r"id=(?P<id>\d+)", [("id", eq("42"))])
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: "id=41", whose capture group `id` is "41", which isn't equal to "42"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn matches_regex_with_captures_explains_regex_mismatch() -> Result<()> {
+        let matcher: super::MatchesRegexWithCapturesMatcher<str, _, _> =
+            matches_regex_with_captures(r"id=(?P<id>\d+)", [("id", eq("42"))]);
+
+        verify_that!(
+            matcher.explain_match("name=x").to_string(),
+            eq("which doesn't match the regular expression \"id=(?P<id>\\\\d+)\"")
+        )
+    }
 }
@@ -13,71 +13,176 @@
 // limitations under the License.
 
 pub mod all_matcher;
+pub mod all_of_matcher;
+pub mod all_unique_matcher;
+pub mod any_matcher;
+pub mod any_of_matcher;
 pub mod anything_matcher;
+pub mod approx_duration_matcher;
+pub mod approx_eq_ulps_matcher;
+pub mod approx_time_matcher;
+pub mod boxed_matcher;
+pub mod breaks_with_matcher;
+pub mod conditional_matcher;
 pub mod conjunction_matcher;
 pub mod container_eq_matcher;
 pub mod contains_matcher;
 pub mod contains_regex_matcher;
+pub mod contains_subsequence_matcher;
+pub mod context_matcher;
+pub mod continues_with_matcher;
+pub mod covers_all_variants_matcher;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod date_time_matchers;
+pub mod derived_matcher;
+pub mod dir_eq_matcher;
 pub mod disjunction_matcher;
 pub mod display_matcher;
 pub mod each_matcher;
+pub mod elements_are_from_matcher;
 pub mod elements_are_matcher;
 pub mod empty_matcher;
 pub mod eq_deref_of_matcher;
+pub mod eq_ignoring_case_matcher;
+pub mod eq_ignoring_whitespace_matcher;
 pub mod eq_matcher;
 pub mod err_matcher;
 pub mod field_matcher;
+pub mod float_class_matchers;
 pub mod ge_matcher;
 pub mod gt_matcher;
 pub mod has_entry_matcher;
+pub mod in_range_matcher;
+pub mod ip_matcher;
+pub mod is_empty_matcher;
 pub mod is_nan_matcher;
+pub mod is_pending_matcher;
+pub mod is_ready_matcher;
+pub mod is_sorted_matcher;
+pub mod is_utf8_matcher;
+pub mod iterators_equal_matcher;
 pub mod le_matcher;
+pub mod len_matcher;
 pub mod lt_matcher;
+pub mod map_matchers;
+pub mod matcher_factory;
+pub mod matcher_macro;
+pub mod matches_glob_matcher;
 pub mod matches_pattern;
 pub mod matches_regex_matcher;
+pub mod multiple_of_matcher;
 pub mod near_matcher;
 pub mod none_matcher;
 pub mod not_matcher;
 pub mod ok_matcher;
 pub mod points_to_matcher;
 pub mod pointwise_matcher;
+pub mod positional_matchers;
 pub mod predicate_matcher;
 pub mod property_matcher;
+pub mod result_of_matcher;
+pub mod round_trips_via_display_matcher;
+#[cfg(feature = "serde")]
+pub mod round_trips_via_matcher;
+#[cfg(feature = "semver")]
+pub mod semver_matcher;
+pub mod set_eq_matcher;
 pub mod size_matcher;
 pub mod some_matcher;
+pub mod starts_with_elements_matcher;
 pub mod str_matcher;
+pub mod string_length_matchers;
 pub mod subset_of_matcher;
 pub mod superset_of_matcher;
 pub mod tuple_matcher;
 pub mod unordered_elements_are_matcher;
+pub mod unordered_entries_are_matcher;
+#[cfg(feature = "url")]
+pub mod url_matcher;
+pub mod weak_matcher;
+pub mod yielded;
+pub mod yields_count_matcher;
 
+pub use all_of_matcher::all_of;
+pub use all_unique_matcher::{all_unique, all_unique_by_key};
+pub use any_of_matcher::any_of;
 pub use anything_matcher::anything;
+pub use approx_duration_matcher::approx_duration;
+pub use approx_eq_ulps_matcher::approx_eq_ulps;
+pub use approx_time_matcher::approx_time;
+pub use boxed_matcher::BoxedMatcherExt;
+pub use breaks_with_matcher::breaks_with;
+pub use conditional_matcher::conditional;
 pub use conjunction_matcher::AndMatcherExt;
 pub use container_eq_matcher::container_eq;
 pub use contains_matcher::contains;
 pub use contains_regex_matcher::contains_regex;
+pub use context_matcher::ContextMatcherExt;
+pub use continues_with_matcher::continues_with;
+pub use covers_all_variants_matcher::{covers_all_variants, EnumVariants};
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use date_time_matchers::{on_date, same_instant_as, within, DateTimeValue};
+pub use derived_matcher::derived;
+pub use dir_eq_matcher::dir_eq;
 pub use disjunction_matcher::OrMatcherExt;
 pub use display_matcher::displays_as;
 pub use each_matcher::each;
+pub use elements_are_from_matcher::elements_are_from;
 pub use empty_matcher::empty;
 pub use eq_deref_of_matcher::eq_deref_of;
+pub use eq_ignoring_case_matcher::eq_ignoring_case;
+pub use eq_ignoring_whitespace_matcher::eq_ignoring_whitespace;
 pub use eq_matcher::eq;
 pub use err_matcher::err;
+pub use float_class_matchers::{is_finite, is_infinite};
 pub use ge_matcher::ge;
 pub use gt_matcher::gt;
 pub use has_entry_matcher::has_entry;
+pub use in_range_matcher::in_range;
+pub use ip_matcher::{has_port, in_subnet, is_loopback, HasIpAddr, HasPort};
+pub use is_empty_matcher::{is_empty, is_not_empty};
 pub use is_nan_matcher::is_nan;
+pub use is_pending_matcher::is_pending;
+pub use is_ready_matcher::is_ready;
+pub use is_sorted_matcher::{is_sorted, is_sorted_by_key};
+pub use is_utf8_matcher::is_utf8;
+pub use iterators_equal_matcher::{iterators_equal, iterators_pointwise};
 pub use le_matcher::le;
+pub use len_matcher::len;
 pub use lt_matcher::lt;
-pub use matches_regex_matcher::matches_regex;
-pub use near_matcher::{approx_eq, near};
+pub use map_matchers::{has_key, has_value};
+pub use matcher_factory::MatcherFactory;
+pub use matches_glob_matcher::matches_glob;
+pub use matches_regex_matcher::{matches_regex, matches_regex_with_captures};
+pub use multiple_of_matcher::{divisible_by, multiple_of};
+pub use near_matcher::{approx_eq, near, relative_near};
 pub use none_matcher::none;
 pub use not_matcher::not;
 pub use ok_matcher::ok;
-pub use points_to_matcher::points_to;
+pub use points_to_matcher::{
+    arc_contains, points_to, points_to_boxed, rc_contains, ref_cell_contains,
+};
+pub use pointwise_matcher::pointwise_fn;
+pub use positional_matchers::{first, last, nth};
 pub use predicate_matcher::{predicate, PredicateMatcher};
+pub use round_trips_via_display_matcher::round_trips_via_display;
+#[cfg(feature = "serde")]
+pub use round_trips_via_matcher::{round_trips_via, Format};
+#[cfg(feature = "semver")]
+pub use semver_matcher::{is_prerelease, newer_than, satisfies_req, VersionLike};
+pub use set_eq_matcher::set_eq;
 pub use size_matcher::size;
 pub use some_matcher::some;
+#[cfg(feature = "unicode-normalization")]
+pub use str_matcher::eq_normalized;
 pub use str_matcher::{contains_substring, ends_with, starts_with, StrMatcherConfigurator};
+pub use string_length_matchers::char_count;
+#[cfg(feature = "unicode-segmentation")]
+pub use string_length_matchers::grapheme_count;
 pub use subset_of_matcher::subset_of;
 pub use superset_of_matcher::superset_of;
+#[cfg(feature = "url")]
+pub use url_matcher::{has_host, has_query_param, has_scheme, UrlLike};
+pub use weak_matcher::{is_dangling, upgrades_to};
+pub use yielded::yielded;
+pub use yields_count_matcher::{counted, yields_count};
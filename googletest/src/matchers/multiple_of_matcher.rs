@@ -0,0 +1,213 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use num_traits::{CheckedRem, PrimInt};
+use std::fmt::Debug;
+
+/// Matches an integer which is a multiple of `divisor`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(12, multiple_of(4))?; // Passes
+/// verify_that!(0u8, multiple_of(7))?; // Passes
+/// verify_that!(-9, multiple_of(3))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(10, multiple_of(4))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On mismatch, the explanation gives the remainder, so the failure of the
+/// last assertion above reads:
+///
+/// ```text
+/// Value of: 10
+/// Expected: is a multiple of 4
+/// Actual: 10, which leaves remainder 2 when divided by 4
+/// ```
+///
+/// The value `divisor` must not be zero. The matcher panics on construction
+/// otherwise.
+pub fn multiple_of<T: PrimInt + CheckedRem + Debug>(divisor: T) -> MultipleOfMatcher<T> {
+    if divisor.is_zero() {
+        panic!("divisor must not be zero");
+    }
+    MultipleOfMatcher { divisor, phrasing: Phrasing::MultipleOf }
+}
+
+/// Matches an integer which is divisible by `divisor`.
+///
+/// This is the same as [`multiple_of`], but describes itself in terms of
+/// divisibility:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let batch_size = 64;
+/// verify_that!(1024, divisible_by(batch_size))?; // Passes
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// The value `divisor` must not be zero. The matcher panics on construction
+/// otherwise.
+pub fn divisible_by<T: PrimInt + CheckedRem + Debug>(divisor: T) -> MultipleOfMatcher<T> {
+    MultipleOfMatcher { phrasing: Phrasing::DivisibleBy, ..multiple_of(divisor) }
+}
+
+/// A matcher which matches integers which are multiples of a given divisor.
+///
+/// See [`multiple_of`] and [`divisible_by`].
+#[derive(Clone, Copy)]
+pub struct MultipleOfMatcher<T> {
+    divisor: T,
+    phrasing: Phrasing,
+}
+
+#[derive(Clone, Copy)]
+enum Phrasing {
+    MultipleOf,
+    DivisibleBy,
+}
+
+impl<T: PrimInt + CheckedRem + Debug> MultipleOfMatcher<T> {
+    fn remainder(&self, actual: &T) -> T {
+        // The only non-zero divisor for which the remainder overflows is -1,
+        // which divides every integer.
+        actual.checked_rem(&self.divisor).unwrap_or_else(T::zero)
+    }
+}
+
+impl<T: PrimInt + CheckedRem + Debug> Matcher for MultipleOfMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        self.remainder(actual).is_zero().into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match (self.phrasing, matcher_result) {
+            (Phrasing::MultipleOf, MatcherResult::Matches) => {
+                format!("is a multiple of {:?}", self.divisor)
+            }
+            (Phrasing::MultipleOf, MatcherResult::DoesNotMatch) => {
+                format!("isn't a multiple of {:?}", self.divisor)
+            }
+            (Phrasing::DivisibleBy, MatcherResult::Matches) => {
+                format!("is divisible by {:?}", self.divisor)
+            }
+            (Phrasing::DivisibleBy, MatcherResult::DoesNotMatch) => {
+                format!("isn't divisible by {:?}", self.divisor)
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let remainder = self.remainder(actual);
+        if remainder.is_zero() {
+            MatchExplanation::create(format!("which {}", self.describe(MatcherResult::Matches)))
+        } else {
+            MatchExplanation::create(format!(
+                "which leaves remainder {:?} when divided by {:?}",
+                remainder, self.divisor
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{divisible_by, multiple_of};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+
+    #[test]
+    fn multiple_of_matches_multiples() -> Result<()> {
+        verify_that!(0, multiple_of(3))?;
+        verify_that!(9, multiple_of(3))?;
+        verify_that!(-9, multiple_of(3))?;
+        verify_that!(9, multiple_of(-3))
+    }
+
+    #[test]
+    fn multiple_of_does_not_match_non_multiples() -> Result<()> {
+        verify_that!(10u64, not(multiple_of(3)))?;
+        verify_that!(-10i8, not(multiple_of(3)))
+    }
+
+    #[test]
+    fn multiple_of_minus_one_matches_minimum_value() -> Result<()> {
+        verify_that!(i32::MIN, multiple_of(-1))
+    }
+
+    #[test]
+    #[should_panic]
+    fn multiple_of_panics_on_zero_divisor() {
+        multiple_of(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn divisible_by_panics_on_zero_divisor() {
+        divisible_by(0u32);
+    }
+
+    #[test]
+    fn divisible_by_matches_multiples() -> Result<()> {
+        verify_that!(1024usize, divisible_by(64))?;
+        verify_that!(1000usize, not(divisible_by(64)))
+    }
+
+    #[test]
+    fn multiple_of_describes_itself() -> Result<()> {
+        verify_that!(multiple_of(4).describe(MatcherResult::Matches), eq("is a multiple of 4"))?;
+        verify_that!(
+            multiple_of(4).describe(MatcherResult::DoesNotMatch),
+            eq("isn't a multiple of 4")
+        )
+    }
+
+    #[test]
+    fn divisible_by_describes_itself() -> Result<()> {
+        verify_that!(divisible_by(4).describe(MatcherResult::Matches), eq("is divisible by 4"))?;
+        verify_that!(
+            divisible_by(4).describe(MatcherResult::DoesNotMatch),
+            eq("isn't divisible by 4")
+        )
+    }
+
+    #[test]
+    fn multiple_of_explains_remainder() -> Result<()> {
+        let result = verify_that!(10, multiple_of(4));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Actual: 10, which leaves remainder 2 when divided by 4"
+            )))
+        )
+    }
+
+    #[test]
+    fn divisible_by_explains_match() -> Result<()> {
+        verify_that!(divisible_by(4).explain_match(&8), displays_as(eq("which is divisible by 4")))
+    }
+}
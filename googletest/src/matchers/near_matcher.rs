@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{Matcher, MatcherResult};
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
 use num_traits::{Float, FloatConst};
 use std::fmt::Debug;
 
@@ -114,7 +114,50 @@ pub fn near<T: Debug + Float + Copy>(expected: T, max_abs_error: T) -> NearMatch
     if max_abs_error < T::zero() {
         panic!("max_abs_error must be non-negative");
     }
-    NearMatcher { expected, max_abs_error, nans_are_equal: false }
+    NearMatcher { expected, max_abs_error, max_rel_error: None, nans_are_equal: false }
+}
+
+/// Matches a value whose relative error with respect to `expected` is at most
+/// `max_rel_error`.
+///
+/// The relative error is the distance between the actual and expected values
+/// divided by the magnitude of `expected`. Unlike [`near`], this scales with
+/// the expected value, so a single tolerance works for values spanning orders
+/// of magnitude:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(1.0001, relative_near(1.0, 0.001))?; // Passes
+/// verify_that!(1000.1, relative_near(1000.0, 0.001))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(1.01, relative_near(1.0, 0.001))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The failure message reports the achieved error:
+///
+/// ```text
+/// Value of: 1.01
+/// Expected: is within a relative error of 0.001 of 1.0
+/// Actual: 1.01, which is 0.010000000000000009 away, a relative error of 0.010000000000000009
+/// ```
+///
+/// Since the tolerance vanishes as `expected` approaches zero, only zero is
+/// relatively near zero. To compare values which may be close to zero, also
+/// allow an absolute error with [`NearMatcher::with_relative_error`] on
+/// [`near`].
+///
+/// The value `max_rel_error` may not be NaN and must be non-negative. The
+/// matcher panics on construction otherwise. Special values are treated as by
+/// [`near`].
+pub fn relative_near<T: Debug + Float + Copy>(expected: T, max_rel_error: T) -> NearMatcher<T> {
+    near(expected, T::zero()).with_relative_error(max_rel_error)
 }
 
 /// Matches a value approximately equal to `expected`.
@@ -131,17 +174,52 @@ pub fn approx_eq<T: Debug + Float + FloatConst + Copy>(expected: T) -> NearMatch
     let five_bits_of_mantissa = (T::one() + T::one()).powi(5);
     let abs_tolerance = five_bits_of_mantissa * T::epsilon();
     let max_abs_error = T::max(expected.abs() * abs_tolerance, abs_tolerance);
-    NearMatcher { expected, max_abs_error, nans_are_equal: false }
+    NearMatcher { expected, max_abs_error, max_rel_error: None, nans_are_equal: false }
 }
 
 /// A matcher which matches floating-point numbers approximately equal to its
 /// expected value.
+#[derive(Clone, Copy)]
 pub struct NearMatcher<T: Debug> {
     expected: T,
     max_abs_error: T,
+    max_rel_error: Option<T>,
     nans_are_equal: bool,
 }
 
+impl<T: Debug + Float> NearMatcher<T> {
+    /// Configures this instance to also match values whose relative error
+    /// with respect to the expected value is at most `max_rel_error`.
+    ///
+    /// The resulting matcher accepts a value if it is within either the
+    /// absolute or the relative tolerance. This is useful when the expected
+    /// value may be close to zero, where a relative tolerance alone is too
+    /// strict:
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!(1e-12, near(0.0, 1e-9).with_relative_error(1e-6))?; // Passes
+    /// verify_that!(1e6 + 0.5, near(1e6, 1e-9).with_relative_error(1e-6))?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    ///
+    /// The value `max_rel_error` may not be NaN and must be non-negative. This
+    /// method panics otherwise.
+    pub fn with_relative_error(mut self, max_rel_error: T) -> Self {
+        if max_rel_error.is_nan() {
+            panic!("max_rel_error must not be NaN");
+        }
+        if max_rel_error < T::zero() {
+            panic!("max_rel_error must be non-negative");
+        }
+        self.max_rel_error = Some(max_rel_error);
+        self
+    }
+}
+
 impl<T: Debug> NearMatcher<T> {
     /// Configures this instance to treat two NaNs as equal.
     ///
@@ -173,27 +251,58 @@ impl<T: Debug + Float> Matcher for NearMatcher<T> {
 
         let delta = *actual - self.expected;
         if delta >= -self.max_abs_error && delta <= self.max_abs_error {
-            MatcherResult::Matches
-        } else {
-            MatcherResult::DoesNotMatch
+            return MatcherResult::Matches;
+        }
+        if let Some(max_rel_error) = self.max_rel_error {
+            let max_error = max_rel_error * self.expected.abs();
+            if delta >= -max_error && delta <= max_error {
+                return MatcherResult::Matches;
+            }
         }
+        MatcherResult::DoesNotMatch
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
-        match matcher_result {
-            MatcherResult::Matches => {
-                format!("is within {:?} of {:?}", self.max_abs_error, self.expected)
+        let tolerance = match self.max_rel_error {
+            None => format!("{:?}", self.max_abs_error),
+            Some(max_rel_error) if self.max_abs_error == T::zero() => {
+                format!("a relative error of {:?}", max_rel_error)
             }
+            Some(max_rel_error) => {
+                format!("{:?} or a relative error of {:?}", self.max_abs_error, max_rel_error)
+            }
+        };
+        match matcher_result {
+            MatcherResult::Matches => format!("is within {} of {:?}", tolerance, self.expected),
             MatcherResult::DoesNotMatch => {
-                format!("isn't within {:?} of {:?}", self.max_abs_error, self.expected)
+                format!("isn't within {} of {:?}", tolerance, self.expected)
             }
         }
     }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let error = (*actual - self.expected).abs();
+        if error.is_nan() {
+            return MatchExplanation::create(format!(
+                "which {}",
+                self.describe(self.matches(actual))
+            ));
+        }
+        if self.max_rel_error.is_some() && self.expected != T::zero() {
+            MatchExplanation::create(format!(
+                "which is {:?} away, a relative error of {:?}",
+                error,
+                error / self.expected.abs()
+            ))
+        } else {
+            MatchExplanation::create(format!("which is {:?} away", error))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{approx_eq, near};
+    use super::{approx_eq, near, relative_near};
     use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
 
@@ -344,4 +453,68 @@ mod tests {
     fn approx_eq_does_not_match_distant_number() -> Result<()> {
         verify_that!(0.0f64, not(approx_eq(1.0f64)))
     }
+
+    #[test]
+    fn relative_near_matches_value_within_relative_error() -> Result<()> {
+        verify_that!(1.0005f64, relative_near(1.0, 0.001))?;
+        verify_that!(1000.5f64, relative_near(1000.0, 0.001))?;
+        verify_that!(-1000.5f64, relative_near(-1000.0, 0.001))
+    }
+
+    #[test]
+    fn relative_near_does_not_match_value_outside_relative_error() -> Result<()> {
+        verify_that!(1.01f64, not(relative_near(1.0, 0.001)))?;
+        verify_that!(1010.0f64, not(relative_near(1000.0, 0.001)))
+    }
+
+    #[test]
+    fn relative_near_only_matches_zero_to_zero() -> Result<()> {
+        verify_that!(0.0f64, relative_near(0.0, 0.1))?;
+        verify_that!(1e-300f64, not(relative_near(0.0, 0.1)))
+    }
+
+    #[test]
+    fn relative_near_describes_relative_tolerance() -> Result<()> {
+        verify_that!(
+            relative_near(1.0f64, 0.5).describe(MatcherResult::Matches),
+            eq("is within a relative error of 0.5 of 1.0")
+        )
+    }
+
+    #[test]
+    fn relative_near_explains_achieved_error() -> Result<()> {
+        verify_that!(
+            relative_near(4.0f64, 0.1).explain_match(&5.0),
+            displays_as(eq("which is 1.0 away, a relative error of 0.25"))
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn relative_near_panics_if_tolerance_is_negative() {
+        relative_near(1.0f64, -1.0);
+    }
+
+    #[test]
+    fn near_with_relative_error_matches_within_either_tolerance() -> Result<()> {
+        let matcher = near(0.0f64, 0.01).with_relative_error(0.1);
+        verify_that!(0.005f64, matcher)?;
+
+        let matcher = near(100.0f64, 0.01).with_relative_error(0.1);
+        verify_that!(105.0f64, matcher)?;
+        verify_that!(115.0f64, not(matcher))
+    }
+
+    #[test]
+    fn near_with_relative_error_describes_both_tolerances() -> Result<()> {
+        verify_that!(
+            near(1.0f64, 0.25).with_relative_error(0.5).describe(MatcherResult::DoesNotMatch),
+            eq("isn't within 0.25 or a relative error of 0.5 of 1.0")
+        )
+    }
+
+    #[test]
+    fn near_explains_achieved_error() -> Result<()> {
+        verify_that!(near(1.0f64, 0.25).explain_match(&2.0), displays_as(eq("which is 1.0 away")))
+    }
 }
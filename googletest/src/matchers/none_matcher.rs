@@ -31,7 +31,7 @@ use std::marker::PhantomData;
 /// # should_pass().unwrap();
 /// # should_fail().unwrap_err();
 /// ```
-pub fn none<T: Debug>() -> impl Matcher<ActualT = Option<T>> {
+pub fn none<T: Debug>() -> impl Matcher<ActualT = Option<T>> + Copy {
     NoneMatcher::<T> { phantom: Default::default() }
 }
 
@@ -39,6 +39,14 @@ struct NoneMatcher<T> {
     phantom: PhantomData<T>,
 }
 
+impl<T> Clone for NoneMatcher<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NoneMatcher<T> {}
+
 impl<T: Debug> Matcher for NoneMatcher<T> {
     type ActualT = Option<T>;
 
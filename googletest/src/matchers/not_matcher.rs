@@ -12,8 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
-use std::{fmt::Debug, marker::PhantomData};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult, TestDebug};
+use std::marker::PhantomData;
 
 /// Matches the actual value exactly when the inner matcher does _not_ match.
 ///
@@ -30,7 +30,7 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # should_pass().unwrap();
 /// # should_fail().unwrap_err();
 /// ```
-pub fn not<T: Debug, InnerMatcherT: Matcher<ActualT = T>>(
+pub fn not<T: TestDebug, InnerMatcherT: Matcher<ActualT = T>>(
     inner: InnerMatcherT,
 ) -> impl Matcher<ActualT = T> {
     NotMatcher::<T, _> { inner, phantom: Default::default() }
@@ -41,7 +41,7 @@ struct NotMatcher<T, InnerMatcherT> {
     phantom: PhantomData<T>,
 }
 
-impl<T: Debug, InnerMatcherT: Matcher<ActualT = T>> Matcher for NotMatcher<T, InnerMatcherT> {
+impl<T: TestDebug, InnerMatcherT: Matcher<ActualT = T>> Matcher for NotMatcher<T, InnerMatcherT> {
     type ActualT = T;
 
     fn matches(&self, actual: &T) -> MatcherResult {
@@ -52,7 +52,8 @@ impl<T: Debug, InnerMatcherT: Matcher<ActualT = T>> Matcher for NotMatcher<T, In
     }
 
     fn explain_match(&self, actual: &T) -> MatchExplanation {
-        self.inner.explain_match(actual)
+        let inner = explain(&self.inner, actual);
+        MatchExplanation::create(inner.to_string()).with_child(inner)
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Matches a `Result` containing `Ok` with a value matched by `inner`.
@@ -58,10 +58,10 @@ impl<T: Debug, E: Debug, InnerMatcherT: Matcher<ActualT = T>> Matcher
 
     fn explain_match(&self, actual: &Self::ActualT) -> MatchExplanation {
         match actual {
-            Ok(o) => MatchExplanation::create(format!(
-                "which is a success {}",
-                self.inner.explain_match(o)
-            )),
+            Ok(o) => {
+                let inner = explain(&self.inner, o);
+                MatchExplanation::create(format!("which is a success {inner}")).with_child(inner)
+            }
             Err(_) => MatchExplanation::create("which is an error".to_string()),
         }
     }
@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::cell::RefCell;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
 
 /// Matches a (smart) pointer pointing to a value matched by the [`Matcher`]
 /// `expected`.
@@ -31,25 +34,57 @@ use std::ops::Deref;
 /// # }
 /// # should_pass().unwrap();
 /// ```
+///
+/// The pointee may be unsized. In particular, this matches the contents of a
+/// [`Cow`][std::borrow::Cow] with matchers for the borrowed type:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::borrow::Cow;
+/// # fn should_pass() -> Result<()> {
+/// let numbers: Cow<[i32]> = Cow::Owned(vec![1, 2, 3]);
+/// verify_that!(numbers, points_to(elements_are![eq(1), eq(2), eq(3)]))?;
+/// verify_that!(numbers, points_to(contains(eq(2))))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// A `Cow<str>` needs no such adapter, since the string matchers and [`eq`]
+/// accept it directly.
+///
+/// [`eq`]: crate::matchers::eq
 pub fn points_to<ExpectedT, MatcherT, ActualT>(
     expected: MatcherT,
 ) -> impl Matcher<ActualT = ActualT>
 where
-    ExpectedT: Debug,
+    ExpectedT: Debug + ?Sized,
     MatcherT: Matcher<ActualT = ExpectedT>,
     ActualT: Deref<Target = ExpectedT> + Debug + ?Sized,
 {
-    PointsToMatcher { expected, phantom: Default::default() }
+    PointsToMatcher::new(expected)
 }
 
-struct PointsToMatcher<ActualT: ?Sized, MatcherT> {
+/// A matcher which dereferences the actual value and matches the result with
+/// the given matcher.
+///
+/// Intended only to be used from the function [`points_to`] and its variants
+/// only. Should not be referenced by code outside this library.
+#[doc(hidden)]
+pub struct PointsToMatcher<ActualT: ?Sized, MatcherT> {
     expected: MatcherT,
     phantom: PhantomData<ActualT>,
 }
 
+impl<ActualT: ?Sized, MatcherT> PointsToMatcher<ActualT, MatcherT> {
+    pub(crate) fn new(expected: MatcherT) -> Self {
+        Self { expected, phantom: Default::default() }
+    }
+}
+
 impl<ExpectedT, MatcherT, ActualT> Matcher for PointsToMatcher<ActualT, MatcherT>
 where
-    ExpectedT: Debug,
+    ExpectedT: Debug + ?Sized,
     MatcherT: Matcher<ActualT = ExpectedT>,
     ActualT: Deref<Target = ExpectedT> + Debug + ?Sized,
 {
@@ -60,7 +95,131 @@ where
     }
 
     fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
-        self.expected.explain_match(actual.deref())
+        let inner = explain(&self.expected, actual.deref());
+        MatchExplanation::create(inner.to_string()).with_child(inner)
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        self.expected.describe(matcher_result)
+    }
+}
+
+/// Matches a [`Box`] pointing to a value matched by the [`Matcher`]
+/// `expected`.
+///
+/// This is the same as [`points_to`] restricted to [`Box`], which helps type
+/// inference when the pointer type is not otherwise known, for example inside
+/// [`field!`][crate::field]:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// #[derive(Debug)]
+/// struct Node {
+///     next: Box<i32>,
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Node { next: Box::new(123) }, field!(Node.next, points_to_boxed(eq(123))))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+pub fn points_to_boxed<ExpectedT: Debug, MatcherT: Matcher<ActualT = ExpectedT>>(
+    expected: MatcherT,
+) -> impl Matcher<ActualT = Box<ExpectedT>> {
+    PointsToMatcher { expected, phantom: Default::default() }
+}
+
+/// Matches an [`Rc`] pointing to a value matched by the [`Matcher`]
+/// `expected`.
+///
+/// This is the same as [`points_to`] restricted to [`Rc`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::rc::Rc;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Rc::new(123), rc_contains(eq(123)))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+pub fn rc_contains<ExpectedT: Debug, MatcherT: Matcher<ActualT = ExpectedT>>(
+    expected: MatcherT,
+) -> impl Matcher<ActualT = Rc<ExpectedT>> {
+    PointsToMatcher { expected, phantom: Default::default() }
+}
+
+/// Matches an [`Arc`] pointing to a value matched by the [`Matcher`]
+/// `expected`.
+///
+/// This is the same as [`points_to`] restricted to [`Arc`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::sync::Arc;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Arc::new(123), arc_contains(eq(123)))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+pub fn arc_contains<ExpectedT: Debug, MatcherT: Matcher<ActualT = ExpectedT>>(
+    expected: MatcherT,
+) -> impl Matcher<ActualT = Arc<ExpectedT>> {
+    PointsToMatcher { expected, phantom: Default::default() }
+}
+
+/// Matches a [`RefCell`] whose value is matched by the [`Matcher`]
+/// `expected`.
+///
+/// The value is borrowed immutably for the duration of the match. A
+/// [`RefCell`] which is currently borrowed mutably does not match.
+///
+/// Since [`Rc`] and [`Arc`] dereference to their contents, this combines
+/// with [`points_to`] to match shared mutable structures:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::{cell::RefCell, rc::Rc};
+/// # fn should_pass() -> Result<()> {
+/// let shared = Rc::new(RefCell::new(vec![1, 2]));
+/// shared.borrow_mut().push(3);
+/// verify_that!(shared, points_to(ref_cell_contains(elements_are![eq(1), eq(2), eq(3)])))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+pub fn ref_cell_contains<ExpectedT: Debug, MatcherT: Matcher<ActualT = ExpectedT>>(
+    expected: MatcherT,
+) -> impl Matcher<ActualT = RefCell<ExpectedT>> {
+    RefCellContainsMatcher { expected }
+}
+
+struct RefCellContainsMatcher<MatcherT> {
+    expected: MatcherT,
+}
+
+impl<ExpectedT: Debug, MatcherT: Matcher<ActualT = ExpectedT>> Matcher
+    for RefCellContainsMatcher<MatcherT>
+{
+    type ActualT = RefCell<ExpectedT>;
+
+    fn matches(&self, actual: &RefCell<ExpectedT>) -> MatcherResult {
+        match actual.try_borrow() {
+            Ok(value) => self.expected.matches(&value),
+            Err(_) => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &RefCell<ExpectedT>) -> MatchExplanation {
+        match actual.try_borrow() {
+            Ok(value) => {
+                let inner = explain(&self.expected, &value);
+                MatchExplanation::create(inner.to_string()).with_child(inner)
+            }
+            Err(_) => MatchExplanation::create("which is currently borrowed mutably".to_string()),
+        }
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
@@ -70,9 +229,13 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::points_to;
+    use super::{arc_contains, points_to, points_to_boxed, rc_contains, ref_cell_contains};
+    use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
+    use std::borrow::Cow;
+    use std::cell::RefCell;
     use std::rc::Rc;
+    use std::sync::Arc;
 
     #[test]
     fn points_to_matches_box_of_int_with_int() -> Result<()> {
@@ -89,6 +252,23 @@ mod tests {
         verify_that!(Rc::new("A string".to_string()), points_to(eq("A string")))
     }
 
+    #[test]
+    fn points_to_matches_cow_of_slice_with_container_matcher() -> Result<()> {
+        let value: Cow<[i32]> = Cow::Owned(vec![1, 2, 3]);
+
+        verify_that!(value, points_to(elements_are![eq(1), eq(2), eq(3)]))?;
+        verify_that!(value, points_to(contains(eq(2))))?;
+        verify_that!(value, points_to(len(eq(3))))
+    }
+
+    #[test]
+    fn points_to_matches_borrowed_cow_of_int_with_int() -> Result<()> {
+        let value = 123;
+        let value: Cow<i32> = Cow::Borrowed(&value);
+
+        verify_that!(value, points_to(gt(100)))
+    }
+
     #[test]
     fn match_explanation_references_actual_value() -> Result<()> {
         let result = verify_that!(&vec![1], points_to(container_eq([])));
@@ -104,4 +284,49 @@ Actual: [
             )))
         )
     }
+
+    #[test]
+    fn points_to_boxed_matches_box_of_int_with_int() -> Result<()> {
+        verify_that!(Box::new(123), points_to_boxed(eq(123)))
+    }
+
+    #[test]
+    fn rc_contains_matches_rc_of_string_with_string_reference() -> Result<()> {
+        verify_that!(Rc::new("A string".to_string()), rc_contains(eq("A string")))
+    }
+
+    #[test]
+    fn arc_contains_does_not_match_arc_of_other_value() -> Result<()> {
+        verify_that!(Arc::new(123), not(arc_contains(eq(234))))
+    }
+
+    #[test]
+    fn smart_pointer_matchers_describe_only_the_pointee() -> Result<()> {
+        verify_that!(arc_contains(eq(123)).describe(MatcherResult::Matches), eq("is equal to 123"))
+    }
+
+    #[test]
+    fn ref_cell_contains_matches_ref_cell_of_int_with_int() -> Result<()> {
+        verify_that!(RefCell::new(123), ref_cell_contains(eq(123)))
+    }
+
+    #[test]
+    fn ref_cell_contains_matches_ref_cell_behind_rc() -> Result<()> {
+        let shared = Rc::new(RefCell::new(123));
+        *shared.borrow_mut() += 1;
+
+        verify_that!(shared, points_to(ref_cell_contains(eq(124))))
+    }
+
+    #[test]
+    fn ref_cell_contains_does_not_match_mutably_borrowed_ref_cell() -> Result<()> {
+        let cell = RefCell::new(123);
+        let _borrow = cell.borrow_mut();
+
+        verify_that!(ref_cell_contains(eq(123)).matches(&cell), eq(MatcherResult::DoesNotMatch))?;
+        verify_that!(
+            ref_cell_contains(eq(123)).explain_match(&cell),
+            displays_as(eq("which is currently borrowed mutably"))
+        )
+    }
 }
@@ -16,6 +16,10 @@
 // macro is documented at the top level.
 #![doc(hidden)]
 
+use crate::matcher::Matcher;
+use crate::matcher_support::ordered_container::OrderedContainer;
+use std::fmt::Debug;
+
 /// Generates a matcher which matches a container each of whose elements match
 /// the given matcher name applied respectively to each element of the given
 /// container.
@@ -52,8 +56,12 @@
 /// # should_pass().unwrap();
 /// ```
 ///
-/// One can pass up to three containers to supply arguments to the function
-/// creating the matcher:
+/// With a single container, this is equivalent to the function
+/// [`pointwise_fn`][crate::matchers::pointwise_fn].
+///
+/// One can pass up to eight containers to supply arguments to the function
+/// creating the matcher. The function receives one item of each container, in
+/// the order in which the containers are given:
 ///
 /// ```
 /// # use googletest::prelude::*;
@@ -94,8 +102,13 @@
 /// ```
 ///
 /// This matcher does not support matching directly against an [`Iterator`]. To
-/// match against an iterator, use [`Iterator::collect`] to build a [`Vec`]
-/// first.
+/// match against an iterator, wrap it with [`yielded`][crate::matchers::yielded]
+/// or use [`Iterator::collect`] to build a [`Vec`] first.
+///
+/// Since the elements are matched in iteration order, the actual value must
+/// implement [`OrderedContainer`][crate::matcher_support::ordered_container::OrderedContainer]. A [`HashMap`][std::collections::HashMap]
+/// or [`HashSet`][std::collections::HashSet], whose iteration order is
+/// unspecified, is rejected at compile time.
 ///
 /// The second argument can be any value implementing `IntoIterator`, such as a
 /// `Vec` or an array. The container does not have to have the same type as the
@@ -121,27 +134,89 @@ macro_rules! pointwise {
         PointwiseMatcher::new($container.into_iter().map($matcher).collect())
     }};
 
-    ($matcher:expr, $left_container:expr, $right_container:expr) => {{
+    ($matcher:expr, $first_container:expr $(, $container:expr)+ $(,)?) => {{
         use $crate::matchers::pointwise_matcher::internal::PointwiseMatcher;
-        PointwiseMatcher::new(
-            $left_container
-                .into_iter()
-                .zip($right_container.into_iter())
-                .map(|(l, r)| $matcher(l, r))
-                .collect(),
-        )
+        PointwiseMatcher::new($crate::__pointwise_zip!(
+            $matcher;
+            first;
+            [first];
+            [second third fourth fifth sixth seventh eighth];
+            $first_container.into_iter();
+            $($container),+
+        ))
     }};
+}
 
-    ($matcher:expr, $left_container:expr, $middle_container:expr, $right_container:expr) => {{
-        use $crate::matchers::pointwise_matcher::internal::PointwiseMatcher;
-        PointwiseMatcher::new(
-            $left_container
-                .into_iter()
-                .zip($right_container.into_iter().zip($middle_container.into_iter()))
-                .map(|(l, (m, r))| $matcher(l, m, r))
-                .collect(),
+/// Zips the containers of an invocation of [`pointwise!`][crate::pointwise]
+/// with more than one container and applies its matcher factory to the items.
+///
+/// The items are bound to the identifiers taken from the third bracketed
+/// list, so this supports up to eight containers.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pointwise_zip {
+    ($matcher:expr; $pattern:tt; [$($item:ident)*]; [$($unused:ident)*]; $zipped:expr;) => {
+        $zipped.map(|$pattern| $matcher($($item),*)).collect()
+    };
+
+    (
+        $matcher:expr;
+        $pattern:tt;
+        [$($item:ident)*];
+        [$next:ident $($unused:ident)*];
+        $zipped:expr;
+        $container:expr $(, $rest:expr)*
+    ) => {
+        $crate::__pointwise_zip!(
+            $matcher;
+            ($pattern, $next);
+            [$($item)* $next];
+            [$($unused)*];
+            $zipped.zip($container.into_iter());
+            $($rest),*
         )
-    }};
+    };
+}
+
+/// Matches a container each of whose elements matches the matcher which
+/// `matcher_factory` creates from the corresponding item of `expected`.
+///
+/// This is the function form of [`pointwise!`][crate::pointwise] with a single
+/// expected container. Since it is an ordinary function, the factory can be
+/// any closure or function, and the resulting matcher can be stored or
+/// returned from a helper without repeating the macro invocation:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let expected_values = vec![1.0, 2.0, 3.0];
+/// verify_that!(vec![1.001, 2.0, 2.999], pointwise_fn(|e| near(e, 0.01), expected_values))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], pointwise_fn(le, [1, 1, 3]))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The explanations and the restrictions on the actual value are those of
+/// `pointwise!`.
+pub fn pointwise_fn<T, ContainerT, ExpectedT, MatcherT>(
+    matcher_factory: impl FnMut(ExpectedT::Item) -> MatcherT,
+    expected: ExpectedT,
+) -> impl Matcher<ActualT = ContainerT>
+where
+    T: Debug,
+    ContainerT: Debug + OrderedContainer + ?Sized,
+    ExpectedT: IntoIterator,
+    MatcherT: Matcher<ActualT = T>,
+    for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
+{
+    internal::PointwiseMatcher::new(expected.into_iter().map(matcher_factory).collect())
 }
 
 /// Module for use only by the procedural macros in this module.
@@ -149,8 +224,9 @@ macro_rules! pointwise {
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
 pub mod internal {
-    use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
     use crate::matcher_support::description::Description;
+    use crate::matcher_support::ordered_container::OrderedContainer;
     use crate::matcher_support::zipped_iterator::zip;
     use std::{fmt::Debug, marker::PhantomData};
 
@@ -169,8 +245,8 @@ pub mod internal {
         }
     }
 
-    impl<T: Debug, MatcherT: Matcher<ActualT = T>, ContainerT: ?Sized + Debug> Matcher
-        for PointwiseMatcher<ContainerT, MatcherT>
+    impl<T: Debug, MatcherT: Matcher<ActualT = T>, ContainerT: ?Sized + Debug + OrderedContainer>
+        Matcher for PointwiseMatcher<ContainerT, MatcherT>
     where
         for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
     {
@@ -197,9 +273,12 @@ pub mod internal {
             let actual_iterator = actual.into_iter();
             let mut zipped_iterator = zip(actual_iterator, self.matchers.iter());
             let mut mismatches = Vec::new();
+            let mut children = Vec::new();
             for (idx, (a, e)) in zipped_iterator.by_ref().enumerate() {
                 if !e.matches(a).into_bool() {
-                    mismatches.push(format!("element #{idx} is {a:?}, {}", e.explain_match(a)));
+                    let inner = explain(e, a);
+                    mismatches.push(format!("element #{idx} is {a:?}, {inner}"));
+                    children.push(inner);
                 }
             }
             if mismatches.is_empty() {
@@ -213,10 +292,11 @@ pub mod internal {
                     ))
                 }
             } else if mismatches.len() == 1 {
-                MatchExplanation::create(format!("where {}", mismatches[0]))
+                MatchExplanation::create(format!("where {}", mismatches[0])).with_children(children)
             } else {
                 let mismatches = mismatches.into_iter().collect::<Description>();
                 MatchExplanation::create(format!("where:\n{}", mismatches.bullet_list().indent()))
+                    .with_children(children)
             }
         }
 
@@ -0,0 +1,264 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Matchers for the element of a container at a given position.
+//!
+//! These match any container over whose reference one can iterate, and apply
+//! a matcher to a single one of its elements, so that a test need not collect
+//! and index the container itself.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// Matches a container whose first element `inner` matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], first(eq(1)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail_1() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], first(eq(2)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # fn should_fail_2() -> Result<()> {
+/// verify_that!(Vec::<i32>::new(), first(anything()))?; // Fails: no first element
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail_1().unwrap_err();
+/// # should_fail_2().unwrap_err();
+/// ```
+///
+/// An empty container does not match.
+pub fn first<ElementT: Debug, ContainerT: Debug + ?Sized>(
+    inner: impl Matcher<ActualT = ElementT>,
+) -> impl Matcher<ActualT = ContainerT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    PositionalMatcher { inner, position: Position::First, phantom: Default::default() }
+}
+
+/// Matches a container whose last element `inner` matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], last(gt(2)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], last(eq(1)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// An empty container does not match.
+pub fn last<ElementT: Debug, ContainerT: Debug + ?Sized>(
+    inner: impl Matcher<ActualT = ElementT>,
+) -> impl Matcher<ActualT = ContainerT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    PositionalMatcher { inner, position: Position::Last, phantom: Default::default() }
+}
+
+/// Matches a container whose element at the zero-based `index` `inner`
+/// matches.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec!["a", "b", "c"], nth(1, eq("b")))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec!["a", "b", "c"], nth(3, anything()))?; // Fails: too short
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// A container with no more than `index` elements does not match, and the
+/// explanation then reports its size.
+pub fn nth<ElementT: Debug, ContainerT: Debug + ?Sized>(
+    index: usize,
+    inner: impl Matcher<ActualT = ElementT>,
+) -> impl Matcher<ActualT = ContainerT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    PositionalMatcher { inner, position: Position::Nth(index), phantom: Default::default() }
+}
+
+enum Position {
+    First,
+    Last,
+    Nth(usize),
+}
+
+struct PositionalMatcher<InnerMatcherT, ContainerT: ?Sized> {
+    inner: InnerMatcherT,
+    position: Position,
+    phantom: PhantomData<ContainerT>,
+}
+
+impl<InnerMatcherT, ContainerT: ?Sized> PositionalMatcher<InnerMatcherT, ContainerT> {
+    /// Returns the element at the position together with its index, or `None`
+    /// together with the size of `actual` if it has no such element.
+    fn element<'a, ElementT>(
+        &self,
+        actual: &'a ContainerT,
+    ) -> std::result::Result<(usize, &'a ElementT), usize>
+    where
+        &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+    {
+        let mut elements = actual.into_iter();
+        match self.position {
+            Position::First => elements.next().map(|element| (0, element)).ok_or(0),
+            Position::Last => elements.enumerate().last().ok_or(0),
+            Position::Nth(index) => {
+                let mut size = 0;
+                for element in elements {
+                    if size == index {
+                        return Ok((index, element));
+                    }
+                    size += 1;
+                }
+                Err(size)
+            }
+        }
+    }
+
+    fn position_name(&self) -> String {
+        match self.position {
+            Position::First => "first element".to_string(),
+            Position::Last => "last element".to_string(),
+            Position::Nth(index) => format!("element #{index}"),
+        }
+    }
+}
+
+impl<ElementT: Debug, InnerMatcherT: Matcher<ActualT = ElementT>, ContainerT: Debug + ?Sized>
+    Matcher for PositionalMatcher<InnerMatcherT, ContainerT>
+where
+    for<'a> &'a ContainerT: IntoIterator<Item = &'a ElementT>,
+{
+    type ActualT = ContainerT;
+
+    fn matches(&self, actual: &ContainerT) -> MatcherResult {
+        match self.element(actual) {
+            Ok((_, element)) => self.inner.matches(element),
+            Err(_) => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!(
+                "has a {}, which {}",
+                self.position_name(),
+                self.inner.describe(MatcherResult::Matches)
+            ),
+            MatcherResult::DoesNotMatch => format!(
+                "doesn't have a {}, or has one which {}",
+                self.position_name(),
+                self.inner.describe(MatcherResult::DoesNotMatch)
+            ),
+        }
+    }
+
+    fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+        match self.element(actual) {
+            Ok((index, element)) => {
+                let inner = explain(&self.inner, element);
+                MatchExplanation::create(format!("whose element #{index} is {element:?}, {inner}"))
+                    .with_child(inner)
+            }
+            Err(0) => MatchExplanation::create("which is empty".to_string()),
+            Err(size) => MatchExplanation::create(format!(
+                "which has no {}, since its size is {size}",
+                self.position_name()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{first, last, nth};
+    use crate::prelude::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn first_matches_first_element() -> Result<()> {
+        expect_that!(vec![1, 2], first(eq(1)));
+        verify_that!(vec![1, 2], not(first(eq(2))))
+    }
+
+    #[test]
+    fn last_matches_last_element() -> Result<()> {
+        expect_that!(VecDeque::from([1, 2]), last(eq(2)));
+        verify_that!(vec![1, 2], not(last(eq(1))))
+    }
+
+    #[test]
+    fn nth_matches_element_at_index() -> Result<()> {
+        expect_that!(vec![1, 2, 3], nth(2, eq(3)));
+        verify_that!(vec![1, 2, 3], not(nth(1, eq(3))))
+    }
+
+    #[test]
+    fn positional_matchers_do_not_match_empty_container() -> Result<()> {
+        expect_that!(Vec::<i32>::new(), not(first(anything())));
+        expect_that!(Vec::<i32>::new(), not(last(anything())));
+        verify_that!(Vec::<i32>::new(), not(nth(0, anything())))
+    }
+
+    #[test]
+    fn last_explains_mismatch_with_index() -> Result<()> {
+        let result = verify_that!(vec![1, 2, 3], last(eq(2)));
+
+        verify_that!(
+            result,
+            err(displays_as(
+                contains_substring("Expected: has a last element, which is equal to 2\n")
+                    .and(contains_substring("whose element #2 is 3, which isn't equal to 2"))
+            ))
+        )
+    }
+
+    #[test]
+    fn nth_explains_container_too_short() -> Result<()> {
+        let result = verify_that!(vec![1, 2], nth(5, eq(1)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("which has no element #5, since its size is 2")))
+        )
+    }
+
+    #[test]
+    fn first_explains_empty_container() -> Result<()> {
+        let result = verify_that!(Vec::<i32>::new(), first(eq(1)));
+
+        verify_that!(result, err(displays_as(contains_substring("Actual: [], which is empty"))))
+    }
+}
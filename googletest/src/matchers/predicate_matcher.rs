@@ -12,8 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{Matcher, MatcherResult};
-use std::{fmt::Debug, marker::PhantomData};
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult, TestDebug};
+use std::marker::PhantomData;
 
 /// Creates a matcher based on the predicate provided.
 ///
@@ -33,7 +33,7 @@ use std::{fmt::Debug, marker::PhantomData};
 /// the closure argument, it is likely that it won't.
 /// See <https://github.com/rust-lang/rust/issues/12679> for update on this issue.
 /// This is easily fixed by explicitly declaring the type of the argument
-pub fn predicate<T: Debug + ?Sized, P>(
+pub fn predicate<T: TestDebug + ?Sized, P>(
     predicate: P,
 ) -> PredicateMatcher<T, P, NoDescription, NoDescription>
 where
@@ -43,11 +43,12 @@ where
         predicate,
         positive_description: NoDescription,
         negative_description: NoDescription,
+        explanation: NoExplanation,
         phantom: Default::default(),
     }
 }
 
-impl<T, P> PredicateMatcher<T, P, NoDescription, NoDescription> {
+impl<T: ?Sized, P, E> PredicateMatcher<T, P, NoDescription, NoDescription, E> {
     /// Configures this instance to provide a more meaningful description.
     ///
     /// For example, to make sure the error message is more useful
@@ -67,11 +68,49 @@ impl<T, P> PredicateMatcher<T, P, NoDescription, NoDescription> {
         self,
         positive_description: D1,
         negative_description: D2,
-    ) -> PredicateMatcher<T, P, D1, D2> {
+    ) -> PredicateMatcher<T, P, D1, D2, E> {
         PredicateMatcher {
             predicate: self.predicate,
             positive_description,
             negative_description,
+            explanation: self.explanation,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T: ?Sized, P, D1, D2> PredicateMatcher<T, P, D1, D2, NoExplanation> {
+    /// Configures this instance to explain why a given value does or does not
+    /// satisfy the predicate.
+    ///
+    /// By default, the explanation only restates the description. For complex
+    /// conditions, `explanation` can instead compute a tailored message from
+    /// the actual value:
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_fail() -> Result<()> {
+    /// verify_that!(
+    ///     vec![1, 2, 3],
+    ///     predicate(|v: &Vec<i32>| v.iter().sum::<i32>() == 10)
+    ///         .with_description("sums to 10", "doesn't sum to 10")
+    ///         .with_explanation(|v: &Vec<i32>| format!("which sums to {}", v.iter().sum::<i32>()))
+    /// )
+    /// # }
+    /// # should_fail().unwrap_err();
+    /// ```
+    ///
+    /// The returned string is shown after the actual value in the failure
+    /// message, so it should read like the default `which ...` explanations.
+    pub fn with_explanation<E>(self, explanation: E) -> PredicateMatcher<T, P, D1, D2, E>
+    where
+        for<'a> E: Fn(&'a T) -> String,
+    {
+        PredicateMatcher {
+            predicate: self.predicate,
+            positive_description: self.positive_description,
+            negative_description: self.negative_description,
+            explanation,
             phantom: Default::default(),
         }
     }
@@ -80,13 +119,30 @@ impl<T, P> PredicateMatcher<T, P, NoDescription, NoDescription> {
 /// A matcher which applies `predicate` on the value.
 ///
 /// See [`predicate`].
-pub struct PredicateMatcher<T: ?Sized, P, D1, D2> {
+pub struct PredicateMatcher<T: ?Sized, P, D1, D2, E = NoExplanation> {
     predicate: P,
     positive_description: D1,
     negative_description: D2,
+    explanation: E,
     phantom: PhantomData<T>,
 }
 
+impl<T: ?Sized, P: Clone, D1: Clone, D2: Clone, E: Clone> Clone
+    for PredicateMatcher<T, P, D1, D2, E>
+{
+    fn clone(&self) -> Self {
+        Self {
+            predicate: self.predicate.clone(),
+            positive_description: self.positive_description.clone(),
+            negative_description: self.negative_description.clone(),
+            explanation: self.explanation.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized, P: Copy, D1: Copy, D2: Copy, E: Copy> Copy for PredicateMatcher<T, P, D1, D2, E> {}
+
 /// A trait to allow [`PredicateMatcher::with_description`] to accept multiple
 /// types.
 ///
@@ -117,11 +173,41 @@ where
     }
 }
 
+/// A trait to allow [`PredicateMatcher::with_explanation`] to be optional.
+///
+/// See [`PredicateMatcher::with_explanation`]
+#[doc(hidden)]
+pub trait PredicateExplanation<T: ?Sized> {
+    fn to_explanation(&self, actual: &T) -> Option<String>;
+}
+
+impl<T: ?Sized> PredicateExplanation<T> for NoExplanation {
+    fn to_explanation(&self, _: &T) -> Option<String> {
+        None
+    }
+}
+
+impl<T: ?Sized, E> PredicateExplanation<T> for E
+where
+    for<'a> E: Fn(&'a T) -> String,
+{
+    fn to_explanation(&self, actual: &T) -> Option<String> {
+        Some(self(actual))
+    }
+}
+
 // Sentinel type to tag a MatcherBuilder as without a description.
 #[doc(hidden)]
+#[derive(Clone, Copy)]
 pub struct NoDescription;
 
-impl<T: Debug, P> Matcher for PredicateMatcher<T, P, NoDescription, NoDescription>
+// Sentinel type to tag a MatcherBuilder as without a custom explanation.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct NoExplanation;
+
+impl<T: TestDebug + ?Sized, P, E: PredicateExplanation<T>> Matcher
+    for PredicateMatcher<T, P, NoDescription, NoDescription, E>
 where
     for<'a> P: Fn(&'a T) -> bool,
 {
@@ -137,10 +223,24 @@ where
             MatcherResult::DoesNotMatch => "does not match".to_string(),
         }
     }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        match self.explanation.to_explanation(actual) {
+            Some(explanation) => MatchExplanation::create(explanation),
+            None => {
+                MatchExplanation::create(format!("which {}", self.describe(self.matches(actual))))
+            }
+        }
+    }
 }
 
-impl<T: Debug, P, D1: PredicateDescription, D2: PredicateDescription> Matcher
-    for PredicateMatcher<T, P, D1, D2>
+impl<
+    T: TestDebug + ?Sized,
+    P,
+    D1: PredicateDescription,
+    D2: PredicateDescription,
+    E: PredicateExplanation<T>,
+> Matcher for PredicateMatcher<T, P, D1, D2, E>
 where
     for<'a> P: Fn(&'a T) -> bool,
 {
@@ -156,12 +256,21 @@ where
             MatcherResult::DoesNotMatch => self.negative_description.to_description(),
         }
     }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        match self.explanation.to_explanation(actual) {
+            Some(explanation) => MatchExplanation::create(explanation),
+            None => {
+                MatchExplanation::create(format!("which {}", self.describe(self.matches(actual))))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::predicate;
-    use crate::matcher::Matcher;
+    use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
 
     // Simple matcher with a description
@@ -225,4 +334,53 @@ mod tests {
         use std::time::Duration;
         verify_that!(Duration::new(0, 0), predicate(Duration::is_zero))
     }
+
+    #[test]
+    fn predicate_matcher_can_be_copied_and_reused() -> Result<()> {
+        let matcher = predicate(|x: &i32| x % 2 == 1).with_description("is odd", "is even");
+        verify_that!(1, matcher)?;
+        verify_that!(vec![3, 5], each(matcher))
+    }
+
+    #[test]
+    fn predicate_matcher_with_explanation_uses_explanation() -> Result<()> {
+        let matcher = predicate(|v: &Vec<i32>| v.iter().sum::<i32>() == 10)
+            .with_description("sums to 10", "doesn't sum to 10")
+            .with_explanation(|v: &Vec<i32>| format!("which sums to {}", v.iter().sum::<i32>()));
+
+        verify_that!(matcher.explain_match(&vec![1, 2, 3]), displays_as(eq("which sums to 6")))
+    }
+
+    #[test]
+    fn predicate_matcher_with_explanation_before_description() -> Result<()> {
+        let matcher = predicate(|x: &i32| *x > 100)
+            .with_explanation(|x: &i32| format!("which is {} short", 101 - x))
+            .with_description("is large", "is small");
+
+        verify_that!(matcher.explain_match(&1), displays_as(eq("which is 100 short")))
+    }
+
+    #[test]
+    fn predicate_matcher_with_explanation_without_description() -> Result<()> {
+        let matcher = predicate(|x: &i32| *x > 100)
+            .with_explanation(|x: &i32| format!("which is {} short", 101 - x));
+
+        verify_that!(matcher.describe(MatcherResult::Matches), eq("matches"))?;
+        verify_that!(matcher.explain_match(&91), displays_as(eq("which is 10 short")))
+    }
+
+    #[test]
+    fn predicate_matcher_with_explanation_shows_explanation_in_failure() -> Result<()> {
+        let result = verify_that!(
+            3,
+            predicate(|x: &i32| x % 2 == 0)
+                .with_description("is even", "is odd")
+                .with_explanation(|x: &i32| format!("which has remainder {}", x % 2))
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring("Actual: 3, which has remainder 1")))
+        )
+    }
 }
@@ -39,6 +39,23 @@
 /// #    .unwrap();
 /// ```
 ///
+/// The inner matcher must match the type which the method returns. Otherwise,
+/// compilation fails with an error at the inner matcher:
+///
+/// ```compile_fail
+/// # use googletest::prelude::*;
+/// # #[derive(Debug)]
+/// # pub struct MyStruct {
+/// #     a_field: u32,
+/// # }
+/// # impl MyStruct {
+/// #     pub fn get_a_field(&self) -> u32 { self.a_field }
+/// # }
+/// let value = MyStruct { a_field: 100 };
+/// verify_that!(value, property!(MyStruct.get_a_field(), starts_with("1")))
+/// #    .unwrap();
+/// ```
+///
 /// **Important**: The method should be pure function with a deterministic
 /// output and no side effects. In particular, in the event of an assertion
 /// failure, it will be invoked a second time, with the assertion failure output
@@ -134,20 +151,32 @@ macro_rules! property_internal {
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
 pub mod internal {
-    use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
-    use std::{fmt::Debug, marker::PhantomData};
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherOf, MatcherResult, TestDebug};
+    use crate::matcher_support::debug_layout::debug_string;
+    use std::marker::PhantomData;
 
     /// **For internal use only. API stablility is not guaranteed!**
     #[doc(hidden)]
-    pub fn property_matcher<OuterT: Debug, InnerT: Debug, MatcherT: Matcher<ActualT = InnerT>>(
-        extractor: impl Fn(&OuterT) -> InnerT,
+    pub fn property_matcher<OuterT, InnerT, ExtractorT, MatcherT>(
+        extractor: ExtractorT,
         property_desc: &'static str,
         inner: MatcherT,
-    ) -> impl Matcher<ActualT = OuterT> {
+    ) -> PropertyMatcher<OuterT, ExtractorT, MatcherT>
+    where
+        OuterT: TestDebug,
+        InnerT: TestDebug,
+        ExtractorT: Fn(&OuterT) -> InnerT,
+        MatcherT: MatcherOf<InnerT>,
+    {
         PropertyMatcher { extractor, property_desc, inner, phantom: Default::default() }
     }
 
-    struct PropertyMatcher<OuterT, ExtractorT, MatcherT> {
+    /// The matcher created by [`property!`][crate::property] for a property
+    /// returned by value.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct PropertyMatcher<OuterT, ExtractorT, MatcherT> {
         extractor: ExtractorT,
         property_desc: &'static str,
         inner: MatcherT,
@@ -156,8 +185,8 @@ pub mod internal {
 
     impl<InnerT, OuterT, ExtractorT, MatcherT> Matcher for PropertyMatcher<OuterT, ExtractorT, MatcherT>
     where
-        InnerT: Debug,
-        OuterT: Debug,
+        InnerT: TestDebug,
+        OuterT: TestDebug,
         ExtractorT: Fn(&OuterT) -> InnerT,
         MatcherT: Matcher<ActualT = InnerT>,
     {
@@ -177,12 +206,13 @@ pub mod internal {
 
         fn explain_match(&self, actual: &OuterT) -> MatchExplanation {
             let actual_inner = (self.extractor)(actual);
+            let inner = explain(&self.inner, &actual_inner);
             MatchExplanation::create(format!(
-                "whose property `{}` is `{:#?}`, {}",
+                "whose property `{}` is `{}`, {inner}",
                 self.property_desc,
-                actual_inner,
-                self.inner.explain_match(&actual_inner)
+                debug_string(&actual_inner),
             ))
+            .with_child(inner)
         }
     }
 
@@ -192,22 +222,27 @@ pub mod internal {
         extractor: fn(&OuterT) -> &InnerT,
         property_desc: &'static str,
         inner: MatcherT,
-    ) -> impl Matcher<ActualT = OuterT>
+    ) -> PropertyRefMatcher<InnerT, OuterT, MatcherT>
     where
-        OuterT: Debug,
-        InnerT: Debug + ?Sized,
-        MatcherT: Matcher<ActualT = InnerT>,
+        OuterT: TestDebug,
+        InnerT: TestDebug + ?Sized,
+        MatcherT: MatcherOf<InnerT>,
     {
         PropertyRefMatcher { extractor, property_desc, inner }
     }
 
-    struct PropertyRefMatcher<InnerT: ?Sized, OuterT, MatcherT> {
+    /// The matcher created by [`property!`][crate::property] for a property
+    /// returned by reference.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct PropertyRefMatcher<InnerT: ?Sized, OuterT, MatcherT> {
         extractor: fn(&OuterT) -> &InnerT,
         property_desc: &'static str,
         inner: MatcherT,
     }
 
-    impl<InnerT: Debug + ?Sized, OuterT: Debug, MatcherT: Matcher<ActualT = InnerT>> Matcher
+    impl<InnerT: TestDebug + ?Sized, OuterT: TestDebug, MatcherT: Matcher<ActualT = InnerT>> Matcher
         for PropertyRefMatcher<InnerT, OuterT, MatcherT>
     {
         type ActualT = OuterT;
@@ -226,12 +261,13 @@ pub mod internal {
 
         fn explain_match(&self, actual: &OuterT) -> MatchExplanation {
             let actual_inner = (self.extractor)(actual);
+            let inner = explain(&self.inner, actual_inner);
             MatchExplanation::create(format!(
-                "whose property `{}` is `{:#?}`, {}",
+                "whose property `{}` is `{}`, {inner}",
                 self.property_desc,
-                actual_inner,
-                self.inner.explain_match(actual_inner)
+                debug_string(actual_inner),
             ))
+            .with_child(inner)
         }
     }
 }
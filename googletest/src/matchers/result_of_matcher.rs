@@ -0,0 +1,200 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macro is documented at the top level.
+#![doc(hidden)]
+
+/// Matches a value which, upon being passed to the given function, produces a
+/// result matched by the given inner matcher.
+///
+/// This is useful for asserting on a computed property of a value without
+/// defining a method for it, as [`property!`][crate::property] would require:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("A string", result_of!(|s: &&str| s.len(), eq(8)))?;
+/// verify_that!(vec![1, 2, 3], result_of!(|v: &Vec<i32>| v.iter().sum::<i32>(), gt(5)))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// Any expression evaluating to a function taking the actual value by
+/// reference may be used, including paths to free functions. The source text
+/// of the expression appears in the failure message:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// fn word_count(s: &&str) -> usize {
+///     s.split_whitespace().count()
+/// }
+///
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("A string", result_of!(word_count, eq(3)))
+/// //  Expected: has result of `word_count` which is equal to 3
+/// //  Actual: "A string", whose result of `word_count` is `2`, which isn't equal to 3
+/// # }
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// **Important**: As with [`property!`][crate::property], the function should
+/// be pure. In the event of an assertion failure, it is invoked a second time,
+/// with the failure output reflecting the *second* invocation.
+#[macro_export]
+macro_rules! result_of {
+    ($($t:tt)*) => { $crate::result_of_internal!($($t)*) }
+}
+
+// Internal-only macro created so that the macro definition does not appear in
+// generated documentation.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! result_of_internal {
+    ($f:expr, $m:expr $(,)?) => {{
+        use $crate::matchers::result_of_matcher::internal::result_of_matcher;
+        result_of_matcher($f, stringify!($f), $m)
+    }};
+}
+
+/// Items for use only by the declarative macros in this module.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult, TestDebug};
+    use crate::matcher_support::debug_layout::debug_string;
+    use std::marker::PhantomData;
+
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn result_of_matcher<ActualT, ResultT, FunctionT, MatcherT>(
+        function: FunctionT,
+        function_desc: &'static str,
+        inner: MatcherT,
+    ) -> ResultOfMatcher<ActualT, FunctionT, MatcherT>
+    where
+        ActualT: TestDebug + ?Sized,
+        ResultT: TestDebug,
+        FunctionT: Fn(&ActualT) -> ResultT,
+        MatcherT: Matcher<ActualT = ResultT>,
+    {
+        ResultOfMatcher { function, function_desc, inner, phantom: Default::default() }
+    }
+
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct ResultOfMatcher<ActualT: ?Sized, FunctionT, MatcherT> {
+        function: FunctionT,
+        function_desc: &'static str,
+        inner: MatcherT,
+        phantom: PhantomData<ActualT>,
+    }
+
+    impl<ActualT, ResultT, FunctionT, MatcherT> Matcher
+        for ResultOfMatcher<ActualT, FunctionT, MatcherT>
+    where
+        ActualT: TestDebug + ?Sized,
+        ResultT: TestDebug,
+        FunctionT: Fn(&ActualT) -> ResultT,
+        MatcherT: Matcher<ActualT = ResultT>,
+    {
+        type ActualT = ActualT;
+
+        fn matches(&self, actual: &ActualT) -> MatcherResult {
+            self.inner.matches(&(self.function)(actual))
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            format!(
+                "has result of `{}` which {}",
+                self.function_desc,
+                self.inner.describe(matcher_result)
+            )
+        }
+
+        fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+            let result = (self.function)(actual);
+            let inner = explain(&self.inner, &result);
+            MatchExplanation::create(format!(
+                "whose result of `{}` is `{}`, {inner}",
+                self.function_desc,
+                debug_string(&result),
+            ))
+            .with_child(inner)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn result_of_matches_closure_result() -> Result<()> {
+        verify_that!(vec![1, 2, 3], result_of!(|v: &Vec<i32>| v.len(), eq(3)))
+    }
+
+    #[test]
+    fn result_of_does_not_match_closure_result() -> Result<()> {
+        verify_that!(vec![1, 2, 3], not(result_of!(|v: &Vec<i32>| v.len(), eq(4))))
+    }
+
+    #[test]
+    fn result_of_matches_function_path() -> Result<()> {
+        fn double(x: &i32) -> i32 {
+            x * 2
+        }
+
+        verify_that!(21, result_of!(double, eq(42)))
+    }
+
+    #[test]
+    fn result_of_works_as_nested_matcher() -> Result<()> {
+        verify_that!(vec!["ab", "cd"], each(result_of!(|s: &&str| s.len(), eq(2))))
+    }
+
+    #[test]
+    fn result_of_describes_function_source() -> Result<()> {
+        let matcher = result_of!(|x: &i32| x + 1, eq(2));
+
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq("has result of `|x: &i32| x + 1` which is equal to 2")
+        )?;
+        verify_that!(
+            matcher.describe(MatcherResult::DoesNotMatch),
+            eq("has result of `|x: &i32| x + 1` which isn't equal to 2")
+        )
+    }
+
+    #[test]
+    fn result_of_explains_mismatch_with_result() -> Result<()> {
+        let result = verify_that!(5, result_of!(|x: &i32| x + 1, eq(2)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Value of: 5
+                Expected: has result of `|x: &i32| x + 1` which is equal to 2
+                Actual: 5, whose result of `|x: &i32| x + 1` is `6`, which isn't equal to 2
+                "
+            ))))
+        )
+    }
+}
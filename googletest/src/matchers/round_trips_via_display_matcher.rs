@@ -0,0 +1,159 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Matches a value which is parsed back into an equal value from its
+/// [`Display`] output.
+///
+/// The actual value is formatted with [`Display`] and the resulting string is
+/// parsed with [`FromStr`]. The value matches if parsing succeeds and yields a
+/// value equal to the actual value. On a mismatch, the explanation shows the
+/// intermediate string together with the parsed value or the parse error.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::net::Ipv4Addr;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(Ipv4Addr::new(127, 0, 0, 1), round_trips_via_display())?; // Passes
+/// verify_that!(1.5f64, round_trips_via_display())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(f64::NAN, round_trips_via_display())?; // Fails: NaN isn't equal to itself
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn round_trips_via_display<T>() -> impl Matcher<ActualT = T>
+where
+    T: Display + FromStr + PartialEq + Debug,
+    T::Err: Debug,
+{
+    RoundTripsViaDisplayMatcher::<T> { phantom: Default::default() }
+}
+
+struct RoundTripsViaDisplayMatcher<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> Matcher for RoundTripsViaDisplayMatcher<T>
+where
+    T: Display + FromStr + PartialEq + Debug,
+    T::Err: Debug,
+{
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        match actual.to_string().parse::<T>() {
+            Ok(parsed) if parsed == *actual => MatcherResult::Matches,
+            _ => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let displayed = actual.to_string();
+        match displayed.parse::<T>() {
+            Ok(parsed) if parsed == *actual => {
+                MatchExplanation::create(format!("which round trips via {displayed:?}"))
+            }
+            Ok(parsed) => MatchExplanation::create(format!(
+                "which displays as {displayed:?}, which parses as {parsed:?}"
+            )),
+            Err(e) => MatchExplanation::create(format!(
+                "which displays as {displayed:?}, which fails to parse with {e:?}"
+            )),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                "is parsed back into an equal value from its Display output".to_string()
+            }
+            MatcherResult::DoesNotMatch => {
+                "isn't parsed back into an equal value from its Display output".to_string()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::round_trips_via_display;
+    use crate::matcher::Matcher;
+    use crate::prelude::*;
+    use std::fmt::{Display, Formatter};
+    use std::str::FromStr;
+
+    /// A value whose `Display` output loses the sign, so that negative values
+    /// do not round trip, and spells out zero, which then fails to parse.
+    #[derive(Debug, PartialEq)]
+    struct Magnitude(i32);
+
+    impl Display for Magnitude {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            if self.0 == 0 { write!(f, "zero") } else { write!(f, "{}", self.0.abs()) }
+        }
+    }
+
+    impl FromStr for Magnitude {
+        type Err = std::num::ParseIntError;
+
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            s.parse().map(Magnitude)
+        }
+    }
+
+    #[test]
+    fn matches_value_which_round_trips() -> Result<()> {
+        verify_that!(Magnitude(3), round_trips_via_display())
+    }
+
+    #[test]
+    fn does_not_match_value_which_parses_differently() -> Result<()> {
+        verify_that!(Magnitude(-3), not(round_trips_via_display()))
+    }
+
+    #[test]
+    fn explains_value_which_parses_differently() -> Result<()> {
+        verify_that!(
+            round_trips_via_display().explain_match(&Magnitude(-3)),
+            displays_as(eq("which displays as \"3\", which parses as Magnitude(3)"))
+        )
+    }
+
+    #[test]
+    fn explains_parse_error() -> Result<()> {
+        verify_that!(
+            round_trips_via_display().explain_match(&Magnitude(0)),
+            displays_as(eq(
+                "which displays as \"zero\", which fails to parse with ParseIntError { kind: \
+                InvalidDigit }"
+            ))
+        )
+    }
+
+    #[test]
+    fn explains_value_which_round_trips() -> Result<()> {
+        verify_that!(
+            round_trips_via_display().explain_match(&Magnitude(3)),
+            displays_as(eq("which round trips via \"3\""))
+        )
+    }
+}
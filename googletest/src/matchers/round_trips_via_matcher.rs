@@ -0,0 +1,158 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::debug_layout::debug_string;
+use crate::matchers::eq_matcher::create_diff;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+};
+
+/// Matches a value which, once serialized with `format` and deserialized
+/// again, equals the original value.
+///
+/// The serialization format is given by an implementation of [`Format`],
+/// typically a thin wrapper around a serde data format crate:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use serde::{de::DeserializeOwned, Serialize};
+/// #[derive(Debug)]
+/// struct Json;
+///
+/// impl Format for Json {
+///     type Error = serde_json::Error;
+///
+///     fn serialize<T: Serialize>(&self, value: &T) -> serde_json::Result<String> {
+///         serde_json::to_string(value)
+///     }
+///
+///     fn deserialize<T: DeserializeOwned>(&self, serialized: &str) -> serde_json::Result<T> {
+///         serde_json::from_str(serialized)
+///     }
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], round_trips_via(Json))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(f64::NAN, round_trips_via(Json))?;  // Fails: NaN serializes as null
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// If the round-tripped value differs, the failure message shows the
+/// serialized form together with a diff of the `Debug` representations of the
+/// original and round-tripped values, which points at the field which
+/// diverged. If serialization or deserialization fails, the failure message
+/// reports the error.
+///
+/// This matcher is only available with the feature `serde`.
+pub fn round_trips_via<
+    FormatT: Format + Debug,
+    ActualT: Serialize + DeserializeOwned + PartialEq + Debug,
+>(
+    format: FormatT,
+) -> impl Matcher<ActualT = ActualT> {
+    RoundTripsViaMatcher { format, phantom: Default::default() }
+}
+
+/// A textual serialization format through which [`round_trips_via`] round
+/// trips values.
+///
+/// googletest does not implement this trait for any format itself, so that
+/// it does not depend on a particular data format crate.
+pub trait Format {
+    /// The error returned when serialization or deserialization fails.
+    type Error: Display;
+
+    /// Serializes `value` into its textual representation in this format.
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<String, Self::Error>;
+
+    /// Deserializes a value from its textual representation `serialized`.
+    fn deserialize<T: DeserializeOwned>(&self, serialized: &str) -> Result<T, Self::Error>;
+}
+
+struct RoundTripsViaMatcher<FormatT, ActualT> {
+    format: FormatT,
+    phantom: PhantomData<ActualT>,
+}
+
+/// The outcome of round tripping a value.
+enum RoundTrip<T, E> {
+    SerializationFailed(E),
+    DeserializationFailed { serialized: String, error: E },
+    Completed { serialized: String, value: T },
+}
+
+impl<FormatT: Format, ActualT: Serialize + DeserializeOwned>
+    RoundTripsViaMatcher<FormatT, ActualT>
+{
+    fn round_trip(&self, actual: &ActualT) -> RoundTrip<ActualT, FormatT::Error> {
+        let serialized = match self.format.serialize(actual) {
+            Ok(serialized) => serialized,
+            Err(error) => return RoundTrip::SerializationFailed(error),
+        };
+        match self.format.deserialize(&serialized) {
+            Ok(value) => RoundTrip::Completed { serialized, value },
+            Err(error) => RoundTrip::DeserializationFailed { serialized, error },
+        }
+    }
+}
+
+impl<FormatT: Format + Debug, ActualT: Serialize + DeserializeOwned + PartialEq + Debug> Matcher
+    for RoundTripsViaMatcher<FormatT, ActualT>
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        match self.round_trip(actual) {
+            RoundTrip::Completed { value, .. } => (value == *actual).into(),
+            _ => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("round trips via {:?}", self.format),
+            MatcherResult::DoesNotMatch => format!("doesn't round trip via {:?}", self.format),
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        match self.round_trip(actual) {
+            RoundTrip::SerializationFailed(error) => {
+                MatchExplanation::create(format!("which fails to serialize with {error}"))
+            }
+            RoundTrip::DeserializationFailed { serialized, error } => {
+                MatchExplanation::create(format!(
+                    "which serializes to {serialized}, which fails to deserialize with {error}"
+                ))
+            }
+            RoundTrip::Completed { serialized, value } if value == *actual => {
+                MatchExplanation::create(format!("which round trips via {serialized}"))
+            }
+            RoundTrip::Completed { serialized, value } => create_diff(
+                &debug_string(actual),
+                &debug_string(&value),
+                &format!("serializes to {serialized}, which deserializes as {value:?}"),
+            ),
+        }
+    }
+}
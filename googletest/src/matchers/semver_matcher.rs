@@ -0,0 +1,420 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use semver::{Version, VersionReq};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a version which satisfies the version requirement `requirement`,
+/// e.g., `">=1.2, <2"`.
+///
+/// The actual value may be a [`semver::Version`] or a string, which is then
+/// parsed as a version. See [`VersionLike`].
+///
+/// If either the actual value or `requirement` cannot be parsed, the matcher
+/// does not match and the failure message reports the parse error, so that it
+/// is not mistaken for a version which does not satisfy the requirement.
+///
+/// This matcher is only available with the feature `semver`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("1.4.0", satisfies_req(">=1.2, <2"))?;  // Passes
+/// verify_that!(semver::Version::new(1, 2, 0), satisfies_req("^1.2"))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail_1() -> Result<()> {
+/// verify_that!("2.0.0", satisfies_req(">=1.2, <2"))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # fn should_fail_2() -> Result<()> {
+/// verify_that!("not a version", satisfies_req(">=1.2, <2"))?;  // Fails with a parse error
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail_1().unwrap_err();
+/// # should_fail_2().unwrap_err();
+/// ```
+pub fn satisfies_req<ActualT: VersionLike + ?Sized>(
+    requirement: &str,
+) -> impl Matcher<ActualT = ActualT> {
+    SatisfiesReqMatcher {
+        requirement: requirement.to_string(),
+        parsed_requirement: VersionReq::parse(requirement).map_err(|e| e.to_string()),
+        phantom: Default::default(),
+    }
+}
+
+/// Matches a version which has a pre-release identifier, e.g., `1.0.0-alpha.1`.
+///
+/// The actual value may be a [`semver::Version`] or a string, which is then
+/// parsed as a version. See [`VersionLike`].
+///
+/// This matcher is only available with the feature `semver`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("1.0.0-rc.1", is_prerelease())?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("1.0.0", is_prerelease())?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_prerelease<ActualT: VersionLike + ?Sized>() -> impl Matcher<ActualT = ActualT> {
+    IsPrereleaseMatcher { phantom: Default::default() }
+}
+
+/// Matches a version which is strictly newer than `expected`, according to
+/// the precedence rules of [semantic versioning](https://semver.org).
+/// Build metadata is ignored, so `1.0.0+b` is not newer than `1.0.0+a`.
+///
+/// Both the actual value and `expected` may be a [`semver::Version`] or a
+/// string, which is then parsed as a version. See [`VersionLike`].
+///
+/// This matcher is only available with the feature `semver`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("1.10.0", newer_than("1.9.3"))?;  // Passes
+/// verify_that!("1.0.0", newer_than("1.0.0-rc.1"))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("1.9.3", newer_than("1.10.0"))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn newer_than<ActualT: VersionLike + ?Sized, ExpectedT: VersionLike>(
+    expected: ExpectedT,
+) -> impl Matcher<ActualT = ActualT> {
+    NewerThanMatcher { expected, phantom: Default::default() }
+}
+
+/// A value which the semantic versioning matchers accept as a version.
+///
+/// This is implemented for [`semver::Version`] itself as well as for string
+/// types, which are parsed with [`Version::parse`].
+pub trait VersionLike: Debug {
+    /// Returns this value as a [`Version`], or a description of why it could
+    /// not be parsed as one.
+    fn to_version(&self) -> Result<Version, String>;
+}
+
+impl VersionLike for Version {
+    fn to_version(&self) -> Result<Version, String> {
+        Ok(self.clone())
+    }
+}
+
+impl VersionLike for str {
+    fn to_version(&self) -> Result<Version, String> {
+        Version::parse(self).map_err(|e| e.to_string())
+    }
+}
+
+impl VersionLike for String {
+    fn to_version(&self) -> Result<Version, String> {
+        self.as_str().to_version()
+    }
+}
+
+impl<T: VersionLike + ?Sized> VersionLike for &T {
+    fn to_version(&self) -> Result<Version, String> {
+        (*self).to_version()
+    }
+}
+
+fn explain_parse_error(error: String) -> MatchExplanation {
+    MatchExplanation::create(format!("which is not a valid version: {error}"))
+}
+
+struct SatisfiesReqMatcher<ActualT: ?Sized> {
+    requirement: String,
+    parsed_requirement: Result<VersionReq, String>,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: VersionLike + ?Sized> Matcher for SatisfiesReqMatcher<ActualT> {
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        match (&self.parsed_requirement, actual.to_version()) {
+            (Ok(requirement), Ok(version)) => requirement.matches(&version).into(),
+            _ => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let requirement = match &self.parsed_requirement {
+            Ok(requirement) => requirement,
+            Err(error) => {
+                return MatchExplanation::create(format!(
+                    "which cannot be checked since the requirement {:?} is invalid: {error}",
+                    self.requirement
+                ));
+            }
+        };
+        match actual.to_version() {
+            Ok(version) if requirement.matches(&version) => {
+                MatchExplanation::create(format!("which satisfies {:?}", self.requirement))
+            }
+            Ok(_) => {
+                MatchExplanation::create(format!("which does not satisfy {:?}", self.requirement))
+            }
+            Err(error) => explain_parse_error(error),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is a version satisfying {:?}", self.requirement)
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't a version satisfying {:?}", self.requirement)
+            }
+        }
+    }
+}
+
+struct IsPrereleaseMatcher<ActualT: ?Sized> {
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: VersionLike + ?Sized> Matcher for IsPrereleaseMatcher<ActualT> {
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        actual.to_version().map(|version| !version.pre.is_empty()).unwrap_or(false).into()
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        match actual.to_version() {
+            Ok(version) if version.pre.is_empty() => {
+                MatchExplanation::create("which is not a pre-release version".to_string())
+            }
+            Ok(version) => MatchExplanation::create(format!(
+                "which is a pre-release version with identifier {:?}",
+                version.pre.as_str()
+            )),
+            Err(error) => explain_parse_error(error),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => "is a pre-release version".to_string(),
+            MatcherResult::DoesNotMatch => "isn't a pre-release version".to_string(),
+        }
+    }
+}
+
+struct NewerThanMatcher<ActualT: ?Sized, ExpectedT> {
+    expected: ExpectedT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: VersionLike + ?Sized, ExpectedT: VersionLike> Matcher
+    for NewerThanMatcher<ActualT, ExpectedT>
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        match (actual.to_version(), self.expected.to_version()) {
+            (Ok(actual), Ok(expected)) => actual.cmp_precedence(&expected).is_gt().into(),
+            _ => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let expected = match self.expected.to_version() {
+            Ok(expected) => expected,
+            Err(error) => {
+                return MatchExplanation::create(format!(
+                    "which cannot be compared since the expected version {:?} is invalid: {error}",
+                    self.expected
+                ));
+            }
+        };
+        match actual.to_version() {
+            Ok(version) if version.cmp_precedence(&expected).is_gt() => {
+                MatchExplanation::create(format!("which is newer than {expected}"))
+            }
+            Ok(_) => MatchExplanation::create(format!("which is not newer than {expected}")),
+            Err(error) => explain_parse_error(error),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("is a version newer than {:?}", self.expected),
+            MatcherResult::DoesNotMatch => {
+                format!("isn't a version newer than {:?}", self.expected)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_prerelease, newer_than, satisfies_req};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use semver::Version;
+
+    #[test]
+    fn satisfies_req_matches_version_string() -> Result<()> {
+        verify_that!("1.4.0", satisfies_req(">=1.2, <2"))
+    }
+
+    #[test]
+    fn satisfies_req_matches_owned_version_string() -> Result<()> {
+        verify_that!("1.4.0".to_string(), satisfies_req(">=1.2, <2"))
+    }
+
+    #[test]
+    fn satisfies_req_matches_version() -> Result<()> {
+        verify_that!(Version::new(1, 2, 3), satisfies_req("~1.2"))
+    }
+
+    #[test]
+    fn satisfies_req_does_not_match_version_outside_requirement() -> Result<()> {
+        verify_that!("2.0.0", not(satisfies_req(">=1.2, <2")))
+    }
+
+    #[test]
+    fn satisfies_req_explains_mismatch() -> Result<()> {
+        let result = verify_that!("2.0.0", satisfies_req(">=1.2, <2"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: is a version satisfying ">=1.2, <2"
+                Actual: "2.0.0", which does not satisfy ">=1.2, <2"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn satisfies_req_reports_invalid_actual_version() -> Result<()> {
+        let result = verify_that!("1.x", satisfies_req(">=1.2"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                r#"Actual: "1.x", which is not a valid version: "#
+            )))
+        )
+    }
+
+    #[test]
+    fn satisfies_req_reports_invalid_requirement() -> Result<()> {
+        let result = verify_that!("1.2.0", satisfies_req(">=>1.2"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                r#"Actual: "1.2.0", which cannot be checked since the requirement ">=>1.2" is invalid: "#
+            )))
+        )
+    }
+
+    #[test]
+    fn is_prerelease_matches_prerelease() -> Result<()> {
+        verify_that!(Version::parse("1.0.0-alpha.1").unwrap(), is_prerelease())
+    }
+
+    #[test]
+    fn is_prerelease_does_not_match_release() -> Result<()> {
+        verify_that!("1.0.0+build.5", not(is_prerelease()))
+    }
+
+    #[test]
+    fn is_prerelease_does_not_match_invalid_version() -> Result<()> {
+        verify_that!(
+            is_prerelease().explain_match(&"1.0"),
+            displays_as(starts_with("which is not a valid version: "))
+        )
+    }
+
+    #[test]
+    fn is_prerelease_explains_match() -> Result<()> {
+        verify_that!(
+            is_prerelease().explain_match(&"1.0.0-beta"),
+            displays_as(eq("which is a pre-release version with identifier \"beta\""))
+        )
+    }
+
+    #[test]
+    fn newer_than_matches_newer_version() -> Result<()> {
+        verify_that!("1.10.0", newer_than("1.9.0"))
+    }
+
+    #[test]
+    fn newer_than_matches_release_against_its_prerelease() -> Result<()> {
+        verify_that!(Version::new(1, 0, 0), newer_than("1.0.0-rc.1"))
+    }
+
+    #[test]
+    fn newer_than_does_not_match_equal_version() -> Result<()> {
+        verify_that!("1.0.0", not(newer_than(Version::new(1, 0, 0))))
+    }
+
+    #[test]
+    fn newer_than_ignores_build_metadata() -> Result<()> {
+        verify_that!("1.0.0+b", not(newer_than("1.0.0+a")))
+    }
+
+    #[test]
+    fn newer_than_describes_itself() -> Result<()> {
+        verify_that!(
+            newer_than::<str, _>("1.0.0").describe(MatcherResult::DoesNotMatch),
+            eq("isn't a version newer than \"1.0.0\"")
+        )
+    }
+
+    #[test]
+    fn newer_than_explains_mismatch() -> Result<()> {
+        let result = verify_that!("1.9.3", newer_than("1.10.0"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                r#"Actual: "1.9.3", which is not newer than 1.10.0"#
+            )))
+        )
+    }
+
+    #[test]
+    fn newer_than_reports_invalid_expected_version() -> Result<()> {
+        verify_that!(
+            newer_than("1").explain_match(&"1.0.0"),
+            displays_as(starts_with(
+                "which cannot be compared since the expected version \"1\" is invalid: "
+            ))
+        )
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::debug_layout::debug_string;
+use crate::matcher_support::description::Description;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a container containing exactly the same items as the container
+/// `expected`, ignoring order and multiplicity.
+///
+/// This is intended for set types such as `HashSet` and `BTreeSet`, whose
+/// iteration order is unspecified or unrelated to the order of the expected
+/// items. On a mismatch, the failure message separately lists the items
+/// missing from the actual value and those which are unexpected in it:
+///
+/// ```text
+/// Actual: {1, 2, 4}, which differs from the expected set
+///   missing from actual: [3]
+///   unexpected in actual: [4]
+/// ```
+///
+/// The element type `ElementT` must implement `PartialEq` to allow element
+/// comparison.
+///
+/// `ActualT` and `ExpectedT` can each be any container a reference to which
+/// implements `IntoIterator`. They need not be the same container type.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::{BTreeSet, HashSet};
+/// # fn should_pass() -> Result<()> {
+/// let value: HashSet<i32> = [1, 2, 3].into();
+/// verify_that!(value, set_eq([3, 2, 1]))?;  // Passes
+/// let value: BTreeSet<&str> = ["a", "b"].into();
+/// verify_that!(value, set_eq(["b", "a", "a"]))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value: HashSet<i32> = [1, 2, 4].into();
+/// verify_that!(value, set_eq([1, 2, 3]))?;  // Fails: 3 is missing, 4 is unexpected
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// A note on performance: This matcher uses a naive algorithm with a worst-case
+/// runtime proportional to the *product* of the sizes of the actual and
+/// expected containers as well as the time to check equality of each pair of
+/// items. It should not be used on especially large containers.
+pub fn set_eq<ElementT: Debug + PartialEq, ActualT: Debug + ?Sized, ExpectedT: Debug>(
+    expected: ExpectedT,
+) -> impl Matcher<ActualT = ActualT>
+where
+    for<'a> &'a ActualT: IntoIterator<Item = &'a ElementT>,
+    for<'a> &'a ExpectedT: IntoIterator<Item = &'a ElementT>,
+{
+    SetEqMatcher::<ActualT, _> { expected, phantom: PhantomData }
+}
+
+struct SetEqMatcher<ActualT: ?Sized, ExpectedT> {
+    expected: ExpectedT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ElementT: Debug + PartialEq, ActualT: Debug + ?Sized, ExpectedT: Debug> Matcher
+    for SetEqMatcher<ActualT, ExpectedT>
+where
+    for<'a> &'a ActualT: IntoIterator<Item = &'a ElementT>,
+    for<'a> &'a ExpectedT: IntoIterator<Item = &'a ElementT>,
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        (missing_items(actual, &self.expected).is_empty()
+            && missing_items(&self.expected, actual).is_empty())
+        .into()
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let missing = missing_items(actual, &self.expected);
+        let unexpected = missing_items(&self.expected, actual);
+        if missing.is_empty() && unexpected.is_empty() {
+            return MatchExplanation::create(
+                "which contains exactly the expected elements".to_string(),
+            );
+        }
+        let mut differences = vec![];
+        if !missing.is_empty() {
+            differences.push(format!("missing from actual: {missing:?}"));
+        }
+        if !unexpected.is_empty() {
+            differences.push(format!("unexpected in actual: {unexpected:?}"));
+        }
+        MatchExplanation::create(format!(
+            "which differs from the expected set\n{}",
+            differences.into_iter().collect::<Description>().indent()
+        ))
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!("is a set equal to {}", debug_string(&self.expected))
+            }
+            MatcherResult::DoesNotMatch => {
+                format!("isn't a set equal to {}", debug_string(&self.expected))
+            }
+        }
+    }
+}
+
+/// Returns the distinct items of `source` which do not appear in `target`, in
+/// the order in which they first appear in `source`.
+fn missing_items<'a, ElementT: PartialEq + 'a, TargetT: ?Sized, SourceT: ?Sized>(
+    target: &TargetT,
+    source: &'a SourceT,
+) -> Vec<&'a ElementT>
+where
+    for<'b> &'b TargetT: IntoIterator<Item = &'b ElementT>,
+    &'a SourceT: IntoIterator<Item = &'a ElementT>,
+{
+    let mut missing: Vec<&ElementT> = vec![];
+    for item in source {
+        if !target.into_iter().any(|t| t == item) && !missing.contains(&item) {
+            missing.push(item);
+        }
+    }
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::set_eq;
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn set_eq_matches_empty_set() -> Result<()> {
+        let value: HashSet<i32> = HashSet::new();
+        verify_that!(value, set_eq([]))
+    }
+
+    #[test]
+    fn set_eq_matches_hash_set_in_any_order() -> Result<()> {
+        let value: HashSet<i32> = [1, 2, 3].into();
+        verify_that!(value, set_eq([3, 1, 2]))
+    }
+
+    #[test]
+    fn set_eq_matches_btree_set_against_hash_set() -> Result<()> {
+        let value: BTreeSet<i32> = [1, 2, 3].into();
+        verify_that!(value, set_eq(HashSet::from([1, 2, 3])))
+    }
+
+    #[test]
+    fn set_eq_ignores_multiplicity() -> Result<()> {
+        let value = vec![1, 1, 2];
+        verify_that!(value, set_eq([2, 1, 2]))
+    }
+
+    #[test]
+    fn set_eq_does_not_match_when_element_is_missing() -> Result<()> {
+        let value: BTreeSet<i32> = [1, 2].into();
+        verify_that!(value, not(set_eq([1, 2, 3])))
+    }
+
+    #[test]
+    fn set_eq_does_not_match_when_element_is_unexpected() -> Result<()> {
+        let value: BTreeSet<i32> = [1, 2, 3].into();
+        verify_that!(value, not(set_eq([1, 2])))
+    }
+
+    #[test]
+    fn set_eq_shows_missing_and_unexpected_elements() -> Result<()> {
+        let value: BTreeSet<i32> = [1, 2, 4, 5].into();
+
+        let result = verify_that!(value, set_eq([1, 2, 3]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                }, which differs from the expected set
+                  missing from actual: [3]
+                  unexpected in actual: [4, 5]
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn set_eq_shows_only_missing_elements_when_none_are_unexpected() -> Result<()> {
+        let value: BTreeSet<i32> = [1].into();
+
+        let result = verify_that!(value, set_eq([1, 2, 2]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                }, which differs from the expected set
+                  missing from actual: [2]
+                "
+            ))))
+        )
+    }
+}
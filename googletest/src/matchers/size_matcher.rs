@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use crate::matcher_support::count_elements::count_elements;
 use std::{fmt::Debug, marker::PhantomData};
 
@@ -83,11 +83,8 @@ where
 
     fn explain_match(&self, actual: &T) -> MatchExplanation {
         let actual_size = count_elements(actual);
-        MatchExplanation::create(format!(
-            "which has size {}, {}",
-            actual_size,
-            self.expected.explain_match(&actual_size)
-        ))
+        let inner = explain(&self.expected, &actual_size);
+        MatchExplanation::create(format!("which has size {actual_size}, {inner}")).with_child(inner)
     }
 }
 
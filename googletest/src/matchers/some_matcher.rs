@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Matches an `Option` containing a value matched by `inner`.
@@ -53,10 +53,10 @@ impl<T: Debug, InnerMatcherT: Matcher<ActualT = T>> Matcher for SomeMatcher<T, I
 
     fn explain_match(&self, actual: &Option<T>) -> MatchExplanation {
         match (self.matches(actual), actual) {
-            (_, Some(t)) => MatchExplanation::create(format!(
-                "which has a value {}",
-                self.inner.explain_match(t)
-            )),
+            (_, Some(t)) => {
+                let inner = explain(&self.inner, t);
+                MatchExplanation::create(format!("which has a value {inner}")).with_child(inner)
+            }
             (_, None) => MatchExplanation::create("which is None".to_string()),
         }
     }
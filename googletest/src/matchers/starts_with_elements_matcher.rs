@@ -0,0 +1,297 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macros are documented at the top level.
+#![doc(hidden)]
+
+/// Matches a container whose leading elements the given matchers match, in
+/// order.
+///
+/// This is the container analogue of the string matcher
+/// [`starts_with`][crate::matchers::starts_with].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], starts_with_elements![eq(1), gt(1)])?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], starts_with_elements![eq(2)])?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On a mismatch, the explanation reports the first position at which the
+/// prefix diverged, or that the container has fewer elements than there are
+/// matchers.
+///
+/// The actual value must be a container implementing [`IntoIterator`].
+///
+/// [`IntoIterator`]: std::iter::IntoIterator
+#[macro_export]
+macro_rules! starts_with_elements {
+    ($($matcher:expr),* $(,)?) => {{
+        use $crate::matchers::starts_with_elements_matcher::internal::{
+            Position, StartsOrEndsWithElements
+        };
+        StartsOrEndsWithElements::new(&[$(&$crate::matcher::IntoMatcher::into_matcher(&$matcher)),*], Position::Start)
+    }}
+}
+
+/// Matches a container whose trailing elements the given matchers match, in
+/// order.
+///
+/// This is the container analogue of the string matcher
+/// [`ends_with`][crate::matchers::ends_with].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], ends_with_elements![lt(3), eq(3)])?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], ends_with_elements![eq(2)])?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// On a mismatch, the explanation reports the first position of the suffix
+/// which diverged, or that the container has fewer elements than there are
+/// matchers.
+///
+/// The actual value must be a container implementing [`IntoIterator`].
+///
+/// [`IntoIterator`]: std::iter::IntoIterator
+#[macro_export]
+macro_rules! ends_with_elements {
+    ($($matcher:expr),* $(,)?) => {{
+        use $crate::matchers::starts_with_elements_matcher::internal::{
+            Position, StartsOrEndsWithElements
+        };
+        StartsOrEndsWithElements::new(&[$(&$crate::matcher::IntoMatcher::into_matcher(&$matcher)),*], Position::End)
+    }}
+}
+
+/// Module for use only by the macros in this module.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+    use crate::matcher_support::description::Description;
+    use crate::matcher_support::zipped_iterator::zip;
+    use std::{fmt::Debug, marker::PhantomData};
+
+    /// Where the matched elements are in the container.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub enum Position {
+        Start,
+        End,
+    }
+
+    /// This struct is meant to be used only by the macros
+    /// `starts_with_elements!` and `ends_with_elements!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct StartsOrEndsWithElements<'a, ContainerT: ?Sized, T: Debug> {
+        elements: &'a [&'a dyn Matcher<ActualT = T>],
+        position: Position,
+        phantom: PhantomData<ContainerT>,
+    }
+
+    impl<'a, ContainerT: ?Sized, T: Debug> StartsOrEndsWithElements<'a, ContainerT, T> {
+        /// Factory only intended for use in the macros `starts_with_elements!`
+        /// and `ends_with_elements!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        pub fn new(elements: &'a [&'a dyn Matcher<ActualT = T>], position: Position) -> Self {
+            Self { elements, position, phantom: Default::default() }
+        }
+
+        /// Returns the index of the first element of `actual` which is too
+        /// few to match or which its corresponding matcher does not match,
+        /// if any.
+        fn first_mismatch(&self, actual: &[&T]) -> Option<Mismatch> {
+            if actual.len() < self.elements.len() {
+                return Some(Mismatch::TooShort);
+            }
+            let offset = match self.position {
+                Position::Start => 0,
+                Position::End => actual.len() - self.elements.len(),
+            };
+            zip(actual[offset..].iter(), self.elements.iter())
+                .position(|(a, e)| !e.matches(a).into_bool())
+                .map(|index| Mismatch::Diverged(offset + index, index))
+        }
+
+        fn affix(&self) -> &'static str {
+            match self.position {
+                Position::Start => "prefix",
+                Position::End => "suffix",
+            }
+        }
+    }
+
+    enum Mismatch {
+        TooShort,
+        /// The index of the element in the container and of its matcher.
+        Diverged(usize, usize),
+    }
+
+    impl<'a, T: Debug, ContainerT: Debug + ?Sized> Matcher
+        for StartsOrEndsWithElements<'a, ContainerT, T>
+    where
+        for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
+    {
+        type ActualT = ContainerT;
+
+        fn matches(&self, actual: &ContainerT) -> MatcherResult {
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            self.first_mismatch(&actual).is_none().into()
+        }
+
+        fn explain_match(&self, actual: &ContainerT) -> MatchExplanation {
+            let actual = actual.into_iter().collect::<Vec<_>>();
+            match self.first_mismatch(&actual) {
+                None => MatchExplanation::create(format!("whose {} matches", self.affix())),
+                Some(Mismatch::TooShort) => MatchExplanation::create(format!(
+                    "whose size {} is less than the {} elements of the {}",
+                    actual.len(),
+                    self.elements.len(),
+                    self.affix()
+                )),
+                Some(Mismatch::Diverged(index, matcher_index)) => {
+                    let element = actual[index];
+                    let inner = explain(self.elements[matcher_index], element);
+                    MatchExplanation::create(format!(
+                        "whose {} diverges at element #{index}, which is {element:?}, {inner}",
+                        self.affix()
+                    ))
+                    .with_child(inner)
+                }
+            }
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            let (verb, position) = match self.position {
+                Position::Start => ("starts", "starting"),
+                Position::End => ("ends", "ending"),
+            };
+            format!(
+                "{} with elements which:\n{}",
+                if matcher_result.into() {
+                    verb.to_string()
+                } else {
+                    format!("isn't a container {position}")
+                },
+                self.elements
+                    .iter()
+                    .map(|matcher| matcher.describe(MatcherResult::Matches))
+                    .collect::<Description>()
+                    .enumerate()
+                    .indent()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn starts_with_elements_matches_prefix() -> Result<()> {
+        expect_that!(vec![1, 2, 3], starts_with_elements![eq(1), eq(2)]);
+        expect_that!(vec![1, 2, 3], starts_with_elements![eq(1), eq(2), eq(3)]);
+        expect_that!(Vec::<i32>::new(), starts_with_elements![]);
+        verify_that!(vec![1, 2, 3], not(starts_with_elements![eq(2)]))
+    }
+
+    #[test]
+    fn ends_with_elements_matches_suffix() -> Result<()> {
+        expect_that!(vec![1, 2, 3], ends_with_elements![eq(2), eq(3)]);
+        expect_that!(vec![1, 2, 3], ends_with_elements![]);
+        verify_that!(vec![1, 2, 3], not(ends_with_elements![eq(1), eq(2)]))
+    }
+
+    #[test]
+    fn starts_with_elements_explains_divergence() -> Result<()> {
+        let first = eq(1);
+        let second = eq(3);
+        let result = verify_that!(vec![1, 2, 3], starts_with_elements![first, second]);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: starts with elements which:
+                  0. is equal to 1
+                  1. is equal to 3
+                Actual: [
+                    1,
+                    2,
+                    3,
+                ], whose prefix diverges at element #1, which is 2, which isn't equal to 3
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn ends_with_elements_explains_divergence_at_index_in_container() -> Result<()> {
+        let result = verify_that!(vec![1, 2, 3, 4], ends_with_elements![eq(2), eq(5), eq(4)]);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "whose suffix diverges at element #2, which is 3, which isn't equal to 5"
+            )))
+        )
+    }
+
+    #[test]
+    fn starts_with_elements_explains_container_too_short() -> Result<()> {
+        let result = verify_that!(vec![1], starts_with_elements![eq(1), eq(2)]);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "whose size 1 is less than the 2 elements of the prefix"
+            )))
+        )
+    }
+
+    #[test]
+    fn ends_with_elements_describes_negation() -> Result<()> {
+        let result = verify_that!(vec![1, 2], not(ends_with_elements![eq(2)]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Expected: isn't a container ending with elements which:\n  0. is equal to 2"
+            )))
+        )
+    }
+}
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::matcher::{Matcher, MatcherResult};
-use crate::matchers::{eq_deref_of_matcher::EqDerefOfMatcher, eq_matcher::EqMatcher};
+use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matchers::{
+    eq_deref_of_matcher::EqDerefOfMatcher, eq_ignoring_case_matcher::fold_case,
+    eq_matcher::EqMatcher,
+};
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -140,6 +143,49 @@ pub fn ends_with<A: ?Sized, T>(expected: T) -> StrMatcher<A, T> {
     }
 }
 
+/// Matches a string equal to `expected` up to Unicode normalization.
+///
+/// Both the actual and the expected value are brought into Unicode
+/// Normalization Form C before comparing them, so that canonically equivalent
+/// strings match even if one uses precomposed characters and the other
+/// combining sequences. Since canonical equivalence is the same under NFC and
+/// NFD, this matches exactly the strings which are equal in either form.
+///
+/// Both the actual value and the expected value may be either a `String` or a
+/// string reference.
+///
+/// ```
+/// # #[cfg(feature = "unicode-normalization")] {
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("Cafe\u{301}", eq_normalized("Caf\u{e9}"))?; // Passes
+/// verify_that!("A\u{30a}ngstro\u{308}m".to_string(), eq_normalized("Ångström"))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("Cafe", eq_normalized("Caf\u{e9}"))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// # }
+/// ```
+///
+/// This is equivalent to
+/// [`eq(expected).ignoring_unicode_normalization()`][StrMatcherConfigurator::ignoring_unicode_normalization].
+/// See the [`StrMatcherConfigurator`] extension trait for more options on how
+/// the string is matched.
+///
+/// This requires the feature `unicode-normalization`.
+#[cfg(feature = "unicode-normalization")]
+pub fn eq_normalized<A: ?Sized, T>(expected: T) -> StrMatcher<A, T> {
+    StrMatcher {
+        configuration: Configuration::default().ignoring_unicode_normalization(),
+        expected,
+        phantom: Default::default(),
+    }
+}
+
 /// Extension trait to configure [`StrMatcher`].
 ///
 /// Matchers which match against string values and, through configuration,
@@ -232,6 +278,53 @@ pub trait StrMatcherConfigurator<ActualT: ?Sized, ExpectedT> {
     /// case characters outside of the codepoints 0-127 covered by ASCII.
     fn ignoring_ascii_case(self) -> StrMatcher<ActualT, ExpectedT>;
 
+    /// Configures the matcher to ignore case when comparing values, using the
+    /// full Unicode case mappings.
+    ///
+    /// Unlike [`ignoring_ascii_case`][StrMatcherConfigurator::ignoring_ascii_case],
+    /// this also matches characters outside of ASCII which differ only in case,
+    /// including those whose case mapping changes their length, such as `ß` and
+    /// `SS`.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!("Straße ÉTÉ", contains_substring("STRASSE été").ignoring_case())?; // Passes
+    /// verify_that!("ERROR: disk full", starts_with("error:").ignoring_case())?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// verify_that!("Warning: disk full", starts_with("error:").ignoring_case())?; // Fails
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    fn ignoring_case(self) -> StrMatcher<ActualT, ExpectedT>;
+
+    /// Configures the matcher to compare values after bringing both into
+    /// Unicode Normalization Form C.
+    ///
+    /// Strings which are canonically equivalent then match even if they encode
+    /// the same characters differently, for example with precomposed or
+    /// combining accents.
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!(
+    ///     "Caf\u{65}\u{301} opened",
+    ///     starts_with("Caf\u{e9}").ignoring_unicode_normalization()
+    /// )?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// ```
+    ///
+    /// This requires the feature `unicode-normalization`.
+    #[cfg(feature = "unicode-normalization")]
+    fn ignoring_unicode_normalization(self) -> StrMatcher<ActualT, ExpectedT>;
+
     /// Configures the matcher to match only strings which otherwise satisfy the
     /// conditions a number times matched by the matcher `times`.
     ///
@@ -262,6 +355,26 @@ pub trait StrMatcherConfigurator<ActualT: ?Sized, ExpectedT> {
     /// # should_fail().unwrap_err();
     /// ```
     ///
+    /// Combine `times` with [`ge`][crate::matchers::ge] to require a minimum
+    /// number of occurrences rather than an exact count. When the matcher
+    /// fails, the explanation lists the byte offsets at which the occurrences
+    /// which were found start:
+    ///
+    /// ```
+    /// # use googletest::prelude::*;
+    /// # fn should_pass() -> Result<()> {
+    /// verify_that!("ok ok ok", contains_substring("ok").times(ge(2)))?; // Passes
+    /// #     Ok(())
+    /// # }
+    /// # fn should_fail() -> Result<()> {
+    /// // Fails with: which contains the substring 3 times, at byte offsets 0, 3, 6
+    /// verify_that!("ok ok ok", contains_substring("ok").times(eq(2)))?;
+    /// #     Ok(())
+    /// # }
+    /// # should_pass().unwrap();
+    /// # should_fail().unwrap_err();
+    /// ```
+    ///
     /// This is only meaningful when the matcher was constructed with
     /// [`contains_substring`]. This method will panic when it is used with any
     /// other matcher construction.
@@ -300,6 +413,15 @@ where
     fn describe(&self, matcher_result: MatcherResult) -> String {
         self.configuration.describe(matcher_result, self.expected.deref())
     }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        match self.configuration.explain_occurrences(self.expected.deref(), actual.as_ref()) {
+            Some(explanation) => MatchExplanation::create(explanation),
+            None => {
+                MatchExplanation::create(format!("which {}", self.describe(self.matches(actual))))
+            }
+        }
+    }
 }
 
 impl<ActualT: ?Sized, ExpectedT, MatcherT: Into<StrMatcher<ActualT, ExpectedT>>>
@@ -331,6 +453,20 @@ impl<ActualT: ?Sized, ExpectedT, MatcherT: Into<StrMatcher<ActualT, ExpectedT>>>
         StrMatcher { configuration: existing.configuration.ignoring_ascii_case(), ..existing }
     }
 
+    fn ignoring_case(self) -> StrMatcher<ActualT, ExpectedT> {
+        let existing = self.into();
+        StrMatcher { configuration: existing.configuration.ignoring_case(), ..existing }
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    fn ignoring_unicode_normalization(self) -> StrMatcher<ActualT, ExpectedT> {
+        let existing = self.into();
+        StrMatcher {
+            configuration: existing.configuration.ignoring_unicode_normalization(),
+            ..existing
+        }
+    }
+
     fn times(
         self,
         times: impl Matcher<ActualT = usize> + 'static,
@@ -377,6 +513,8 @@ struct Configuration {
     ignore_leading_whitespace: bool,
     ignore_trailing_whitespace: bool,
     case_policy: CasePolicy,
+    #[cfg(feature = "unicode-normalization")]
+    normalize_unicode: bool,
     times: Option<Box<dyn Matcher<ActualT = usize>>>,
 }
 
@@ -394,40 +532,39 @@ enum CasePolicy {
     #[default]
     Respect,
     IgnoreAscii,
+    IgnoreUnicode,
 }
 
 impl Configuration {
     // The entry point for all string matching. StrMatcher::matches redirects
     // immediately to this function.
     fn do_strings_match(&self, expected: &str, actual: &str) -> bool {
-        let (expected, actual) =
-            match (self.ignore_leading_whitespace, self.ignore_trailing_whitespace) {
-                (true, true) => (expected.trim(), actual.trim()),
-                (true, false) => (expected.trim_start(), actual.trim_start()),
-                (false, true) => (expected.trim_end(), actual.trim_end()),
-                (false, false) => (expected, actual),
-            };
+        let (expected, actual) = self.trim(expected, actual);
+        let (expected, actual) = (self.normalize(expected), self.normalize(actual));
+        let (expected, actual) = (expected.as_ref(), actual.as_ref());
         match self.mode {
             MatchMode::Equals => match self.case_policy {
-                CasePolicy::Respect => expected == actual,
+                CasePolicy::Respect | CasePolicy::IgnoreUnicode => expected == actual,
                 CasePolicy::IgnoreAscii => expected.eq_ignore_ascii_case(actual),
             },
             MatchMode::Contains => match self.case_policy {
-                CasePolicy::Respect => self.does_containment_match(actual, expected),
+                CasePolicy::Respect | CasePolicy::IgnoreUnicode => {
+                    self.does_containment_match(actual, expected)
+                }
                 CasePolicy::IgnoreAscii => self.does_containment_match(
                     actual.to_ascii_lowercase().as_str(),
                     expected.to_ascii_lowercase().as_str(),
                 ),
             },
             MatchMode::StartsWith => match self.case_policy {
-                CasePolicy::Respect => actual.starts_with(expected),
+                CasePolicy::Respect | CasePolicy::IgnoreUnicode => actual.starts_with(expected),
                 CasePolicy::IgnoreAscii => {
                     actual.len() >= expected.len()
                         && actual[..expected.len()].eq_ignore_ascii_case(expected)
                 }
             },
             MatchMode::EndsWith => match self.case_policy {
-                CasePolicy::Respect => actual.ends_with(expected),
+                CasePolicy::Respect | CasePolicy::IgnoreUnicode => actual.ends_with(expected),
                 CasePolicy::IgnoreAscii => {
                     actual.len() >= expected.len()
                         && actual[actual.len() - expected.len()..].eq_ignore_ascii_case(expected)
@@ -436,6 +573,35 @@ impl Configuration {
         }
     }
 
+    // Removes whitespace from the given strings as configured.
+    fn trim<'a>(&self, expected: &'a str, actual: &'a str) -> (&'a str, &'a str) {
+        match (self.ignore_leading_whitespace, self.ignore_trailing_whitespace) {
+            (true, true) => (expected.trim(), actual.trim()),
+            (true, false) => (expected.trim_start(), actual.trim_start()),
+            (false, true) => (expected.trim_end(), actual.trim_end()),
+            (false, false) => (expected, actual),
+        }
+    }
+
+    // Brings the given string into the form in which the comparisons of
+    // do_strings_match take place: in Normalization Form C if requested, and
+    // with its case folded when ignoring Unicode case. All other
+    // configurations leave the string untouched.
+    fn normalize<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        let value = Cow::Borrowed(value);
+        #[cfg(feature = "unicode-normalization")]
+        let value = if self.normalize_unicode {
+            use unicode_normalization::UnicodeNormalization;
+            Cow::Owned(value.nfc().collect())
+        } else {
+            value
+        };
+        match self.case_policy {
+            CasePolicy::IgnoreUnicode => Cow::Owned(fold_case(&value)),
+            CasePolicy::Respect | CasePolicy::IgnoreAscii => value,
+        }
+    }
+
     // Returns whether actual contains expected a number of times matched by the
     // matcher self.times. Does not take other configuration into account.
     fn does_containment_match(&self, actual: &str, expected: &str) -> bool {
@@ -449,6 +615,53 @@ impl Configuration {
         }
     }
 
+    // Lists the byte offsets in actual at which the disjoint occurrences of
+    // expected start, taking all other configuration into account. The offsets
+    // refer to the string after any Unicode normalization or case folding.
+    fn occurrences(&self, expected: &str, actual: &str) -> Vec<usize> {
+        let leading_whitespace = if self.ignore_leading_whitespace {
+            actual.len() - actual.trim_start().len()
+        } else {
+            0
+        };
+        let (expected, actual) = self.trim(expected, actual);
+        let (mut expected, mut actual) = (self.normalize(expected), self.normalize(actual));
+        if let CasePolicy::IgnoreAscii = self.case_policy {
+            expected = Cow::Owned(expected.to_ascii_lowercase());
+            actual = Cow::Owned(actual.to_ascii_lowercase());
+        }
+        actual
+            .match_indices(expected.as_ref())
+            .map(|(offset, _)| offset + leading_whitespace)
+            .collect()
+    }
+
+    // Explains how often and where expected occurs in actual if the matcher
+    // constrains the number of occurrences, and returns None otherwise.
+    fn explain_occurrences(&self, expected: &str, actual: &str) -> Option<String> {
+        const MAX_LISTED_OFFSETS: usize = 10;
+        self.times.as_ref()?;
+        let offsets = self.occurrences(expected, actual);
+        let listed = offsets
+            .iter()
+            .take(MAX_LISTED_OFFSETS)
+            .map(|offset| offset.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let explanation = match offsets.len() {
+            0 => "which does not contain the substring".to_string(),
+            1 => format!("which contains the substring once, at byte offset {listed}"),
+            count if count <= MAX_LISTED_OFFSETS => {
+                format!("which contains the substring {count} times, at byte offsets {listed}")
+            }
+            count => format!(
+                "which contains the substring {count} times, at byte offsets {listed} and {} more",
+                count - MAX_LISTED_OFFSETS
+            ),
+        };
+        Some(explanation)
+    }
+
     // StrMatcher::describe redirects immediately to this function.
     fn describe(&self, matcher_result: MatcherResult, expected: &str) -> String {
         let mut addenda: Vec<Cow<'static, str>> = Vec::with_capacity(3);
@@ -461,6 +674,11 @@ impl Configuration {
         match self.case_policy {
             CasePolicy::Respect => {}
             CasePolicy::IgnoreAscii => addenda.push("ignoring ASCII case".into()),
+            CasePolicy::IgnoreUnicode => addenda.push("ignoring case".into()),
+        }
+        #[cfg(feature = "unicode-normalization")]
+        if self.normalize_unicode {
+            addenda.push("ignoring Unicode normalization".into());
         }
         if let Some(times) = self.times.as_ref() {
             addenda.push(format!("count {}", times.describe(matcher_result)).into());
@@ -504,6 +722,15 @@ impl Configuration {
         Self { case_policy: CasePolicy::IgnoreAscii, ..self }
     }
 
+    fn ignoring_case(self) -> Self {
+        Self { case_policy: CasePolicy::IgnoreUnicode, ..self }
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    fn ignoring_unicode_normalization(self) -> Self {
+        Self { normalize_unicode: true, ..self }
+    }
+
     fn times(self, times: impl Matcher<ActualT = usize> + 'static) -> Self {
         Self { times: Some(Box::new(times)), ..self }
     }
@@ -511,6 +738,8 @@ impl Configuration {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "unicode-normalization")]
+    use super::eq_normalized;
     use super::{contains_substring, ends_with, starts_with, StrMatcher, StrMatcherConfigurator};
     use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
@@ -654,6 +883,16 @@ mod tests {
         verify_that!("Some string", contains_substring("STR").ignoring_ascii_case())
     }
 
+    #[test]
+    fn matches_cow_string_without_conversion() -> Result<()> {
+        let borrowed: std::borrow::Cow<str> = "Some string".into();
+        let owned: std::borrow::Cow<str> = String::from("Some string").into();
+
+        verify_that!(borrowed, contains_substring("str"))?;
+        verify_that!(owned, starts_with("Some"))?;
+        verify_that!(owned, eq("Some string"))
+    }
+
     #[test]
     fn contains_substring_matches_correct_number_of_substrings() -> Result<()> {
         verify_that!("Some string", contains_substring("str").times(eq(1)))
@@ -872,4 +1111,189 @@ mod tests {
             eq("does not end with \"A string\"")
         )
     }
+
+    #[test]
+    fn contains_substring_matches_ignoring_unicode_case() -> Result<()> {
+        verify_that!("Straße ÉTÉ", contains_substring("strasse été").ignoring_case())
+    }
+
+    #[test]
+    fn contains_substring_counts_occurrences_ignoring_unicode_case() -> Result<()> {
+        verify_that!("Éa éb ÉC", contains_substring("é").ignoring_case().times(eq(3)))
+    }
+
+    #[test]
+    fn starts_with_matches_prefix_ignoring_unicode_case() -> Result<()> {
+        verify_that!("ΣΊΣΥΦΟΣ", starts_with("σίσ").ignoring_case())
+    }
+
+    #[test]
+    fn starts_with_does_not_match_wrong_prefix_ignoring_unicode_case() -> Result<()> {
+        verify_that!("Warning: x", not(starts_with("ERROR").ignoring_case()))
+    }
+
+    #[test]
+    fn ends_with_matches_suffix_ignoring_unicode_case() -> Result<()> {
+        verify_that!("Grüße", ends_with("ÜSSE").ignoring_case())
+    }
+
+    #[test]
+    fn eq_matches_ignoring_unicode_case() -> Result<()> {
+        verify_that!("Ünïcödé", eq("üNÏCÖDÉ").ignoring_case())
+    }
+
+    #[test]
+    fn ignoring_ascii_case_does_not_ignore_unicode_case() -> Result<()> {
+        verify_that!("ÜBER", not(eq("über").ignoring_ascii_case()))
+    }
+
+    #[test]
+    fn describes_itself_ignoring_case() -> Result<()> {
+        let matcher: StrMatcher<&str, _> = ends_with("A string").ignoring_case();
+        verify_that!(
+            Matcher::describe(&matcher, MatcherResult::Matches),
+            eq("ends with suffix \"A string\" (ignoring case)")
+        )
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn contains_substring_matches_ignoring_unicode_normalization() -> Result<()> {
+        verify_that!(
+            "The cafe\u{301} is open",
+            contains_substring("caf\u{e9}").ignoring_unicode_normalization()
+        )
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn ends_with_respects_unicode_normalization_by_default() -> Result<()> {
+        verify_that!("cafe\u{301}", not(ends_with("caf\u{e9}")))
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn ends_with_matches_ignoring_unicode_normalization_and_case() -> Result<()> {
+        verify_that!(
+            "CAFE\u{301}",
+            ends_with("caf\u{e9}").ignoring_unicode_normalization().ignoring_case()
+        )
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn eq_normalized_matches_differently_composed_strings() -> Result<()> {
+        verify_that!("A\u{30a}ngstro\u{308}m", eq_normalized("\u{c5}ngstr\u{f6}m"))?;
+        verify_that!("\u{c5}ngstr\u{f6}m".to_string(), eq_normalized("A\u{30a}ngstro\u{308}m"))
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn eq_normalized_does_not_match_different_characters() -> Result<()> {
+        verify_that!("Cafe", not(eq_normalized("Caf\u{e9}")))
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn eq_normalized_does_not_apply_compatibility_normalization() -> Result<()> {
+        verify_that!("\u{fb01}le", not(eq_normalized("file")))
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn eq_normalized_can_be_configured_further() -> Result<()> {
+        verify_that!(
+            " CAFE\u{301}",
+            eq_normalized("caf\u{e9}").ignoring_case().ignoring_leading_whitespace()
+        )
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn eq_normalized_describes_itself() -> Result<()> {
+        let matcher: StrMatcher<&str, _> = eq_normalized("A string");
+        verify_that!(
+            Matcher::describe(&matcher, MatcherResult::DoesNotMatch),
+            eq("isn't equal to \"A string\" (ignoring Unicode normalization)")
+        )
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn describes_itself_ignoring_unicode_normalization() -> Result<()> {
+        let matcher: StrMatcher<&str, _> =
+            starts_with("A string").ignoring_case().ignoring_unicode_normalization();
+        verify_that!(
+            Matcher::describe(&matcher, MatcherResult::Matches),
+            eq("starts with prefix \"A string\" (ignoring case, ignoring Unicode normalization)")
+        )
+    }
+
+    #[test]
+    fn contains_substring_times_explains_offsets_of_occurrences() -> Result<()> {
+        let result = verify_that!("ok ok ok", contains_substring("ok").times(eq(2)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Actual: \"ok ok ok\", which contains the substring 3 times, at byte offsets 0, 3, 6"
+            )))
+        )
+    }
+
+    #[test]
+    fn contains_substring_times_explains_single_occurrence() -> Result<()> {
+        let matcher: StrMatcher<&str, _> = contains_substring("ok").times(ge(2));
+
+        verify_that!(
+            matcher.explain_match(&"not ok").to_string(),
+            eq("which contains the substring once, at byte offset 4")
+        )
+    }
+
+    #[test]
+    fn contains_substring_times_explains_missing_substring() -> Result<()> {
+        let matcher: StrMatcher<&str, _> = contains_substring("ok").times(ge(1));
+
+        verify_that!(
+            matcher.explain_match(&"failed").to_string(),
+            eq("which does not contain the substring")
+        )
+    }
+
+    #[test]
+    fn contains_substring_times_abbreviates_many_offsets() -> Result<()> {
+        let matcher: StrMatcher<String, _> = contains_substring("a").times(eq(1));
+
+        verify_that!(
+            matcher.explain_match(&"a".repeat(12)).to_string(),
+            eq(
+                "which contains the substring 12 times, at byte offsets 0, 1, 2, 3, 4, 5, 6, 7, 8, 9 \
+                and 2 more"
+            )
+        )
+    }
+
+    #[test]
+    fn contains_substring_times_offsets_refer_to_untrimmed_actual_value() -> Result<()> {
+        let matcher: StrMatcher<&str, _> = contains_substring("OK")
+            .ignoring_leading_whitespace()
+            .ignoring_ascii_case()
+            .times(eq(1));
+
+        verify_that!(
+            matcher.explain_match(&"  ok ok").to_string(),
+            eq("which contains the substring 2 times, at byte offsets 2, 5")
+        )
+    }
+
+    #[test]
+    fn contains_substring_without_times_explains_with_description() -> Result<()> {
+        let matcher: StrMatcher<&str, _> = contains_substring("ok");
+
+        verify_that!(
+            matcher.explain_match(&"failed").to_string(),
+            eq("which does not contain a substring \"ok\"")
+        )
+    }
 }
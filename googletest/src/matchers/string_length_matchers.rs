@@ -0,0 +1,225 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a string whose number of [`char`]s matches `expected`.
+///
+/// Unlike [`str::len`], this counts Unicode scalar values rather than bytes.
+/// The actual value may be either a `String` or a string reference.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("Grüße", char_count(eq(5)))?; // Passes, although "Grüße".len() == 7
+/// verify_that!("Grüße".to_string(), char_count(lt(6)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("Grüße", char_count(eq(7)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// A `char` need not correspond to what a reader perceives as a single
+/// character. Use [`grapheme_count`] to count those instead.
+pub fn char_count<T: AsRef<str> + Debug + ?Sized, E: Matcher<ActualT = usize>>(
+    expected: E,
+) -> impl Matcher<ActualT = T> {
+    StringLengthMatcher { expected, unit: Unit::Char, phantom: Default::default() }
+}
+
+/// Matches a string whose number of extended grapheme clusters matches
+/// `expected`.
+///
+/// An extended grapheme cluster approximates a character as a reader perceives
+/// it, such as a letter followed by combining accents or an emoji composed of
+/// several code points. The actual value may be either a `String` or a string
+/// reference.
+///
+/// ```
+/// # #[cfg(feature = "unicode-segmentation")] {
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("cafe\u{301}", grapheme_count(eq(4)))?; // Passes, but has 5 chars
+/// verify_that!("👩‍🔬", grapheme_count(eq(1)))?; // Passes, but has 3 chars
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("cafe\u{301}", grapheme_count(eq(5)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// # }
+/// ```
+///
+/// This requires the feature `unicode-segmentation`.
+#[cfg(feature = "unicode-segmentation")]
+pub fn grapheme_count<T: AsRef<str> + Debug + ?Sized, E: Matcher<ActualT = usize>>(
+    expected: E,
+) -> impl Matcher<ActualT = T> {
+    StringLengthMatcher { expected, unit: Unit::Grapheme, phantom: Default::default() }
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Char,
+    #[cfg(feature = "unicode-segmentation")]
+    Grapheme,
+}
+
+impl Unit {
+    fn count(self, value: &str) -> usize {
+        match self {
+            Unit::Char => value.chars().count(),
+            #[cfg(feature = "unicode-segmentation")]
+            Unit::Grapheme => {
+                use unicode_segmentation::UnicodeSegmentation;
+                value.graphemes(true).count()
+            }
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Unit::Char => "character",
+            #[cfg(feature = "unicode-segmentation")]
+            Unit::Grapheme => "grapheme cluster",
+        }
+    }
+}
+
+struct StringLengthMatcher<T: ?Sized, E> {
+    expected: E,
+    unit: Unit,
+    phantom: PhantomData<T>,
+}
+
+impl<T: AsRef<str> + Debug + ?Sized, E: Matcher<ActualT = usize>> Matcher
+    for StringLengthMatcher<T, E>
+{
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        self.expected.matches(&self.unit.count(actual.as_ref()))
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!(
+            "has a {} count, which {}",
+            self.unit.name(),
+            self.expected.describe(matcher_result)
+        )
+    }
+
+    fn explain_match(&self, actual: &T) -> MatchExplanation {
+        let count = self.unit.count(actual.as_ref());
+        let inner = explain(&self.expected, &count);
+        MatchExplanation::create(format!(
+            "which has {count} {}{}, {inner}",
+            self.unit.name(),
+            if count == 1 { "" } else { "s" },
+        ))
+        .with_child(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::char_count;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn char_count_counts_chars_rather_than_bytes() -> Result<()> {
+        verify_that!("Grüße", char_count(eq(5)))
+    }
+
+    #[test]
+    fn char_count_matches_owned_string() -> Result<()> {
+        verify_that!("Grüße".to_string(), char_count(ge(5)))
+    }
+
+    #[test]
+    fn char_count_does_not_match_byte_length() -> Result<()> {
+        verify_that!("Grüße", not(char_count(eq(7))))
+    }
+
+    #[test]
+    fn char_count_describes_itself() -> Result<()> {
+        let matcher = char_count::<str, _>(eq(3));
+
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq("has a character count, which is equal to 3")
+        )
+    }
+
+    #[test]
+    fn char_count_explains_mismatch() -> Result<()> {
+        let result = verify_that!("日本", char_count(gt(2)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: has a character count, which is greater than 2
+                Actual: "日本", which has 2 characters, which is less than or equal to 2
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn char_count_explains_single_character() -> Result<()> {
+        let matcher = char_count::<str, _>(eq(2));
+
+        verify_that!(
+            matcher.explain_match("é").to_string(),
+            eq("which has 1 character, which isn't equal to 2")
+        )
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    mod grapheme_count {
+        use super::super::grapheme_count;
+        use crate::matcher::Matcher;
+        use crate::prelude::*;
+
+        #[test]
+        fn grapheme_count_counts_combining_sequences_once() -> Result<()> {
+            verify_that!("cafe\u{301}", grapheme_count(eq(4)))
+        }
+
+        #[test]
+        fn grapheme_count_counts_emoji_sequence_once() -> Result<()> {
+            verify_that!("\u{1f469}\u{200d}\u{1f52c}!", grapheme_count(eq(2)))
+        }
+
+        #[test]
+        fn grapheme_count_explains_mismatch() -> Result<()> {
+            let matcher = grapheme_count::<String, _>(eq(5));
+
+            verify_that!(
+                matcher.explain_match(&"cafe\u{301}".to_string()).to_string(),
+                eq("which has 4 grapheme clusters, which isn't equal to 5")
+            )
+        }
+    }
+}
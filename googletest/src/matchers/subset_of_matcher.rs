@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::debug_layout::debug_string;
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Matches a container all of whose items are in the given container
@@ -73,6 +74,11 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # should_pass().unwrap();
 /// ```
 ///
+/// On a mismatch, the explanation lists the elements of the actual container
+/// which `superset` lacks, together with their indices. To match the elements
+/// against matchers rather than expected values, use
+/// [`is_contained_in!`][crate::is_contained_in].
+///
 /// A note on performance: This matcher uses a naive algorithm with a worst-case
 /// runtime proportional to the *product* of the sizes of the actual and
 /// expected containers as well as the time to check equality of each pair of
@@ -84,7 +90,7 @@ where
     for<'a> &'a ActualT: IntoIterator<Item = &'a ElementT>,
     for<'a> &'a ExpectedT: IntoIterator<Item = &'a ElementT>,
 {
-    SubsetOfMatcher::<ActualT, _> { superset, phantom: PhantomData::default() }
+    SubsetOfMatcher::<ActualT, _> { superset, phantom: PhantomData }
 }
 
 struct SubsetOfMatcher<ActualT: ?Sized, ExpectedT> {
@@ -114,7 +120,7 @@ where
             .into_iter()
             .enumerate()
             .filter(|&(_, actual_item)| self.expected_is_missing(actual_item))
-            .map(|(idx, actual_item)| format!("{actual_item:#?} at #{idx}"))
+            .map(|(idx, actual_item)| format!("{} at #{idx}", debug_string(actual_item)))
             .collect::<Vec<_>>();
 
         match unexpected_elements.len() {
@@ -132,8 +138,10 @@ where
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
         match matcher_result {
-            MatcherResult::Matches => format!("is a subset of {:#?}", self.superset),
-            MatcherResult::DoesNotMatch => format!("isn't a subset of {:#?}", self.superset),
+            MatcherResult::Matches => format!("is a subset of {}", debug_string(&self.superset)),
+            MatcherResult::DoesNotMatch => {
+                format!("isn't a subset of {}", debug_string(&self.superset))
+            }
         }
     }
 }
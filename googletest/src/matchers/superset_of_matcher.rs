@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::debug_layout::debug_string;
 use std::{fmt::Debug, marker::PhantomData};
 
 /// Matches a container containing all of the items in the given container
@@ -73,6 +74,10 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # should_pass().unwrap();
 /// ```
 ///
+/// On a mismatch, the explanation lists the items of `subset` which the actual
+/// container lacks. To match the elements against matchers rather than
+/// expected values, use [`contains_each!`][crate::contains_each].
+///
 /// A note on performance: This matcher uses a naive algorithm with a worst-case
 /// runtime proportional to the *product* of the sizes of the actual and
 /// expected containers as well as the time to check equality of each pair of
@@ -110,12 +115,15 @@ where
     }
 
     fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
-        let missing_items: Vec<_> = self
-            .subset
-            .into_iter()
-            .filter(|expected_item| actual_is_missing(actual, expected_item))
-            .map(|expected_item| format!("{expected_item:#?}"))
-            .collect();
+        // Multiplicity is ignored, so an item repeated in the subset is listed
+        // only once.
+        let mut missing_items: Vec<&ElementT> = vec![];
+        for expected_item in &self.subset {
+            if actual_is_missing(actual, expected_item) && !missing_items.contains(&expected_item) {
+                missing_items.push(expected_item);
+            }
+        }
+        let missing_items: Vec<_> = missing_items.into_iter().map(debug_string).collect();
         match missing_items.len() {
             0 => MatchExplanation::create("whose no element is missing".to_string()),
             1 => {
@@ -130,8 +138,10 @@ where
 
     fn describe(&self, matcher_result: MatcherResult) -> String {
         match matcher_result {
-            MatcherResult::Matches => format!("is a superset of {:#?}", self.subset),
-            MatcherResult::DoesNotMatch => format!("isn't a superset of {:#?}", self.subset),
+            MatcherResult::Matches => format!("is a superset of {}", debug_string(&self.subset)),
+            MatcherResult::DoesNotMatch => {
+                format!("isn't a superset of {}", debug_string(&self.subset))
+            }
         }
     }
 }
@@ -277,4 +287,10 @@ Actual: [
             )))
         )
     }
+    #[test]
+    fn superset_of_lists_repeated_missing_item_once() -> Result<()> {
+        let result = verify_that!(vec![1], superset_of([2, 2, 1]));
+
+        verify_that!(result, err(displays_as(contains_substring("whose element 2 is missing"))))
+    }
 }
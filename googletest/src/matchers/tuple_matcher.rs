@@ -313,7 +313,7 @@ macro_rules! tuple_internal {
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
 pub mod internal {
-    use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
     use std::{
         fmt::{Debug, Write},
         marker::PhantomData,
@@ -362,18 +362,21 @@ pub mod internal {
 
                 fn explain_match(&self, actual: &($($field_type,)*)) -> MatchExplanation {
                     let mut explanation = format!("which {}", self.describe(self.matches(actual)));
+                    let mut children = vec![];
                     $(match self.$field_number.matches(&actual.$field_number) {
                         MatcherResult::Matches => {},
                         MatcherResult::DoesNotMatch => {
+                            let inner = explain(&self.$field_number, &actual.$field_number);
                             writeln!(
                                 &mut explanation,
                                 concat!("Element #", $field_number, " is {:?}, {}"),
                                 actual.$field_number,
-                                self.$field_number.explain_match(&actual.$field_number)
+                                inner
                             ).unwrap();
+                            children.push(inner);
                         }
                     })*
-                    MatchExplanation::create(explanation)
+                    MatchExplanation::create(explanation).with_children(children)
                 }
 
                 fn describe(&self, matcher_result: MatcherResult) -> String {
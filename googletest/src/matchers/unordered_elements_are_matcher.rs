@@ -42,6 +42,9 @@
 /// # should_fail_3().unwrap_err();
 /// ```
 ///
+/// A plain value can be given in place of a matcher, in which case it matches
+/// the elements equal to it.
+///
 /// The actual value must be a container implementing [`IntoIterator`]. This
 /// includes standard containers, slices (when dereferenced) and arrays.
 ///
@@ -62,7 +65,8 @@
 /// ```
 ///
 /// This matcher does not support matching directly against an [`Iterator`]. To
-/// match against an iterator, use [`Iterator::collect`] to build a [`Vec`].
+/// match against an iterator, wrap it with [`yielded`][crate::matchers::yielded]
+/// or use [`Iterator::collect`] to build a [`Vec`].
 ///
 /// The matcher proceeds in three stages:
 ///
@@ -103,7 +107,7 @@ macro_rules! unordered_elements_are {
             UnorderedElementsOfMapAreMatcher, Requirements
         };
         UnorderedElementsOfMapAreMatcher::new(
-            [$((&$key_matcher, &$value_matcher)),*],
+            [$((Box::new($crate::matcher::IntoMatcher::into_matcher(&$key_matcher)), Box::new($crate::matcher::IntoMatcher::into_matcher(&$value_matcher)))),*],
             Requirements::PerfectMatch
         )
     }};
@@ -112,7 +116,7 @@ macro_rules! unordered_elements_are {
         use $crate::matchers::unordered_elements_are_matcher::internal::{
             UnorderedElementsAreMatcher, Requirements
         };
-        UnorderedElementsAreMatcher::new([$(&$matcher),*], Requirements::PerfectMatch)
+        UnorderedElementsAreMatcher::new([$(Box::new($crate::matcher::IntoMatcher::into_matcher(&$matcher))),*], Requirements::PerfectMatch)
     }};
 }
 
@@ -168,7 +172,8 @@ macro_rules! unordered_elements_are {
 /// ```
 ///
 /// This matcher does not support matching directly against an [`Iterator`]. To
-/// match against an iterator, use [`Iterator::collect`] to build a [`Vec`].
+/// match against an iterator, wrap it with [`yielded`][crate::matchers::yielded]
+/// or use [`Iterator::collect`] to build a [`Vec`].
 ///
 /// The matcher proceeds in three stages:
 ///
@@ -185,6 +190,9 @@ macro_rules! unordered_elements_are {
 ///    message then shows the best matching it could find, including which
 ///    matchers did not have corresponding unique elements in the container.
 ///
+/// To compare the elements with a container of expected values, ignoring
+/// multiplicity, use [`superset_of`][crate::matchers::superset_of] instead.
+///
 /// [`IntoIterator`]: std::iter::IntoIterator
 /// [`Iterator`]: std::iter::Iterator
 /// [`Iterator::collect`]: std::iter::Iterator::collect
@@ -205,7 +213,7 @@ macro_rules! contains_each {
             UnorderedElementsOfMapAreMatcher, Requirements
         };
         UnorderedElementsOfMapAreMatcher::new(
-            [$((&$key_matcher, &$value_matcher)),*],
+            [$((Box::new($crate::matcher::IntoMatcher::into_matcher(&$key_matcher)), Box::new($crate::matcher::IntoMatcher::into_matcher(&$value_matcher)))),*],
             Requirements::Superset
         )
     }};
@@ -214,7 +222,7 @@ macro_rules! contains_each {
         use $crate::matchers::unordered_elements_are_matcher::internal::{
             UnorderedElementsAreMatcher, Requirements
         };
-        UnorderedElementsAreMatcher::new([$(&$matcher),*], Requirements::Superset)
+        UnorderedElementsAreMatcher::new([$(Box::new($crate::matcher::IntoMatcher::into_matcher(&$matcher))),*], Requirements::Superset)
     }}
 }
 
@@ -274,7 +282,8 @@ macro_rules! contains_each {
 /// ```
 ///
 /// This matcher does not support matching directly against an [`Iterator`]. To
-/// match against an iterator, use [`Iterator::collect`] to build a [`Vec`].
+/// match against an iterator, wrap it with [`yielded`][crate::matchers::yielded]
+/// or use [`Iterator::collect`] to build a [`Vec`].
 ///
 /// The matcher proceeds in three stages:
 ///
@@ -291,6 +300,9 @@ macro_rules! contains_each {
 ///    message then shows the best matching it could find, including which
 ///    container elements did not have corresponding matchers.
 ///
+/// To compare the elements with a container of expected values, ignoring
+/// multiplicity, use [`subset_of`][crate::matchers::subset_of] instead.
+///
 /// [`IntoIterator`]: std::iter::IntoIterator
 /// [`Iterator`]: std::iter::Iterator
 /// [`Iterator::collect`]: std::iter::Iterator::collect
@@ -311,7 +323,7 @@ macro_rules! is_contained_in {
             UnorderedElementsOfMapAreMatcher, Requirements
         };
         UnorderedElementsOfMapAreMatcher::new(
-            [$((&$key_matcher, &$value_matcher)),*],
+            [$((Box::new($crate::matcher::IntoMatcher::into_matcher(&$key_matcher)), Box::new($crate::matcher::IntoMatcher::into_matcher(&$value_matcher)))),*],
             Requirements::Subset
         )
     }};
@@ -320,7 +332,7 @@ macro_rules! is_contained_in {
         use $crate::matchers::unordered_elements_are_matcher::internal::{
             UnorderedElementsAreMatcher, Requirements
         };
-        UnorderedElementsAreMatcher::new([$(&$matcher),*], Requirements::Subset)
+        UnorderedElementsAreMatcher::new([$(Box::new($crate::matcher::IntoMatcher::into_matcher(&$matcher))),*], Requirements::Subset)
     }}
 }
 
@@ -331,6 +343,7 @@ macro_rules! is_contained_in {
 pub mod internal {
     use crate::matcher::{MatchExplanation, Matcher, MatcherResult};
     use crate::matcher_support::count_elements::count_elements;
+    use crate::matcher_support::debug_layout::debug_string;
     use crate::matcher_support::description::Description;
     use std::collections::HashSet;
     use std::fmt::{Debug, Display};
@@ -342,7 +355,7 @@ pub mod internal {
     /// **For internal use only. API stablility is not guaranteed!**
     #[doc(hidden)]
     pub struct UnorderedElementsAreMatcher<'a, ContainerT: ?Sized, T: Debug, const N: usize> {
-        elements: [&'a dyn Matcher<ActualT = T>; N],
+        elements: [Box<dyn Matcher<ActualT = T> + 'a>; N],
         requirements: Requirements,
         phantom: PhantomData<ContainerT>,
     }
@@ -351,7 +364,7 @@ pub mod internal {
         UnorderedElementsAreMatcher<'a, ContainerT, T, N>
     {
         pub fn new(
-            elements: [&'a dyn Matcher<ActualT = T>; N],
+            elements: [Box<dyn Matcher<ActualT = T> + 'a>; N],
             requirements: Requirements,
         ) -> Self {
             Self { elements, requirements, phantom: Default::default() }
@@ -415,6 +428,13 @@ pub mod internal {
         }
     }
 
+    /// A pair of matchers for the key and the value of a map entry.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub type KeyValueMatcher<'a, KeyT, ValueT> =
+        (Box<dyn Matcher<ActualT = KeyT> + 'a>, Box<dyn Matcher<ActualT = ValueT> + 'a>);
+
     /// This is the analogue to [UnorderedElementsAreMatcher] for maps and
     /// map-like collections.
     ///
@@ -426,7 +446,7 @@ pub mod internal {
         KeyT: Debug,
         ValueT: Debug,
     {
-        elements: [(&'a dyn Matcher<ActualT = KeyT>, &'a dyn Matcher<ActualT = ValueT>); N],
+        elements: [KeyValueMatcher<'a, KeyT, ValueT>; N],
         requirements: Requirements,
         phantom: PhantomData<ContainerT>,
     }
@@ -435,7 +455,7 @@ pub mod internal {
         UnorderedElementsOfMapAreMatcher<'a, ContainerT, KeyT, ValueT, N>
     {
         pub fn new(
-            elements: [(&'a dyn Matcher<ActualT = KeyT>, &'a dyn Matcher<ActualT = ValueT>); N],
+            elements: [KeyValueMatcher<'a, KeyT, ValueT>; N],
             requirements: Requirements,
         ) -> Self {
             Self { elements, requirements, phantom: Default::default() }
@@ -572,7 +592,7 @@ pub mod internal {
     impl<const N: usize> MatchMatrix<N> {
         fn generate<'a, T: Debug, ContainerT: Debug + ?Sized>(
             actual: &ContainerT,
-            expected: &[&'a dyn Matcher<ActualT = T>; N],
+            expected: &[Box<dyn Matcher<ActualT = T> + 'a>; N],
         ) -> Self
         where
             for<'b> &'b ContainerT: IntoIterator<Item = &'b T>,
@@ -589,7 +609,7 @@ pub mod internal {
 
         fn generate_for_map<'a, KeyT: Debug, ValueT: Debug, ContainerT: Debug + ?Sized>(
             actual: &ContainerT,
-            expected: &[(&'a dyn Matcher<ActualT = KeyT>, &'a dyn Matcher<ActualT = ValueT>); N],
+            expected: &[KeyValueMatcher<'a, KeyT, ValueT>; N],
         ) -> Self
         where
             for<'b> &'b ContainerT: IntoIterator<Item = (&'b KeyT, &'b ValueT)>,
@@ -933,7 +953,7 @@ pub mod internal {
         fn get_explanation<'a, T: Debug, ContainerT: Debug + ?Sized>(
             &self,
             actual: &ContainerT,
-            expected: &[&'a dyn Matcher<ActualT = T>; N],
+            expected: &[Box<dyn Matcher<ActualT = T> + 'a>; N],
             requirements: Requirements,
         ) -> Option<String>
         where
@@ -957,8 +977,8 @@ pub mod internal {
 
             let unmatched_actual = self.get_unmatched_actual().map(|actual_idx| {
                 format!(
-                    "Actual element {:#?} at index {actual_idx} did not match any remaining expected element.",
-                    actual[actual_idx]
+                    "Actual element {} at index {actual_idx} did not match any remaining expected element.",
+                    debug_string(&actual[actual_idx])
                 )
             });
 
@@ -980,7 +1000,7 @@ pub mod internal {
         fn get_explanation_for_map<'a, KeyT: Debug, ValueT: Debug, ContainerT: Debug + ?Sized>(
             &self,
             actual: &ContainerT,
-            expected: &[(&'a dyn Matcher<ActualT = KeyT>, &'a dyn Matcher<ActualT = ValueT>); N],
+            expected: &[KeyValueMatcher<'a, KeyT, ValueT>; N],
             requirements: Requirements,
         ) -> Option<String>
         where
@@ -1009,9 +1029,9 @@ pub mod internal {
             let unmatched_actual = self.get_unmatched_actual()
                 .map(|actual_idx| {
                     format!(
-                        "Actual element {:#?} => {:#?} at index {actual_idx} did not match any remaining expected element.",
-                        actual[actual_idx].0,
-                        actual[actual_idx].1,
+                        "Actual element {} => {} at index {actual_idx} did not match any remaining expected element.",
+                        debug_string(&actual[actual_idx].0),
+                        debug_string(&actual[actual_idx].1),
                     )
                 });
 
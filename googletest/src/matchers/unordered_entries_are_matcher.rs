@@ -0,0 +1,262 @@
+// Copyright 2022 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macro is documented at the top level.
+#![doc(hidden)]
+
+/// Matches a map whose entries are exactly the given keys, in any order, with
+/// values matching the corresponding matchers.
+///
+/// Each argument has the form `key => value_matcher`. The keys are compared
+/// with `==`, while the values are matched by the matchers:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::HashMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = HashMap::from([("alice", 31), ("bob", 25)]);
+/// verify_that!(value, unordered_entries_are!["bob" => lt(30), "alice" => eq(31)])?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = HashMap::from([("alice", 31), ("bob", 25)]);
+/// verify_that!(value, unordered_entries_are!["alice" => eq(30), "carol" => anything()])?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The actual value can be any map over whose reference one can iterate to
+/// obtain `(&key, &value)` pairs, such as
+/// [`HashMap`][std::collections::HashMap] and
+/// [`BTreeMap`][std::collections::BTreeMap].
+///
+/// On a mismatch, the explanation lists the expected keys which are missing,
+/// the keys which are present without being expected, and the values which
+/// do not match, each separately.
+#[macro_export]
+macro_rules! unordered_entries_are {
+    ($($key:expr => $value_matcher:expr),* $(,)?) => {{
+        use $crate::matchers::unordered_entries_are_matcher::internal::UnorderedEntriesAreMatcher;
+        UnorderedEntriesAreMatcher::new([$(($key, Box::new($crate::matcher::IntoMatcher::into_matcher(&$value_matcher)))),*])
+    }};
+}
+
+/// Functionality needed by the [`unordered_entries_are`] macro.
+///
+/// For internal use only. API stablility is not guaranteed!
+#[doc(hidden)]
+pub mod internal {
+    use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+    use crate::matcher_support::description::Description;
+    use std::fmt::Debug;
+    use std::marker::PhantomData;
+
+    /// A matcher for a map whose entries are exactly the keys in `entries`
+    /// with values matching their matchers.
+    ///
+    /// For internal use only. API stablility is not guaranteed!
+    #[doc(hidden)]
+    pub struct UnorderedEntriesAreMatcher<'a, MapT: ?Sized, KeyT, ValueT, const N: usize> {
+        entries: [(KeyT, Box<dyn Matcher<ActualT = ValueT> + 'a>); N],
+        phantom: PhantomData<MapT>,
+    }
+
+    /// An entry of the actual map whose value does not match, along with the
+    /// matcher of its value.
+    type Mismatch<'b, KeyT, ValueT> = (&'b KeyT, &'b ValueT, &'b dyn Matcher<ActualT = ValueT>);
+
+    impl<'a, MapT: ?Sized, KeyT, ValueT, const N: usize>
+        UnorderedEntriesAreMatcher<'a, MapT, KeyT, ValueT, N>
+    {
+        /// Constructs an [`UnorderedEntriesAreMatcher`] with the given
+        /// expected keys and value matchers.
+        ///
+        /// Intended for use only by the [`unordered_entries_are`] macro.
+        pub fn new(entries: [(KeyT, Box<dyn Matcher<ActualT = ValueT> + 'a>); N]) -> Self {
+            Self { entries, phantom: Default::default() }
+        }
+    }
+
+    impl<'a, MapT, KeyT, ValueT, const N: usize> UnorderedEntriesAreMatcher<'a, MapT, KeyT, ValueT, N>
+    where
+        MapT: ?Sized,
+        KeyT: PartialEq + Debug,
+        ValueT: Debug,
+        for<'b> &'b MapT: IntoIterator<Item = (&'b KeyT, &'b ValueT)>,
+    {
+        /// Returns the expected keys missing from `actual`, the keys of
+        /// `actual` which are not expected, and the entries of `actual` whose
+        /// values do not match, along with their matchers.
+        fn differences<'b>(
+            &'b self,
+            actual: &'b MapT,
+        ) -> (Vec<String>, Vec<String>, Vec<Mismatch<'b, KeyT, ValueT>>) {
+            let mut missing = vec![];
+            let mut mismatched = vec![];
+            for (expected_key, matcher) in &self.entries {
+                match actual.into_iter().find(|(key, _)| *key == expected_key) {
+                    None => missing.push(format!("{expected_key:?}")),
+                    Some((key, value)) if !matcher.matches(value).into_bool() => {
+                        mismatched.push((key, value, matcher.as_ref()))
+                    }
+                    Some(_) => {}
+                }
+            }
+            let unexpected = actual
+                .into_iter()
+                .filter(|(key, _)| !self.entries.iter().any(|(expected, _)| expected == *key))
+                .map(|(key, _)| format!("{key:?}"))
+                .collect();
+            (missing, unexpected, mismatched)
+        }
+    }
+
+    impl<'a, MapT, KeyT, ValueT, const N: usize> Matcher
+        for UnorderedEntriesAreMatcher<'a, MapT, KeyT, ValueT, N>
+    where
+        MapT: Debug + ?Sized,
+        KeyT: PartialEq + Debug,
+        ValueT: Debug,
+        for<'b> &'b MapT: IntoIterator<Item = (&'b KeyT, &'b ValueT)>,
+    {
+        type ActualT = MapT;
+
+        fn matches(&self, actual: &MapT) -> MatcherResult {
+            let (missing, unexpected, mismatched) = self.differences(actual);
+            (missing.is_empty() && unexpected.is_empty() && mismatched.is_empty()).into()
+        }
+
+        fn explain_match(&self, actual: &MapT) -> MatchExplanation {
+            let (missing, unexpected, mismatched) = self.differences(actual);
+            let mut problems = vec![];
+            if !missing.is_empty() {
+                problems.push(format!("is missing the keys {}", missing.join(", ")));
+            }
+            if !unexpected.is_empty() {
+                problems.push(format!("has the unexpected keys {}", unexpected.join(", ")));
+            }
+            let children = mismatched
+                .iter()
+                .map(|(_, value, matcher)| explain(*matcher, *value))
+                .collect::<Vec<_>>();
+            if !mismatched.is_empty() {
+                problems.push(format!(
+                    "has values which don't match:\n{}",
+                    mismatched
+                        .iter()
+                        .zip(&children)
+                        .map(|((key, value, _), inner)| format!("{key:?} => {value:?}, {inner}"))
+                        .collect::<Description>()
+                        .indent()
+                ));
+            }
+            if problems.is_empty() {
+                return MatchExplanation::create("which has exactly the expected entries".into());
+            }
+            MatchExplanation::create(format!(
+                "which\n{}",
+                problems.into_iter().collect::<Description>().bullet_list().indent()
+            ))
+            .with_children(children)
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> String {
+            format!(
+                "{} exactly the following entries in any order:\n{}",
+                if matcher_result.into() { "contains" } else { "doesn't contain" },
+                self.entries
+                    .iter()
+                    .map(|(key, matcher)| format!(
+                        "{key:?} => {}",
+                        matcher.describe(MatcherResult::Matches)
+                    ))
+                    .collect::<Description>()
+                    .indent()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::internal;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn matches_entries_in_any_order() -> Result<()> {
+        let value = HashMap::from([(1, "one"), (2, "two"), (3, "three")]);
+
+        verify_that!(
+            value,
+            unordered_entries_are![3 => eq("three"), 1 => eq("one"), 2 => anything()]
+        )
+    }
+
+    #[test]
+    fn matches_empty_map() -> Result<()> {
+        verify_that!(BTreeMap::<i32, i32>::new(), unordered_entries_are![])
+    }
+
+    #[test]
+    fn does_not_match_missing_or_extra_key() -> Result<()> {
+        let value = BTreeMap::from([(1, 1), (2, 2)]);
+
+        expect_that!(value, not(unordered_entries_are![1 => eq(1)]));
+        verify_that!(value, not(unordered_entries_are![1 => eq(1), 2 => eq(2), 3 => eq(3)]))
+    }
+
+    #[test]
+    fn describes_expected_entries() -> Result<()> {
+        let (first_matcher, second_matcher) = (eq(2), gt(4));
+        let matcher: internal::UnorderedEntriesAreMatcher<BTreeMap<i32, i32>, _, _, 2> =
+            unordered_entries_are![1 => first_matcher, 3 => second_matcher];
+
+        verify_that!(
+            matcher.describe(MatcherResult::Matches),
+            eq(indoc!(
+                "
+                contains exactly the following entries in any order:
+                  1 => is equal to 2
+                  3 => is greater than 4"
+            ))
+        )
+    }
+
+    #[test]
+    fn explains_missing_unexpected_and_mismatched_entries_separately() -> Result<()> {
+        let result = verify_that!(
+            BTreeMap::from([("alice", 31), ("bob", 25), ("dave", 40)]),
+            unordered_entries_are!["alice" => eq(30), "bob" => lt(30), "carol" => anything()]
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                }, which
+                  * is missing the keys "carol"
+                  * has the unexpected keys "dave"
+                  * has values which don't match:
+                      "alice" => 31, which isn't equal to 30
+                "#
+            ))))
+        )
+    }
+}
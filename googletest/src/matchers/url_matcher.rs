@@ -0,0 +1,488 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use crate::matcher_support::description::Description;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use url::Url;
+
+/// Matches a URL whose scheme is `expected`, ignoring ASCII case.
+///
+/// The actual value may be a [`url::Url`] or a string, which is then parsed
+/// as a URL. See [`UrlLike`].
+///
+/// On a mismatch, the failure message lists all components of the parsed
+/// URL.
+///
+/// This matcher is only available with the feature `url`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("https://example.com/", has_scheme("https"))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("http://example.com/", has_scheme("https"))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_scheme<ActualT: UrlLike + ?Sized>(expected: &str) -> impl Matcher<ActualT = ActualT> {
+    HasSchemeMatcher { expected: expected.to_ascii_lowercase(), phantom: Default::default() }
+}
+
+/// Matches a URL which has a host matched by `inner`.
+///
+/// A URL without a host, such as `mailto:someone@example.com`, does not
+/// match.
+///
+/// The actual value may be a [`url::Url`] or a string, which is then parsed
+/// as a URL. See [`UrlLike`].
+///
+/// On a mismatch, the failure message lists all components of the parsed
+/// URL.
+///
+/// This matcher is only available with the feature `url`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("https://example.com/index.html", has_host(eq("example.com")))?;  // Passes
+/// verify_that!("https://api.example.com/", has_host(ends_with(".example.com")))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("https://example.org/", has_host(eq("example.com")))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_host<ActualT: UrlLike + ?Sized, InnerMatcherT: Matcher<ActualT = String>>(
+    inner: InnerMatcherT,
+) -> impl Matcher<ActualT = ActualT> {
+    HasHostMatcher { inner, phantom: Default::default() }
+}
+
+/// Matches a URL which has a query parameter `name` with a value matched by
+/// `inner`.
+///
+/// The name and value of each query parameter are percent-decoded before
+/// being compared. If the parameter occurs more than once in the query, the
+/// URL matches if `inner` matches any of its values.
+///
+/// The actual value may be a [`url::Url`] or a string, which is then parsed
+/// as a URL. See [`UrlLike`].
+///
+/// On a mismatch, the failure message lists all components of the parsed
+/// URL.
+///
+/// This matcher is only available with the feature `url`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("https://example.com/list?page=2", has_query_param("page", eq("2")))?;  // Passes
+/// verify_that!("https://example.com/?q=a%20b", has_query_param("q", eq("a b")))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!("https://example.com/list?page=3", has_query_param("page", eq("2")))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_query_param<ActualT: UrlLike + ?Sized, InnerMatcherT: Matcher<ActualT = String>>(
+    name: &str,
+    inner: InnerMatcherT,
+) -> impl Matcher<ActualT = ActualT> {
+    HasQueryParamMatcher { name: name.to_string(), inner, phantom: Default::default() }
+}
+
+/// A value which the URL matchers accept as a URL.
+///
+/// This is implemented for [`url::Url`] itself as well as for string types,
+/// which are parsed with [`Url::parse`].
+pub trait UrlLike: Debug {
+    /// Returns this value as a [`Url`], or a description of why it could not
+    /// be parsed as one.
+    fn to_url(&self) -> Result<Url, String>;
+}
+
+impl UrlLike for Url {
+    fn to_url(&self) -> Result<Url, String> {
+        Ok(self.clone())
+    }
+}
+
+impl UrlLike for str {
+    fn to_url(&self) -> Result<Url, String> {
+        Url::parse(self).map_err(|e| e.to_string())
+    }
+}
+
+impl UrlLike for String {
+    fn to_url(&self) -> Result<Url, String> {
+        self.as_str().to_url()
+    }
+}
+
+impl<T: UrlLike + ?Sized> UrlLike for &T {
+    fn to_url(&self) -> Result<Url, String> {
+        (*self).to_url()
+    }
+}
+
+/// Returns `explanation` followed by an indented list of the components of
+/// `url`, omitting those which are absent.
+fn explain_with_components(explanation: String, url: &Url) -> MatchExplanation {
+    let mut components = vec![format!("scheme: {:?}", url.scheme())];
+    if !url.username().is_empty() {
+        components.push(format!("username: {:?}", url.username()));
+    }
+    if let Some(host) = url.host_str() {
+        components.push(format!("host: {host:?}"));
+    }
+    if let Some(port) = url.port() {
+        components.push(format!("port: {port}"));
+    }
+    components.push(format!("path: {:?}", url.path()));
+    if let Some(query) = url.query() {
+        components.push(format!("query: {query:?}"));
+    }
+    if let Some(fragment) = url.fragment() {
+        components.push(format!("fragment: {fragment:?}"));
+    }
+    let components = components.into_iter().collect::<Description>().indent();
+    let breakdown = std::iter::once(format!("parsed as:\n{components}")).collect::<Description>();
+    MatchExplanation::create(format!("{explanation}\n{}", breakdown.indent()))
+}
+
+fn explain_parse_error(error: String) -> MatchExplanation {
+    MatchExplanation::create(format!("which is not a valid URL: {error}"))
+}
+
+struct HasSchemeMatcher<ActualT: ?Sized> {
+    expected: String,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: UrlLike + ?Sized> Matcher for HasSchemeMatcher<ActualT> {
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        actual.to_url().map(|url| url.scheme() == self.expected).unwrap_or(false).into()
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        match actual.to_url() {
+            Ok(url) => {
+                explain_with_components(format!("which has scheme {:?}", url.scheme()), &url)
+            }
+            Err(error) => explain_parse_error(error),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!("is a URL with scheme {:?}", self.expected),
+            MatcherResult::DoesNotMatch => {
+                format!("isn't a URL with scheme {:?}", self.expected)
+            }
+        }
+    }
+}
+
+struct HasHostMatcher<ActualT: ?Sized, InnerMatcherT> {
+    inner: InnerMatcherT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: UrlLike + ?Sized, InnerMatcherT: Matcher<ActualT = String>> Matcher
+    for HasHostMatcher<ActualT, InnerMatcherT>
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        match actual.to_url().ok().as_ref().and_then(Url::host_str) {
+            Some(host) => self.inner.matches(&host.to_string()),
+            None => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let url = match actual.to_url() {
+            Ok(url) => url,
+            Err(error) => return explain_parse_error(error),
+        };
+        match url.host_str() {
+            Some(host) => {
+                let inner = explain(&self.inner, &host.to_string());
+                explain_with_components(format!("which has host {host:?}, {inner}"), &url)
+                    .with_child(inner)
+            }
+            None => explain_with_components("which has no host".to_string(), &url),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!(
+                "is a URL with a host which {}",
+                self.inner.describe(MatcherResult::Matches)
+            ),
+            MatcherResult::DoesNotMatch => format!(
+                "isn't a URL with a host which {}",
+                self.inner.describe(MatcherResult::Matches)
+            ),
+        }
+    }
+}
+
+struct HasQueryParamMatcher<ActualT: ?Sized, InnerMatcherT> {
+    name: String,
+    inner: InnerMatcherT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: UrlLike + ?Sized, InnerMatcherT: Matcher<ActualT = String>>
+    HasQueryParamMatcher<ActualT, InnerMatcherT>
+{
+    fn values(&self, url: &Url) -> Vec<String> {
+        url.query_pairs()
+            .filter(|(name, _)| *name == self.name)
+            .map(|(_, value)| value.into_owned())
+            .collect()
+    }
+}
+
+impl<ActualT: UrlLike + ?Sized, InnerMatcherT: Matcher<ActualT = String>> Matcher
+    for HasQueryParamMatcher<ActualT, InnerMatcherT>
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        match actual.to_url() {
+            Ok(url) => {
+                self.values(&url).iter().any(|value| self.inner.matches(value).into_bool()).into()
+            }
+            Err(_) => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        let url = match actual.to_url() {
+            Ok(url) => url,
+            Err(error) => return explain_parse_error(error),
+        };
+        let values = self.values(&url);
+        match values.as_slice() {
+            [] => explain_with_components(
+                format!("which has no query parameter {:?}", self.name),
+                &url,
+            ),
+            [value] => {
+                let inner = explain(&self.inner, value);
+                explain_with_components(
+                    format!(
+                        "which has query parameter {:?} with value {value:?}, {inner}",
+                        self.name
+                    ),
+                    &url,
+                )
+                .with_child(inner)
+            }
+            values => explain_with_components(
+                format!("which has query parameter {:?} with values {values:?}", self.name),
+                &url,
+            ),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => format!(
+                "is a URL with a query parameter {:?} whose value {}",
+                self.name,
+                self.inner.describe(MatcherResult::Matches)
+            ),
+            MatcherResult::DoesNotMatch => format!(
+                "isn't a URL with a query parameter {:?} whose value {}",
+                self.name,
+                self.inner.describe(MatcherResult::Matches)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_host, has_query_param, has_scheme};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use url::Url;
+
+    #[test]
+    fn has_scheme_matches_url_string() -> Result<()> {
+        verify_that!("https://example.com/", has_scheme("https"))
+    }
+
+    #[test]
+    fn has_scheme_matches_url() -> Result<()> {
+        verify_that!(Url::parse("ftp://example.com/file").unwrap(), has_scheme("FTP"))
+    }
+
+    #[test]
+    fn has_scheme_does_not_match_other_scheme() -> Result<()> {
+        verify_that!("http://example.com/".to_string(), not(has_scheme("https")))
+    }
+
+    #[test]
+    fn has_scheme_explains_mismatch_with_components() -> Result<()> {
+        let result = verify_that!("http://user@example.com:8080/a/b?x=1#top", has_scheme("https"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: is a URL with scheme "https"
+                Actual: "http://user@example.com:8080/a/b?x=1#top", which has scheme "http"
+                  parsed as:
+                    scheme: "http"
+                    username: "user"
+                    host: "example.com"
+                    port: 8080
+                    path: "/a/b"
+                    query: "x=1"
+                    fragment: "top"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn has_scheme_reports_invalid_url() -> Result<()> {
+        let result = verify_that!("example.com", has_scheme("https"));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                r#"Actual: "example.com", which is not a valid URL: relative URL without a base"#
+            )))
+        )
+    }
+
+    #[test]
+    fn has_host_matches_host() -> Result<()> {
+        verify_that!("https://example.com/index.html", has_host(eq("example.com")))
+    }
+
+    #[test]
+    fn has_host_does_not_match_url_without_host() -> Result<()> {
+        verify_that!("mailto:someone@example.com", not(has_host(anything())))
+    }
+
+    #[test]
+    fn has_host_explains_mismatch() -> Result<()> {
+        let result = verify_that!("https://example.org/", has_host(eq("example.com")));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Expected: is a URL with a host which is equal to "example.com"
+                Actual: "https://example.org/", which has host "example.org", which isn't equal to "example.com"
+                  parsed as:
+                    scheme: "https"
+                    host: "example.org"
+                    path: "/"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn has_host_explains_missing_host() -> Result<()> {
+        verify_that!(
+            has_host(eq("example.com")).explain_match(&"data:text/plain,hello"),
+            displays_as(starts_with("which has no host\n"))
+        )
+    }
+
+    #[test]
+    fn has_query_param_matches_decoded_value() -> Result<()> {
+        verify_that!("https://example.com/?q=a%20b&page=2", has_query_param("q", eq("a b")))
+    }
+
+    #[test]
+    fn has_query_param_matches_any_repeated_value() -> Result<()> {
+        verify_that!("https://example.com/?tag=a&tag=b", has_query_param("tag", eq("b")))
+    }
+
+    #[test]
+    fn has_query_param_does_not_match_missing_parameter() -> Result<()> {
+        verify_that!("https://example.com/?page=2", not(has_query_param("size", anything())))
+    }
+
+    #[test]
+    fn has_query_param_describes_itself() -> Result<()> {
+        verify_that!(
+            has_query_param::<str, _>("page", eq("2")).describe(MatcherResult::DoesNotMatch),
+            eq("isn't a URL with a query parameter \"page\" whose value is equal to \"2\"")
+        )
+    }
+
+    #[test]
+    fn has_query_param_explains_mismatch() -> Result<()> {
+        let result =
+            verify_that!("https://example.com/list?page=3", has_query_param("page", eq("2")));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: "https://example.com/list?page=3", which has query parameter "page" with value "3", which isn't equal to "2"
+                  parsed as:
+                    scheme: "https"
+                    host: "example.com"
+                    path: "/list"
+                    query: "page=3"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn has_query_param_explains_missing_parameter() -> Result<()> {
+        verify_that!(
+            has_query_param("page", eq("2")).explain_match(&"https://example.com/"),
+            displays_as(starts_with("which has no query parameter \"page\"\n"))
+        )
+    }
+
+    #[test]
+    fn has_query_param_explains_repeated_parameter() -> Result<()> {
+        verify_that!(
+            has_query_param("tag", eq("c")).explain_match(&"https://example.com/?tag=a&tag=b"),
+            displays_as(starts_with(
+                "which has query parameter \"tag\" with values [\"a\", \"b\"]\n"
+            ))
+        )
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// Matches a weak reference which can be upgraded to a strong reference
+/// pointing to a value matched by `inner`.
+///
+/// This supports both [`std::rc::Weak`] and [`std::sync::Weak`].
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::rc::Rc;
+/// # fn should_pass() -> Result<()> {
+/// let strong = Rc::new(123);
+/// verify_that!(Rc::downgrade(&strong), upgrades_to(eq(123)))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// let weak = Rc::downgrade(&Rc::new(123));
+/// verify_that!(weak, upgrades_to(eq(123)))?;  // Fails: the value was dropped
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn upgrades_to<T: Debug + ?Sized, ActualT: WeakReference<Target = T>>(
+    inner: impl Matcher<ActualT = T>,
+) -> impl Matcher<ActualT = ActualT> {
+    UpgradesToMatcher { inner, phantom: Default::default() }
+}
+
+/// Matches a weak reference whose value has been dropped, so that it can no
+/// longer be upgraded to a strong reference.
+///
+/// This supports both [`std::rc::Weak`] and [`std::sync::Weak`]. It is useful
+/// for verifying that a cache evicts its entries or that a reference cycle has
+/// been broken.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::sync::Arc;
+/// # fn should_pass() -> Result<()> {
+/// let weak = Arc::downgrade(&Arc::new(123));
+/// verify_that!(weak, is_dangling())?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// let strong = Arc::new(123);
+/// verify_that!(Arc::downgrade(&strong), is_dangling())?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_dangling<ActualT: WeakReference>() -> impl Matcher<ActualT = ActualT> {
+    IsDanglingMatcher::<ActualT> { phantom: Default::default() }
+}
+
+/// A weak reference which the matchers [`upgrades_to`] and [`is_dangling`]
+/// support.
+///
+/// This is implemented for [`std::rc::Weak`] and [`std::sync::Weak`].
+pub trait WeakReference: Debug {
+    /// The type of the value to which the reference points.
+    type Target: Debug + ?Sized;
+
+    /// The strong reference which [`upgrade_reference`][Self::upgrade_reference]
+    /// produces.
+    type Strong: Deref<Target = Self::Target>;
+
+    /// Attempts to upgrade this to a strong reference, returning `None` if the
+    /// value has been dropped.
+    fn upgrade_reference(&self) -> Option<Self::Strong>;
+}
+
+impl<T: Debug + ?Sized> WeakReference for std::rc::Weak<T> {
+    type Target = T;
+    type Strong = std::rc::Rc<T>;
+
+    fn upgrade_reference(&self) -> Option<Self::Strong> {
+        self.upgrade()
+    }
+}
+
+impl<T: Debug + ?Sized> WeakReference for std::sync::Weak<T> {
+    type Target = T;
+    type Strong = std::sync::Arc<T>;
+
+    fn upgrade_reference(&self) -> Option<Self::Strong> {
+        self.upgrade()
+    }
+}
+
+struct UpgradesToMatcher<ActualT, InnerMatcherT> {
+    inner: InnerMatcherT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<T: Debug + ?Sized, ActualT: WeakReference<Target = T>, InnerMatcherT: Matcher<ActualT = T>>
+    Matcher for UpgradesToMatcher<ActualT, InnerMatcherT>
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        match actual.upgrade_reference() {
+            Some(strong) => self.inner.matches(strong.deref()),
+            None => MatcherResult::DoesNotMatch,
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        match actual.upgrade_reference() {
+            Some(strong) => {
+                let inner = explain(&self.inner, strong.deref());
+                MatchExplanation::create(format!("which points to {:?}, {inner}", strong.deref()))
+                    .with_child(inner)
+            }
+            None => MatchExplanation::create("which is dangling".to_string()),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => {
+                format!(
+                    "can be upgraded to a value which {}",
+                    self.inner.describe(MatcherResult::Matches)
+                )
+            }
+            MatcherResult::DoesNotMatch => {
+                format!(
+                    "is dangling or can be upgraded to a value which {}",
+                    self.inner.describe(MatcherResult::DoesNotMatch)
+                )
+            }
+        }
+    }
+}
+
+struct IsDanglingMatcher<ActualT> {
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: WeakReference> Matcher for IsDanglingMatcher<ActualT> {
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        actual.upgrade_reference().is_none().into()
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> MatchExplanation {
+        match actual.upgrade_reference() {
+            Some(strong) => {
+                MatchExplanation::create(format!("which points to {:?}", strong.deref()))
+            }
+            None => MatchExplanation::create("which is dangling".to_string()),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        match matcher_result {
+            MatcherResult::Matches => "is dangling".to_string(),
+            MatcherResult::DoesNotMatch => "can be upgraded".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_dangling, upgrades_to};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[test]
+    fn upgrades_to_matches_live_rc_weak() -> Result<()> {
+        let strong = Rc::new(1);
+        verify_that!(Rc::downgrade(&strong), upgrades_to(eq(1)))
+    }
+
+    #[test]
+    fn upgrades_to_matches_live_arc_weak() -> Result<()> {
+        let strong = Arc::new("value".to_string());
+        verify_that!(Arc::downgrade(&strong), upgrades_to(eq("value")))
+    }
+
+    #[test]
+    fn upgrades_to_matches_unsized_target() -> Result<()> {
+        let strong: Rc<str> = Rc::from("value");
+        verify_that!(Rc::downgrade(&strong), upgrades_to(starts_with("val")))
+    }
+
+    #[test]
+    fn upgrades_to_does_not_match_wrong_value() -> Result<()> {
+        let strong = Rc::new(1);
+        verify_that!(Rc::downgrade(&strong), not(upgrades_to(eq(2))))
+    }
+
+    #[test]
+    fn upgrades_to_does_not_match_dangling_reference() -> Result<()> {
+        let weak = Rc::downgrade(&Rc::new(1));
+        verify_that!(weak, not(upgrades_to(eq(1))))
+    }
+
+    #[test]
+    fn upgrades_to_full_error_message() -> Result<()> {
+        let strong = Rc::new(2);
+        let result = verify_that!(Rc::downgrade(&strong), upgrades_to(eq(1)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: can be upgraded to a value which is equal to 1
+                Actual: (Weak), which points to 2, which isn't equal to 1
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn upgrades_to_describe_does_not_match() -> Result<()> {
+        verify_that!(
+            upgrades_to::<i32, std::rc::Weak<i32>>(eq(1)).describe(MatcherResult::DoesNotMatch),
+            eq("is dangling or can be upgraded to a value which isn't equal to 1")
+        )
+    }
+
+    #[test]
+    fn is_dangling_matches_dropped_arc() -> Result<()> {
+        let weak = Arc::downgrade(&Arc::new(1));
+        verify_that!(weak, is_dangling())
+    }
+
+    #[test]
+    fn is_dangling_matches_empty_weak() -> Result<()> {
+        verify_that!(std::rc::Weak::<i32>::new(), is_dangling())
+    }
+
+    #[test]
+    fn is_dangling_does_not_match_live_rc() -> Result<()> {
+        let strong = Rc::new(1);
+        verify_that!(Rc::downgrade(&strong), not(is_dangling()))
+    }
+
+    #[test]
+    fn is_dangling_full_error_message() -> Result<()> {
+        let strong = Rc::new(1);
+        let result = verify_that!(Rc::downgrade(&strong), is_dangling());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: is dangling
+                Actual: (Weak), which points to 1
+                "
+            ))))
+        )
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Formatter};
+
+/// Wraps the items yielded by `items` so that they can be matched with the
+/// container matchers.
+///
+/// Container matchers such as [`elements_are!`][crate::elements_are],
+/// [`contains`][crate::matchers::contains], and
+/// [`each`][crate::matchers::each] iterate over references to the elements of
+/// the actual container, so they cannot match an iterator yielding owned
+/// items, such as an adapter computing its items or the result of `drain()`.
+/// This consumes such an iterator and stores its items, so that the result
+/// can be matched like a [`Vec`] without naming the type to collect into:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// let words = ["apple", "banana"];
+/// verify_that!(yielded(words.iter().map(|w| w.len())), elements_are![eq(5), eq(6)])?; // Passes
+///
+/// let mut queue = vec![3, 1, 2];
+/// verify_that!(yielded(queue.drain(..)), unordered_elements_are![eq(1), eq(2), eq(3)])?; // Passes
+/// verify_that!(queue, empty())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(yielded((1..4).map(|i| i * 2)), contains(eq(3)))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The iterator must be finite. An iterator which implements [`Clone`] can
+/// also be matched in order without storing its items using
+/// [`iterates_as!`][crate::iterates_as].
+pub fn yielded<I: IntoIterator>(items: I) -> Yielded<I::Item> {
+    Yielded { items: items.into_iter().collect() }
+}
+
+/// The items of an iterator wrapped with [`yielded`].
+///
+/// Its `Debug` implementation shows the items as a list.
+pub struct Yielded<T> {
+    items: Vec<T>,
+}
+
+impl<T: Debug> Debug for Yielded<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.items.fmt(f)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Yielded<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::yielded;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn yielded_supports_size() -> Result<()> {
+        verify_that!(yielded((0..5).map(|i| i * i)), size(eq(5)))
+    }
+
+    #[test]
+    fn yielded_supports_each() -> Result<()> {
+        verify_that!(yielded("abc".chars().map(|c| c.to_ascii_uppercase())), each(lt('D')))
+    }
+
+    #[test]
+    fn yielded_shows_items_in_failure_message() -> Result<()> {
+        let result = verify_that!(yielded([1, 2].into_iter()), elements_are![eq(1), eq(3)]);
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: [
+                    1,
+                    2,
+                ],"
+            ))))
+        )
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::matcher::{explain, MatchExplanation, Matcher, MatcherResult};
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+/// Matches an iterator wrapped with [`counted`] whose number of items matches
+/// `expected`.
+///
+/// Unlike [`size`][crate::matchers::size], this places no bounds at all on the
+/// items of the iterator. In particular, they need not implement `Debug`. To
+/// make that possible, the iterator must be wrapped with [`counted`], which
+/// provides the `Debug` implementation which assertions require in place of
+/// that of the iterator. The iterator is consumed when the matcher is first
+/// invoked.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// struct NotDebug;
+///
+/// # fn should_pass() -> Result<()> {
+/// let items = vec![NotDebug, NotDebug, NotDebug];
+/// verify_that!(counted(items.into_iter()), yields_count(eq(3)))?;  // Passes
+/// let generated = std::iter::repeat_with(|| NotDebug).take(5);
+/// verify_that!(counted(generated), yields_count(gt(3)))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(counted(std::iter::empty::<NotDebug>()), yields_count(eq(1)))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn yields_count<I: Iterator, E: Matcher<ActualT = usize>>(
+    expected: E,
+) -> impl Matcher<ActualT = Counted<I>> {
+    YieldsCountMatcher { expected, phantom: Default::default() }
+}
+
+/// Wraps `iterator` so that its number of items can be matched with
+/// [`yields_count`].
+pub fn counted<I: Iterator>(iterator: I) -> Counted<I> {
+    Counted { iterator: RefCell::new(Some(iterator)), count: Cell::new(None) }
+}
+
+/// An iterator wrapped with [`counted`].
+///
+/// Its `Debug` implementation does not show the items of the iterator, so
+/// that they need not implement `Debug`.
+pub struct Counted<I> {
+    iterator: RefCell<Option<I>>,
+    count: Cell<Option<usize>>,
+}
+
+impl<I: Iterator> Counted<I> {
+    /// Consumes the wrapped iterator on the first call, returning the number
+    /// of items it yielded on this and every subsequent call.
+    fn items_yielded(&self) -> usize {
+        if let Some(count) = self.count.get() {
+            return count;
+        }
+        let count = self.iterator.borrow_mut().take().map(Iterator::count).unwrap_or_default();
+        self.count.set(Some(count));
+        count
+    }
+}
+
+impl<I> Debug for Counted<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "an iterator")
+    }
+}
+
+struct YieldsCountMatcher<I, E> {
+    expected: E,
+    phantom: PhantomData<I>,
+}
+
+impl<I: Iterator, E: Matcher<ActualT = usize>> Matcher for YieldsCountMatcher<I, E> {
+    type ActualT = Counted<I>;
+
+    fn matches(&self, actual: &Counted<I>) -> MatcherResult {
+        self.expected.matches(&actual.items_yielded())
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> String {
+        format!("yields a number of items which {}", self.expected.describe(matcher_result))
+    }
+
+    fn explain_match(&self, actual: &Counted<I>) -> MatchExplanation {
+        let count = actual.items_yielded();
+        let inner = explain(&self.expected, &count);
+        MatchExplanation::create(format!("which yielded {count} items, {inner}")).with_child(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{counted, yields_count};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    struct NotDebug;
+
+    #[test]
+    fn yields_count_matches_iterator_of_non_debug_items() -> Result<()> {
+        verify_that!(counted(vec![NotDebug, NotDebug].into_iter()), yields_count(eq(2)))
+    }
+
+    #[test]
+    fn yields_count_matches_empty_iterator() -> Result<()> {
+        verify_that!(counted(std::iter::empty::<NotDebug>()), yields_count(eq(0)))
+    }
+
+    #[test]
+    fn yields_count_does_not_match_wrong_count() -> Result<()> {
+        verify_that!(counted([NotDebug].into_iter()), not(yields_count(eq(2))))
+    }
+
+    #[test]
+    fn yields_count_consumes_iterator_only_once() -> Result<()> {
+        let mut calls = 0;
+        let iterator = counted(std::iter::from_fn(|| {
+            calls += 1;
+            (calls <= 3).then_some(NotDebug)
+        }));
+        let matcher = yields_count(eq(3));
+
+        verify_that!(matcher.matches(&iterator), eq(MatcherResult::Matches))?;
+        verify_that!(matcher.matches(&iterator), eq(MatcherResult::Matches))
+    }
+
+    #[test]
+    fn yields_count_full_error_message() -> Result<()> {
+        let result = verify_that!(counted([NotDebug].into_iter()), yields_count(gt(1)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: yields a number of items which is greater than 1
+                Actual: an iterator, which yielded 1 items, which is less than or equal to 1
+                "
+            ))))
+        )
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Matching values of types which implement neither [`Debug`] nor
+//! [`TestDebug`][crate::matcher::TestDebug].
+//!
+//! Matchers render the actual value in failure messages, and hence require
+//! it to implement [`TestDebug`][crate::matcher::TestDebug], which every type
+//! implementing [`Debug`] does. A value of a third-party type implementing
+//! neither can be wrapped with [`opaque`], which renders it with a
+//! placeholder naming its type:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::opaque::Opaque;
+//! struct Handle {
+//!     // Some fields which do not implement Debug...
+//!     id: u32,
+//! }
+//!
+//! # fn should_pass() -> Result<()> {
+//! let handle = Handle { id: 7 };
+//! verify_that!(opaque(handle), predicate(|handle: &Opaque<Handle>| handle.id == 7))
+//! # }
+//! # should_pass().unwrap();
+//! ```
+//!
+//! The wrapper dereferences to the wrapped value, so that matchers such as
+//! [`property!`][crate::property] can access its methods and fields.
+
+use std::fmt::{Debug, Formatter, Result};
+use std::ops::{Deref, DerefMut};
+
+/// Wraps `value`, so that it can be matched although its type does not
+/// implement [`Debug`].
+///
+/// In failure messages, the value is rendered as `<opaque T>`, where `T` is
+/// the name of its type.
+pub fn opaque<T>(value: T) -> Opaque<T> {
+    Opaque(value)
+}
+
+/// A value rendered with a placeholder instead of its [`Debug`] output.
+///
+/// Construct with [`opaque`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Opaque<T>(pub T);
+
+impl<T> Opaque<T> {
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Debug for Opaque<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "<opaque {}>", std::any::type_name::<T>())
+    }
+}
+
+impl<T> Deref for Opaque<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Opaque<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Opaque;
+    use crate::prelude::*;
+
+    struct NotDebug {
+        value: i32,
+    }
+
+    impl NotDebug {
+        fn value(&self) -> i32 {
+            self.value
+        }
+    }
+
+    #[test]
+    fn matches_wrapped_value_through_property() -> Result<()> {
+        type OpaqueNotDebug = Opaque<NotDebug>;
+
+        verify_that!(opaque(NotDebug { value: 3 }), property!(OpaqueNotDebug.value(), eq(3)))
+    }
+
+    #[test]
+    fn matches_wrapped_values_for_equality() -> Result<()> {
+        #[derive(PartialEq)]
+        struct Comparable(i32);
+
+        verify_that!(opaque(Comparable(1)), eq(opaque(Comparable(1))))
+    }
+
+    #[test]
+    fn renders_placeholder_in_failure_message() -> Result<()> {
+        let result = verify_that!(
+            opaque(NotDebug { value: 3 }),
+            predicate(|actual: &Opaque<NotDebug>| actual.value == 4)
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(
+                "Actual: <opaque googletest::opaque::tests::NotDebug>,"
+            )))
+        )
+    }
+
+    #[test]
+    fn into_inner_returns_wrapped_value() -> Result<()> {
+        verify_that!(opaque(NotDebug { value: 5 }).into_inner().value, eq(5))
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Policies for retrying a check until it succeeds.
+//!
+//! Assertions about state which settles asynchronously, such as a background
+//! worker eventually processing a message, have to check the state
+//! repeatedly. A [`PollPolicy`] describes how often to check and for how
+//! long, so that the same strategy can be shared between such assertions and
+//! configured for a whole test through [`Config::poll_policy`] rather than
+//! hardcoded into each of them:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::poll::PollPolicy;
+//! # use std::sync::atomic::{AtomicU32, Ordering};
+//! # use std::time::Duration;
+//! # fn should_pass() -> Result<()> {
+//! let counter = AtomicU32::new(0);
+//! let policy = PollPolicy::exponential_backoff(Duration::from_millis(1), 2).max_attempts(5);
+//!
+//! let value = policy.poll(|| {
+//!     let value = counter.fetch_add(1, Ordering::SeqCst);
+//!     (value >= 2).then_some(value)
+//! });
+//!
+//! verify_that!(value, some(eq(2)))
+//! # }
+//! # should_pass().unwrap();
+//! ```
+//!
+//! Waiting goes through [`clock::sleep`], so a test which installs a
+//! [`FakeClock`][crate::clock::FakeClock] polls without actually waiting.
+//!
+//! [`Config::poll_policy`]: crate::config::Config::poll_policy
+
+use crate::clock;
+use crate::config::config;
+use crate::generators::Rng;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How often and how many times a check is attempted until it succeeds.
+///
+/// A policy starts from a [fixed interval][PollPolicy::fixed] or an
+/// [exponential backoff][PollPolicy::exponential_backoff] and is refined with
+/// the builder methods. The default policy makes up to 50 attempts 100 ms
+/// apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PollPolicy {
+    initial_interval: Duration,
+    backoff_factor: u32,
+    max_interval: Option<Duration>,
+    jitter: Duration,
+    max_attempts: u32,
+}
+
+impl Default for PollPolicy {
+    fn default() -> Self {
+        Self::fixed(Duration::from_millis(100)).max_attempts(50)
+    }
+}
+
+impl PollPolicy {
+    /// Returns the policy in effect on the current thread, as set through
+    /// [`Config::poll_policy`][crate::config::Config::poll_policy].
+    pub fn current() -> Self {
+        config().poll_policy
+    }
+
+    /// Creates a policy waiting `interval` between consecutive attempts.
+    pub fn fixed(interval: Duration) -> Self {
+        Self::exponential_backoff(interval, 1)
+    }
+
+    /// Creates a policy waiting `initial_interval` after the first attempt
+    /// and multiplying the wait by `factor` after each further attempt.
+    ///
+    /// Panics if `factor` is zero.
+    pub fn exponential_backoff(initial_interval: Duration, factor: u32) -> Self {
+        assert!(factor > 0, "The backoff factor must be positive");
+        Self {
+            initial_interval,
+            backoff_factor: factor,
+            max_interval: None,
+            jitter: Duration::ZERO,
+            max_attempts: 10,
+        }
+    }
+
+    /// Caps the wait between consecutive attempts at `max_interval`.
+    pub fn max_interval(self, max_interval: Duration) -> Self {
+        Self { max_interval: Some(max_interval), ..self }
+    }
+
+    /// Adds to each wait a random duration between zero and `jitter`, so that
+    /// concurrent pollers do not check in lockstep.
+    pub fn jitter(self, jitter: Duration) -> Self {
+        Self { jitter, ..self }
+    }
+
+    /// Limits the number of attempts, including the first one, to
+    /// `max_attempts`.
+    ///
+    /// Panics if `max_attempts` is zero.
+    pub fn max_attempts(self, max_attempts: u32) -> Self {
+        assert!(max_attempts > 0, "A poll policy must make at least one attempt");
+        Self { max_attempts, ..self }
+    }
+
+    /// Returns the waits between consecutive attempts, of which there is one
+    /// fewer than the maximum number of attempts.
+    pub fn delays(&self) -> impl Iterator<Item = Duration> {
+        let policy = *self;
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos());
+        let mut rng = Rng::from_seed(nanos as u64);
+        let mut interval = Some(self.initial_interval);
+        (1..self.max_attempts).map(move |_| {
+            let mut delay = interval.unwrap_or(Duration::MAX);
+            if let Some(max_interval) = policy.max_interval {
+                delay = delay.min(max_interval);
+            }
+            interval = interval.and_then(|interval| interval.checked_mul(policy.backoff_factor));
+            let jitter = rng.up_to(policy.jitter.as_nanos());
+            delay.saturating_add(Duration::from_nanos(jitter as u64))
+        })
+    }
+
+    /// Calls `attempt` until it returns `Some`, waiting between consecutive
+    /// calls according to this policy.
+    ///
+    /// Returns the value of the first successful attempt, or `None` if every
+    /// permitted attempt failed.
+    pub fn poll<T>(&self, mut attempt: impl FnMut() -> Option<T>) -> Option<T> {
+        let mut delays = self.delays();
+        loop {
+            if let Some(value) = attempt() {
+                return Some(value);
+            }
+            clock::sleep(delays.next()?);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PollPolicy;
+    use crate::clock::FakeClock;
+    use crate::prelude::*;
+    use std::time::Duration;
+
+    #[test]
+    fn fixed_policy_waits_same_interval() -> Result<()> {
+        let policy = PollPolicy::fixed(Duration::from_millis(10)).max_attempts(4);
+
+        verify_that!(policy.delays().collect::<Vec<_>>(), each(eq(Duration::from_millis(10))))?;
+        verify_that!(policy.delays().count(), eq(3))
+    }
+
+    #[test]
+    fn exponential_backoff_multiplies_interval_up_to_maximum() -> Result<()> {
+        let policy = PollPolicy::exponential_backoff(Duration::from_millis(1), 3)
+            .max_interval(Duration::from_millis(20))
+            .max_attempts(5);
+
+        verify_that!(
+            policy.delays().collect::<Vec<_>>(),
+            elements_are![
+                eq(Duration::from_millis(1)),
+                eq(Duration::from_millis(3)),
+                eq(Duration::from_millis(9)),
+                eq(Duration::from_millis(20)),
+            ]
+        )
+    }
+
+    #[test]
+    fn jitter_extends_each_delay_by_at_most_jitter() -> Result<()> {
+        let policy = PollPolicy::fixed(Duration::from_millis(10))
+            .jitter(Duration::from_millis(5))
+            .max_attempts(20);
+
+        verify_that!(
+            policy.delays().collect::<Vec<_>>(),
+            each(all!(ge(Duration::from_millis(10)), le(Duration::from_millis(15))))
+        )
+    }
+
+    #[test]
+    fn poll_returns_first_successful_attempt() -> Result<()> {
+        let clock = FakeClock::install();
+        let mut attempts = 0;
+
+        let value = PollPolicy::fixed(Duration::from_secs(1)).poll(|| {
+            attempts += 1;
+            (attempts == 3).then_some(attempts)
+        });
+
+        verify_that!(value, some(eq(3)))?;
+        verify_that!(clock.elapsed(), eq(Duration::from_secs(2)))
+    }
+
+    #[test]
+    fn poll_gives_up_after_max_attempts() -> Result<()> {
+        let _clock = FakeClock::install();
+        let mut attempts = 0;
+
+        let value = PollPolicy::fixed(Duration::from_secs(1)).max_attempts(4).poll(|| {
+            attempts += 1;
+            None::<()>
+        });
+
+        verify_that!(value, none())?;
+        verify_that!(attempts, eq(4))
+    }
+
+    #[test]
+    fn current_policy_follows_config() -> Result<()> {
+        let policy = PollPolicy::fixed(Duration::from_millis(1)).max_attempts(2);
+        let _guard = crate::config().poll_policy(policy).apply();
+
+        verify_that!(PollPolicy::current(), eq(policy))
+    }
+}
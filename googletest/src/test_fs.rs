@@ -0,0 +1,233 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A scratch directory tree for tests which manipulate files.
+//!
+//! A [`TestFs`] is created from a declarative description of its files and
+//! directories. Paths are resolved relative to its root, so that a test can
+//! refer to its files concisely, and the tree is removed once the fixture is
+//! dropped:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::test_fs::TestFs;
+//! # fn should_pass() -> Result<()> {
+//! let fs = TestFs::create([("config/app.toml", "name = \"app\""), ("logs/", "")])?;
+//!
+//! std::fs::write(fs.path("logs/app.log"), "started")?;
+//!
+//! verify_that!(fs.read("logs/app.log")?, eq("started"))
+//! # }
+//! # should_pass().unwrap();
+//! ```
+//!
+//! If an assertion fails or the test panics while the fixture exists, the tree
+//! is preserved instead and its location printed, so that it can be
+//! inspected.
+//!
+//! A `TestFs` refers to its root as a path, so the filesystem matchers apply to
+//! it directly:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # use googletest::test_fs::TestFs;
+//! # fn should_pass() -> Result<()> {
+//! let fs = TestFs::create([("input.txt", "data")])?;
+//!
+//! std::fs::copy(fs.path("input.txt"), fs.path("output.txt"))?;
+//!
+//! let expected = TestFs::create([("input.txt", "data"), ("output.txt", "data")])?;
+//! verify_that!(fs, dir_eq(&expected))
+//! # }
+//! # should_pass().unwrap();
+//! ```
+
+use crate::Result;
+use crate::internal::test_outcome::TestOutcome;
+use std::fmt::{Debug, Formatter};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A scratch directory tree, removed when dropped unless the test failed.
+///
+/// See the [module documentation][self].
+pub struct TestFs {
+    root: PathBuf,
+    failed_assertions_at_creation: usize,
+}
+
+impl TestFs {
+    /// Creates a new scratch directory containing the given entries.
+    ///
+    /// Each entry is a path relative to the root together with the contents
+    /// of the file at that path. A path ending in `/` denotes a directory, in
+    /// which case the contents are ignored. Missing parent directories are
+    /// created.
+    pub fn create<'a>(entries: impl IntoIterator<Item = (&'a str, &'a str)>) -> Result<Self> {
+        let nanos =
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or(0);
+        let root = std::env::temp_dir().join(format!(
+            "googletest-{}-{}-{nanos}",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        ));
+        std::fs::create_dir_all(&root)?;
+        let fs =
+            Self { root, failed_assertions_at_creation: TestOutcome::assertion_counts().failed };
+        for (path, contents) in entries {
+            if path.ends_with('/') {
+                std::fs::create_dir_all(fs.path(path))?;
+            } else {
+                fs.write(path, contents)?;
+            }
+        }
+        Ok(fs)
+    }
+
+    /// The root of the directory tree.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Resolves `relative` against the root of the directory tree.
+    ///
+    /// Panics if `relative` is absolute or contains `..`, since it might then
+    /// not refer to an entry of the tree.
+    pub fn path(&self, relative: impl AsRef<Path>) -> PathBuf {
+        let relative = relative.as_ref();
+        assert!(
+            relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_) | Component::CurDir)),
+            "TestFs paths must be relative and must not contain `..`, got {relative:?}"
+        );
+        self.root.join(relative)
+    }
+
+    /// Reads the file at the path `relative` to the root as a string.
+    pub fn read(&self, relative: impl AsRef<Path>) -> Result<String> {
+        Ok(std::fs::read_to_string(self.path(relative))?)
+    }
+
+    /// Writes `contents` to the file at the path `relative` to the root,
+    /// creating missing parent directories.
+    pub fn write(&self, relative: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> Result<()> {
+        let path = self.path(relative);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Ok(std::fs::write(path, contents)?)
+    }
+}
+
+impl AsRef<Path> for TestFs {
+    fn as_ref(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl Debug for TestFs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TestFs").field(&self.root).finish()
+    }
+}
+
+impl Drop for TestFs {
+    fn drop(&mut self) {
+        let assertion_failed =
+            TestOutcome::assertion_counts().failed > self.failed_assertions_at_creation;
+        if assertion_failed || std::thread::panicking() {
+            println!("Preserved the test filesystem at {}", self.root.display());
+        } else {
+            let _ = std::fs::remove_dir_all(&self.root);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestFs;
+    use crate::prelude::*;
+
+    #[test]
+    fn creates_files_and_directories() -> Result<()> {
+        let fs = TestFs::create([("a/b.txt", "contents"), ("c/d/", "ignored")])?;
+
+        expect_that!(fs.read("a/b.txt")?, eq("contents"));
+        verify_that!(fs.path("c/d").is_dir(), eq(true))
+    }
+
+    #[test]
+    fn resolves_paths_relative_to_root() -> Result<()> {
+        let fs = TestFs::create([])?;
+
+        verify_that!(fs.path("x/y"), eq(fs.root().join("x").join("y")))
+    }
+
+    #[test]
+    #[should_panic(expected = "TestFs paths must be relative")]
+    fn rejects_absolute_paths() {
+        // Not created on disk, since the panic preserves the tree.
+        let fs = TestFs {
+            root: std::env::temp_dir().join("googletest-never-created"),
+            failed_assertions_at_creation: 0,
+        };
+        let _ = fs.path(std::env::temp_dir());
+    }
+
+    #[test]
+    #[should_panic(expected = "TestFs paths must be relative and must not contain `..`")]
+    fn rejects_paths_leaving_the_tree() {
+        // Not created on disk, since the panic preserves the tree.
+        let fs = TestFs {
+            root: std::env::temp_dir().join("googletest-never-created"),
+            failed_assertions_at_creation: 0,
+        };
+        let _ = fs.path("a/../../b");
+    }
+
+    #[test]
+    fn matches_directory_tree_with_dir_eq() -> Result<()> {
+        let fs = TestFs::create([("a.txt", "hello"), ("b/", "")])?;
+        let expected = TestFs::create([("a.txt", "hello"), ("b/", "")])?;
+
+        verify_that!(fs, dir_eq(&expected))
+    }
+
+    #[test]
+    fn removes_tree_when_dropped() -> Result<()> {
+        let fs = TestFs::create([("file", "contents")])?;
+        let root = fs.root().to_path_buf();
+
+        drop(fs);
+
+        verify_that!(root.exists(), eq(false))
+    }
+
+    #[test]
+    fn preserves_tree_when_assertion_failed() -> Result<()> {
+        let fs = TestFs::create([("file", "contents")])?;
+        let root = fs.root().to_path_buf();
+        let _ = verify_that!(fs.read("file")?, eq("other contents"));
+
+        drop(fs);
+
+        let preserved = root.exists();
+        let _ = std::fs::remove_dir_all(&root);
+        verify_that!(preserved, eq(true))
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Attribution of failures on other threads to the test which started them.
+//!
+//! The outcome of a test is tracked on the thread running it, so a non-fatal
+//! failure recorded with [`expect_that!`][crate::expect_that] on another
+//! thread would otherwise not fail the test. A [`TestScope`] obtained on the
+//! test's thread carries the test to other threads, on which
+//! [`TestScope::enter`] attributes the failures they record to the test. The
+//! failures are reported with the name of the thread on which they occurred
+//! and fail the test when it ends. These threads also follow the
+//! [`FakeClock`][crate::clock::FakeClock] which the test installs.
+//!
+//! [`spawn`] starts a thread within the scope of the current test directly:
+//!
+//! ```ignore
+//! #[googletest::test]
+//! fn workers_process_their_items() -> Result<()> {
+//!     let worker = googletest::test_scope::spawn(|| {
+//!         expect_that!(process(1), eq(2));  // Fails the test if it does not match.
+//!     });
+//!     worker.join().unwrap();
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Threads from a pool, which already run when the test starts, enter the
+//! scope for the duration of each task instead:
+//!
+//! ```ignore
+//! let scope = TestScope::current();
+//! pool.execute(move || {
+//!     let _guard = scope.enter();
+//!     expect_that!(process(1), eq(2));
+//! });
+//! ```
+//!
+//! Failures recorded after the test has ended are lost, so the test should
+//! wait for the work it started to finish.
+
+use crate::clock::{self, FakeClocks};
+use crate::internal::test_outcome::{FailureInbox, TestOutcome};
+use std::thread::JoinHandle;
+
+/// The test running on some thread, to which failures on other threads are
+/// attributed.
+///
+/// See the [module documentation][self].
+#[derive(Clone)]
+pub struct TestScope {
+    inbox: FailureInbox,
+    fake_clocks: FakeClocks,
+}
+
+impl TestScope {
+    /// Returns the scope of the test running on the current thread.
+    ///
+    /// If the current thread has itself entered the scope of a test on
+    /// another thread, this is the scope of that test.
+    pub fn current() -> Self {
+        Self { inbox: TestOutcome::failure_inbox(), fake_clocks: clock::fake_clocks() }
+    }
+
+    /// Attributes the non-fatal failures recorded on the current thread to
+    /// the test of this scope, and applies its
+    /// [`FakeClock`][crate::clock::FakeClock] to the current thread, until
+    /// the returned guard is dropped.
+    pub fn enter(&self) -> TestScopeGuard {
+        TestScopeGuard {
+            previous: TestOutcome::forward_failures_to(Some(self.inbox.clone())),
+            previous_fake_clocks: clock::use_fake_clocks(Some(self.fake_clocks.clone())),
+        }
+    }
+
+    /// Spawns a thread which runs `f` within this scope.
+    pub fn spawn<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> JoinHandle<T> {
+        let scope = self.clone();
+        std::thread::spawn(move || {
+            let _guard = scope.enter();
+            f()
+        })
+    }
+}
+
+/// Spawns a thread which runs `f` within the scope of the test running on
+/// the current thread.
+///
+/// This is equivalent to `TestScope::current().spawn(f)`.
+pub fn spawn<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> JoinHandle<T> {
+    TestScope::current().spawn(f)
+}
+
+/// Ends the attribution of failures to a test on another thread when dropped.
+///
+/// Returned by [`TestScope::enter`].
+#[must_use = "the scope is left as soon as the guard is dropped"]
+pub struct TestScopeGuard {
+    previous: Option<FailureInbox>,
+    previous_fake_clocks: Option<FakeClocks>,
+}
+
+impl Drop for TestScopeGuard {
+    fn drop(&mut self) {
+        TestOutcome::forward_failures_to(self.previous.take());
+        clock::use_fake_clocks(self.previous_fake_clocks.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spawn, TestScope};
+    use crate::prelude::*;
+
+    #[test]
+    fn forwards_failures_on_spawned_thread_to_scope() -> Result<()> {
+        let scope = TestScope::current();
+
+        std::thread::Builder::new()
+            .name("worker".into())
+            .spawn({
+                let scope = scope.clone();
+                move || {
+                    let _guard = scope.enter();
+                    expect_that!(1, eq(2));
+                }
+            })?
+            .join()
+            .unwrap();
+
+        let failures = scope.inbox.failures.lock().unwrap().clone();
+        verify_that!(
+            failures,
+            elements_are![all!(
+                contains_substring("Expected: is equal to 2"),
+                ends_with("  on thread worker\n")
+            )]
+        )
+    }
+
+    #[test]
+    fn spawned_thread_forwards_to_current_scope() -> Result<()> {
+        spawn(|| expect_that!(1, eq(2))).join().unwrap();
+
+        let failures = TestScope::current().inbox.failures.lock().unwrap().clone();
+        verify_that!(failures, size(eq(1)))
+    }
+
+    #[test]
+    fn stops_forwarding_once_guard_is_dropped() -> Result<()> {
+        let scope = TestScope::current();
+
+        std::thread::spawn({
+            let scope = scope.clone();
+            move || {
+                drop(scope.enter());
+                expect_that!(1, eq(2));
+            }
+        })
+        .join()
+        .unwrap();
+
+        let failures = scope.inbox.failures.lock().unwrap().clone();
+        verify_that!(failures, empty())
+    }
+}
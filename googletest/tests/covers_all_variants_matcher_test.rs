@@ -0,0 +1,72 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::matcher::Matcher;
+use googletest::prelude::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumVariants)]
+enum Message {
+    Ping,
+    Text(String),
+    Move { x: i32, y: i32 },
+}
+
+#[test]
+fn covers_all_variants_matches_container_with_every_variant() -> Result<()> {
+    let messages = [Message::Move { x: 1, y: 2 }, Message::Text("hi".into()), Message::Ping];
+
+    verify_that!(messages, covers_all_variants())
+}
+
+#[test]
+fn covers_all_variants_matches_container_with_repeated_variants() -> Result<()> {
+    let messages =
+        vec![Message::Ping, Message::Ping, Message::Text("a".into()), Message::Move { x: 0, y: 0 }];
+
+    verify_that!(messages, covers_all_variants())
+}
+
+#[test]
+fn covers_all_variants_explains_missing_variants() -> Result<()> {
+    let messages = vec![Message::Text("a".into())];
+
+    verify_that!(
+        covers_all_variants().explain_match(&messages),
+        displays_as(eq("which is missing the variants Ping, Move"))
+    )
+}
+
+#[test]
+fn covers_all_variants_explains_single_missing_variant() -> Result<()> {
+    let messages = vec![Message::Ping, Message::Text("a".into())];
+
+    verify_that!(
+        covers_all_variants().explain_match(&messages),
+        displays_as(eq("which is missing the variant Move"))
+    )
+}
+
+#[test]
+fn covers_all_variants_produces_failure_message() -> Result<()> {
+    let result = verify_that!(Vec::<Message>::new(), covers_all_variants());
+
+    verify_that!(
+        result,
+        err(displays_as(contains_regex(
+            "Expected: contains every variant of .*::Message\n\
+            Actual: \\[\\], which is missing the variants Ping, Text, Move\n"
+        )))
+    )
+}
@@ -40,6 +40,27 @@ fn elements_are_supports_trailing_comma() -> Result<()> {
     verify_that!(value, elements_are![eq(1), eq(2), eq(3),])
 }
 
+#[test]
+fn elements_are_matches_plain_values() -> Result<()> {
+    let value = vec![1, 2, 3];
+    verify_that!(value, elements_are![1, 2, 3])
+}
+
+#[test]
+fn elements_are_matches_mix_of_plain_values_and_matchers() -> Result<()> {
+    let value = vec!["a".to_string(), "bc".to_string()];
+    verify_that!(value, elements_are!["a", starts_with("b")])
+}
+
+#[test]
+fn elements_are_describes_plain_values_as_equality() -> Result<()> {
+    let result = verify_that!(vec![1, 4], elements_are![1, 2]);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("where element #1 is 4, which isn't equal to 2")))
+    )
+}
+
 #[test]
 fn elements_are_returns_no_match_when_expected_and_actual_sizes_differ() -> Result<()> {
     let value = vec![1, 2];
@@ -77,7 +98,7 @@ fn elements_are_produces_correct_failure_message_nested() -> Result<()> {
         result,
         err(displays_as(contains_substring(indoc!(
             "
-                Value of: vec![vec! [0, 1], vec! [1, 2]]
+                Value of: vec![vec![0, 1], vec![1, 2]]
                 Expected: has elements:
                   0. has elements:
                        0. is equal to 1
@@ -112,3 +133,46 @@ fn elements_are_explain_match_wrong_size() -> Result<()> {
         displays_as(eq("whose size is 2"))
     )
 }
+
+#[test]
+fn iterates_as_matches_iterator() -> Result<()> {
+    let words = ["apple", "banana"];
+    verify_that!(words.iter().map(|w| w.len()), iterates_as![eq(5), eq(6)])
+}
+
+#[test]
+fn iterates_as_matches_range() -> Result<()> {
+    verify_that!(1..4, iterates_as![eq(1), eq(2), eq(3)])
+}
+
+#[test]
+fn iterates_as_returns_no_match_when_expected_and_actual_sizes_differ() -> Result<()> {
+    verify_that!(1..3, not(iterates_as![eq(1), eq(2), eq(3)]))
+}
+
+#[test]
+fn iterates_as_produces_correct_failure_message() -> Result<()> {
+    let result = verify_that!([1, 4, 3].into_iter(), iterates_as![eq(1), eq(2), eq(3)]);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+                Expected: yields elements:
+                  0. is equal to 1
+                  1. is equal to 2
+                  2. is equal to 3
+                Actual: IntoIter(
+                    [
+                        1,
+                        4,
+                        3,
+                    ],
+                ), where element #1 is 4, which isn't equal to 2"
+        ))))
+    )
+}
+
+#[test]
+fn iterates_as_explain_match_wrong_size() -> Result<()> {
+    verify_that!(iterates_as![eq(1)].explain_match(&(1..3)), displays_as(eq("whose size is 2")))
+}
@@ -58,6 +58,7 @@ fn struct_in_other_module_matches() -> Result<()> {
 }
 
 #[derive(Debug)]
+#[allow(dead_code)]
 struct Tuple(i32, String);
 
 #[test]
@@ -134,3 +135,75 @@ fn matches_struct_like_enum_value() -> Result<()> {
 
     verify_that!(value, field!(AnEnum::AValue.a_field, eq(123)))
 }
+
+#[test]
+fn matches_nested_field_path() -> Result<()> {
+    #[derive(Debug)]
+    struct InnerStruct {
+        value: u32,
+    }
+    #[derive(Debug)]
+    struct OuterStruct {
+        inner: InnerStruct,
+    }
+    let value = OuterStruct { inner: InnerStruct { value: 3 } };
+
+    verify_that!(value, field!(OuterStruct.inner.value, eq(3)))
+}
+
+#[test]
+fn matches_nested_field_path_through_box_and_reference() -> Result<()> {
+    #[derive(Debug)]
+    struct InnerStruct(u32);
+    #[derive(Debug)]
+    struct MiddleStruct<'a> {
+        inner: &'a InnerStruct,
+    }
+    #[derive(Debug)]
+    struct OuterStruct<'a> {
+        middle: Box<MiddleStruct<'a>>,
+    }
+    let inner = InnerStruct(3);
+    let value = OuterStruct { middle: Box::new(MiddleStruct { inner: &inner }) };
+
+    verify_that!(value, field!(OuterStruct.middle.inner.0, eq(3)))
+}
+
+#[test]
+fn matches_nested_field_path_in_enum_variant() -> Result<()> {
+    #[derive(Debug)]
+    struct InnerStruct {
+        value: u32,
+    }
+    #[derive(Debug)]
+    enum AnEnum {
+        #[allow(dead_code)] // This variant is intentionally unused.
+        AValue(InnerStruct),
+        AnotherValue,
+    }
+
+    verify_that!(AnEnum::AValue(InnerStruct { value: 3 }), field!(AnEnum::AValue.0.value, eq(3)))?;
+    verify_that!(AnEnum::AnotherValue, not(field!(AnEnum::AValue.0.value, eq(3))))
+}
+
+#[test]
+fn nested_field_path_error_message_shows_full_path() -> Result<()> {
+    #[derive(Debug)]
+    struct InnerStruct {
+        value: u32,
+    }
+    #[derive(Debug)]
+    struct OuterStruct {
+        inner: InnerStruct,
+    }
+    let value = OuterStruct { inner: InnerStruct { value: 3 } };
+
+    let result = verify_that!(value, field!(OuterStruct.inner.value, eq(4)));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "Expected: has field `inner.value`, which is equal to 4"
+        )))
+    )
+}
@@ -13,10 +13,16 @@
 // limitations under the License.
 
 mod all_matcher_test;
+mod covers_all_variants_matcher_test;
 mod elements_are_matcher_test;
 mod field_matcher_test;
+mod matcher_factory_test;
+mod matcher_fn_test;
+mod matcher_macro_test;
 mod matches_pattern_test;
 mod pointwise_matcher_test;
 mod property_matcher_test;
+mod property_test;
 mod tuple_matcher_test;
 mod unordered_elements_are_matcher_test;
+mod verify_assertion_count_test;
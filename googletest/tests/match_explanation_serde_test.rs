@@ -0,0 +1,42 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Not a module of tests/lib.rs: referencing serde_json brings its
+// `PartialEq<serde_json::Value>` implementations into scope, which would make
+// the types in other tests ambiguous.
+#![cfg(feature = "serde")]
+
+use googletest::prelude::*;
+
+#[test]
+fn match_explanation_serializes_as_tree() -> Result<()> {
+    let failure = verify_that!(Some(3), some(eq(2))).unwrap_err();
+
+    let json = serde_json::to_value(*failure.explanation.unwrap()).unwrap();
+
+    verify_that!(
+        json,
+        eq(serde_json::json!({
+            "matcher": "SomeMatcher",
+            "actual": "Some(\n    3,\n)",
+            "explanation": "which has a value which isn't equal to 2",
+            "children": [{
+                "matcher": "EqMatcher",
+                "actual": "3",
+                "explanation": "which isn't equal to 2",
+                "children": [],
+            }],
+        }))
+    )
+}
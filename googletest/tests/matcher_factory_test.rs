@@ -0,0 +1,110 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::matcher::{Matcher, MatcherResult};
+use googletest::prelude::*;
+use indoc::indoc;
+
+#[derive(Debug, MatcherFactory)]
+struct HttpResponse {
+    status: u16,
+    body: String,
+}
+
+#[derive(Debug, MatcherFactory)]
+struct Point(i32, i32);
+
+#[derive(Debug, MatcherFactory)]
+struct Wrapper<'a, T> {
+    value: &'a T,
+}
+
+#[derive(Debug, MatcherFactory)]
+struct HTTPHeader {
+    name: String,
+}
+
+#[test]
+fn matches_struct_whose_fields_all_match() -> Result<()> {
+    let response = HttpResponse { status: 200, body: "All OK".into() };
+
+    verify_that!(response, http_response_matcher(eq(200), contains_substring("OK")))
+}
+
+#[test]
+fn does_not_match_struct_with_one_mismatching_field() -> Result<()> {
+    let response = HttpResponse { status: 200, body: "All OK".into() };
+
+    verify_that!(response, not(http_response_matcher(eq(200), contains_substring("Error"))))
+}
+
+#[test]
+fn matches_tuple_struct() -> Result<()> {
+    verify_that!(Point(1, 2), point_matcher(eq(1), gt(0)))
+}
+
+#[test]
+fn matches_generic_struct_with_lifetime() -> Result<()> {
+    let value = 123;
+
+    verify_that!(Wrapper { value: &value }, wrapper_matcher(points_to(eq(123))))
+}
+
+#[test]
+fn names_factory_of_struct_with_acronym_in_snake_case() -> Result<()> {
+    verify_that!(HTTPHeader { name: "Host".into() }, http_header_matcher(eq("Host")))
+}
+
+#[test]
+fn describes_all_fields() -> Result<()> {
+    let matcher = point_matcher(eq(1), eq(2));
+
+    verify_that!(
+        matcher.describe(MatcherResult::Matches),
+        eq(indoc!(
+            "
+            is Point which has all the following properties:
+              * has field `0`, which is equal to 1
+              * has field `1`, which is equal to 2"
+        ))
+    )
+}
+
+#[test]
+fn explains_only_the_mismatching_field() -> Result<()> {
+    let response = HttpResponse { status: 200, body: "All OK".into() };
+
+    let result = verify_that!(response, http_response_matcher(eq(404), anything()));
+
+    verify_that!(
+        result,
+        err(displays_as(all!(
+            contains_substring("}, which has field `status`, which isn't equal to 404"),
+            not(contains_substring("which has field `body`"))
+        )))
+    )
+}
+
+#[test]
+fn explains_each_mismatching_field() -> Result<()> {
+    let result = verify_that!(Point(1, 2), point_matcher(eq(3), eq(4)));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "\n  * which has field `0`, which isn't equal to 3\n  * which has field `1`, which isn't \
+             equal to 4\n"
+        )))
+    )
+}
@@ -0,0 +1,106 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::matcher::{Matcher, MatcherResult};
+use googletest::prelude::*;
+
+#[googletest::matcher_fn]
+fn is_prime(n: &u64) -> bool {
+    *n >= 2 && (2..*n).take_while(|d| d * d <= *n).all(|d| n % d != 0)
+}
+
+#[googletest::matcher_fn]
+fn is_multiple_of(n: &u64, k: u64) -> bool {
+    n % k == 0
+}
+
+#[googletest::matcher_fn]
+fn has_length_between(s: &str, low: usize, high: usize) -> bool {
+    (low..=high).contains(&s.len())
+}
+
+#[googletest::matcher_fn]
+fn palindrome(s: &str) -> bool {
+    s.chars().eq(s.chars().rev())
+}
+
+#[googletest::matcher_fn]
+fn is_default<T: Default + PartialEq + std::fmt::Debug>(value: &T) -> bool {
+    *value == T::default()
+}
+
+#[test]
+fn matches_value_satisfying_predicate() -> Result<()> {
+    verify_that!(7, is_prime())
+}
+
+#[test]
+fn does_not_match_value_not_satisfying_predicate() -> Result<()> {
+    verify_that!(8, not(is_prime()))
+}
+
+#[test]
+fn passes_further_arguments_to_predicate() -> Result<()> {
+    verify_that!(12, is_multiple_of(3))?;
+    verify_that!(12, not(is_multiple_of(5)))
+}
+
+#[test]
+fn passes_several_further_arguments_to_predicate() -> Result<()> {
+    verify_that!(*"abc", has_length_between(1, 3))
+}
+
+#[test]
+fn matches_with_generic_predicate() -> Result<()> {
+    verify_that!(0, is_default())?;
+    verify_that!(String::from("a"), not(is_default()))
+}
+
+#[test]
+fn can_be_used_inside_other_matchers() -> Result<()> {
+    verify_that!(vec![2, 3, 5, 7], each(is_prime()))
+}
+
+#[test]
+fn describes_matcher_from_name_starting_with_is() -> Result<()> {
+    verify_that!(is_prime().describe(MatcherResult::Matches), eq("is prime"))?;
+    verify_that!(is_prime().describe(MatcherResult::DoesNotMatch), eq("isn't prime"))
+}
+
+#[test]
+fn describes_matcher_with_arguments() -> Result<()> {
+    verify_that!(is_multiple_of(3).describe(MatcherResult::Matches), eq("is multiple of (k = 3)"))?;
+    verify_that!(
+        has_length_between(1, 3).describe(MatcherResult::DoesNotMatch),
+        eq("doesn't have length between (low = 1, high = 3)")
+    )
+}
+
+#[test]
+fn describes_matcher_from_other_name() -> Result<()> {
+    verify_that!(
+        palindrome().describe(MatcherResult::Matches),
+        eq("matches predicate `palindrome`")
+    )
+}
+
+#[test]
+fn shows_description_in_assertion_failure() -> Result<()> {
+    let result = verify_that!(10, is_multiple_of(3));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("Value of: 10\nExpected: is multiple of (k = 3)\n")))
+    )
+}
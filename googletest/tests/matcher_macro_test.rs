@@ -0,0 +1,90 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::matcher::{Matcher, MatcherResult};
+use googletest::prelude::*;
+
+googletest::matcher! {
+    fn is_within(expected: f64, tolerance: f64) -> Matcher<ActualT = f64> {
+        matches: |actual| (actual - expected).abs() <= *tolerance,
+        describe: "is within {tolerance} of {expected}",
+        describe_negated: "isn't within {tolerance} of {expected}",
+        explain: |actual| format!("which is {} away", (actual - expected).abs()),
+    }
+}
+
+googletest::matcher! {
+    fn has_word_count(count: usize) -> Matcher<ActualT = String> {
+        matches: |actual| actual.split_whitespace().count() == *count,
+        describe: "has {count} words",
+        describe_negated: "doesn't have {count} words"
+    }
+}
+
+googletest::matcher! {
+    fn is_blank() -> Matcher<ActualT = String> {
+        matches: |actual| actual.trim().is_empty(),
+        describe: "is blank",
+        describe_negated: "isn't blank",
+    }
+}
+
+#[test]
+fn matches_value_satisfying_expression() -> Result<()> {
+    verify_that!(1.05, is_within(1.0, 0.1))
+}
+
+#[test]
+fn does_not_match_value_not_satisfying_expression() -> Result<()> {
+    verify_that!(1.5, not(is_within(1.0, 0.1)))
+}
+
+#[test]
+fn matches_with_matcher_without_parameters() -> Result<()> {
+    verify_that!(String::from("  "), is_blank())?;
+    verify_that!(String::from(" a "), not(is_blank()))
+}
+
+#[test]
+fn can_be_used_inside_other_matchers() -> Result<()> {
+    verify_that!(vec![String::from("one two"), String::from("three four")], each(has_word_count(2)))
+}
+
+#[test]
+fn describes_matcher_with_parameters() -> Result<()> {
+    verify_that!(is_within(1.0, 0.1).describe(MatcherResult::Matches), eq("is within 0.1 of 1"))?;
+    verify_that!(
+        is_within(1.0, 0.1).describe(MatcherResult::DoesNotMatch),
+        eq("isn't within 0.1 of 1")
+    )
+}
+
+#[test]
+fn explains_mismatch_with_explain_expression() -> Result<()> {
+    let result = verify_that!(1.5, is_within(1.0, 0.1));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "Value of: 1.5\nExpected: is within 0.1 of 1\nActual: 1.5, which is 0.5 away\n"
+        )))
+    )
+}
+
+#[test]
+fn explains_mismatch_from_description_without_explain_expression() -> Result<()> {
+    let result = verify_that!(String::from("one"), has_word_count(2));
+
+    verify_that!(result, err(displays_as(contains_substring("which doesn't have 2 words"))))
+}
@@ -108,6 +108,61 @@ fn matches_struct_containing_nested_struct_with_field() -> Result<()> {
     )
 }
 
+#[test]
+fn matches_struct_containing_boxed_struct_with_deref_field() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct {
+        a_nested_struct: Box<ANestedStruct>,
+    }
+    #[derive(Debug)]
+    struct ANestedStruct {
+        a_field: u32,
+    }
+    let actual = AStruct { a_nested_struct: Box::new(ANestedStruct { a_field: 123 }) };
+
+    verify_that!(
+        actual,
+        matches_pattern!(AStruct { *a_nested_struct: matches_pattern!(ANestedStruct {
+            a_field: eq(123)
+        }) })
+    )
+}
+
+#[test]
+fn matches_struct_containing_rc_and_arc_with_deref_fields() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct {
+        an_rc: std::rc::Rc<u32>,
+        an_arc: std::sync::Arc<String>,
+        a_field: u32,
+    }
+    let actual = AStruct {
+        an_rc: std::rc::Rc::new(123),
+        an_arc: std::sync::Arc::new("A string".into()),
+        a_field: 234,
+    };
+
+    verify_that!(
+        actual,
+        matches_pattern!(AStruct {
+            *an_rc: eq(123),
+            a_field: eq(234),
+            *an_arc: starts_with("A"),
+        })
+    )
+}
+
+#[test]
+fn does_not_match_struct_with_mismatching_deref_field() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct {
+        a_field: Box<u32>,
+    }
+    let actual = AStruct { a_field: Box::new(123) };
+
+    verify_that!(actual, not(matches_pattern!(AStruct { *a_field: eq(234) })))
+}
+
 #[test]
 fn has_correct_assertion_failure_message_for_single_field() -> Result<()> {
     #[derive(Debug)]
@@ -396,6 +451,118 @@ fn does_not_match_wrong_enum_value() -> Result<()> {
     verify_that!(actual, not(matches_pattern!(AnEnum::A(eq(123)))))
 }
 
+#[test]
+fn matches_struct_with_rest_pattern() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct {
+        a_field: u32,
+        #[allow(unused)]
+        another_field: u32,
+    }
+    let actual = AStruct { a_field: 123, another_field: 234 };
+
+    verify_that!(actual, matches_pattern!(AStruct { a_field: eq(123), .. }))
+}
+
+#[test]
+fn matches_struct_with_two_fields_and_rest_pattern() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct {
+        a_field: u32,
+        another_field: u32,
+        #[allow(unused)]
+        a_third_field: u32,
+    }
+    let actual = AStruct { a_field: 123, another_field: 234, a_third_field: 345 };
+
+    verify_that!(actual, matches_pattern!(AStruct { a_field: eq(123), another_field: eq(234), .. }))
+}
+
+#[test]
+fn does_not_match_struct_with_rest_pattern_and_mismatching_field() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct {
+        a_field: u32,
+        #[allow(unused)]
+        another_field: u32,
+    }
+    let actual = AStruct { a_field: 123, another_field: 234 };
+
+    verify_that!(actual, not(matches_pattern!(AStruct { a_field: eq(234), .. })))
+}
+
+#[test]
+fn matches_struct_with_method_and_rest_pattern() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct {
+        a_field: u32,
+    }
+
+    impl AStruct {
+        fn get_field(&self) -> u32 {
+            self.a_field
+        }
+    }
+
+    let actual = AStruct { a_field: 123 };
+
+    verify_that!(actual, matches_pattern!(AStruct { get_field(): eq(123), .. }))
+}
+
+#[test]
+fn matches_tuple_struct_with_rest_pattern() -> Result<()> {
+    #[derive(Debug)]
+    struct AStruct(u32, u32, #[allow(unused)] u32);
+    let actual = AStruct(123, 234, 345);
+
+    verify_that!(actual, matches_pattern!(AStruct(eq(123), eq(234), ..)))
+}
+
+#[test]
+fn matches_enum_variant_with_only_rest_pattern() -> Result<()> {
+    #[derive(Debug)]
+    enum AnEnum {
+        #[allow(unused)]
+        A(u32),
+        #[allow(unused)]
+        B { a_field: u32 },
+    }
+
+    verify_that!(AnEnum::A(123), matches_pattern!(AnEnum::A(..)))?;
+    verify_that!(AnEnum::B { a_field: 123 }, matches_pattern!(AnEnum::B { .. }))
+}
+
+#[test]
+fn does_not_match_wrong_enum_variant_with_only_rest_pattern() -> Result<()> {
+    #[derive(Debug)]
+    enum AnEnum {
+        #[allow(unused)]
+        A(u32),
+        #[allow(unused)]
+        B { a_field: u32 },
+    }
+
+    verify_that!(AnEnum::A(123), not(matches_pattern!(AnEnum::B { .. })))?;
+    verify_that!(AnEnum::B { a_field: 123 }, not(matches_pattern!(AnEnum::A(..))))
+}
+
+#[test]
+fn has_correct_assertion_failure_message_for_only_rest_pattern() -> Result<()> {
+    #[derive(Debug)]
+    enum AnEnum {
+        #[allow(unused)]
+        A(u32),
+        B,
+    }
+
+    let result = verify_that!(AnEnum::B, matches_pattern!(AnEnum::A(..)));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("Expected: matches the pattern AnEnum")))
+    )
+}
+
 #[test]
 fn matches_struct_with_a_method() -> Result<()> {
     #[derive(Debug)]
@@ -180,3 +180,47 @@ fn pointwise_matches_single_element_with_three_containers() -> Result<()> {
         pointwise!(|v, t, u| near(v, t * u), vec![1.0f32], vec![0.0001f32], vec![0.5f32])
     )
 }
+
+#[test]
+fn pointwise_passes_items_of_three_containers_in_order() -> Result<()> {
+    let value = vec![123, 456];
+    verify_that!(value, pointwise!(|a, b, c| eq(a * 100 + b * 10 + c), [1, 4], [2, 5], [3, 6]))
+}
+
+#[test]
+fn pointwise_matches_with_four_containers() -> Result<()> {
+    let value = vec![10, 20];
+    verify_that!(value, pointwise!(|a, b, c, d| eq(a * b - c + d), [2, 4], [5, 5], [1, 2], [1, 2]))
+}
+
+#[test]
+fn pointwise_with_multiple_containers_supports_trailing_comma() -> Result<()> {
+    let value = vec![1.00001f32];
+    verify_that!(value, pointwise!(near, vec![1.0], vec![0.0001],))
+}
+
+#[test]
+fn pointwise_fn_matches_with_closure() -> Result<()> {
+    let expected_values = vec![1.0, 2.0];
+    verify_that!(vec![1.00001, 1.99999], pointwise_fn(|e| near(e, 0.0001), expected_values))
+}
+
+#[test]
+fn pointwise_fn_matches_with_function() -> Result<()> {
+    verify_that!(vec![1, 2], pointwise_fn(le, [1, 3]))
+}
+
+#[test]
+fn pointwise_fn_does_not_match_value_of_wrong_length() -> Result<()> {
+    verify_that!(vec![1, 2], not(pointwise_fn(eq, [1])))
+}
+
+#[test]
+fn pointwise_fn_explains_mismatch() -> Result<()> {
+    let result = verify_that!(vec![1, 2, 3], pointwise_fn(eq, [1, 4, 3]));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring("where element #1 is 2, which isn't equal to 4")))
+    )
+}
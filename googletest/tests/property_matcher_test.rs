@@ -36,6 +36,14 @@ impl SomeStruct {
     fn get_property_ref_with_params(&self, _a: u32, _b: u32) -> &u32 {
         &self.a_property
     }
+
+    fn get_a_collection(&self) -> Vec<u32> {
+        vec![]
+    }
+
+    fn get_a_collection_ref(&self) -> &[u32] {
+        &[]
+    }
 }
 
 #[test]
@@ -136,11 +144,6 @@ fn describes_itself_in_not_matching_case() -> Result<()> {
 
 #[test]
 fn explains_mismatch_referencing_explanation_of_inner_matcher() -> Result<()> {
-    impl SomeStruct {
-        fn get_a_collection(&self) -> Vec<u32> {
-            vec![]
-        }
-    }
     let value = SomeStruct { a_property: 2 };
     let result = verify_that!(value, property!(SomeStruct.get_a_collection(), container_eq([1])));
 
@@ -170,12 +173,6 @@ fn describes_itself_in_not_matching_case_for_ref() -> Result<()> {
 
 #[test]
 fn explains_mismatch_referencing_explanation_of_inner_matcher_for_ref() -> Result<()> {
-    static EMPTY_COLLECTION: Vec<u32> = vec![];
-    impl SomeStruct {
-        fn get_a_collection_ref(&self) -> &[u32] {
-            &EMPTY_COLLECTION
-        }
-    }
     let value = SomeStruct { a_property: 2 };
     let result =
         verify_that!(value, property!(ref SomeStruct.get_a_collection_ref(), container_eq([1])));
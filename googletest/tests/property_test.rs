@@ -0,0 +1,64 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::generators::{any, one_of, range, string_of, vec_of};
+use googletest::prelude::*;
+
+#[googletest::property_test(a = any::<i32>(), b = any::<i32>())]
+fn property_test_runs_with_two_parameters(a: i32, b: i32) -> Result<()> {
+    verify_that!(a.wrapping_add(b), eq(b.wrapping_add(a)))
+}
+
+#[googletest::property_test(value = range(10..20))]
+fn property_test_uses_given_generator(value: u64) -> Result<()> {
+    verify_that!(value, all!(ge(10), lt(20)))
+}
+
+#[googletest::property_test(values = vec_of(any::<u8>(), 0..10))]
+fn property_test_accepts_mutable_parameters(mut values: Vec<u8>) -> Result<()> {
+    values.sort();
+    verify_that!(is_sorted(&values), eq(true))
+}
+
+#[googletest::property_test(word = string_of(range('a'..='z'), 1..5), sep = one_of([",", ";"]))]
+fn property_test_supports_non_fatal_assertions(word: String, sep: &str) -> Result<()> {
+    let joined = [word.as_str(), word.as_str()].join(sep);
+    expect_that!(joined.split(sep).collect::<Vec<_>>(), each(eq(word.as_str())));
+    Ok(())
+}
+
+#[googletest::property_test(
+    config = googletest::config().max_failures(1),
+    value = any::<bool>(),
+)]
+fn property_test_accepts_config(value: bool) -> Result<()> {
+    verify_that!(value.to_string(), eq(if value { "true" } else { "false" }))
+}
+
+#[googletest::property_test(
+    depends_on = "property_test_without_parameters_runs",
+    value = any::<u8>(),
+)]
+fn property_test_accepts_dependencies(value: u8) -> Result<()> {
+    verify_that!(value.checked_add(0), some(eq(value)))
+}
+
+#[googletest::property_test()]
+fn property_test_without_parameters_runs() -> Result<()> {
+    Ok(())
+}
+
+fn is_sorted(values: &[u8]) -> bool {
+    values.windows(2).all(|pair| pair[0] <= pair[1])
+}
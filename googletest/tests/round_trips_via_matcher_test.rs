@@ -0,0 +1,99 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Not a module of tests/lib.rs: referencing serde_json brings its
+// `PartialEq<serde_json::Value>` implementations into scope, which would make
+// the types in other tests ambiguous.
+#![cfg(feature = "serde")]
+
+use googletest::matcher::{Matcher, MatcherResult};
+use googletest::prelude::*;
+use indoc::indoc;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[derive(Debug)]
+struct Json;
+
+impl Format for Json {
+    type Error = serde_json::Error;
+
+    fn serialize<T: Serialize>(&self, value: &T) -> serde_json::Result<String> {
+        serde_json::to_string(value)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, serialized: &str) -> serde_json::Result<T> {
+        serde_json::from_str(serialized)
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Settings {
+    name: String,
+    retries: u32,
+    #[serde(skip)]
+    verbose: bool,
+}
+
+#[test]
+fn round_trips_via_matches_value_which_round_trips() -> Result<()> {
+    let settings = Settings { name: "app".into(), retries: 3, verbose: false };
+
+    verify_that!(settings, round_trips_via(Json))
+}
+
+#[test]
+fn round_trips_via_does_not_match_value_which_changes_in_round_trip() -> Result<()> {
+    let settings = Settings { name: "app".into(), retries: 3, verbose: true };
+
+    verify_that!(round_trips_via(Json).matches(&settings), eq(MatcherResult::DoesNotMatch))
+}
+
+#[test]
+fn round_trips_via_shows_diverging_field() -> Result<()> {
+    let result = verify_that!(
+        Settings { name: "app".into(), retries: 3, verbose: true },
+        round_trips_via(Json)
+    );
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            r#"
+            which serializes to {"name":"app","retries":3}, which deserializes as Settings { name: "app", retries: 3, verbose: false }
+            Debug diff:
+             Settings {
+                 name: "app",
+                 retries: 3,
+            +    verbose: false,
+                          ^^^^^
+            -    verbose: true,
+                          ^^^^
+             }
+            "#
+        ))))
+    )
+}
+
+#[test]
+fn round_trips_via_reports_deserialization_error() -> Result<()> {
+    let result = verify_that!(f64::NAN, round_trips_via(Json));
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(
+            "which serializes to null, which fails to deserialize with invalid type: null, \
+            expected f64"
+        )))
+    )
+}
@@ -35,6 +35,33 @@ fn unordered_elements_are_matches_vector() -> Result<()> {
     verify_that!(value, unordered_elements_are![eq(1), eq(2), eq(3)])
 }
 
+#[test]
+fn unordered_elements_are_matches_plain_values() -> Result<()> {
+    let value = vec![1, 2, 3];
+    verify_that!(value, unordered_elements_are![3, gt(1), 1])
+}
+
+#[test]
+fn unordered_elements_are_matches_map_with_plain_values() -> Result<()> {
+    let value: HashMap<u32, &str> = HashMap::from_iter([(1, "One"), (2, "Two")]);
+    verify_that!(value, unordered_elements_are![(2, "Two"), (eq(1), starts_with("O"))])
+}
+
+#[test]
+fn contains_each_and_is_contained_in_match_plain_values() -> Result<()> {
+    let value = vec![1, 2, 3];
+    expect_that!(value, contains_each![3, 1]);
+    verify_that!(value, is_contained_in![1, 2, 3, 4])
+}
+
+#[test]
+fn unordered_elements_are_borrows_its_matchers() -> Result<()> {
+    let first = eq(1);
+    let second = eq(2);
+    expect_that!(vec![1, 2], unordered_elements_are![first, second]);
+    verify_that!(vec![2, 1], unordered_elements_are![first, second])
+}
+
 #[test]
 fn unordered_elements_are_matches_slice() -> Result<()> {
     let value = vec![1, 2, 3];
@@ -0,0 +1,70 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use googletest::prelude::*;
+use indoc::indoc;
+
+#[test]
+fn verify_assertion_count_passes_when_count_matches() -> Result<()> {
+    verify_assertion_count!(eq(2), {
+        verify_that!(1, eq(1))?;
+        verify_pred!(str::is_empty(""))?;
+    })
+}
+
+#[test]
+fn verify_assertion_count_counts_failed_assertions() -> Result<()> {
+    verify_assertion_count!(eq(1), {
+        let _ = verify_that!(1, eq(2));
+    })
+}
+
+#[test]
+fn verify_assertion_count_counts_assertions_in_callbacks() -> Result<()> {
+    verify_assertion_count!(eq(3), {
+        [1, 2, 3].iter().for_each(|value| {
+            let _ = verify_that!(value, gt(&0));
+        });
+    })
+}
+
+#[test]
+fn verify_assertion_count_does_not_count_assertions_outside_block() -> Result<()> {
+    verify_that!(1, eq(1))?;
+
+    verify_assertion_count!(eq(0), {})
+}
+
+#[test]
+fn verify_assertion_count_returns_value_of_block() -> Result<()> {
+    let value = verify_assertion_count!(eq(0), { 42 })?;
+
+    verify_that!(value, eq(42))
+}
+
+#[test]
+fn verify_assertion_count_reports_observed_count() -> Result<()> {
+    let result = verify_assertion_count!(gt(0), {});
+
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+            Value of: number of assertions evaluated by the block
+            Expected: is greater than 0
+            Actual: 0, which is less than or equal to 0
+            "
+        ))))
+    )
+}
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use quote::quote;
-use syn::{parse_macro_input, Attribute, ItemFn, ReturnType};
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, DeriveInput, Expr, ExprLit, FnArg, Ident, ItemFn, Lit, LitStr, Member,
+    MetaNameValue, Pat, Path, ReturnType, Signature, Token, Type, parse_macro_input, parse_quote,
+};
 
 /// Marks a test to be run by the Google Rust test runner.
 ///
@@ -49,15 +53,86 @@ use syn::{parse_macro_input, Attribute, ItemFn, ReturnType};
 /// }
 /// ```
 ///
+/// The rendering and reporting of failures can be configured for the whole
+/// test with the argument `config`, which takes a
+/// [`googletest::config::Config`]:
+///
+/// ```ignore
+/// #[googletest::test(config = googletest::config().max_failures(3))]
+/// fn should_work() -> googletest::Result {
+///     ...
+/// }
+/// ```
+///
+/// A test can declare that it depends on other tests with the argument
+/// `depends_on`, which names a test function annotated with
+/// `#[googletest::test]` relative to the current module and may be repeated:
+///
+/// ```ignore
+/// #[googletest::test(depends_on = "setup_schema")]
+/// fn inserts_user() -> googletest::Result<()> {
+///     ...
+/// }
+/// ```
+///
+/// The prerequisites run first, unless they already ran as a prerequisite,
+/// and the test is reported as skipped if one of them does not pass. A
+/// prerequisite which the test harness reaches later reports its earlier
+/// result rather than running again. The test fails if a prerequisite belongs
+/// to another shard of a sharded test run, and is skipped if the dependencies
+/// form a cycle. A prerequisite which the test harness already ran on its own
+/// runs again for its dependent, since tests without dependencies are not
+/// tracked.
+///
+/// The Rust test harness has no notion of skipped tests, so it counts a
+/// skipped test as passed. The test prints a line starting with `SKIPPED`
+/// and is marked as skipped in the XML report requested by Bazel.
+///
+/// The memory which the test allocates can be limited with the arguments
+/// `max_alloc`, for the peak allocation, and `max_total_alloc`, for the
+/// total allocation, provided a
+/// [`googletest::allocation::TrackingAllocator`] is the global allocator:
+///
+/// ```ignore
+/// #[googletest::test(max_alloc = "256MB")]
+/// fn should_work() -> googletest::Result {
+///     ...
+/// }
+/// ```
+///
+/// With [`Config::catch_panics`], a panic in the test is caught and reported
+/// as a failure of the test, like a failed assertion.
+///
+/// [`Config::catch_panics`]: config/struct.Config.html#method.catch_panics
+/// [`googletest::allocation::TrackingAllocator`]: allocation/struct.TrackingAllocator.html
 /// [`googletest::Result`]: type.Result.html
+/// [`googletest::config::Config`]: config/struct.Config.html
 #[proc_macro_attribute]
 pub fn test(
-    _args: proc_macro::TokenStream,
+    args: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
+    let args =
+        parse_macro_input!(args with Punctuated::<MetaNameValue, Token![,]>::parse_terminated);
+    let mut arguments = TestArguments::default();
+    for arg in args {
+        match arguments.parse(arg) {
+            Ok(None) => {}
+            Ok(Some(arg)) => {
+                return syn::Error::new_spanned(
+                    arg.path,
+                    "Unknown argument to #[googletest::test]; expected `config`, `depends_on`, \
+                    `max_alloc`, or `max_total_alloc`",
+                )
+                .to_compile_error()
+                .into();
+            }
+            Err(e) => return e.to_compile_error().into(),
+        }
+    }
     let mut parsed_fn = parse_macro_input!(input as ItemFn);
     let attrs = parsed_fn.attrs.drain(..).collect::<Vec<_>>();
-    let (mut sig, block) = (parsed_fn.sig, parsed_fn.block);
+    let (sig, block) = (parsed_fn.sig, parsed_fn.block);
     let ReturnType::Type(_, output_type) = sig.output.clone() else {
         return quote! {
             compile_error!(
@@ -65,7 +140,6 @@ pub fn test(
             );
         }.into();
     };
-    sig.output = ReturnType::Default;
     let (maybe_closure, invocation) = if sig.asyncness.is_some() {
         (
             // In the async case, the ? operator returns from the *block* rather than the
@@ -74,7 +148,7 @@ pub fn test(
             // so we can't use the same solution as the sync case below.
             quote! {},
             quote! {
-                async { #block }.await
+                googletest::internal::panics::run_async_test(async { #block }).await
             },
         )
     } else {
@@ -86,17 +160,239 @@ pub fn test(
                 let test = move || #block;
             },
             quote! {
-                test()
+                googletest::internal::panics::run_test(test)
+            },
+        )
+    };
+    expand_test(arguments, attrs, sig, maybe_closure, invocation, output_type)
+}
+
+/// Marks a property test, which runs many times with randomly generated
+/// inputs.
+///
+/// Each parameter of the test function is paired with an expression
+/// evaluating to a [`googletest::generators::Generator`] of its values in an
+/// argument of the attribute named after the parameter:
+///
+/// ```ignore
+/// use googletest::generators::{any, range};
+///
+/// #[googletest::property_test(a = any::<i32>(), b = range(0..100))]
+/// fn addition_is_commutative(a: i32, b: i32) -> googletest::Result<()> {
+///     verify_that!(a.wrapping_add(b), eq(b.wrapping_add(a)))
+/// }
+/// ```
+///
+/// The test passes if the body passes for every input. Otherwise, it fails at
+/// the first failing input, which is reported together with the seed which
+/// reproduces it when set in the environment variable
+/// `GOOGLETEST_PROPERTY_SEED`. The number of inputs is 100 unless set in the
+/// environment variable `GOOGLETEST_PROPERTY_CASES`.
+///
+/// The test accepts the same arguments as [`#[googletest::test]`][macro@test]
+/// besides the generators, namely `config`, `depends_on`, `max_alloc`, and
+/// `max_total_alloc`, and catches panics in the same way.
+///
+/// [`googletest::generators::Generator`]: generators/trait.Generator.html
+#[proc_macro_attribute]
+pub fn property_test(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args =
+        parse_macro_input!(args with Punctuated::<MetaNameValue, Token![,]>::parse_terminated);
+    let mut arguments = TestArguments::default();
+    let mut generators = vec![];
+    for arg in args {
+        match arguments.parse(arg) {
+            Ok(None) => {}
+            Ok(Some(arg)) => {
+                let Some(parameter) = arg.path.get_ident() else {
+                    return syn::Error::new_spanned(
+                        arg.path,
+                        "Expected the name of a parameter, `config`, `depends_on`, `max_alloc`, or \
+                        `max_total_alloc` as argument to #[googletest::property_test]",
+                    )
+                    .to_compile_error()
+                    .into();
+                };
+                generators.push((parameter.clone(), arg.value));
+            }
+            Err(e) => return e.to_compile_error().into(),
+        }
+    }
+    let mut parsed_fn = parse_macro_input!(input as ItemFn);
+    let attrs = parsed_fn.attrs.drain(..).collect::<Vec<_>>();
+    let (mut sig, block) = (parsed_fn.sig, parsed_fn.block);
+    if let Some(asyncness) = sig.asyncness {
+        return syn::Error::new_spanned(asyncness, "Property tests cannot be async")
+            .to_compile_error()
+            .into();
+    }
+    let ReturnType::Type(_, output_type) = sig.output.clone() else {
+        return quote! {
+            compile_error!(
+                "Test function with the #[googletest::property_test] attribute must return googletest::Result<()>"
+            );
+        }.into();
+    };
+    let mut patterns = vec![];
+    let mut names = vec![];
+    let mut types = vec![];
+    let mut parameter_generators = vec![];
+    for input in &sig.inputs {
+        let FnArg::Typed(parameter) = input else {
+            return syn::Error::new_spanned(input, "Property tests cannot take `self`")
+                .to_compile_error()
+                .into();
+        };
+        let Pat::Ident(pattern) = parameter.pat.as_ref() else {
+            return syn::Error::new_spanned(
+                &parameter.pat,
+                "Parameters of property tests must be plain identifiers",
+            )
+            .to_compile_error()
+            .into();
+        };
+        let Some(index) = generators.iter().position(|(name, _)| *name == pattern.ident) else {
+            return syn::Error::new_spanned(
+                &pattern.ident,
+                format!(
+                    "Missing generator for this parameter; add `{} = ...` to \
+                    #[googletest::property_test]",
+                    pattern.ident
+                ),
+            )
+            .to_compile_error()
+            .into();
+        };
+        parameter_generators.push(generators.remove(index).1);
+        patterns.push(parameter.pat.clone());
+        names.push(pattern.ident.clone());
+        types.push(parameter.ty.clone());
+    }
+    if let Some((name, _)) = generators.first() {
+        return syn::Error::new_spanned(name, "No parameter of the test function has this name")
+            .to_compile_error()
+            .into();
+    }
+    sig.inputs.clear();
+    let invocation = quote! {
+        googletest::internal::panics::run_test(move || {
+            googletest::internal::property::run_property(
+                (#(#parameter_generators,)*),
+                |(#(#names,)*): &(#(#types,)*)| {
+                    vec![#(format!(concat!(stringify!(#names), " = {:?}"), #names)),*]
+                },
+                |(#(#patterns,)*): (#(#types,)*)| -> #output_type #block,
+            )
+        })
+    };
+    expand_test(arguments, attrs, sig, quote! {}, invocation, quote! { googletest::Result<()> })
+}
+
+/// The arguments which `#[googletest::test]` and `#[googletest::property_test]`
+/// both accept.
+#[derive(Default)]
+struct TestArguments {
+    config: Option<Expr>,
+    prerequisites: Vec<(LitStr, Path)>,
+    max_alloc: Option<usize>,
+    max_total_alloc: Option<usize>,
+}
+
+impl TestArguments {
+    /// Records `arg` if it is one of the shared arguments, otherwise returns it
+    /// back.
+    fn parse(&mut self, arg: MetaNameValue) -> syn::Result<Option<MetaNameValue>> {
+        if arg.path.is_ident("config") {
+            self.config = Some(arg.value);
+        } else if arg.path.is_ident("max_alloc") {
+            self.max_alloc = Some(parse_size(&arg.value)?);
+        } else if arg.path.is_ident("max_total_alloc") {
+            self.max_total_alloc = Some(parse_size(&arg.value)?);
+        } else if arg.path.is_ident("depends_on") {
+            let Expr::Lit(ExprLit { lit: Lit::Str(name), .. }) = &arg.value else {
+                return Err(syn::Error::new_spanned(
+                    arg.value,
+                    "Expected the name of a test as a string literal",
+                ));
+            };
+            self.prerequisites.push((name.clone(), name.parse::<Path>()?));
+        } else {
+            return Ok(Some(arg));
+        }
+        Ok(None)
+    }
+}
+
+/// Generates the function of a test with the signature `sig` and the
+/// attributes `attrs`.
+///
+/// The function runs `setup` followed by `invocation`, which evaluates to the
+/// `Result` of `googletest::internal::panics::run_test` or `run_async_test`
+/// wrapping a value of type `result_type`, within the machinery which both
+/// `#[googletest::test]` and `#[googletest::property_test]` provide:
+/// configuration, sharding, dependencies, allocation limits, and the
+/// reporting of the outcome.
+fn expand_test(
+    arguments: TestArguments,
+    attrs: Vec<Attribute>,
+    mut sig: Signature,
+    setup: impl ToTokens,
+    invocation: impl ToTokens,
+    result_type: impl ToTokens,
+) -> proc_macro::TokenStream {
+    let TestArguments { config, prerequisites, max_alloc, max_total_alloc } = arguments;
+    let apply_config = config.map(|config| {
+        quote! {
+            let _config_guard = googletest::config::Config::apply(#config);
+        }
+    });
+    let (prerequisite_names, prerequisite_paths): (Vec<_>, Vec<_>) =
+        prerequisites.into_iter().unzip();
+    let (limit_allocations, check_allocations) = if max_alloc.is_some() || max_total_alloc.is_some()
+    {
+        let [peak, total] = [max_alloc, max_total_alloc]
+            .map(|limit| limit.map_or(quote! { None }, |limit| quote! { Some(#limit) }));
+        (
+            quote! {
+                let allocations =
+                    googletest::allocation::AllocationLimit { peak: #peak, total: #total }.start();
+            },
+            quote! {
+                allocations.check();
             },
         )
+    } else {
+        (quote! {}, quote! {})
     };
+    sig.output = ReturnType::Default;
+    let test_name = sig.ident.clone();
     let function = quote! {
         #(#attrs)*
         #sig -> std::result::Result<(), ()> {
-            #maybe_closure
-            use googletest::internal::test_outcome::TestOutcome;
-            TestOutcome::init_current_test_outcome();
-            let result: #output_type = #invocation;
+            #setup
+            use googletest::internal::dependencies::{self, TestFn};
+            use googletest::internal::test_outcome::{TestInfo, TestOutcome};
+            #apply_config
+            let info = TestInfo { module_path: module_path!(), name: stringify!(#test_name) };
+            let _run = match dependencies::start(
+                info,
+                &[#((#prerequisite_names, #prerequisite_paths as TestFn)),*],
+            ) {
+                Ok(run) => run,
+                Err(result) => return result,
+            };
+            if !TestOutcome::init_current_test_outcome(info) {
+                return Ok(());
+            }
+            #limit_allocations
+            let result: #result_type = match #invocation {
+                Ok(result) => result,
+                Err(panic) => return TestOutcome::close_current_test_outcome(Err::<(), _>(panic)),
+            };
+            #check_allocations
             TestOutcome::close_current_test_outcome(result)
         }
     };
@@ -111,6 +407,311 @@ pub fn test(
     output.into()
 }
 
+/// Turns a predicate function into a matcher factory of the same name.
+///
+/// The first parameter of the function must be a reference to the value to be
+/// matched. Any further parameters become the parameters of the factory and
+/// must be plain identifiers whose types implement `Clone` and `Debug`. The
+/// type of the value to be matched must implement `Debug`.
+///
+/// ```ignore
+/// #[googletest::matcher_fn]
+/// fn is_multiple_of(n: &u64, k: u64) -> bool {
+///     n % k == 0
+/// }
+///
+/// verify_that!(12, is_multiple_of(3))
+/// ```
+///
+/// The description of the matcher is derived from the name of the function and
+/// the values of the further arguments. Names starting with `is_` and `has_`
+/// read naturally, e.g. "is multiple of (k = 3)" and "isn't multiple of
+/// (k = 3)". Any other name `name` gives "matches predicate `name`".
+#[proc_macro_attribute]
+pub fn matcher_fn(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    if !args.is_empty() {
+        return quote! {
+            compile_error!("#[googletest::matcher_fn] does not take any arguments");
+        }
+        .into();
+    }
+    let parsed_fn = parse_macro_input!(input as ItemFn);
+    let ItemFn { attrs, vis, sig, block } = parsed_fn;
+    let mut inputs = sig.inputs.iter();
+    let Some(FnArg::Typed(actual)) = inputs.next() else {
+        return syn::Error::new_spanned(
+            &sig,
+            "A matcher function must take a reference to the value to be matched as its first \
+            parameter",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let Type::Reference(actual_reference) = actual.ty.as_ref() else {
+        return syn::Error::new_spanned(
+            &actual.ty,
+            "The first parameter of a matcher function must be a reference",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let actual_type = &actual_reference.elem;
+    let mut names = vec![];
+    let mut types = vec![];
+    for input in inputs {
+        let FnArg::Typed(parameter) = input else {
+            return syn::Error::new_spanned(input, "A matcher function cannot take `self`")
+                .to_compile_error()
+                .into();
+        };
+        let Pat::Ident(pattern) = parameter.pat.as_ref() else {
+            return syn::Error::new_spanned(
+                &parameter.pat,
+                "Parameters of matcher functions must be plain identifiers",
+            )
+            .to_compile_error()
+            .into();
+        };
+        names.push(&pattern.ident);
+        types.push(&parameter.ty);
+    }
+    let name = &sig.ident;
+    let name_text = name.to_string();
+    let (positive, negative) = if let Some(rest) = name_text.strip_prefix("is_") {
+        let rest = rest.replace('_', " ");
+        (format!("is {rest}"), format!("isn't {rest}"))
+    } else if let Some(rest) = name_text.strip_prefix("has_") {
+        let rest = rest.replace('_', " ");
+        (format!("has {rest}"), format!("doesn't have {rest}"))
+    } else {
+        (
+            format!("matches predicate `{name_text}`"),
+            format!("doesn't match predicate `{name_text}`"),
+        )
+    };
+    let arguments_format =
+        names.iter().map(|name| format!("{name} = {{:?}}")).collect::<Vec<_>>().join(", ");
+    let (positive_format, negative_format) = if names.is_empty() {
+        (positive, negative)
+    } else {
+        (format!("{positive} ({arguments_format})"), format!("{negative} ({arguments_format})"))
+    };
+    let (impl_generics, _, where_clause) = sig.generics.split_for_impl();
+    let predicate_inputs = &sig.inputs;
+    quote! {
+        #(#attrs)*
+        #vis fn #name #impl_generics(#(#names: #types),*)
+            -> impl googletest::matcher::Matcher<ActualT = #actual_type>
+        #where_clause
+        {
+            fn predicate #impl_generics(#predicate_inputs) -> bool #where_clause #block
+            let positive_description = format!(#positive_format, #(&#names),*);
+            let negative_description = format!(#negative_format, #(&#names),*);
+            googletest::matchers::predicate(move |actual: &#actual_type| {
+                predicate(actual, #(::std::clone::Clone::clone(&#names)),*)
+            })
+            .with_description(positive_description, negative_description)
+        }
+    }
+    .into()
+}
+
+/// Derives `googletest::matchers::EnumVariants` for an enum, so that a
+/// collection of its values can be checked with
+/// `googletest::matchers::covers_all_variants`.
+///
+/// ```ignore
+/// #[derive(Debug, googletest::matchers::EnumVariants)]
+/// enum Command {
+///     Start,
+///     Stop { force: bool },
+///     Resize(u32, u32),
+/// }
+/// ```
+#[proc_macro_derive(EnumVariants)]
+pub fn derive_enum_variants(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let Data::Enum(data) = &input.data else {
+        return syn::Error::new_spanned(&input.ident, "EnumVariants can only be derived for enums")
+            .to_compile_error()
+            .into();
+    };
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let variants = data.variants.iter().map(|variant| &variant.ident).collect::<Vec<_>>();
+    quote! {
+        impl #impl_generics googletest::matchers::EnumVariants for #name #type_generics
+        #where_clause
+        {
+            const VARIANTS: &'static [&'static str] = &[#(stringify!(#variants)),*];
+
+            fn variant(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants { .. } => stringify!(#variants),)*
+                }
+            }
+        }
+    }
+    .into()
+}
+
+/// Derives a matcher factory for a struct, which takes one matcher for each
+/// field and matches values of the struct whose fields are all matched.
+///
+/// The factory is a function named after the struct in snake case followed by
+/// `_matcher`, with the same visibility as the struct. Its parameters are named
+/// after the fields, or `field_0`, `field_1`, ... for a tuple struct.
+///
+/// ```ignore
+/// #[derive(Debug, googletest::matchers::MatcherFactory)]
+/// struct HttpResponse {
+///     status: u16,
+///     body: String,
+/// }
+///
+/// verify_that!(response, http_response_matcher(eq(200), contains_substring("OK")))
+/// ```
+#[proc_macro_derive(MatcherFactory)]
+pub fn derive_matcher_factory(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "MatcherFactory can only be derived for structs",
+        )
+        .to_compile_error()
+        .into();
+    };
+    if data.fields.is_empty() {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "MatcherFactory can only be derived for structs with at least one field",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let name = &input.ident;
+    let visibility = &input.vis;
+    let factory_name =
+        Ident::new(&format!("{}_matcher", to_snake_case(&name.to_string())), name.span());
+    let factory_doc = format!(
+        "Matches a [`{name}`] whose fields are matched by the given matchers.\n\n\
+         Generated by `#[derive(MatcherFactory)]`."
+    );
+    let mut generics = input.generics.clone();
+    generics.params.insert(0, parse_quote!('__matcher));
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, type_generics, where_clause) = input.generics.split_for_impl();
+    let where_predicates =
+        where_clause.map(|clause| clause.predicates.iter()).into_iter().flatten();
+    let members = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(index.into()),
+        })
+        .collect::<Vec<_>>();
+    let parameters = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => Ident::new(&format!("field_{index}"), name.span()),
+        })
+        .collect::<Vec<_>>();
+    let types = data.fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    // The struct's implied bounds such as `T: 'a` for a field `&'a T` do not carry over to the
+    // factory, so they are stated explicitly.
+    let lifetimes = input.generics.lifetimes().map(|param| &param.lifetime).collect::<Vec<_>>();
+    let outlives_bounds = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .map(|ident| quote! { #ident: #(#lifetimes)+* })
+        .filter(|_| !lifetimes.is_empty());
+    quote! {
+        #[doc = #factory_doc]
+        #[allow(clippy::too_many_arguments)]
+        #visibility fn #factory_name #impl_generics(
+            #(#parameters: impl googletest::matcher::Matcher<ActualT = #types> + '__matcher),*
+        ) -> impl googletest::matcher::Matcher<ActualT = #name #type_generics> + '__matcher
+        where
+            #name #type_generics: std::fmt::Debug + '__matcher,
+            #(#types: std::fmt::Debug + '__matcher,)*
+            #(#outlives_bounds,)*
+            #(#where_predicates,)*
+        {
+            use googletest::matchers::matcher_factory::internal::StructMatcher;
+            use googletest::matchers::field_matcher::internal::field_matcher;
+            StructMatcher::new(
+                stringify!(#name),
+                vec![#(
+                    Box::new(field_matcher(
+                        |actual: &#name #type_generics| Some(&actual.#members),
+                        stringify!(#members),
+                        #parameters,
+                    ))
+                ),*],
+            )
+        }
+    }
+    .into()
+}
+
+/// Converts an identifier in upper camel case such as `HTTPResponse` to snake
+/// case such as `http_response`.
+fn to_snake_case(name: &str) -> String {
+    let characters = name.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    for (index, character) in characters.iter().enumerate() {
+        if character.is_uppercase() && index > 0 {
+            let previous = characters[index - 1];
+            let next_is_lowercase =
+                matches!(characters.get(index + 1), Some(c) if c.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                result.push('_');
+            }
+        }
+        result.extend(character.to_lowercase());
+    }
+    result
+}
+
+/// Parses a number of bytes such as `"256MB"` from the value of an argument.
+fn parse_size(value: &Expr) -> syn::Result<usize> {
+    let Expr::Lit(ExprLit { lit: Lit::Str(size), .. }) = value else {
+        return Err(syn::Error::new_spanned(value, "Expected a size such as \"256MB\""));
+    };
+    let text = size.value();
+    let digits = text.trim_end_matches(|c: char| !c.is_ascii_digit());
+    let multiplier: usize = match text[digits.len()..].trim() {
+        "" | "B" => 1,
+        "KB" => 1 << 10,
+        "MB" => 1 << 20,
+        "GB" => 1 << 30,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                size,
+                "Expected a number of bytes optionally followed by B, KB, MB, or GB",
+            ));
+        }
+    };
+    digits
+        .parse::<usize>()
+        .ok()
+        .and_then(|count| count.checked_mul(multiplier))
+        .ok_or_else(|| syn::Error::new_spanned(size, "Expected a size such as \"256MB\""))
+}
+
 fn is_test_attribute(attr: &Attribute) -> bool {
     let Some(first_segment) = attr.path().segments.first() else {
         return false;
@@ -30,16 +30,27 @@ INTEGRATION_TEST_BINARIES=(
   "custom_error_message"
   "expect_pred_failure"
   "expect_that_failure"
+  "expect_that_within_failure"
+  "failing_property_test"
   "failure_due_to_fail_macro"
   "failure_due_to_fail_macro_with_empty_message"
   "failure_due_to_fail_macro_with_format_arguments"
   "failure_due_to_returned_error"
+  "failure_on_spawned_thread"
+  "failures_beyond_max_failures"
   "first_failure_aborts"
   "google_test_with_rstest"
+  "ignored_assertion_result"
   "non_fatal_failure_in_subroutine"
+  "panic_caught_as_failure"
+  "panic_not_caught"
+  "shared_fixture_torn_down_after_last_user"
   "simple_assertion_failure"
   "simple_assertion_failure_with_assert_that"
+  "stray_threads"
+  "test_exceeding_allocation_limit"
   "test_returning_anyhow_error"
+  "test_with_failing_prerequisite"
   "two_expect_pred_failures"
   "two_expect_that_failures"
   "two_non_fatal_failures"